@@ -550,6 +550,7 @@ fn encode(item: &Self::Counterpart) -> Self {
                 navigation_source: data.navigation_source,
                 figure_of_merit: data.figure_of_merit,
             }),
+            Self::Counterpart::Undetermined(bytes) => Self::Undetermined(*bytes),
         }
     }
 
@@ -576,6 +577,7 @@ fn decode(&self) -> Self::Counterpart {
                     .with_figure_of_merit(data.figure_of_merit)
                     .build(),
             ),
+            Mode5BasicData::Undetermined(bytes) => Self::Counterpart::Undetermined(*bytes),
         }
     }
 }
@@ -587,6 +589,7 @@ fn encode(item: &Self::Counterpart) -> Self {
         match item {
             Self::Counterpart::Interrogator(data) => Self::Interrogator(data.clone()),
             Self::Counterpart::Transponder(data) => Self::Transponder(data.clone()),
+            Self::Counterpart::Undetermined(bytes) => Self::Undetermined(*bytes),
         }
     }
 
@@ -594,6 +597,7 @@ fn decode(&self) -> Self::Counterpart {
         match self {
             ModeSBasicData::Interrogator(data) => Self::Counterpart::Interrogator(data.clone()),
             ModeSBasicData::Transponder(data) => Self::Counterpart::Transponder(data.clone()),
+            ModeSBasicData::Undetermined(bytes) => Self::Counterpart::Undetermined(*bytes),
         }
     }
 }
@@ -11,7 +11,8 @@
 use dis_rs::iff::model::{
     IffDataRecord, InformationLayers, Mode5InterrogatorStatus, Mode5MessageFormats,
     Mode5TransponderBasicData, ModeSInterrogatorBasicData, ModeSTransponderBasicData, SystemId,
-    SystemSpecificData, SystemStatus,
+    SystemSpecificData, SystemStatus, MODE_5_BASIC_DATA_LENGTH_OCTETS,
+    MODE_S_BASIC_DATA_LENGTH_OCTETS,
 };
 
 #[derive(Clone, Default, Debug, PartialEq)]
@@ -354,6 +355,7 @@ fn record_length(&self) -> usize {
 pub enum Mode5BasicData {
     Interrogator(Mode5InterrogatorBasicData),
     Transponder(Mode5TransponderBasicData),
+    Undetermined([u8; MODE_5_BASIC_DATA_LENGTH_OCTETS]), // raw basic data, when the system type does not determine the format
 }
 
 impl Default for Mode5BasicData {
@@ -367,6 +369,7 @@ fn record_length(&self) -> usize {
         match self {
             Mode5BasicData::Interrogator(basic_data) => basic_data.record_length(),
             Mode5BasicData::Transponder(basic_data) => basic_data.record_length(),
+            Mode5BasicData::Undetermined(bytes) => bytes.len() * EIGHT_BITS,
         }
     }
 }
@@ -394,6 +397,7 @@ fn record_length(&self) -> usize {
 pub enum ModeSBasicData {
     Interrogator(ModeSInterrogatorBasicData),
     Transponder(ModeSTransponderBasicData),
+    Undetermined([u8; MODE_S_BASIC_DATA_LENGTH_OCTETS]), // raw basic data, when the system type does not determine the format
 }
 
 impl Default for ModeSBasicData {
@@ -407,6 +411,7 @@ fn record_length(&self) -> usize {
         match self {
             ModeSBasicData::Interrogator(basic_data) => basic_data.record_length(),
             ModeSBasicData::Transponder(basic_data) => basic_data.record_length(),
+            ModeSBasicData::Undetermined(bytes) => bytes.len() * EIGHT_BITS,
         }
     }
 }
@@ -28,7 +28,6 @@
     ModeSAltitude, ModeSInterrogatorBasicData, ModeSInterrogatorStatus, ModeSLevelsPresent,
     ModeSTransponderBasicData, ModeSTransponderStatus, SystemId, SystemSpecificData, SystemStatus,
 };
-use dis_rs::DisError;
 use nom::complete::take;
 use nom::multi::count;
 use nom::IResult;
@@ -255,11 +254,9 @@ fn fundamental_parameter_data_record(
     let (input, specific_data_1): (BitInput, u8) = take(EIGHT_BITS)(input)?;
     let (input, specific_data_2): (BitInput, u8) = take(EIGHT_BITS)(input)?;
     let (input, specific_data_3): (BitInput, u8) = take(EIGHT_BITS)(input)?;
-    let system_specific_data = SystemSpecificData::builder()
-        .with_part_1(specific_data_1)
-        .with_part_2(specific_data_2)
-        .with_part_3(specific_data_3)
-        .build();
+    // The system type is not known here; the data is interpreted when decoding the IFF body.
+    let system_specific_data =
+        SystemSpecificData::Unspecified([specific_data_1, specific_data_2, specific_data_3]);
 
     Ok((
         input,
@@ -299,9 +296,7 @@ fn iff_layer_3(
                 layer_header,
                 reporting_simulation_site,
                 reporting_simulation_application,
-                mode_5_basic_data: mode_5_basic_data.unwrap_or(Mode5BasicData::Transponder(
-                    Mode5TransponderBasicData::default(),
-                )),
+                mode_5_basic_data,
                 iff_data_records,
             },
         ))
@@ -311,7 +306,7 @@ fn iff_layer_3(
 #[allow(clippy::match_same_arms)]
 fn mode_5_basic_data(
     iff_system_type: &IffSystemType,
-) -> impl Fn(BitInput) -> IResult<BitInput, Result<Mode5BasicData, DisError>> + '_ {
+) -> impl Fn(BitInput) -> IResult<BitInput, Mode5BasicData> + '_ {
     move |input: BitInput| {
         let (input, mode_5_basic_data) = match iff_system_type {
             IffSystemType::MarkXXIIATCRBSTransponder
@@ -321,7 +316,7 @@ fn mode_5_basic_data(
             | IffSystemType::Mode5Transponder
             | IffSystemType::ModeSTransponder => {
                 let (input, basic_data) = mode_5_transponder_basic_data(input)?;
-                (input, Ok(Mode5BasicData::Transponder(basic_data)))
+                (input, Mode5BasicData::Transponder(basic_data))
             }
             IffSystemType::MarkXXIIATCRBSInterrogator
             | IffSystemType::SovietInterrogator
@@ -329,15 +324,17 @@ fn mode_5_basic_data(
             | IffSystemType::Mode5Interrogator
             | IffSystemType::ModeSInterrogator => {
                 let (input, basic_data) = mode_5_interrogator_basic_data(input)?;
-                (input, Ok(Mode5BasicData::Interrogator(basic_data)))
+                (input, Mode5BasicData::Interrogator(basic_data))
             }
+            // the system type does not determine the format, so the basic data is carried as raw octets
             IffSystemType::MarkXIIACombinedInterrogatorTransponder_CIT_
             | IffSystemType::MarkXIICombinedInterrogatorTransponder_CIT_
-            | IffSystemType::TCASACASTransceiver => {
-                (input, Err(DisError::IffUndeterminedSystemType))
+            | IffSystemType::TCASACASTransceiver
+            | IffSystemType::NotUsed_InvalidValue_
+            | IffSystemType::Unspecified(_) => {
+                let (input, raw) = undetermined_basic_data(input)?;
+                (input, Mode5BasicData::Undetermined(raw))
             }
-            IffSystemType::NotUsed_InvalidValue_ => (input, Err(DisError::IffIncorrectSystemType)),
-            IffSystemType::Unspecified(_) => (input, Err(DisError::IffIncorrectSystemType)),
         };
 
         Ok((input, mode_5_basic_data))
@@ -393,6 +390,16 @@ fn mode_5_interrogator_basic_data(
     ))
 }
 
+fn undetermined_basic_data<const LENGTH: usize>(
+    input: BitInput,
+) -> IResult<BitInput, [u8; LENGTH]> {
+    let (input, bytes): (BitInput, Vec<u8>) = count(take(EIGHT_BITS), LENGTH)(input)?;
+    let mut raw = [0u8; LENGTH];
+    raw.copy_from_slice(&bytes);
+
+    Ok((input, raw))
+}
+
 fn iff_data_record(input: BitInput) -> IResult<BitInput, IffDataRecord> {
     const THREE_OCTETS: usize = 3;
     let (input, record_type): (BitInput, u32) = take(SIXTEEN_BITS)(input)?;
@@ -434,9 +441,7 @@ fn iff_layer_4(
                 layer_header,
                 reporting_simulation_site,
                 reporting_simulation_application,
-                mode_s_basic_data: mode_s_basic_data.unwrap_or(ModeSBasicData::Transponder(
-                    ModeSTransponderBasicData::default(),
-                )),
+                mode_s_basic_data,
                 iff_data_records,
             },
         ))
@@ -446,7 +451,7 @@ fn iff_layer_4(
 #[allow(clippy::match_same_arms)]
 fn mode_s_basic_data(
     iff_system_type: &IffSystemType,
-) -> impl Fn(BitInput) -> IResult<BitInput, Result<ModeSBasicData, DisError>> + '_ {
+) -> impl Fn(BitInput) -> IResult<BitInput, ModeSBasicData> + '_ {
     move |input: BitInput| {
         let (input, mode_s_basic_data) = match iff_system_type {
             IffSystemType::MarkXXIIATCRBSTransponder
@@ -456,7 +461,7 @@ fn mode_s_basic_data(
             | IffSystemType::Mode5Transponder
             | IffSystemType::ModeSTransponder => {
                 let (input, basic_data) = mode_s_transponder_basic_data(input)?;
-                (input, Ok(ModeSBasicData::Transponder(basic_data)))
+                (input, ModeSBasicData::Transponder(basic_data))
             }
             IffSystemType::MarkXXIIATCRBSInterrogator
             | IffSystemType::SovietInterrogator
@@ -464,15 +469,17 @@ fn mode_s_basic_data(
             | IffSystemType::Mode5Interrogator
             | IffSystemType::ModeSInterrogator => {
                 let (input, basic_data) = mode_s_interrogator_basic_data(input)?;
-                (input, Ok(ModeSBasicData::Interrogator(basic_data)))
+                (input, ModeSBasicData::Interrogator(basic_data))
             }
+            // the system type does not determine the format, so the basic data is carried as raw octets
             IffSystemType::MarkXIIACombinedInterrogatorTransponder_CIT_
             | IffSystemType::MarkXIICombinedInterrogatorTransponder_CIT_
-            | IffSystemType::TCASACASTransceiver => {
-                (input, Err(DisError::IffUndeterminedSystemType))
+            | IffSystemType::TCASACASTransceiver
+            | IffSystemType::NotUsed_InvalidValue_
+            | IffSystemType::Unspecified(_) => {
+                let (input, raw) = undetermined_basic_data(input)?;
+                (input, ModeSBasicData::Undetermined(raw))
             }
-            IffSystemType::NotUsed_InvalidValue_ => (input, Err(DisError::IffIncorrectSystemType)),
-            IffSystemType::Unspecified(_) => (input, Err(DisError::IffIncorrectSystemType)),
         };
 
         Ok((input, mode_s_basic_data))
@@ -180,12 +180,10 @@ fn serialize(&self, buf: &mut BitBuffer, cursor: usize) -> usize {
         let cursor =
             write_value_unsigned::<u8>(buf, cursor, THREE_BITS, self.applicable_modes.into());
 
-        let cursor =
-            write_value_unsigned(buf, cursor, EIGHT_BITS, self.system_specific_data.part_1);
-        let cursor =
-            write_value_unsigned(buf, cursor, EIGHT_BITS, self.system_specific_data.part_2);
-        let cursor =
-            write_value_unsigned(buf, cursor, EIGHT_BITS, self.system_specific_data.part_3);
+        let [part_1, part_2, part_3] = self.system_specific_data.to_bytes();
+        let cursor = write_value_unsigned(buf, cursor, EIGHT_BITS, part_1);
+        let cursor = write_value_unsigned(buf, cursor, EIGHT_BITS, part_2);
+        let cursor = write_value_unsigned(buf, cursor, EIGHT_BITS, part_3);
 
         cursor
     }
@@ -274,6 +272,9 @@ fn serialize(&self, buf: &mut BitBuffer, cursor: usize) -> usize {
         match self {
             Mode5BasicData::Interrogator(record) => record.serialize(buf, cursor),
             Mode5BasicData::Transponder(record) => SerializeCdis::serialize(record, buf, cursor),
+            Mode5BasicData::Undetermined(bytes) => bytes.iter().fold(cursor, |cursor, byte| {
+                write_value_unsigned(buf, cursor, EIGHT_BITS, *byte)
+            }),
         }
     }
 }
@@ -349,6 +350,9 @@ fn serialize(&self, buf: &mut BitBuffer, cursor: usize) -> usize {
         match self {
             ModeSBasicData::Interrogator(record) => record.serialize(buf, cursor),
             ModeSBasicData::Transponder(record) => SerializeCdis::serialize(record, buf, cursor),
+            ModeSBasicData::Undetermined(bytes) => bytes.iter().fold(cursor, |cursor, byte| {
+                write_value_unsigned(buf, cursor, EIGHT_BITS, *byte)
+            }),
         }
     }
 }
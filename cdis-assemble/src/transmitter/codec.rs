@@ -7,15 +7,18 @@
 };
 use crate::records::model::{BeamAntennaPattern, EntityId, EntityType};
 use crate::transmitter::model::{
-    ModulationType, TransmitFrequencyBandwidthFloat, Transmitter, TransmitterFrequencyFloat,
-    TransmitterUnits,
+    CdisAntennaPattern, ModulationType, TransmitFrequencyBandwidthFloat, Transmitter,
+    TransmitterFrequencyFloat, TransmitterUnits, MAX_ANTENNA_PATTERN_OCTETS,
 };
 use crate::types::model::{CdisFloat, UVINT16, UVINT8};
 use crate::{BodyProperties, CdisBody};
-use dis_rs::enumerations::{TransmitterCryptoSystem, TransmitterInputSource};
+use dis_rs::enumerations::{
+    TransmitterAntennaPatternType, TransmitterCryptoSystem, TransmitterInputSource,
+};
 use dis_rs::model::{
     EntityType as DisEntityType, Location as DisLocation, Location, Orientation, PduBody, VectorF32,
 };
+use dis_rs::transmitter::model::{AntennaPattern, ModulationParameters};
 use std::time::Instant;
 
 type Counterpart = dis_rs::transmitter::model::Transmitter;
@@ -145,20 +148,23 @@ pub fn encode(
 
         // include when modulation parameters are present
         let modulation_parameters = if let Some(params) = &item.modulation_parameters {
-            params.clone()
+            params.to_bytes()
         } else {
             Vec::default()
         };
 
-        // include when antenna_pattern is not zeroed
-        let (antenna_pattern_type, antenna_pattern) = if let Some(pattern) = &item.antenna_pattern {
-            (
-                Some(item.antenna_pattern_type),
-                Some(BeamAntennaPattern::encode(pattern)),
-            )
-        } else {
-            (None, None)
+        // include when antenna_pattern is present; C-DIS only defines a compressed Beam Antenna Pattern record,
+        // patterns of other types are included as DIS records when they fit the antenna pattern length field
+        let antenna_pattern = match (&item.antenna_pattern, item.antenna_pattern_type) {
+            (Some(AntennaPattern::Beam(pattern)), TransmitterAntennaPatternType::Beam) => Some(
+                CdisAntennaPattern::Beam(BeamAntennaPattern::encode(pattern)),
+            ),
+            (Some(pattern), _) if pattern.length() <= MAX_ANTENNA_PATTERN_OCTETS => {
+                Some(CdisAntennaPattern::Other(pattern.to_bytes()))
+            }
+            _ => None,
         };
+        let antenna_pattern_type = antenna_pattern.as_ref().map(|_| item.antenna_pattern_type);
 
         let input_source: u8 = item.input_source.into();
 
@@ -328,32 +334,42 @@ pub fn decode(
             }
         };
 
-        (
-            Counterpart::builder()
-                .with_radio_reference_id(self.radio_reference_id.decode())
-                .with_radio_number(self.radio_number.value)
-                .with_radio_type(radio_type)
-                .with_transmit_state(self.transmit_state)
-                .with_input_source(TransmitterInputSource::from(self.input_source.value))
-                .with_antenna_location(antenna_location)
-                .with_relative_antenna_location(relative_antenna_location)
-                .with_antenna_pattern_type(self.antenna_pattern_type.unwrap_or_default()) // zeroed when None?
-                .with_frequency(frequency)
-                .with_transmit_frequency_bandwidth(transmit_frequency_bandwidth)
-                .with_power(power)
-                .with_modulation_type(modulation_type)
-                .with_crypto_system(self.crypto_system.unwrap_or_default()) // zeroed when None?
-                .with_crypto_key_id(self.crypto_key_id.unwrap_or_default()) // zeroed when None?
-                .with_modulation_parameters(self.modulation_parameters.clone())
-                .with_antenna_pattern(
-                    self.antenna_pattern
-                        .map(|pattern| pattern.decode())
-                        .unwrap_or_default(),
-                ) // zeroed when None?
-                .with_variable_transmitter_parameters(self.variable_transmitter_parameters.clone())
-                .build(),
-            state_result,
-        )
+        let antenna_pattern_type = self.antenna_pattern_type.unwrap_or_default(); // zeroed when None?
+        let body = Counterpart::builder()
+            .with_radio_reference_id(self.radio_reference_id.decode())
+            .with_radio_number(self.radio_number.value)
+            .with_radio_type(radio_type)
+            .with_transmit_state(self.transmit_state)
+            .with_input_source(TransmitterInputSource::from(self.input_source.value))
+            .with_antenna_location(antenna_location)
+            .with_relative_antenna_location(relative_antenna_location)
+            .with_antenna_pattern_type(antenna_pattern_type)
+            .with_frequency(frequency)
+            .with_transmit_frequency_bandwidth(transmit_frequency_bandwidth)
+            .with_power(power)
+            .with_modulation_type(modulation_type)
+            .with_crypto_system(self.crypto_system.unwrap_or_default()) // zeroed when None?
+            .with_crypto_key_id(self.crypto_key_id.unwrap_or_default()) // zeroed when None?
+            .with_variable_transmitter_parameters(self.variable_transmitter_parameters.clone());
+        let body = if self.modulation_parameters.is_empty() {
+            body
+        } else {
+            body.with_modulation_parameters(ModulationParameters::from_bytes(
+                &modulation_type,
+                &self.modulation_parameters,
+            ))
+        };
+        let body = match &self.antenna_pattern {
+            Some(CdisAntennaPattern::Beam(pattern)) => {
+                body.with_antenna_pattern(AntennaPattern::Beam(pattern.decode()))
+            }
+            Some(CdisAntennaPattern::Other(octets)) => {
+                body.with_antenna_pattern(AntennaPattern::from_bytes(antenna_pattern_type, octets))
+            }
+            None => body,
+        };
+
+        (body.build(), state_result)
     }
 }
 
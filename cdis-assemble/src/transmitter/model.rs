@@ -1,6 +1,6 @@
 use crate::constants::{
-    EIGHT_BITS, FORTY_EIGHT_BITS, FOUR_BITS, SEVENTEEN_BITS, SIXTEEN_BITS, TWENTY_EIGHT_BITS,
-    TWENTY_FOUR_BITS, TWENTY_ONE_BITS,
+    EIGHT_BITS, FORTY_EIGHT_BITS, FOUR_BITS, SEVENTEEN_BITS, SIXTEEN_BITS, TEN_BITS,
+    TWENTY_EIGHT_BITS, TWENTY_FOUR_BITS, TWENTY_ONE_BITS,
 };
 use crate::parsing::BitInput;
 use crate::records::model::{
@@ -37,7 +37,7 @@ pub struct Transmitter {
     pub crypto_system: Option<TransmitterCryptoSystem>,
     pub crypto_key_id: Option<CryptoKeyId>,
     pub modulation_parameters: Vec<u8>,
-    pub antenna_pattern: Option<BeamAntennaPattern>,
+    pub antenna_pattern: Option<CdisAntennaPattern>,
     pub variable_transmitter_parameters: Vec<VariableTransmitterParameter>,
 }
 
@@ -131,7 +131,7 @@ fn body_length_bits(&self) -> usize {
                 0
             }
             + (self.modulation_parameters.len() * EIGHT_BITS)
-            + if let Some(record) = self.antenna_pattern {
+            + if let Some(record) = &self.antenna_pattern {
                 record.record_length()
             } else {
                 0
@@ -140,7 +140,7 @@ fn body_length_bits(&self) -> usize {
                 .variable_transmitter_parameters
                 .iter()
                 .map(|param| {
-                    VARIABLE_TRANSMITTER_PARAM_CONST_BIT_SIZE + (param.fields.len() * EIGHT_BITS)
+                    VARIABLE_TRANSMITTER_PARAM_CONST_BIT_SIZE + (param.fields.length() * EIGHT_BITS)
                 })
                 .sum::<usize>()
     }
@@ -173,6 +173,28 @@ impl TransmitterFieldsPresent {
     pub const MODULATION_PARAMETERS_BIT: u8 = 0x01;
 }
 
+/// The largest antenna pattern that fits the ten-bit Antenna Pattern Length field, which holds the length in bits.
+pub const MAX_ANTENNA_PATTERN_OCTETS: usize = ((1 << TEN_BITS) - 1) / EIGHT_BITS;
+
+/// Antenna Pattern field of the C-DIS Transmitter PDU.
+///
+/// C-DIS defines a compressed record for the Beam Antenna Pattern only.
+/// Patterns of other types are carried as the octets of the DIS record.
+#[derive(Clone, Debug, PartialEq)]
+pub enum CdisAntennaPattern {
+    Beam(BeamAntennaPattern),
+    Other(Vec<u8>),
+}
+
+impl CdisRecord for CdisAntennaPattern {
+    fn record_length(&self) -> usize {
+        match self {
+            CdisAntennaPattern::Beam(pattern) => pattern.record_length(),
+            CdisAntennaPattern::Other(octets) => octets.len() * EIGHT_BITS,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug, PartialEq)]
 pub struct TransmitterUnits {
     pub world_location_altitude: UnitsDekameters,
@@ -7,8 +7,8 @@
     world_coordinates,
 };
 use crate::transmitter::model::{
-    CdisSpreadSpectrum, ModulationType, TransmitFrequencyBandwidthFloat, Transmitter,
-    TransmitterFieldsPresent, TransmitterFrequencyFloat, TransmitterUnits,
+    CdisAntennaPattern, CdisSpreadSpectrum, ModulationType, TransmitFrequencyBandwidthFloat,
+    Transmitter, TransmitterFieldsPresent, TransmitterFrequencyFloat, TransmitterUnits,
 };
 use crate::types::model::CdisFloat;
 use crate::types::parser::{uvint16, uvint8};
@@ -17,7 +17,9 @@
     TransmitterAntennaPatternType, TransmitterCryptoSystem, TransmitterTransmitState,
     VariableRecordType,
 };
-use dis_rs::transmitter::model::{CryptoKeyId, VariableTransmitterParameter};
+use dis_rs::transmitter::model::{
+    CryptoKeyId, VariableTransmitterParameter, VariableTransmitterParameterFields,
+};
 use nom::complete::take;
 use nom::multi::count;
 use nom::IResult;
@@ -44,7 +46,11 @@ pub(crate) fn transmitter_body(input: BitInput) -> IResult<BitInput, CdisBody> {
 
     let (input, input_source) = uvint8(input)?;
 
-    let (input, nr_of_variable_transmitter_parameters) = uvint8(input)?;
+    let (input, nr_of_variable_transmitter_parameters) = parse_field_when_present(
+        fields_present,
+        TransmitterFieldsPresent::VARIABLE_PARAMETERS_BIT,
+        uvint8,
+    )(input)?;
 
     let (input, antenna_location) = parse_field_when_present(
         fields_present,
@@ -64,7 +70,7 @@ pub(crate) fn transmitter_body(input: BitInput) -> IResult<BitInput, CdisBody> {
     )(input)?;
     let antenna_pattern_type = antenna_pattern_type.map(TransmitterAntennaPatternType::from);
 
-    let (input, _antenna_pattern_length): (BitInput, Option<u16>) = parse_field_when_present(
+    let (input, antenna_pattern_length): (BitInput, Option<u16>) = parse_field_when_present(
         fields_present,
         TransmitterFieldsPresent::ANTENNA_PATTERN_BIT,
         take(TEN_BITS),
@@ -150,14 +156,19 @@ pub(crate) fn transmitter_body(input: BitInput) -> IResult<BitInput, CdisBody> {
         (input, vec![])
     };
 
-    let (input, antenna_pattern) = if field_present(
-        fields_present,
-        TransmitterFieldsPresent::ANTENNA_PATTERN_BIT,
-    ) {
-        let (input, pattern) = beam_antenna_pattern(input)?;
-        (input, Some(pattern))
-    } else {
-        (input, None)
+    let (input, antenna_pattern) = match antenna_pattern_type {
+        Some(TransmitterAntennaPatternType::Beam) => {
+            let (input, pattern) = beam_antenna_pattern(input)?;
+            (input, Some(CdisAntennaPattern::Beam(pattern)))
+        }
+        Some(_) => {
+            let (input, octets): (BitInput, Vec<u8>) = count(
+                take(EIGHT_BITS),
+                usize::from(antenna_pattern_length.unwrap_or_default()) / EIGHT_BITS,
+            )(input)?;
+            (input, Some(CdisAntennaPattern::Other(octets)))
+        }
+        None => (input, None),
     };
 
     let (input, variable_transmitter_parameters) = if field_present(
@@ -166,7 +177,7 @@ pub(crate) fn transmitter_body(input: BitInput) -> IResult<BitInput, CdisBody> {
     ) {
         let (input, params): (BitInput, Vec<VariableTransmitterParameter>) = count(
             variable_transmitter_parameter,
-            nr_of_variable_transmitter_parameters.value as usize,
+            nr_of_variable_transmitter_parameters.map_or(0, |number| number.value as usize),
         )(input)?;
         (input, params)
     } else {
@@ -210,11 +221,15 @@ fn variable_transmitter_parameter(
     let nr_of_records = record_length.saturating_sub(SIX_OCTETS);
     let (input, record_specific_fields): (BitInput, Vec<u8>) =
         count(take(EIGHT_BITS), nr_of_records)(input)?;
+    let record_type = VariableRecordType::from(record_type);
 
     Ok((
         input,
         VariableTransmitterParameter::default()
-            .with_record_type(VariableRecordType::from(record_type))
-            .with_fields(record_specific_fields),
+            .with_record_type(record_type)
+            .with_fields(VariableTransmitterParameterFields::from_bytes(
+                record_type,
+                &record_specific_fields,
+            )),
     ))
 }
@@ -3,7 +3,7 @@
     THREE_BITS, TWO_BITS,
 };
 use crate::records::model::{BeamAntennaPattern, CdisRecord};
-use crate::transmitter::model::Transmitter;
+use crate::transmitter::model::{CdisAntennaPattern, Transmitter};
 use crate::types::model::{CdisFloat, UVINT8};
 use crate::writing::{
     serialize_when_present, write_value_signed, write_value_unsigned, SerializeCdis,
@@ -53,7 +53,7 @@ fn serialize(&self, buf: &mut BitBuffer, cursor: usize) -> usize {
         } else {
             cursor
         };
-        let cursor = if let Some(pattern) = self.antenna_pattern {
+        let cursor = if let Some(pattern) = &self.antenna_pattern {
             write_value_unsigned(buf, cursor, TEN_BITS, pattern.record_length())
         } else {
             cursor
@@ -108,7 +108,7 @@ fn serialize(&self, buf: &mut BitBuffer, cursor: usize) -> usize {
             cursor
         };
 
-        let cursor = if let Some(pattern) = self.antenna_pattern {
+        let cursor = if let Some(pattern) = &self.antenna_pattern {
             pattern.serialize(buf, cursor)
         } else {
             cursor
@@ -123,6 +123,17 @@ fn serialize(&self, buf: &mut BitBuffer, cursor: usize) -> usize {
     }
 }
 
+impl SerializeCdis for CdisAntennaPattern {
+    fn serialize(&self, buf: &mut BitBuffer, cursor: usize) -> usize {
+        match self {
+            CdisAntennaPattern::Beam(pattern) => pattern.serialize(buf, cursor),
+            CdisAntennaPattern::Other(octets) => octets.iter().fold(cursor, |cursor, octet| {
+                write_value_unsigned(buf, cursor, EIGHT_BITS, *octet)
+            }),
+        }
+    }
+}
+
 impl SerializeCdis for BeamAntennaPattern {
     #[allow(clippy::let_and_return)]
     fn serialize(&self, buf: &mut BitBuffer, cursor: usize) -> usize {
@@ -147,9 +158,10 @@ fn serialize(&self, buf: &mut BitBuffer, cursor: usize) -> usize {
         const SIX_OCTETS: usize = 6;
         let record_type: u32 = self.record_type.into();
         let cursor = write_value_unsigned(buf, cursor, THIRTY_TWO_BITS, record_type);
-        let record_length = self.fields.len() + SIX_OCTETS;
+        let fields = self.fields.to_bytes();
+        let record_length = fields.len() + SIX_OCTETS;
         let cursor = write_value_unsigned(buf, cursor, SIXTEEN_BITS, record_length);
-        let cursor = self.fields.iter().fold(cursor, |cursor, byte| {
+        let cursor = fields.iter().fold(cursor, |cursor, byte| {
             write_value_unsigned(buf, cursor, EIGHT_BITS, *byte)
         });
         cursor
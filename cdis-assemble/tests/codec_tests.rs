@@ -38,7 +38,8 @@
 };
 use dis_rs::signal::model::EncodingScheme;
 use dis_rs::transmitter::model::{
-    BeamAntennaPattern, CryptoKeyId, ModulationType, SpreadSpectrum, Transmitter,
+    BeamAntennaPattern, CryptoKeyId, ModulationParameters, ModulationType,
+    SphericalHarmonicAntennaPattern, SpreadSpectrum, Transmitter,
 };
 
 #[test]
@@ -1297,8 +1298,13 @@ fn codec_consistency_transmitter_full_mode() {
         )
         .with_crypto_system(TransmitterCryptoSystem::NoEncryptionDevice)
         .with_crypto_key_id(CryptoKeyId::default())
-        .with_modulation_parameters(vec![])
-        .with_antenna_pattern(BeamAntennaPattern::default().with_e_x(20.0).with_e_z(10.0))
+        .with_modulation_parameters(ModulationParameters::Unspecified(vec![]))
+        .with_antenna_pattern(
+            BeamAntennaPattern::default()
+                .with_e_x(20.0)
+                .with_e_z(10.0)
+                .to_antenna_pattern(),
+        )
         .build()
         .into_pdu_body();
     let dis_header =
@@ -1360,8 +1366,13 @@ fn codec_consistency_transmitter_partial_mode() {
         )
         .with_crypto_system(TransmitterCryptoSystem::KGV135A)
         .with_crypto_key_id(CryptoKeyId::default())
-        .with_modulation_parameters(vec![])
-        .with_antenna_pattern(BeamAntennaPattern::default().with_e_x(20.0).with_e_z(10.0))
+        .with_modulation_parameters(ModulationParameters::Unspecified(vec![]))
+        .with_antenna_pattern(
+            BeamAntennaPattern::default()
+                .with_e_x(20.0)
+                .with_e_z(10.0)
+                .to_antenna_pattern(),
+        )
         .build()
         .into_pdu_body();
     let dis_header =
@@ -1396,6 +1407,49 @@ fn codec_consistency_transmitter_partial_mode() {
     );
 }
 
+#[test]
+fn codec_consistency_transmitter_spherical_harmonic_antenna_pattern() {
+    let mut encoder_state = EncoderState::new();
+    let codec_options = CodecOptions::new_full_update();
+    let mut decoder_state = DecoderState::new();
+
+    let dis_body = Transmitter::builder()
+        .with_radio_reference_id(EntityId::new(10, 10, 10))
+        .with_radio_number(1)
+        .with_transmit_state(TransmitterTransmitState::OnAndTransmitting)
+        .with_antenna_pattern_type(TransmitterAntennaPatternType::SphericalHarmonic)
+        .with_antenna_pattern(
+            SphericalHarmonicAntennaPattern::new()
+                .with_order(1)
+                .with_coefficients(vec![1.0, 0.5, 0.25, 0.125])
+                .to_antenna_pattern(),
+        )
+        .build()
+        .into_pdu_body();
+    let dis_header =
+        PduHeader::new_v7(7, PduType::Transmitter).with_pdu_status(PduStatus::default());
+    let dis_pdu_in = Pdu::finalize_from_parts(dis_header, dis_body, 0);
+
+    let (cdis_pdu, _state_result) =
+        CdisPdu::encode(&dis_pdu_in, &mut encoder_state, &codec_options);
+    let mut buf: BitBuffer = BitBuffer::ZERO;
+    let written_bits = cdis_pdu.serialize(&mut buf, 0);
+    let written_bytes = written_bits.div_ceil(EIGHT_BITS);
+
+    let parsed_cdis_pdus = cdis_assemble::parse(&buf.data[..written_bytes]).unwrap();
+    let parsed_cdis_pdu = parsed_cdis_pdus.first().unwrap();
+    assert_eq!(cdis_pdu.body, parsed_cdis_pdu.body);
+
+    let (dis_pdu_out, _state_result) = parsed_cdis_pdu.decode(&mut decoder_state, &codec_options);
+    let (PduBody::Transmitter(body_in), PduBody::Transmitter(body_out)) =
+        (dis_pdu_in.body, dis_pdu_out.body)
+    else {
+        panic!("Expected Transmitter PDU bodies");
+    };
+    assert_eq!(body_in.antenna_pattern_type, body_out.antenna_pattern_type);
+    assert_eq!(body_in.antenna_pattern, body_out.antenna_pattern);
+}
+
 #[test]
 fn codec_consistency_iff_full_mode() {
     let mut encoder_state = EncoderState::new();
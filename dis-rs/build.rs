@@ -771,14 +771,20 @@ mod generation {
 
     pub fn generate(items: &Vec<GenerationItem>) -> TokenStream {
         let mut generated_items = vec![];
+        let mut bitfield_listings = vec![];
+        let mut enumeration_values = vec![];
 
         let lookup_xref = |xref: usize| items.iter().find(|&it| it.uid() == xref);
 
         for item in items {
             match item {
-                GenerationItem::Enum(e) => generated_items.push(generate_enum(e, lookup_xref)),
+                GenerationItem::Enum(e) => {
+                    generated_items.push(generate_enum(e, lookup_xref));
+                    enumeration_values.push(quote_enum_value_conversion(e));
+                }
                 GenerationItem::Bitfield(b) => {
                     generated_items.push(generate_bitfield(b, lookup_xref));
+                    bitfield_listings.push(quote_bitfield_field_listing(b));
                 }
             }
         }
@@ -798,7 +804,33 @@ pub mod enumerations {
                 use serde::{Deserialize, Serialize};
 
                 #(#generated_items)*
+
+                /// The numeric value of `value` when it is one of the generated enumerations.
+                #[allow(clippy::useless_conversion)]
+                pub(crate) fn enumeration_value(value: &dyn std::any::Any) -> Option<u64> {
+                    type Conversion = fn(&dyn std::any::Any) -> Option<u64>;
+                    static CONVERSIONS: std::sync::OnceLock<
+                        std::collections::HashMap<std::any::TypeId, Conversion>,
+                    > = std::sync::OnceLock::new();
+
+                    CONVERSIONS
+                        .get_or_init(|| std::collections::HashMap::from([#(#enumeration_values),*]))
+                        .get(&(*value).type_id())
+                        .and_then(|conversion| conversion(value))
+                }
+            }
+
+            /// Invokes the callback macro with the field listing of the generated bitfield records,
+            /// in the format of `common::fields::with_model_fields`.
+            macro_rules! with_bitfield_fields {
+                ($callback:ident) => {
+                    $callback! {
+                        #(#bitfield_listings)*
+                    }
+                };
             }
+
+            pub(crate) use with_bitfield_fields;
         )
     }
 
@@ -817,6 +849,8 @@ fn generate_enum<'a, F>(item: &Enum, lookup_xref: F) -> TokenStream
         let display_impl = quote_enum_display_impl(item, &name_ident);
         // generate Default impl
         let default_impl = quote_enum_default_impl(&name_ident);
+        // generate Arbitrary impl
+        let arbitrary_impl = quote_enum_arbitrary_impl(item, &name_ident);
         quote!(
             #decl
 
@@ -828,6 +862,24 @@ fn generate_enum<'a, F>(item: &Enum, lookup_xref: F) -> TokenStream
 
             #default_impl
 
+            #arbitrary_impl
+
+        )
+    }
+
+    /// Generates the entry of the enumeration in the conversions of `enumeration_value`.
+    fn quote_enum_value_conversion(e: &Enum) -> TokenStream {
+        let name_ident = format_ident!("{}", format_name(e.name.as_str(), e.uid));
+        let discriminant_ident = format_ident!("{}", size_to_type(e.size));
+        quote!(
+            (
+                std::any::TypeId::of::<#name_ident>(),
+                (|value: &dyn std::any::Any| {
+                    value
+                        .downcast_ref::<#name_ident>()
+                        .map(|value| u64::from(#discriminant_ident::from(*value)))
+                }) as Conversion
+            )
         )
     }
 
@@ -1097,6 +1149,29 @@ fn default() -> Self {
         )
     }
 
+    /// Generates an `Arbitrary` impl that only produces values that fit the size of the field on the wire,
+    /// which are converted to the enum in the same way as when parsing.
+    fn quote_enum_arbitrary_impl(e: &Enum, name_ident: &Ident) -> TokenStream {
+        let size_ident = format_ident!("{}", size_to_type(e.size));
+        let max_value = match e.size {
+            64 | 32 | 16 | 8 => quote!(#size_ident::MAX),
+            size if size < 8 => {
+                #[allow(clippy::cast_possible_truncation)]
+                let max_literal = discriminant_literal(2usize.pow(size as u32) - 1, size);
+                quote!(#max_literal)
+            }
+            _ => quote!(u8::MAX),
+        };
+        quote!(
+            #[cfg(feature = "arbitrary")]
+            impl<'a> ::arbitrary::Arbitrary<'a> for #name_ident {
+                fn arbitrary(u: &mut ::arbitrary::Unstructured<'a>) -> ::arbitrary::Result<Self> {
+                    Ok(#name_ident::from(u.int_in_range(0..=#max_value)?))
+                }
+            }
+        )
+    }
+
     fn generate_bitfield<'a, F>(item: &Bitfield, lookup_xref: F) -> TokenStream
     where
         F: Fn(usize) -> Option<&'a GenerationItem>,
@@ -1105,6 +1180,7 @@ fn generate_bitfield<'a, F>(item: &Bitfield, lookup_xref: F) -> TokenStream
         let from = quote_bitfield_from_impl(item, &lookup_xref); // struct from u32
         let into = quote_bitfield_into_impl(item, &lookup_xref); // struct into u32
         let display = quote_bitfield_display_impl(item);
+        let arbitrary = quote_bitfield_arbitrary_impl(item);
 
         quote!(
             #decl
@@ -1114,6 +1190,8 @@ fn generate_bitfield<'a, F>(item: &Bitfield, lookup_xref: F) -> TokenStream
             #into
 
             #display
+
+            #arbitrary
         )
     }
 
@@ -1161,6 +1239,21 @@ fn quote_bitfield_decl_fields<'a, F>(
         generated_fields
     }
 
+    /// Generates the field listing of a bitfield record, of which all fields are handled as a whole.
+    fn quote_bitfield_field_listing(item: &Bitfield) -> TokenStream {
+        let name_ident = format_ident!("{}", format_name(item.name.as_str(), item.uid));
+        let field_idents: Vec<Ident> = item
+            .fields
+            .iter()
+            .map(|field| format_ident!("{}", format_field_name(field.name.as_str())))
+            .collect();
+        quote!(
+            #name_ident {
+                #(#field_idents: leaf),*
+            }
+        )
+    }
+
     fn quote_bitfield_from_impl<'a, F>(item: &Bitfield, lookup_xref: F) -> TokenStream
     where
         F: Fn(usize) -> Option<&'a GenerationItem>,
@@ -1293,6 +1386,22 @@ fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         )
     }
 
+    /// Generates an `Arbitrary` impl that decodes the bitfield from arbitrary raw bits.
+    fn quote_bitfield_arbitrary_impl(item: &Bitfield) -> TokenStream {
+        let formatted_name = format_name(item.name.as_str(), item.uid);
+        let name_ident = format_ident!("{}", formatted_name);
+        let size_ident = format_ident!("{}", size_to_type(item.size));
+
+        quote!(
+            #[cfg(feature = "arbitrary")]
+            impl<'a> ::arbitrary::Arbitrary<'a> for #name_ident {
+                fn arbitrary(u: &mut ::arbitrary::Unstructured<'a>) -> ::arbitrary::Result<Self> {
+                    Ok(#name_ident::from(u.arbitrary::<#size_ident>()?))
+                }
+            }
+        )
+    }
+
     fn size_to_type(data_size: usize) -> &'static str {
         #[allow(clippy::match_same_arms)]
         match data_size {
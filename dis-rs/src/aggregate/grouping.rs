@@ -0,0 +1,365 @@
+use crate::common::entity_state::model::{EntityAppearance, EntityState};
+use crate::common::is_group_of::model::{
+    GEDEntityLocation, GEDEntityOrientation, GEDRecord1, GEDRecord2, GEDRecord3, GEDRecord4,
+    GEDRecord5, GEDRecord6, GEDRecord7, GEDRecord8, GEDRecord9, GroupEntityDescription,
+    GroupReferencePoint, IsGroupOf,
+};
+use crate::common::model::{EntityId, Location, Orientation};
+use crate::enumerations::IsGroupOfGroupedEntityCategory;
+use crate::utils::{ecef_to_geodetic_lla, geodetic_lla_to_ecef};
+use crate::DisError;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Resolution of the GED entity orientation angles, in radians
+const GED_ORIENTATION_RESOLUTION: f32 = 0.025;
+
+/// An individual entity of an `IsGroupOf` PDU, expanded to absolute coordinates.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GroupedEntity {
+    pub entity_id: EntityId,
+    /// Location in world (geocentric) coordinates
+    pub location: Location,
+    pub orientation: Orientation,
+    pub appearance: EntityAppearance,
+    /// Speed in meters per second
+    pub speed: f32,
+}
+
+/// Expands the Group Entity Description records of an `IsGroupOf` PDU into the absolute location and orientation
+/// of each grouped entity.
+///
+/// The latitude and longitude of the group reference point are in radians. The offsets of the entities are in meters,
+/// in a local East-North-Up frame at the reference point on the WGS-84 ellipsoid. The orientation angles have a
+/// resolution of 25 milliradians. Entity ids are formed from the simulation address of the group.
+/// `GroupEntityDescription::Undefined` records are skipped.
+#[must_use]
+pub fn expand_group(group: &IsGroupOf) -> Vec<GroupedEntity> {
+    group
+        .descriptions
+        .iter()
+        .filter_map(|description| {
+            let (entity_number, location, appearance, orientation, speed) = match description {
+                GroupEntityDescription::Undefined => return None,
+                GroupEntityDescription::BasicGroundCombatVehicle(ged) => (
+                    ged.entity_id,
+                    &ged.location,
+                    &ged.appearance,
+                    &ged.orientation,
+                    f32::from(ged.speed as i8),
+                ),
+                GroupEntityDescription::EnhancedGroundCombatVehicle(ged) => {
+                    let ged = &ged.basic_ground_combat_vehicle;
+                    (
+                        ged.entity_id,
+                        &ged.location,
+                        &ged.appearance,
+                        &ged.orientation,
+                        f32::from(ged.speed as i8),
+                    )
+                }
+                GroupEntityDescription::BasicGroundCombatSoldier(ged) => (
+                    ged.entity_id,
+                    &ged.location,
+                    &ged.appearance,
+                    &ged.orientation,
+                    f32::from(ged.speed as i8),
+                ),
+                GroupEntityDescription::EnhancedGroundCombatSoldier(ged) => {
+                    let ged = &ged.basic_ground_combat_soldier;
+                    (
+                        ged.entity_id,
+                        &ged.location,
+                        &ged.appearance,
+                        &ged.orientation,
+                        f32::from(ged.speed as i8),
+                    )
+                }
+                GroupEntityDescription::BasicRotorWingAircraft(ged) => (
+                    ged.entity_id,
+                    &ged.location,
+                    &ged.appearance,
+                    &ged.orientation,
+                    f32::from(ged.movement_speed as i16),
+                ),
+                GroupEntityDescription::EnhancedRotorWingAircraft(ged) => {
+                    let ged = &ged.basic_rotor_wing_aircraft;
+                    (
+                        ged.entity_id,
+                        &ged.location,
+                        &ged.appearance,
+                        &ged.orientation,
+                        f32::from(ged.movement_speed as i16),
+                    )
+                }
+                GroupEntityDescription::BasicFixedWingAircraft(ged) => (
+                    ged.entity_id,
+                    &ged.location,
+                    &ged.appearance,
+                    &ged.orientation,
+                    f32::from(ged.movement_speed as i16),
+                ),
+                GroupEntityDescription::EnhancedFixedWingAircraft(ged) => {
+                    let ged = &ged.basic_fixed_wing_aircraft;
+                    (
+                        ged.entity_id,
+                        &ged.location,
+                        &ged.appearance,
+                        &ged.orientation,
+                        f32::from(ged.movement_speed as i16),
+                    )
+                }
+                GroupEntityDescription::GroundLogisticsVehicle(ged) => (
+                    ged.entity_id,
+                    &ged.location,
+                    &ged.appearance,
+                    &ged.orientation,
+                    f32::from(ged.speed as i16),
+                ),
+            };
+
+            Some(GroupedEntity {
+                entity_id: EntityId::new_sim_address(
+                    group.group_id.simulation_address,
+                    entity_number,
+                ),
+                location: local_offset_to_location(
+                    &group.group_reference_point,
+                    f64::from(location.x_offset as i16),
+                    f64::from(location.y_offset as i16),
+                    f64::from(location.z_offset as i16),
+                ),
+                orientation: Orientation::new(
+                    ged_angle_to_radians(orientation.psi),
+                    ged_angle_to_radians(orientation.theta),
+                    ged_angle_to_radians(orientation.phi),
+                ),
+                appearance: appearance.clone(),
+                speed,
+            })
+        })
+        .collect()
+}
+
+/// Builds an `IsGroupOf` PDU body describing `entities` with Group Entity Description records of `category`.
+///
+/// The group reference point is placed at the center of the entities. Fields of the GED records that cannot be
+/// derived from an Entity State PDU (e.g., fuel and ammunition status) are left zero.
+///
+/// # Errors
+/// Returns `DisError::GroupedEntityOutOfRange` when an entity is more than 32 km from the group reference point.
+pub fn group_entities(
+    group_id: EntityId,
+    category: IsGroupOfGroupedEntityCategory,
+    entities: &[EntityState],
+) -> Result<IsGroupOf, DisError> {
+    let reference_point = center_of(entities);
+    let descriptions = entities
+        .iter()
+        .map(|entity| group_entity_description(&reference_point, category, entity))
+        .collect::<Result<Vec<_>, DisError>>()?;
+
+    Ok(IsGroupOf::builder()
+        .with_group_id(group_id)
+        .with_grouped_entity_category(category)
+        .with_group_reference_point(reference_point)
+        .with_descriptions(descriptions)
+        .build())
+}
+
+/// Converts an East-North-Up offset in meters from a group reference point to a location in world coordinates.
+#[must_use]
+pub fn local_offset_to_location(
+    reference_point: &GroupReferencePoint,
+    east: f64,
+    north: f64,
+    up: f64,
+) -> Location {
+    let (sin_lat, cos_lat) = reference_point.latitude.sin_cos();
+    let (sin_lon, cos_lon) = reference_point.longitude.sin_cos();
+    let (x, y, z) = geodetic_lla_to_ecef(reference_point.latitude, reference_point.longitude, 0.0);
+
+    Location::new(
+        x - sin_lon * east - sin_lat * cos_lon * north + cos_lat * cos_lon * up,
+        y + cos_lon * east - sin_lat * sin_lon * north + cos_lat * sin_lon * up,
+        z + cos_lat * north + sin_lat * up,
+    )
+}
+
+/// Converts a location in world coordinates to an East-North-Up offset `(east, north, up)` in meters
+/// from a group reference point.
+#[must_use]
+pub fn location_to_local_offset(
+    reference_point: &GroupReferencePoint,
+    location: &Location,
+) -> (f64, f64, f64) {
+    let (sin_lat, cos_lat) = reference_point.latitude.sin_cos();
+    let (sin_lon, cos_lon) = reference_point.longitude.sin_cos();
+    let (x, y, z) = geodetic_lla_to_ecef(reference_point.latitude, reference_point.longitude, 0.0);
+    let dx = location.x_coordinate - x;
+    let dy = location.y_coordinate - y;
+    let dz = location.z_coordinate - z;
+
+    (
+        -sin_lon * dx + cos_lon * dy,
+        -sin_lat * cos_lon * dx - sin_lat * sin_lon * dy + cos_lat * dz,
+        cos_lat * cos_lon * dx + cos_lat * sin_lon * dy + sin_lat * dz,
+    )
+}
+
+fn center_of(entities: &[EntityState]) -> GroupReferencePoint {
+    if entities.is_empty() {
+        return GroupReferencePoint::default();
+    }
+    let count = entities.len() as f64;
+    let (x, y, z) = entities.iter().fold((0.0, 0.0, 0.0), |(x, y, z), entity| {
+        (
+            x + entity.entity_location.x_coordinate / count,
+            y + entity.entity_location.y_coordinate / count,
+            z + entity.entity_location.z_coordinate / count,
+        )
+    });
+    let (latitude, longitude, _altitude) = ecef_to_geodetic_lla(x, y, z);
+
+    GroupReferencePoint::default()
+        .with_latitude(latitude)
+        .with_longitude(longitude)
+}
+
+fn group_entity_description(
+    reference_point: &GroupReferencePoint,
+    category: IsGroupOfGroupedEntityCategory,
+    entity: &EntityState,
+) -> Result<GroupEntityDescription, DisError> {
+    let entity_id = entity.entity_id.entity_id;
+    let location = ged_location(reference_point, entity)?;
+    let appearance = entity.entity_appearance.clone();
+    let orientation = GEDEntityOrientation {
+        psi: radians_to_ged_angle(entity.entity_orientation.psi),
+        theta: radians_to_ged_angle(entity.entity_orientation.theta),
+        phi: radians_to_ged_angle(entity.entity_orientation.phi),
+    };
+    let velocity = &entity.entity_linear_velocity;
+    let speed = (velocity.first_vector_component.powi(2)
+        + velocity.second_vector_component.powi(2)
+        + velocity.third_vector_component.powi(2))
+    .sqrt()
+    .round();
+    let speed_u8 = speed.min(f32::from(i8::MAX)) as u8;
+    let speed_u16 = speed.min(f32::from(i16::MAX)) as u16;
+
+    let ground_combat_vehicle = || GEDRecord1 {
+        entity_id,
+        location: location.clone(),
+        appearance: appearance.clone(),
+        orientation: orientation.clone(),
+        speed: speed_u8,
+        ..Default::default()
+    };
+    let ground_combat_soldier = || GEDRecord3 {
+        entity_id,
+        location: location.clone(),
+        appearance: appearance.clone(),
+        orientation: orientation.clone(),
+        speed: speed_u8,
+        ..Default::default()
+    };
+    let rotor_wing_aircraft = || GEDRecord5 {
+        entity_id,
+        location: location.clone(),
+        appearance: appearance.clone(),
+        orientation: orientation.clone(),
+        movement_speed: speed_u16,
+        ..Default::default()
+    };
+    let fixed_wing_aircraft = || GEDRecord7 {
+        entity_id,
+        location: location.clone(),
+        appearance: appearance.clone(),
+        orientation: orientation.clone(),
+        movement_speed: speed_u16,
+        ..Default::default()
+    };
+
+    Ok(match category {
+        IsGroupOfGroupedEntityCategory::BasicGroundCombatVehicle => {
+            GroupEntityDescription::BasicGroundCombatVehicle(ground_combat_vehicle())
+        }
+        IsGroupOfGroupedEntityCategory::EnhancedGroundCombatVehicle => {
+            GroupEntityDescription::EnhancedGroundCombatVehicle(GEDRecord2 {
+                basic_ground_combat_vehicle: ground_combat_vehicle(),
+                ..Default::default()
+            })
+        }
+        IsGroupOfGroupedEntityCategory::BasicGroundCombatSoldier => {
+            GroupEntityDescription::BasicGroundCombatSoldier(ground_combat_soldier())
+        }
+        IsGroupOfGroupedEntityCategory::EnhancedGroundCombatSoldier => {
+            GroupEntityDescription::EnhancedGroundCombatSoldier(GEDRecord4 {
+                basic_ground_combat_soldier: ground_combat_soldier(),
+                ..Default::default()
+            })
+        }
+        IsGroupOfGroupedEntityCategory::BasicRotorWingAircraft => {
+            GroupEntityDescription::BasicRotorWingAircraft(rotor_wing_aircraft())
+        }
+        IsGroupOfGroupedEntityCategory::EnhancedRotorWingAircraft => {
+            GroupEntityDescription::EnhancedRotorWingAircraft(GEDRecord6 {
+                basic_rotor_wing_aircraft: rotor_wing_aircraft(),
+                ..Default::default()
+            })
+        }
+        IsGroupOfGroupedEntityCategory::BasicFixedWingAircraft => {
+            GroupEntityDescription::BasicFixedWingAircraft(fixed_wing_aircraft())
+        }
+        IsGroupOfGroupedEntityCategory::EnhancedFixedWingAircraft => {
+            GroupEntityDescription::EnhancedFixedWingAircraft(GEDRecord8 {
+                basic_fixed_wing_aircraft: fixed_wing_aircraft(),
+                ..Default::default()
+            })
+        }
+        IsGroupOfGroupedEntityCategory::GroundLogisticsVehicle => {
+            GroupEntityDescription::GroundLogisticsVehicle(GEDRecord9 {
+                entity_id,
+                location,
+                appearance,
+                orientation,
+                speed: speed_u16,
+            })
+        }
+        IsGroupOfGroupedEntityCategory::Undefined
+        | IsGroupOfGroupedEntityCategory::Unspecified(_) => GroupEntityDescription::Undefined,
+    })
+}
+
+fn ged_location(
+    reference_point: &GroupReferencePoint,
+    entity: &EntityState,
+) -> Result<GEDEntityLocation, DisError> {
+    let (east, north, up) = location_to_local_offset(reference_point, &entity.entity_location);
+    let offset = |meters: f64| {
+        let meters = meters.round();
+        if (f64::from(i16::MIN)..=f64::from(i16::MAX)).contains(&meters) {
+            Ok(meters as i16 as u16)
+        } else {
+            Err(DisError::GroupedEntityOutOfRange(entity.entity_id))
+        }
+    };
+
+    Ok(GEDEntityLocation {
+        x_offset: offset(east)?,
+        y_offset: offset(north)?,
+        z_offset: offset(up)?,
+    })
+}
+
+fn ged_angle_to_radians(angle: u8) -> f32 {
+    f32::from(angle as i8) * GED_ORIENTATION_RESOLUTION
+}
+
+fn radians_to_ged_angle(radians: f32) -> u8 {
+    (radians / GED_ORIENTATION_RESOLUTION)
+        .round()
+        .clamp(f32::from(i8::MIN), f32::from(i8::MAX)) as i8 as u8
+}
@@ -0,0 +1,163 @@
+pub mod grouping;
+pub mod tracker;
+
+#[cfg(test)]
+mod tests {
+    use crate::aggregate::grouping::{
+        expand_group, group_entities, local_offset_to_location, location_to_local_offset,
+    };
+    use crate::aggregate::tracker::AggregateTracker;
+    use crate::aggregate_state::model::AggregateState;
+    use crate::entity_state::model::EntityState;
+    use crate::enumerations::IsGroupOfGroupedEntityCategory;
+    use crate::is_group_of::model::{GroupEntityDescription, GroupReferencePoint};
+    use crate::model::{EntityId, Location, Orientation, VectorF32};
+    use crate::DisError;
+
+    fn distance(a: &Location, b: &Location) -> f64 {
+        ((a.x_coordinate - b.x_coordinate).powi(2)
+            + (a.y_coordinate - b.y_coordinate).powi(2)
+            + (a.z_coordinate - b.z_coordinate).powi(2))
+        .sqrt()
+    }
+
+    #[test]
+    fn local_offsets_round_trip() {
+        let reference_point = GroupReferencePoint::default()
+            .with_latitude(0.9)
+            .with_longitude(0.1);
+        let location = local_offset_to_location(&reference_point, 100.0, -250.0, 30.0);
+        let (east, north, up) = location_to_local_offset(&reference_point, &location);
+        assert!((east - 100.0).abs() < 1e-6);
+        assert!((north + 250.0).abs() < 1e-6);
+        assert!((up - 30.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn group_entities_and_expand() {
+        let reference_point = GroupReferencePoint::default()
+            .with_latitude(0.9)
+            .with_longitude(0.1);
+        let entities = vec![
+            EntityState::builder()
+                .with_entity_id(EntityId::new(1, 2, 10))
+                .with_location(local_offset_to_location(&reference_point, 0.0, 0.0, 0.0))
+                .with_orientation(Orientation::new(1.0, 0.0, -0.5))
+                .with_velocity(VectorF32::new(3.0, 4.0, 0.0))
+                .build(),
+            EntityState::builder()
+                .with_entity_id(EntityId::new(1, 2, 11))
+                .with_location(local_offset_to_location(
+                    &reference_point,
+                    500.0,
+                    200.0,
+                    0.0,
+                ))
+                .build(),
+        ];
+
+        let group = group_entities(
+            EntityId::new(1, 2, 1),
+            IsGroupOfGroupedEntityCategory::BasicGroundCombatVehicle,
+            &entities,
+        )
+        .unwrap();
+        assert_eq!(group.descriptions.len(), 2);
+        assert!(matches!(
+            group.descriptions[0],
+            GroupEntityDescription::BasicGroundCombatVehicle(_)
+        ));
+
+        let expanded = expand_group(&group);
+        assert_eq!(expanded.len(), 2);
+        for (grouped, entity) in expanded.iter().zip(&entities) {
+            assert_eq!(grouped.entity_id, entity.entity_id);
+            assert!(distance(&grouped.location, &entity.entity_location) < 1.0);
+        }
+        assert!((expanded[0].orientation.psi - 1.0).abs() < 0.0125);
+        assert!((expanded[0].orientation.phi + 0.5).abs() < 0.0125);
+        assert_eq!(expanded[0].speed, 5.0);
+    }
+
+    #[test]
+    fn entities_far_from_the_group_are_rejected() {
+        let reference_point = GroupReferencePoint::default()
+            .with_latitude(0.9)
+            .with_longitude(0.1);
+        let entities = vec![
+            EntityState::builder()
+                .with_entity_id(EntityId::new(1, 2, 10))
+                .with_location(local_offset_to_location(&reference_point, 0.0, 0.0, 0.0))
+                .build(),
+            EntityState::builder()
+                .with_entity_id(EntityId::new(1, 2, 11))
+                .with_location(local_offset_to_location(
+                    &reference_point,
+                    80_000.0,
+                    0.0,
+                    0.0,
+                ))
+                .build(),
+        ];
+
+        assert_eq!(
+            group_entities(
+                EntityId::new(1, 2, 1),
+                IsGroupOfGroupedEntityCategory::BasicGroundCombatVehicle,
+                &entities,
+            ),
+            Err(DisError::GroupedEntityOutOfRange(EntityId::new(1, 2, 10)))
+        );
+    }
+
+    #[test]
+    fn aggregate_hierarchy() {
+        let mut tracker = AggregateTracker::new();
+        let battalion = EntityId::new(1, 2, 100);
+        let company = EntityId::new(1, 2, 101);
+
+        assert!(tracker.process(
+            &AggregateState::builder()
+                .with_aggregate_id(battalion)
+                .with_aggregate(company)
+                .with_entity(EntityId::new(1, 2, 1))
+                .build()
+                .into_pdu_body()
+        ));
+        assert!(tracker.process(
+            &AggregateState::builder()
+                .with_aggregate_id(company)
+                .with_entities(vec![EntityId::new(1, 2, 2), EntityId::new(1, 2, 3)])
+                .build()
+                .into_pdu_body()
+        ));
+
+        assert_eq!(tracker.root_aggregates(), vec![battalion]);
+        assert_eq!(tracker.parent(&company), Some(battalion));
+        assert_eq!(tracker.parent(&EntityId::new(1, 2, 3)), Some(company));
+        let mut entities = tracker.all_entities(&battalion);
+        entities.sort_by_key(|id| id.entity_id);
+        assert_eq!(
+            entities,
+            vec![
+                EntityId::new(1, 2, 1),
+                EntityId::new(1, 2, 2),
+                EntityId::new(1, 2, 3)
+            ]
+        );
+
+        // the company leaves the battalion
+        tracker.process(
+            &AggregateState::builder()
+                .with_aggregate_id(battalion)
+                .with_entity(EntityId::new(1, 2, 1))
+                .build()
+                .into_pdu_body(),
+        );
+        assert_eq!(tracker.parent(&company), None);
+        assert_eq!(tracker.root_aggregates(), vec![battalion, company]);
+
+        tracker.remove_aggregate(&company);
+        assert_eq!(tracker.parent(&EntityId::new(1, 2, 2)), None);
+    }
+}
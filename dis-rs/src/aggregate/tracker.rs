@@ -0,0 +1,122 @@
+use crate::aggregate::grouping::{expand_group, GroupedEntity};
+use crate::common::aggregate_state::model::AggregateState;
+use crate::common::model::{EntityId, PduBody};
+use std::collections::{HashMap, HashSet};
+
+/// Maintains the hierarchy of aggregates, sub-aggregates and entities from Aggregate State PDUs,
+/// and the grouped entities of `IsGroupOf` PDUs.
+///
+/// Each Aggregate State PDU replaces the previously received state of the aggregate, including its list of
+/// sub-aggregates and entities.
+#[derive(Clone, Debug, Default)]
+pub struct AggregateTracker {
+    aggregates: HashMap<EntityId, AggregateState>,
+    parents: HashMap<EntityId, EntityId>,
+    groups: HashMap<EntityId, Vec<GroupedEntity>>,
+}
+
+impl AggregateTracker {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Processes a received PDU body. Returns `true` when the hierarchy or the grouped entities were updated.
+    pub fn process(&mut self, body: &PduBody) -> bool {
+        match body {
+            PduBody::AggregateState(state) => {
+                self.update_aggregate(state.clone());
+                true
+            }
+            PduBody::IsGroupOf(group) => {
+                self.groups.insert(group.group_id, expand_group(group));
+                true
+            }
+            _ => false,
+        }
+    }
+
+    #[must_use]
+    pub fn aggregate(&self, aggregate_id: &EntityId) -> Option<&AggregateState> {
+        self.aggregates.get(aggregate_id)
+    }
+
+    pub fn aggregates(&self) -> impl Iterator<Item = &AggregateState> {
+        self.aggregates.values()
+    }
+
+    /// The aggregate that lists the provided aggregate or entity as a member.
+    #[must_use]
+    pub fn parent(&self, id: &EntityId) -> Option<EntityId> {
+        self.parents.get(id).copied()
+    }
+
+    /// The known aggregates that are not a sub-aggregate of another known aggregate.
+    #[must_use]
+    pub fn root_aggregates(&self) -> Vec<EntityId> {
+        let mut roots: Vec<_> = self
+            .aggregates
+            .keys()
+            .filter(|id| !self.parents.contains_key(*id))
+            .copied()
+            .collect();
+        roots.sort_by_key(|id| (id.simulation_address, id.entity_id));
+        roots
+    }
+
+    /// The entities of an aggregate, including the entities of its sub-aggregates at any depth.
+    #[must_use]
+    pub fn all_entities(&self, aggregate_id: &EntityId) -> Vec<EntityId> {
+        let mut entities = vec![];
+        let mut visited = HashSet::new();
+        let mut pending = vec![*aggregate_id];
+
+        while let Some(id) = pending.pop() {
+            if !visited.insert(id) {
+                continue;
+            }
+            if let Some(aggregate) = self.aggregates.get(&id) {
+                entities.extend(aggregate.entities.iter().copied());
+                pending.extend(aggregate.aggregates.iter().copied());
+            }
+        }
+        entities
+    }
+
+    /// The grouped entities of the `IsGroupOf` PDU with `group_id`, expanded to absolute coordinates.
+    #[must_use]
+    pub fn grouped_entities(&self, group_id: &EntityId) -> Option<&[GroupedEntity]> {
+        self.groups.get(group_id).map(Vec::as_slice)
+    }
+
+    /// Removes an aggregate, e.g. when it is no longer updated. Its members are no longer part of the hierarchy.
+    pub fn remove_aggregate(&mut self, aggregate_id: &EntityId) -> Option<AggregateState> {
+        let removed = self.aggregates.remove(aggregate_id)?;
+        self.remove_members(aggregate_id, &removed);
+        Some(removed)
+    }
+
+    /// Removes the grouped entities of an `IsGroupOf` PDU.
+    pub fn remove_group(&mut self, group_id: &EntityId) -> Option<Vec<GroupedEntity>> {
+        self.groups.remove(group_id)
+    }
+
+    fn update_aggregate(&mut self, state: AggregateState) {
+        let aggregate_id = state.aggregate_id;
+        if let Some(previous) = self.aggregates.remove(&aggregate_id) {
+            self.remove_members(&aggregate_id, &previous);
+        }
+        for member in state.aggregates.iter().chain(state.entities.iter()) {
+            self.parents.insert(*member, aggregate_id);
+        }
+        self.aggregates.insert(aggregate_id, state);
+    }
+
+    fn remove_members(&mut self, aggregate_id: &EntityId, state: &AggregateState) {
+        for member in state.aggregates.iter().chain(state.entities.iter()) {
+            if self.parents.get(member) == Some(aggregate_id) {
+                self.parents.remove(member);
+            }
+        }
+    }
+}
@@ -0,0 +1,172 @@
+use crate::common::model::{EntityId, EventId, PduBody, SimulationAddress};
+use crate::common::Interaction;
+use crate::fixed_parameters::{ALL_ENTITIES, NO_ENTITY};
+use std::collections::{HashSet, VecDeque};
+use std::time::{Duration, SystemTime};
+
+/// Lowest entity or event number that can be allocated
+const FIRST_NUMBER: u16 = NO_ENTITY + 1;
+/// Highest entity or event number that can be allocated, below the `ALL_ENTITIES` wildcard
+const LAST_NUMBER: u16 = ALL_ENTITIES as u16 - 1;
+/// Default time a released entity id is quarantined before it is reused
+pub const DEFAULT_QUARANTINE: Duration = Duration::from_secs(60);
+
+/// Use of the simulation address of an `IdAllocator` observed in a received PDU.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ForeignUse {
+    pub entity_id: EntityId,
+    /// Whether the entity id is currently allocated by this allocator
+    pub allocated_locally: bool,
+}
+
+/// Allocates `EntityId`s and `EventId`s for a `SimulationAddress`.
+///
+/// Entity and event numbers are allocated in the range 1 to 0xFFFE, skipping the reserved `NO_ENTITY` and
+/// `ALL_ENTITIES` values, and wrapping around after 0xFFFE. Released entity ids are quarantined for a configurable
+/// duration before they are allocated again, so that other simulations have removed the previous entity.
+#[derive(Clone, Debug)]
+pub struct IdAllocator {
+    simulation_address: SimulationAddress,
+    quarantine: Duration,
+    next_entity: u16,
+    next_event: u16,
+    allocated: HashSet<u16>,
+    quarantined: HashSet<u16>,
+    releases: VecDeque<(u16, SystemTime)>,
+}
+
+impl IdAllocator {
+    #[must_use]
+    pub fn new(simulation_address: SimulationAddress) -> Self {
+        Self {
+            simulation_address,
+            quarantine: DEFAULT_QUARANTINE,
+            next_entity: FIRST_NUMBER,
+            next_event: FIRST_NUMBER,
+            allocated: HashSet::new(),
+            quarantined: HashSet::new(),
+            releases: VecDeque::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn with_quarantine(mut self, quarantine: Duration) -> Self {
+        self.quarantine = quarantine;
+        self
+    }
+
+    #[must_use]
+    pub fn simulation_address(&self) -> SimulationAddress {
+        self.simulation_address
+    }
+
+    #[must_use]
+    pub fn quarantine(&self) -> Duration {
+        self.quarantine
+    }
+
+    /// Allocates the next free entity id.
+    /// Returns `None` when all entity numbers are allocated or quarantined.
+    pub fn allocate_entity_id(&mut self, now: SystemTime) -> Option<EntityId> {
+        self.end_quarantine(now);
+
+        let candidate = (0..=(LAST_NUMBER - FIRST_NUMBER))
+            .map(|offset| wrapping_number(self.next_entity, offset))
+            .find(|number| self.is_free(*number))?;
+        self.allocated.insert(candidate);
+        self.next_entity = wrapping_number(candidate, 1);
+
+        Some(EntityId::new_sim_address(
+            self.simulation_address,
+            candidate,
+        ))
+    }
+
+    /// Marks an entity id chosen by the application as allocated.
+    /// Returns `false` when the id does not belong to the simulation address, is reserved, or is not free.
+    pub fn claim_entity_id(&mut self, entity_id: &EntityId, now: SystemTime) -> bool {
+        self.end_quarantine(now);
+
+        if entity_id.simulation_address != self.simulation_address
+            || !is_allocatable(entity_id.entity_id)
+            || !self.is_free(entity_id.entity_id)
+        {
+            return false;
+        }
+        self.allocated.insert(entity_id.entity_id)
+    }
+
+    /// Releases an allocated entity id, which is quarantined before it is allocated again.
+    /// Returns `false` when the id was not allocated by this allocator.
+    pub fn release_entity_id(&mut self, entity_id: &EntityId, now: SystemTime) -> bool {
+        if entity_id.simulation_address != self.simulation_address
+            || !self.allocated.remove(&entity_id.entity_id)
+        {
+            return false;
+        }
+        self.quarantined.insert(entity_id.entity_id);
+        self.releases.push_back((entity_id.entity_id, now));
+        true
+    }
+
+    #[must_use]
+    pub fn is_allocated(&self, entity_id: &EntityId) -> bool {
+        entity_id.simulation_address == self.simulation_address
+            && self.allocated.contains(&entity_id.entity_id)
+    }
+
+    /// The number of allocated entity ids.
+    #[must_use]
+    pub fn allocated_count(&self) -> usize {
+        self.allocated.len()
+    }
+
+    /// Allocates the next event id. Event numbers are not released; they wrap around after 0xFFFE.
+    pub fn allocate_event_id(&mut self) -> EventId {
+        let number = self.next_event;
+        self.next_event = wrapping_number(number, 1);
+        EventId::new_sim_address(self.simulation_address, number)
+    }
+
+    /// Checks whether the originator of a PDU received from the network uses the simulation address of this allocator.
+    ///
+    /// When the own PDUs are not looped back, any reported use indicates another application using the same
+    /// site and application numbers. Otherwise only uses that are not `allocated_locally` indicate a conflict.
+    #[must_use]
+    pub fn detect_foreign_use(&self, body: &PduBody) -> Option<ForeignUse> {
+        body.originator()
+            .filter(|originator| originator.simulation_address == self.simulation_address)
+            .map(|originator| ForeignUse {
+                entity_id: *originator,
+                allocated_locally: self.allocated.contains(&originator.entity_id),
+            })
+    }
+
+    fn is_free(&self, number: u16) -> bool {
+        !self.allocated.contains(&number) && !self.quarantined.contains(&number)
+    }
+
+    fn end_quarantine(&mut self, now: SystemTime) {
+        while let Some((number, released)) = self.releases.front().copied() {
+            let ended = now
+                .duration_since(released)
+                .is_ok_and(|elapsed| elapsed >= self.quarantine);
+            if !ended {
+                break;
+            }
+            self.releases.pop_front();
+            self.quarantined.remove(&number);
+        }
+    }
+}
+
+fn is_allocatable(number: u16) -> bool {
+    (FIRST_NUMBER..=LAST_NUMBER).contains(&number)
+}
+
+/// Advances `number` by `offset` within the allocatable range, wrapping around after the last number.
+fn wrapping_number(number: u16, offset: u16) -> u16 {
+    let range = u32::from(LAST_NUMBER - FIRST_NUMBER) + 1;
+    let position = (u32::from(number.max(FIRST_NUMBER) - FIRST_NUMBER) + u32::from(offset)) % range;
+    FIRST_NUMBER + position as u16
+}
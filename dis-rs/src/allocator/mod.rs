@@ -0,0 +1,111 @@
+pub mod id_allocator;
+
+#[cfg(test)]
+mod tests {
+    use crate::allocator::id_allocator::IdAllocator;
+    use crate::entity_state::model::EntityState;
+    use crate::model::{EntityId, SimulationAddress};
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    fn now() -> SystemTime {
+        UNIX_EPOCH + Duration::from_secs(1_700_000_000)
+    }
+
+    #[test]
+    fn allocates_sequential_entity_ids() {
+        let mut allocator = IdAllocator::new(SimulationAddress::new(1, 2));
+
+        let first = allocator.allocate_entity_id(now()).unwrap();
+        let second = allocator.allocate_entity_id(now()).unwrap();
+        assert_eq!(first, EntityId::new(1, 2, 1));
+        assert_eq!(second, EntityId::new(1, 2, 2));
+        assert!(allocator.is_allocated(&first));
+        assert_eq!(allocator.allocated_count(), 2);
+    }
+
+    #[test]
+    fn released_entity_ids_are_quarantined() {
+        let mut allocator =
+            IdAllocator::new(SimulationAddress::new(1, 2)).with_quarantine(Duration::from_secs(10));
+        assert!(allocator.claim_entity_id(&EntityId::new(1, 2, 0xFFFE), now()));
+        let last = EntityId::new(1, 2, 0xFFFE);
+
+        assert!(allocator.release_entity_id(&last, now()));
+        assert!(!allocator.release_entity_id(&last, now()));
+        assert!(!allocator.claim_entity_id(&last, now() + Duration::from_secs(5)));
+        assert!(allocator.claim_entity_id(&last, now() + Duration::from_secs(10)));
+
+        assert!(!allocator.claim_entity_id(&EntityId::new(1, 2, 0), now()));
+        assert!(!allocator.claim_entity_id(&EntityId::new(1, 2, 0xFFFF), now()));
+        assert!(!allocator.claim_entity_id(&EntityId::new(1, 3, 5), now()));
+    }
+
+    #[test]
+    fn entity_ids_wrap_around_and_skip_reserved_values() {
+        let mut allocator =
+            IdAllocator::new(SimulationAddress::new(1, 2)).with_quarantine(Duration::from_secs(10));
+        for number in 2..=0xFFFD {
+            assert!(allocator.claim_entity_id(&EntityId::new(1, 2, number), now()));
+        }
+
+        assert_eq!(
+            allocator.allocate_entity_id(now()),
+            Some(EntityId::new(1, 2, 1))
+        );
+        assert_eq!(
+            allocator.allocate_entity_id(now()),
+            Some(EntityId::new(1, 2, 0xFFFE))
+        );
+        assert_eq!(allocator.allocate_entity_id(now()), None);
+
+        allocator.release_entity_id(&EntityId::new(1, 2, 7), now());
+        assert_eq!(allocator.allocate_entity_id(now()), None);
+        assert_eq!(
+            allocator.allocate_entity_id(now() + Duration::from_secs(10)),
+            Some(EntityId::new(1, 2, 7))
+        );
+    }
+
+    #[test]
+    fn event_ids_wrap_around() {
+        let mut allocator = IdAllocator::new(SimulationAddress::new(1, 2));
+
+        let first = allocator.allocate_event_id();
+        assert_eq!(first.event_id, 1);
+        for _ in 2..=0xFFFE {
+            allocator.allocate_event_id();
+        }
+        assert_eq!(allocator.allocate_event_id().event_id, 1);
+    }
+
+    #[test]
+    fn detects_foreign_use_of_simulation_address() {
+        let mut allocator = IdAllocator::new(SimulationAddress::new(1, 2));
+        let own = allocator.allocate_entity_id(now()).unwrap();
+
+        let foreign = EntityState::builder()
+            .with_entity_id(EntityId::new(1, 2, 500))
+            .build()
+            .into_pdu_body();
+        let detected = allocator.detect_foreign_use(&foreign).unwrap();
+        assert_eq!(detected.entity_id, EntityId::new(1, 2, 500));
+        assert!(!detected.allocated_locally);
+
+        let looped_back = EntityState::builder()
+            .with_entity_id(own)
+            .build()
+            .into_pdu_body();
+        assert!(
+            allocator
+                .detect_foreign_use(&looped_back)
+                .unwrap()
+                .allocated_locally
+        );
+
+        let other = EntityState::builder()
+            .with_entity_id(EntityId::new(1, 3, 500))
+            .build()
+            .into_pdu_body();
+        assert!(allocator.detect_foreign_use(&other).is_none());
+    }
+}
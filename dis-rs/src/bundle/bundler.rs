@@ -0,0 +1,129 @@
+use crate::common::model::Pdu;
+use crate::constants::EIGHT_OCTETS;
+use crate::DisError;
+use bytes::{BufMut, Bytes, BytesMut};
+use std::time::{Duration, SystemTime};
+
+/// Default maximum size of a datagram: an Ethernet MTU of 1500 octets minus the IPv4 and UDP headers
+pub const DEFAULT_MAX_DATAGRAM_SIZE: usize = 1472;
+pub const DEFAULT_FLUSH_TIMEOUT: Duration = Duration::from_millis(10);
+
+/// Packs PDUs into datagrams for sending, following the bundling rules of IEEE 1278.1-2012.
+///
+/// Each PDU in a bundle starts on an 8-octet boundary, padded with zero octets, and PDUs are never split
+/// across datagrams. A datagram is completed when the next PDU does not fit in it, or when the oldest PDU in it
+/// has waited for the flush timeout, which bounds the latency added by bundling.
+/// A PDU that is larger than the maximum datagram size on its own is put in a datagram by itself.
+///
+/// The bundler does not perform any IO; completed datagrams are collected with `PduBundler::take_datagrams`.
+#[derive(Clone, Debug)]
+pub struct PduBundler {
+    max_datagram_size: usize,
+    flush_timeout: Duration,
+    buffer: BytesMut,
+    pdus_in_buffer: usize,
+    oldest: Option<SystemTime>,
+    datagrams: Vec<Bytes>,
+}
+
+impl Default for PduBundler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PduBundler {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            max_datagram_size: DEFAULT_MAX_DATAGRAM_SIZE,
+            flush_timeout: DEFAULT_FLUSH_TIMEOUT,
+            buffer: BytesMut::with_capacity(DEFAULT_MAX_DATAGRAM_SIZE),
+            pdus_in_buffer: 0,
+            oldest: None,
+            datagrams: Vec::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn with_max_datagram_size(mut self, max_datagram_size: usize) -> Self {
+        self.max_datagram_size = max_datagram_size;
+        self
+    }
+
+    #[must_use]
+    pub fn with_flush_timeout(mut self, flush_timeout: Duration) -> Self {
+        self.flush_timeout = flush_timeout;
+        self
+    }
+
+    #[must_use]
+    pub fn max_datagram_size(&self) -> usize {
+        self.max_datagram_size
+    }
+
+    #[must_use]
+    pub fn flush_timeout(&self) -> Duration {
+        self.flush_timeout
+    }
+
+    /// The number of PDUs in the datagram that is being filled.
+    #[must_use]
+    pub fn pending_pdus(&self) -> usize {
+        self.pdus_in_buffer
+    }
+
+    /// Adds the PDU to the datagram that is being filled, completing that datagram first when the PDU does not fit.
+    ///
+    /// # Errors
+    /// Returns a `DisError` when the PDU cannot be serialized.
+    pub fn push(&mut self, pdu: &Pdu, now: SystemTime) -> Result<(), DisError> {
+        let pdu_length = pdu.pdu_length() as usize;
+        let offset = aligned(self.buffer.len());
+        if self.pdus_in_buffer > 0 && offset + pdu_length > self.max_datagram_size {
+            self.flush();
+        }
+
+        let padding = aligned(self.buffer.len()) - self.buffer.len();
+        self.buffer.reserve(padding + pdu_length);
+        self.buffer.put_bytes(0, padding);
+        pdu.serialize(&mut self.buffer)?;
+        self.pdus_in_buffer += 1;
+        self.oldest.get_or_insert(now);
+
+        if self.buffer.len() >= self.max_datagram_size {
+            self.flush();
+        }
+        Ok(())
+    }
+
+    /// Completes the datagram that is being filled when its oldest PDU has waited for the flush timeout.
+    pub fn poll(&mut self, now: SystemTime) {
+        let expired = self.oldest.is_some_and(|oldest| {
+            now.duration_since(oldest).unwrap_or_default() >= self.flush_timeout
+        });
+        if expired {
+            self.flush();
+        }
+    }
+
+    /// Completes the datagram that is being filled, if it contains any PDUs.
+    pub fn flush(&mut self) {
+        if self.pdus_in_buffer == 0 {
+            return;
+        }
+        self.datagrams.push(self.buffer.split().freeze());
+        self.pdus_in_buffer = 0;
+        self.oldest = None;
+    }
+
+    /// Takes the completed datagrams, ready to be sent.
+    pub fn take_datagrams(&mut self) -> Vec<Bytes> {
+        std::mem::take(&mut self.datagrams)
+    }
+}
+
+/// The length rounded up to the next 8-octet boundary.
+pub(crate) fn aligned(length: usize) -> usize {
+    length.div_ceil(EIGHT_OCTETS) * EIGHT_OCTETS
+}
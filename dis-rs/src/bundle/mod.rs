@@ -0,0 +1,85 @@
+pub mod bundler;
+
+#[cfg(test)]
+mod tests {
+    use crate::bundle::bundler::PduBundler;
+    use crate::enumerations::PduType;
+    use crate::model::{DisTimeStamp, Pdu, PduHeader};
+    use crate::other::model::Other;
+    use crate::parse;
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    fn now() -> SystemTime {
+        UNIX_EPOCH + Duration::from_secs(1_700_000_000)
+    }
+
+    fn other_pdu(body_length: usize) -> Pdu {
+        Pdu::finalize_from_parts(
+            PduHeader::new_v7(1, PduType::Other),
+            Other::builder()
+                .with_body(vec![0xAB; body_length])
+                .build()
+                .into_pdu_body(),
+            DisTimeStamp::new_absolute_from_secs(100),
+        )
+    }
+
+    #[test]
+    fn bundled_pdus_are_aligned() {
+        let mut bundler = PduBundler::new();
+        let pdu = other_pdu(5);
+        bundler.push(&pdu, now()).unwrap();
+        bundler.push(&pdu, now()).unwrap();
+        bundler.push(&pdu, now()).unwrap();
+        assert_eq!(bundler.pending_pdus(), 3);
+        assert!(bundler.take_datagrams().is_empty());
+
+        bundler.flush();
+        let datagrams = bundler.take_datagrams();
+        assert_eq!(datagrams.len(), 1);
+        // two PDUs of 17 octets padded to 24, followed by the last PDU without padding
+        assert_eq!(datagrams[0].len(), 24 + 24 + 17);
+        assert_eq!(datagrams[0][24], datagrams[0][0]);
+
+        let parsed = parse(&datagrams[0]).unwrap();
+        assert_eq!(parsed.len(), 3);
+        assert!(parsed.iter().all(|parsed| parsed.body == pdu.body));
+    }
+
+    #[test]
+    fn pdus_are_never_split() {
+        let mut bundler = PduBundler::new().with_max_datagram_size(64);
+        let pdu = other_pdu(20);
+        bundler.push(&pdu, now()).unwrap();
+        bundler.push(&pdu, now()).unwrap();
+        bundler.push(&pdu, now()).unwrap();
+
+        let datagrams = bundler.take_datagrams();
+        assert_eq!(datagrams.len(), 1);
+        assert_eq!(datagrams[0].len(), 32 + 32);
+        assert_eq!(bundler.pending_pdus(), 1);
+
+        let large = other_pdu(100);
+        bundler.push(&large, now()).unwrap();
+        let datagrams = bundler.take_datagrams();
+        assert_eq!(datagrams.len(), 2);
+        assert_eq!(datagrams[1].len(), large.pdu_length() as usize);
+        assert_eq!(bundler.pending_pdus(), 0);
+    }
+
+    #[test]
+    fn flush_on_timeout() {
+        let mut bundler = PduBundler::new().with_flush_timeout(Duration::from_millis(20));
+        bundler.push(&other_pdu(5), now()).unwrap();
+        bundler
+            .push(&other_pdu(5), now() + Duration::from_millis(15))
+            .unwrap();
+
+        bundler.poll(now() + Duration::from_millis(19));
+        assert!(bundler.take_datagrams().is_empty());
+        bundler.poll(now() + Duration::from_millis(20));
+        let datagrams = bundler.take_datagrams();
+        assert_eq!(datagrams.len(), 1);
+        assert_eq!(parse(&datagrams[0]).unwrap().len(), 2);
+    }
+}
@@ -0,0 +1,172 @@
+mod packet;
+pub mod pcap;
+
+#[cfg(test)]
+mod tests {
+    use crate::capture::pcap::{
+        read_capture, read_capture_pdus, write_pcap, write_pcap_pdus, CapturedDatagram, CapturedPdu,
+    };
+    use crate::enumerations::PduType;
+    use crate::model::{DisTimeStamp, Pdu, PduHeader};
+    use crate::other::model::Other;
+    use crate::DisError;
+    use bytes::Bytes;
+    use std::time::{Duration, UNIX_EPOCH};
+
+    const PCAP_HEADER_OCTETS: usize = 24;
+    const PCAP_RECORD_HEADER_OCTETS: usize = 16;
+
+    fn datagram(source: &str, destination: &str, length: usize) -> CapturedDatagram {
+        CapturedDatagram {
+            source: source.parse().unwrap(),
+            destination: destination.parse().unwrap(),
+            captured_at: UNIX_EPOCH + Duration::from_secs(1_700_000_000),
+            payload: Bytes::from(vec![0x5A; length]),
+        }
+    }
+
+    fn captured_pdu(source: &str, destination: &str) -> CapturedPdu {
+        CapturedPdu {
+            pdu: Pdu::finalize_from_parts(
+                PduHeader::new_v7(1, PduType::Other),
+                Other::builder()
+                    .with_body(vec![0xAB; 12])
+                    .build()
+                    .into_pdu_body(),
+                DisTimeStamp::new_absolute_from_secs(100),
+            ),
+            source: source.parse().unwrap(),
+            destination: destination.parse().unwrap(),
+            captured_at: UNIX_EPOCH + Duration::from_micros(1_700_000_000_123_456),
+        }
+    }
+
+    /// The Ethernet frame of the single datagram in a pcap file written by `write_pcap`.
+    fn ethernet_frame(datagram: &CapturedDatagram) -> Vec<u8> {
+        write_pcap(&[datagram.clone()]).unwrap()[PCAP_HEADER_OCTETS + PCAP_RECORD_HEADER_OCTETS..]
+            .to_vec()
+    }
+
+    /// A VLAN-tagged Ethernet frame with the fragment at `offset` of the IPv4 packet in `frame`.
+    fn fragment(frame: &[u8], offset: usize, more_fragments: bool, length: usize) -> Vec<u8> {
+        let (addresses, ip_header, ip_payload) = (&frame[..12], &frame[14..34], &frame[34..]);
+        let data = &ip_payload[offset..offset + length];
+        let mut header = ip_header.to_vec();
+        header[2..4].copy_from_slice(&((ip_header.len() + data.len()) as u16).to_be_bytes());
+        let flags_and_offset = (if more_fragments { 0x2000 } else { 0 }) | (offset / 8) as u16;
+        header[6..8].copy_from_slice(&flags_and_offset.to_be_bytes());
+
+        let mut frame = addresses.to_vec();
+        frame.extend_from_slice(&[0x81, 0x00, 0x00, 0x05, 0x08, 0x00]);
+        frame.extend_from_slice(&header);
+        frame.extend_from_slice(data);
+        frame
+    }
+
+    /// A pcap file with the Ethernet `frames`, each captured at the paired number of seconds since the epoch.
+    fn capture(frames: &[(u32, Vec<u8>)]) -> Vec<u8> {
+        let mut capture = write_pcap(&[]).unwrap();
+        for (seconds, frame) in frames {
+            capture.extend_from_slice(&seconds.to_le_bytes());
+            capture.extend_from_slice(&0u32.to_le_bytes());
+            capture.extend_from_slice(&(frame.len() as u32).to_le_bytes());
+            capture.extend_from_slice(&(frame.len() as u32).to_le_bytes());
+            capture.extend_from_slice(frame);
+        }
+        capture
+    }
+
+    #[test]
+    fn write_and_read_pdus() {
+        let pdus = vec![
+            captured_pdu("10.0.0.1:3000", "239.1.2.3:3000"),
+            captured_pdu("[fe80::1]:3000", "[ff02::1]:3000"),
+        ];
+        let capture = write_pcap_pdus(&pdus).unwrap();
+        assert_eq!(read_capture_pdus(&capture).unwrap(), pdus);
+
+        assert!(matches!(
+            write_pcap(&[datagram("10.0.0.1:3000", "[::1]:3000", 10)]),
+            Err(DisError::InvalidCapture(_))
+        ));
+        assert!(matches!(
+            read_capture(b"not a capture"),
+            Err(DisError::InvalidCapture(_))
+        ));
+    }
+
+    #[test]
+    fn reassemble_vlan_tagged_fragments() {
+        let original = datagram("10.0.0.1:3000", "10.0.0.2:3000", 100);
+        let frame = ethernet_frame(&original);
+
+        let capture = capture(&[
+            (1_700_000_000, fragment(&frame, 56, false, 52)),
+            (1_700_000_000, fragment(&frame, 0, true, 56)),
+        ]);
+        assert_eq!(read_capture(&capture).unwrap(), vec![original]);
+    }
+
+    #[test]
+    fn discard_timed_out_fragments() {
+        let original = datagram("10.0.0.1:3000", "10.0.0.2:3000", 100);
+        let frame = ethernet_frame(&original);
+
+        let capture = capture(&[
+            (1_700_000_000, fragment(&frame, 56, false, 52)),
+            (1_700_000_030, fragment(&frame, 0, true, 56)),
+        ]);
+        assert!(read_capture(&capture).unwrap().is_empty());
+    }
+
+    #[test]
+    fn read_packets_without_total_length() {
+        let original = datagram("10.0.0.1:3000", "10.0.0.2:3000", 100);
+        // as captured before TCP segmentation offloading, with padding of the link layer
+        let mut frame = ethernet_frame(&original);
+        frame[16..18].copy_from_slice(&[0, 0]);
+        frame.extend_from_slice(&[0; 4]);
+
+        let capture = capture(&[(1_700_000_000, frame)]);
+        assert_eq!(read_capture(&capture).unwrap(), vec![original]);
+    }
+
+    #[test]
+    fn read_big_endian_pcapng() {
+        fn block(block_type: u32, body: &[u8]) -> Vec<u8> {
+            let length = (12 + body.len()) as u32;
+            let mut block = block_type.to_be_bytes().to_vec();
+            block.extend_from_slice(&length.to_be_bytes());
+            block.extend_from_slice(body);
+            block.extend_from_slice(&length.to_be_bytes());
+            block
+        }
+
+        let mut original = datagram("[2001:db8::1]:3000", "[2001:db8::2]:3000", 21);
+        original.captured_at = UNIX_EPOCH + Duration::from_nanos(1_700_000_000_123_456_789);
+        let frame = ethernet_frame(&original);
+
+        let mut section_header = 0x1A2B_3C4Du32.to_be_bytes().to_vec();
+        section_header.extend_from_slice(&[0, 1, 0, 0]);
+        section_header.extend_from_slice(&(-1i64).to_be_bytes());
+        // Ethernet, with the if_tsresol option set to nanoseconds
+        let interface = [0, 1, 0, 0, 0, 0, 0, 0, 0, 9, 0, 1, 9, 0, 0, 0, 0, 0, 0, 0];
+        let timestamp = 1_700_000_000_123_456_789u64;
+        let mut packet = 0u32.to_be_bytes().to_vec();
+        packet.extend_from_slice(&((timestamp >> 32) as u32).to_be_bytes());
+        packet.extend_from_slice(&(timestamp as u32).to_be_bytes());
+        packet.extend_from_slice(&(frame.len() as u32).to_be_bytes());
+        packet.extend_from_slice(&(frame.len() as u32).to_be_bytes());
+        packet.extend_from_slice(&frame);
+        packet.resize(packet.len().div_ceil(4) * 4, 0);
+
+        let capture = [
+            block(0x0A0D_0D0A, &section_header),
+            block(1, &interface),
+            block(5, &[0; 8]),
+            block(6, &packet),
+        ]
+        .concat();
+        assert_eq!(read_capture(&capture).unwrap(), vec![original]);
+    }
+}
@@ -0,0 +1,447 @@
+use crate::capture::pcap::CapturedDatagram;
+use crate::DisError;
+use bytes::{BufMut, Bytes, BytesMut};
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+use std::time::{Duration, SystemTime};
+
+pub(crate) const LINKTYPE_NULL: u32 = 0;
+pub(crate) const LINKTYPE_ETHERNET: u32 = 1;
+pub(crate) const LINKTYPE_RAW: u32 = 101;
+pub(crate) const LINKTYPE_LINUX_SLL: u32 = 113;
+pub(crate) const LINKTYPE_IPV4: u32 = 228;
+pub(crate) const LINKTYPE_IPV6: u32 = 229;
+pub(crate) const LINKTYPE_LINUX_SLL2: u32 = 276;
+
+const ETHERTYPE_IPV4: u16 = 0x0800;
+const ETHERTYPE_IPV6: u16 = 0x86DD;
+const ETHERTYPE_VLAN: u16 = 0x8100;
+const ETHERTYPE_QINQ: u16 = 0x88A8;
+
+const IP_PROTOCOL_UDP: u8 = 17;
+const IPV6_HOP_BY_HOP_OPTIONS: u8 = 0;
+const IPV6_ROUTING: u8 = 43;
+const IPV6_FRAGMENT: u8 = 44;
+const IPV6_DESTINATION_OPTIONS: u8 = 60;
+
+const ETHERNET_HEADER_OCTETS: usize = 14;
+const LINUX_SLL_HEADER_OCTETS: usize = 16;
+const LINUX_SLL2_HEADER_OCTETS: usize = 20;
+const IPV4_HEADER_OCTETS: usize = 20;
+const IPV6_HEADER_OCTETS: usize = 40;
+const UDP_HEADER_OCTETS: usize = 8;
+
+const IPV4_DONT_FRAGMENT: u16 = 0x4000;
+const IPV4_MORE_FRAGMENTS: u16 = 0x2000;
+const IPV4_FRAGMENT_OFFSET: u16 = 0x1FFF;
+const DEFAULT_HOP_LIMIT: u8 = 64;
+
+/// The time after which the fragments of an incomplete packet are discarded, as the reassembly timeout of Linux.
+const FRAGMENT_TIMEOUT: Duration = Duration::from_secs(30);
+/// The number of incomplete packets of which fragments are kept, after which those of the oldest are discarded.
+const MAX_FRAGMENTED_PACKETS: usize = 1024;
+
+/// The address family values of the BSD loopback encapsulation for IPv4 and IPv6.
+const NULL_FAMILY_IPV4: u32 = 2;
+const NULL_FAMILY_IPV6: [u32; 3] = [24, 28, 30];
+
+fn be_u16_at(data: &[u8], offset: usize) -> Option<u16> {
+    Some(u16::from_be_bytes(
+        data.get(offset..offset + 2)?.try_into().ok()?,
+    ))
+}
+
+fn be_u32_at(data: &[u8], offset: usize) -> Option<u32> {
+    Some(u32::from_be_bytes(
+        data.get(offset..offset + 4)?.try_into().ok()?,
+    ))
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+struct FragmentKey {
+    source: IpAddr,
+    destination: IpAddr,
+    identification: u32,
+}
+
+struct Fragments {
+    parts: Vec<(usize, Vec<u8>)>,
+    total_length: Option<usize>,
+    first_captured_at: SystemTime,
+}
+
+/// Extracts UDP datagrams from captured link-layer frames, reassembling fragmented IP packets.
+///
+/// Frames that do not contain (a fragment of) an UDP datagram are ignored. The fragments of packets that are not
+/// complete within `FRAGMENT_TIMEOUT` of capture time are discarded, as are those of the oldest packet when more than
+/// `MAX_FRAGMENTED_PACKETS` packets are incomplete.
+#[derive(Default)]
+pub(crate) struct FrameDecoder {
+    fragments: HashMap<FragmentKey, Fragments>,
+}
+
+impl FrameDecoder {
+    pub(crate) fn decode(
+        &mut self,
+        link_type: u32,
+        frame: &[u8],
+        captured_at: SystemTime,
+    ) -> Option<CapturedDatagram> {
+        let (ether_type, packet) = link_layer(link_type, frame)?;
+        match ether_type {
+            ETHERTYPE_IPV4 => self.ipv4(packet, captured_at),
+            ETHERTYPE_IPV6 => self.ipv6(packet, captured_at),
+            _ => None,
+        }
+    }
+
+    fn ipv4(&mut self, packet: &[u8], captured_at: SystemTime) -> Option<CapturedDatagram> {
+        let version_and_length = *packet.first()?;
+        let header_length = usize::from(version_and_length & 0x0F) * 4;
+        if version_and_length >> 4 != 4 || header_length < IPV4_HEADER_OCTETS {
+            return None;
+        }
+        // the total length excludes any padding of the link layer,
+        // but is zero in packets captured before segmentation offloading
+        let packet = match be_u16_at(packet, 2)? {
+            0 => packet,
+            total_length => packet.get(..usize::from(total_length))?,
+        };
+        let identification = be_u16_at(packet, 4)?;
+        let flags_and_offset = be_u16_at(packet, 6)?;
+        if *packet.get(9)? != IP_PROTOCOL_UDP {
+            return None;
+        }
+        let source = IpAddr::V4(Ipv4Addr::from(be_u32_at(packet, 12)?));
+        let destination = IpAddr::V4(Ipv4Addr::from(be_u32_at(packet, 16)?));
+        let payload = packet.get(header_length..)?;
+
+        let more_fragments = flags_and_offset & IPV4_MORE_FRAGMENTS != 0;
+        let offset = usize::from(flags_and_offset & IPV4_FRAGMENT_OFFSET) * 8;
+        let segment = if more_fragments || offset > 0 {
+            let key = FragmentKey {
+                source,
+                destination,
+                identification: u32::from(identification),
+            };
+            Cow::Owned(self.reassemble(key, offset, more_fragments, payload, captured_at)?)
+        } else {
+            Cow::Borrowed(payload)
+        };
+        udp(source, destination, &segment, captured_at)
+    }
+
+    fn ipv6(&mut self, packet: &[u8], captured_at: SystemTime) -> Option<CapturedDatagram> {
+        if *packet.first()? >> 4 != 6 {
+            return None;
+        }
+        let payload_length = usize::from(be_u16_at(packet, 4)?);
+        let mut next_header = *packet.get(6)?;
+        let source = IpAddr::V6(Ipv6Addr::from(u128::from_be_bytes(
+            packet.get(8..24)?.try_into().ok()?,
+        )));
+        let destination = IpAddr::V6(Ipv6Addr::from(u128::from_be_bytes(
+            packet.get(24..40)?.try_into().ok()?,
+        )));
+        let mut payload = packet.get(IPV6_HEADER_OCTETS..IPV6_HEADER_OCTETS + payload_length)?;
+
+        let mut fragment = None;
+        loop {
+            match next_header {
+                IPV6_HOP_BY_HOP_OPTIONS | IPV6_ROUTING | IPV6_DESTINATION_OPTIONS => {
+                    let length = (usize::from(*payload.get(1)?) + 1) * 8;
+                    next_header = *payload.first()?;
+                    payload = payload.get(length..)?;
+                }
+                IPV6_FRAGMENT => {
+                    next_header = *payload.first()?;
+                    let offset_and_flags = be_u16_at(payload, 2)?;
+                    let key = FragmentKey {
+                        source,
+                        destination,
+                        identification: be_u32_at(payload, 4)?,
+                    };
+                    fragment = Some((
+                        key,
+                        usize::from(offset_and_flags & 0xFFF8),
+                        offset_and_flags & 0x0001 != 0,
+                    ));
+                    payload = payload.get(8..)?;
+                }
+                _ => break,
+            }
+        }
+        if next_header != IP_PROTOCOL_UDP {
+            return None;
+        }
+
+        let segment = match fragment {
+            Some((key, offset, more_fragments)) => {
+                Cow::Owned(self.reassemble(key, offset, more_fragments, payload, captured_at)?)
+            }
+            None => Cow::Borrowed(payload),
+        };
+        udp(source, destination, &segment, captured_at)
+    }
+
+    /// Stores a fragment, and returns the reassembled payload once all fragments of the packet have been captured.
+    fn reassemble(
+        &mut self,
+        key: FragmentKey,
+        offset: usize,
+        more_fragments: bool,
+        data: &[u8],
+        captured_at: SystemTime,
+    ) -> Option<Vec<u8>> {
+        self.evict(key, captured_at);
+        let fragments = self.fragments.entry(key).or_insert_with(|| Fragments {
+            parts: Vec::new(),
+            total_length: None,
+            first_captured_at: captured_at,
+        });
+        fragments.parts.push((offset, data.to_vec()));
+        if !more_fragments {
+            fragments.total_length = Some(offset + data.len());
+        }
+        let total_length = fragments.total_length?;
+
+        fragments.parts.sort_by_key(|(offset, _)| *offset);
+        let mut payload = Vec::with_capacity(total_length);
+        for (offset, data) in &fragments.parts {
+            if *offset > payload.len() {
+                return None;
+            }
+            let overlap = payload.len() - offset;
+            if overlap < data.len() {
+                payload.extend_from_slice(&data[overlap..]);
+            }
+        }
+        if payload.len() < total_length {
+            return None;
+        }
+        payload.truncate(total_length);
+        self.fragments.remove(&key);
+        Some(payload)
+    }
+
+    /// Discards the fragments of packets that timed out at `captured_at`, and those of the oldest packet
+    /// when no more incomplete packets can be kept to store a fragment of the packet of `key`.
+    fn evict(&mut self, key: FragmentKey, captured_at: SystemTime) {
+        self.fragments.retain(|_, fragments| {
+            !captured_at
+                .duration_since(fragments.first_captured_at)
+                .is_ok_and(|age| age >= FRAGMENT_TIMEOUT)
+        });
+        if self.fragments.len() >= MAX_FRAGMENTED_PACKETS && !self.fragments.contains_key(&key) {
+            let oldest = self
+                .fragments
+                .iter()
+                .min_by_key(|(_, fragments)| fragments.first_captured_at)
+                .map(|(key, _)| *key);
+            if let Some(oldest) = oldest {
+                self.fragments.remove(&oldest);
+            }
+        }
+    }
+}
+
+/// The ether type of the network layer packet in a frame, and the packet itself.
+fn link_layer(link_type: u32, frame: &[u8]) -> Option<(u16, &[u8])> {
+    match link_type {
+        LINKTYPE_ETHERNET => {
+            let mut offset = ETHERNET_HEADER_OCTETS - 2;
+            let mut ether_type = be_u16_at(frame, offset)?;
+            while ether_type == ETHERTYPE_VLAN || ether_type == ETHERTYPE_QINQ {
+                offset += 4;
+                ether_type = be_u16_at(frame, offset)?;
+            }
+            Some((ether_type, frame.get(offset + 2..)?))
+        }
+        LINKTYPE_NULL => {
+            let family = frame.get(..4)?.try_into().ok()?;
+            let family = if u32::from_le_bytes(family) <= u32::from(u16::MAX) {
+                u32::from_le_bytes(family)
+            } else {
+                u32::from_be_bytes(family)
+            };
+            let ether_type = if family == NULL_FAMILY_IPV4 {
+                ETHERTYPE_IPV4
+            } else if NULL_FAMILY_IPV6.contains(&family) {
+                ETHERTYPE_IPV6
+            } else {
+                return None;
+            };
+            Some((ether_type, frame.get(4..)?))
+        }
+        LINKTYPE_RAW => match *frame.first()? >> 4 {
+            4 => Some((ETHERTYPE_IPV4, frame)),
+            6 => Some((ETHERTYPE_IPV6, frame)),
+            _ => None,
+        },
+        LINKTYPE_IPV4 => Some((ETHERTYPE_IPV4, frame)),
+        LINKTYPE_IPV6 => Some((ETHERTYPE_IPV6, frame)),
+        LINKTYPE_LINUX_SLL => Some((
+            be_u16_at(frame, LINUX_SLL_HEADER_OCTETS - 2)?,
+            frame.get(LINUX_SLL_HEADER_OCTETS..)?,
+        )),
+        LINKTYPE_LINUX_SLL2 => Some((be_u16_at(frame, 0)?, frame.get(LINUX_SLL2_HEADER_OCTETS..)?)),
+        _ => None,
+    }
+}
+
+fn udp(
+    source: IpAddr,
+    destination: IpAddr,
+    segment: &[u8],
+    captured_at: SystemTime,
+) -> Option<CapturedDatagram> {
+    let source_port = be_u16_at(segment, 0)?;
+    let destination_port = be_u16_at(segment, 2)?;
+    let length = usize::from(be_u16_at(segment, 4)?);
+    let payload = segment.get(UDP_HEADER_OCTETS..length)?;
+    Some(CapturedDatagram {
+        source: SocketAddr::new(source, source_port),
+        destination: SocketAddr::new(destination, destination_port),
+        captured_at,
+        payload: Bytes::copy_from_slice(payload),
+    })
+}
+
+/// Encapsulates a datagram in synthetic Ethernet, IP and UDP headers.
+pub(crate) fn ethernet_frame(datagram: &CapturedDatagram) -> Result<Vec<u8>, DisError> {
+    let too_large = || {
+        DisError::InvalidCapture(format!(
+            "a datagram of {} bytes does not fit in an IP packet",
+            datagram.payload.len()
+        ))
+    };
+    let udp_length = UDP_HEADER_OCTETS + datagram.payload.len();
+    let (source, destination) = (datagram.source.ip(), datagram.destination.ip());
+
+    let mut buf = BytesMut::with_capacity(ETHERNET_HEADER_OCTETS + IPV6_HEADER_OCTETS + udp_length);
+    buf.put_slice(&mac_address(destination));
+    buf.put_slice(&mac_address(source));
+    match (source, destination) {
+        (IpAddr::V4(source_ip), IpAddr::V4(destination_ip)) => {
+            let total_length =
+                u16::try_from(IPV4_HEADER_OCTETS + udp_length).map_err(|_| too_large())?;
+            buf.put_u16(ETHERTYPE_IPV4);
+            let mut header = BytesMut::with_capacity(IPV4_HEADER_OCTETS);
+            header.put_u8(0x45);
+            header.put_u8(0);
+            header.put_u16(total_length);
+            header.put_u16(0);
+            header.put_u16(IPV4_DONT_FRAGMENT);
+            header.put_u8(DEFAULT_HOP_LIMIT);
+            header.put_u8(IP_PROTOCOL_UDP);
+            header.put_u16(0);
+            header.put_slice(&source_ip.octets());
+            header.put_slice(&destination_ip.octets());
+            let checksum = internet_checksum(&[&header[..]]);
+            header[10..12].copy_from_slice(&checksum.to_be_bytes());
+            buf.put_slice(&header);
+        }
+        (IpAddr::V6(source_ip), IpAddr::V6(destination_ip)) => {
+            buf.put_u16(ETHERTYPE_IPV6);
+            buf.put_u32(0x6000_0000);
+            buf.put_u16(u16::try_from(udp_length).map_err(|_| too_large())?);
+            buf.put_u8(IP_PROTOCOL_UDP);
+            buf.put_u8(DEFAULT_HOP_LIMIT);
+            buf.put_slice(&source_ip.octets());
+            buf.put_slice(&destination_ip.octets());
+        }
+        _ => {
+            return Err(DisError::InvalidCapture(format!(
+                "the source {} and destination {} of a datagram do not use the same IP version",
+                datagram.source, datagram.destination
+            )))
+        }
+    }
+
+    let udp_length = u16::try_from(udp_length).map_err(|_| too_large())?;
+    let mut header = BytesMut::with_capacity(UDP_HEADER_OCTETS);
+    header.put_u16(datagram.source.port());
+    header.put_u16(datagram.destination.port());
+    header.put_u16(udp_length);
+    header.put_u16(0);
+    let checksum = udp_checksum(source, destination, &header, &datagram.payload);
+    header[6..8].copy_from_slice(&checksum.to_be_bytes());
+    buf.put_slice(&header);
+    buf.put_slice(&datagram.payload);
+
+    Ok(buf.to_vec())
+}
+
+/// A MAC address for an IP address: the multicast MAC address for multicast groups, the broadcast MAC address
+/// for the IPv4 broadcast address, and a locally administered address derived from the IP address otherwise.
+fn mac_address(address: IpAddr) -> [u8; 6] {
+    match address {
+        IpAddr::V4(ip) if ip.is_multicast() => {
+            let [_, b, c, d] = ip.octets();
+            [0x01, 0x00, 0x5E, b & 0x7F, c, d]
+        }
+        IpAddr::V4(ip) if ip.is_broadcast() => [0xFF; 6],
+        IpAddr::V4(ip) => {
+            let [a, b, c, d] = ip.octets();
+            [0x02, 0x00, a, b, c, d]
+        }
+        IpAddr::V6(ip) => {
+            let octets = ip.octets();
+            let prefix = if ip.is_multicast() { 0x33 } else { 0x02 };
+            [
+                prefix, prefix, octets[12], octets[13], octets[14], octets[15],
+            ]
+        }
+    }
+}
+
+fn udp_checksum(source: IpAddr, destination: IpAddr, header: &[u8], payload: &[u8]) -> u16 {
+    let length = header.len() + payload.len();
+    let mut pseudo_header = BytesMut::with_capacity(IPV6_HEADER_OCTETS);
+    match (source, destination) {
+        (IpAddr::V4(source), IpAddr::V4(destination)) => {
+            pseudo_header.put_slice(&source.octets());
+            pseudo_header.put_slice(&destination.octets());
+            pseudo_header.put_u8(0);
+            pseudo_header.put_u8(IP_PROTOCOL_UDP);
+            pseudo_header.put_u16(length as u16);
+        }
+        _ => {
+            pseudo_header.put_slice(&ip_octets(source));
+            pseudo_header.put_slice(&ip_octets(destination));
+            pseudo_header.put_u32(length as u32);
+            pseudo_header.put_u32(u32::from(IP_PROTOCOL_UDP));
+        }
+    }
+    // a computed checksum of zero is transmitted as all ones, as zero means that no checksum is present
+    match internet_checksum(&[&pseudo_header[..], header, payload]) {
+        0 => 0xFFFF,
+        checksum => checksum,
+    }
+}
+
+fn ip_octets(address: IpAddr) -> [u8; 16] {
+    match address {
+        IpAddr::V4(ip) => ip.to_ipv6_mapped().octets(),
+        IpAddr::V6(ip) => ip.octets(),
+    }
+}
+
+/// The one's complement checksum of RFC 1071 over the concatenated parts, which have an even length except the last.
+fn internet_checksum(parts: &[&[u8]]) -> u16 {
+    let mut sum: u32 = parts
+        .iter()
+        .flat_map(|part| part.chunks(2))
+        .map(|pair| {
+            u32::from(u16::from_be_bytes([
+                pair[0],
+                pair.get(1).copied().unwrap_or(0),
+            ]))
+        })
+        .sum();
+    while sum > 0xFFFF {
+        sum = (sum & 0xFFFF) + (sum >> 16);
+    }
+    !(sum as u16)
+}
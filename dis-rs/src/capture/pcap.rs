@@ -0,0 +1,374 @@
+use crate::capture::packet::{ethernet_frame, FrameDecoder, LINKTYPE_ETHERNET};
+use crate::common::model::Pdu;
+use crate::common::parse;
+use crate::DisError;
+use bytes::{BufMut, Bytes, BytesMut};
+use nom::bytes::complete::take;
+use nom::error::{Error, ErrorKind};
+use nom::number::complete as number;
+use nom::number::Endianness;
+use nom::IResult;
+use std::net::SocketAddr;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+const PCAP_MAGIC_MICROSECONDS: u32 = 0xA1B2_C3D4;
+const PCAP_MAGIC_NANOSECONDS: u32 = 0xA1B2_3C4D;
+const PCAP_VERSION_MAJOR: u16 = 2;
+const PCAP_VERSION_MINOR: u16 = 4;
+const PCAP_SNAPLEN: u32 = 262_144;
+
+const PCAPNG_SECTION_HEADER_BLOCK: u32 = 0x0A0D_0D0A;
+const PCAPNG_INTERFACE_DESCRIPTION_BLOCK: u32 = 1;
+const PCAPNG_PACKET_BLOCK: u32 = 2;
+const PCAPNG_SIMPLE_PACKET_BLOCK: u32 = 3;
+const PCAPNG_ENHANCED_PACKET_BLOCK: u32 = 6;
+const PCAPNG_BYTE_ORDER_MAGIC: u32 = 0x1A2B_3C4D;
+const PCAPNG_OPTION_END: u16 = 0;
+const PCAPNG_OPTION_IF_TSRESOL: u16 = 9;
+const PCAPNG_BLOCK_OCTETS: u32 = 12;
+
+const NANOSECONDS_PER_SECOND: u64 = 1_000_000_000;
+const MICROSECONDS_PER_SECOND: u64 = 1_000_000;
+
+/// An UDP datagram extracted from a capture, with its endpoints and the time it was captured.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CapturedDatagram {
+    pub source: SocketAddr,
+    pub destination: SocketAddr,
+    pub captured_at: SystemTime,
+    pub payload: Bytes,
+}
+
+/// A PDU read from a capture, with the endpoints of the datagram that contained it and the time it was captured.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CapturedPdu {
+    pub pdu: Pdu,
+    pub source: SocketAddr,
+    pub destination: SocketAddr,
+    pub captured_at: SystemTime,
+}
+
+/// A link-layer frame in a capture file.
+struct Frame<'a> {
+    link_type: u32,
+    captured_at: SystemTime,
+    data: &'a [u8],
+}
+
+/// The capture interfaces described in a pcapng section.
+struct Interface {
+    link_type: u32,
+    units_per_second: u64,
+}
+
+fn invalid_capture(error: nom::Err<Error<&[u8]>>) -> DisError {
+    let reason = match error {
+        nom::Err::Error(error) | nom::Err::Failure(error) => error.code.description(),
+        nom::Err::Incomplete(_) => "incomplete input",
+    };
+    DisError::InvalidCapture(format!("malformed pcap or pcapng data ({reason})"))
+}
+
+/// Reads the UDP datagrams from a pcap or pcapng capture.
+///
+/// Frames captured on Ethernet (optionally VLAN-tagged), Linux cooked, loopback or raw IP interfaces are supported.
+/// Fragmented IPv4 and IPv6 packets are reassembled, and frames that do not contain an UDP datagram are skipped.
+/// A truncated record at the end of a pcap file, as left by an interrupted capture, is ignored.
+/// Packets from pcapng Simple Packet Blocks have no timestamp, and are reported as captured at the `UNIX_EPOCH`.
+///
+/// # Errors
+/// Returns `DisError::InvalidCapture` when the input is not a valid pcap or pcapng file.
+pub fn read_capture(input: &[u8]) -> Result<Vec<CapturedDatagram>, DisError> {
+    let mut decoder = FrameDecoder::default();
+    Ok(read_frames(input)?
+        .into_iter()
+        .filter_map(|frame| decoder.decode(frame.link_type, frame.data, frame.captured_at))
+        .collect())
+}
+
+/// Reads the DIS PDUs from a pcap or pcapng capture.
+///
+/// Datagrams that do not contain valid DIS PDUs are skipped. See `read_capture` for the supported captures.
+///
+/// # Errors
+/// Returns `DisError::InvalidCapture` when the input is not a valid pcap or pcapng file.
+pub fn read_capture_pdus(input: &[u8]) -> Result<Vec<CapturedPdu>, DisError> {
+    Ok(read_capture(input)?
+        .into_iter()
+        .filter_map(|datagram| {
+            let pdus = parse(&datagram.payload).ok()?;
+            Some(pdus.into_iter().map(move |pdu| CapturedPdu {
+                pdu,
+                source: datagram.source,
+                destination: datagram.destination,
+                captured_at: datagram.captured_at,
+            }))
+        })
+        .flatten()
+        .collect())
+}
+
+/// Writes the datagrams as a pcap file, with synthetic Ethernet, IP and UDP headers.
+///
+/// The MAC addresses are derived from the IP addresses, using the multicast and broadcast MAC addresses
+/// where applicable.
+///
+/// # Errors
+/// Returns `DisError::InvalidCapture` when the source and destination of a datagram use different IP versions,
+/// or when a datagram is too large for an IP packet.
+pub fn write_pcap(datagrams: &[CapturedDatagram]) -> Result<Vec<u8>, DisError> {
+    let mut buf = BytesMut::new();
+    buf.put_u32_le(PCAP_MAGIC_MICROSECONDS);
+    buf.put_u16_le(PCAP_VERSION_MAJOR);
+    buf.put_u16_le(PCAP_VERSION_MINOR);
+    buf.put_u32_le(0);
+    buf.put_u32_le(0);
+    buf.put_u32_le(PCAP_SNAPLEN);
+    buf.put_u32_le(LINKTYPE_ETHERNET);
+
+    for datagram in datagrams {
+        let frame = ethernet_frame(datagram)?;
+        let since_epoch = datagram
+            .captured_at
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default();
+        buf.put_u32_le(since_epoch.as_secs() as u32);
+        buf.put_u32_le(since_epoch.subsec_micros());
+        buf.put_u32_le(frame.len() as u32);
+        buf.put_u32_le(frame.len() as u32);
+        buf.put_slice(&frame);
+    }
+
+    Ok(buf.to_vec())
+}
+
+/// Writes the PDUs as a pcap file, each PDU in its own datagram. See `write_pcap`.
+///
+/// # Errors
+/// Returns a `DisError` when a PDU cannot be serialized, or when `write_pcap` fails.
+pub fn write_pcap_pdus(pdus: &[CapturedPdu]) -> Result<Vec<u8>, DisError> {
+    let datagrams = pdus
+        .iter()
+        .map(|captured| {
+            let mut buf = BytesMut::with_capacity(captured.pdu.pdu_length() as usize);
+            captured.pdu.serialize(&mut buf)?;
+            Ok(CapturedDatagram {
+                source: captured.source,
+                destination: captured.destination,
+                captured_at: captured.captured_at,
+                payload: buf.freeze(),
+            })
+        })
+        .collect::<Result<Vec<_>, DisError>>()?;
+    write_pcap(&datagrams)
+}
+
+fn read_frames(input: &[u8]) -> Result<Vec<Frame<'_>>, DisError> {
+    let magic: [u8; 4] = input
+        .get(..4)
+        .and_then(|magic| magic.try_into().ok())
+        .ok_or_else(|| DisError::InvalidCapture("the input is too short".to_string()))?;
+    // the block type of the pcapng Section Header Block reads the same in both byte orders
+    if u32::from_le_bytes(magic) == PCAPNG_SECTION_HEADER_BLOCK {
+        read_pcapng(input)
+    } else {
+        read_pcap(input, magic)
+    }
+}
+
+fn read_pcap(input: &[u8], magic: [u8; 4]) -> Result<Vec<Frame<'_>>, DisError> {
+    let (endianness, nanoseconds_per_unit) =
+        match (u32::from_le_bytes(magic), u32::from_be_bytes(magic)) {
+            (PCAP_MAGIC_MICROSECONDS, _) => (Endianness::Little, 1_000),
+            (PCAP_MAGIC_NANOSECONDS, _) => (Endianness::Little, 1),
+            (_, PCAP_MAGIC_MICROSECONDS) => (Endianness::Big, 1_000),
+            (_, PCAP_MAGIC_NANOSECONDS) => (Endianness::Big, 1),
+            _ => {
+                return Err(DisError::InvalidCapture(
+                    "the input is not a pcap or pcapng file".to_string(),
+                ))
+            }
+        };
+
+    let (mut input, link_type) = pcap_header(input, endianness).map_err(invalid_capture)?;
+    let mut frames = Vec::new();
+    while let Ok((rest, (seconds, fraction, data))) = pcap_record(input, endianness) {
+        input = rest;
+        frames.push(Frame {
+            link_type,
+            captured_at: UNIX_EPOCH
+                + Duration::from_secs(u64::from(seconds))
+                + Duration::from_nanos(u64::from(fraction) * nanoseconds_per_unit),
+            data,
+        });
+    }
+    Ok(frames)
+}
+
+fn pcap_header(input: &[u8], endianness: Endianness) -> IResult<&[u8], u32> {
+    let (input, _magic_and_version) = take(8usize)(input)?;
+    let (input, _time_zone_and_accuracy) = take(8usize)(input)?;
+    let (input, _snaplen) = number::u32(endianness)(input)?;
+    let (input, link_type) = number::u32(endianness)(input)?;
+    // the upper bits of the link type may contain the FCS length
+    Ok((input, link_type & 0x0FFF_FFFF))
+}
+
+fn pcap_record(input: &[u8], endianness: Endianness) -> IResult<&[u8], (u32, u32, &[u8])> {
+    let (input, seconds) = number::u32(endianness)(input)?;
+    let (input, fraction) = number::u32(endianness)(input)?;
+    let (input, captured_length) = number::u32(endianness)(input)?;
+    let (input, _original_length) = number::u32(endianness)(input)?;
+    let (input, data) = take(captured_length)(input)?;
+    Ok((input, (seconds, fraction, data)))
+}
+
+fn read_pcapng(mut input: &[u8]) -> Result<Vec<Frame<'_>>, DisError> {
+    let mut endianness = Endianness::Little;
+    let mut interfaces: Vec<Interface> = Vec::new();
+    let mut frames = Vec::new();
+
+    while !input.is_empty() {
+        if input.get(..4) == Some(&PCAPNG_SECTION_HEADER_BLOCK.to_le_bytes()[..]) {
+            // each section declares its own byte order
+            let (_, byte_order_magic) =
+                number::u32(Endianness::Little)(input.get(8..).unwrap_or_default())
+                    .map_err(invalid_capture)?;
+            endianness = if byte_order_magic == PCAPNG_BYTE_ORDER_MAGIC {
+                Endianness::Little
+            } else if byte_order_magic.swap_bytes() == PCAPNG_BYTE_ORDER_MAGIC {
+                Endianness::Big
+            } else {
+                return Err(DisError::InvalidCapture(
+                    "the pcapng section has an invalid byte-order magic".to_string(),
+                ));
+            };
+            interfaces.clear();
+        }
+
+        let (rest, (block_type, body)) =
+            pcapng_block(input, endianness).map_err(invalid_capture)?;
+        input = rest;
+        let (interface_id, timestamp, data) = match block_type {
+            PCAPNG_INTERFACE_DESCRIPTION_BLOCK => {
+                let (_, interface) =
+                    interface_description(body, endianness).map_err(invalid_capture)?;
+                interfaces.push(interface);
+                continue;
+            }
+            PCAPNG_ENHANCED_PACKET_BLOCK => {
+                let (_, packet) = enhanced_packet(body, endianness).map_err(invalid_capture)?;
+                packet
+            }
+            PCAPNG_PACKET_BLOCK => {
+                let (_, packet) = obsolete_packet(body, endianness).map_err(invalid_capture)?;
+                packet
+            }
+            PCAPNG_SIMPLE_PACKET_BLOCK => {
+                let (data, original_length) =
+                    number::u32(endianness)(body).map_err(invalid_capture)?;
+                let length = data.len().min(original_length as usize);
+                (0, None, &data[..length])
+            }
+            _ => continue,
+        };
+
+        let interface = interfaces.get(interface_id as usize).ok_or_else(|| {
+            DisError::InvalidCapture(format!(
+                "a packet refers to the undescribed interface {interface_id}"
+            ))
+        })?;
+        frames.push(Frame {
+            link_type: interface.link_type,
+            captured_at: timestamp.map_or(UNIX_EPOCH, |timestamp| interface.time(timestamp)),
+            data,
+        });
+    }
+    Ok(frames)
+}
+
+fn pcapng_block(input: &[u8], endianness: Endianness) -> IResult<&[u8], (u32, &[u8])> {
+    let (input, block_type) = number::u32(endianness)(input)?;
+    let (input, total_length) = number::u32(endianness)(input)?;
+    if total_length < PCAPNG_BLOCK_OCTETS {
+        return Err(nom::Err::Error(Error::new(input, ErrorKind::Verify)));
+    }
+    let (input, body) = take(total_length - PCAPNG_BLOCK_OCTETS)(input)?;
+    let (input, _total_length) = number::u32(endianness)(input)?;
+    Ok((input, (block_type, body)))
+}
+
+fn interface_description(input: &[u8], endianness: Endianness) -> IResult<&[u8], Interface> {
+    let (input, link_type) = number::u16(endianness)(input)?;
+    let (input, _reserved) = number::u16(endianness)(input)?;
+    let (mut input, _snaplen) = number::u32(endianness)(input)?;
+
+    let mut units_per_second = MICROSECONDS_PER_SECOND;
+    while !input.is_empty() {
+        let (rest, code) = number::u16(endianness)(input)?;
+        let (rest, length) = number::u16(endianness)(rest)?;
+        let (rest, value) = take(length)(rest)?;
+        let (rest, _padding) = take((4 - length % 4) % 4)(rest)?;
+        input = rest;
+        match (code, value) {
+            (PCAPNG_OPTION_END, _) => break,
+            (PCAPNG_OPTION_IF_TSRESOL, [resolution]) => {
+                units_per_second = if resolution & 0x80 == 0 {
+                    10u64.saturating_pow(u32::from(*resolution))
+                } else {
+                    2u64.saturating_pow(u32::from(resolution & 0x7F))
+                };
+            }
+            _ => {}
+        }
+    }
+
+    Ok((
+        input,
+        Interface {
+            link_type: u32::from(link_type),
+            units_per_second: units_per_second.max(1),
+        },
+    ))
+}
+
+type PacketData<'a> = (u32, Option<u64>, &'a [u8]);
+
+fn enhanced_packet(input: &[u8], endianness: Endianness) -> IResult<&[u8], PacketData<'_>> {
+    let (input, interface_id) = number::u32(endianness)(input)?;
+    let (input, (timestamp, data)) = packet_fields(input, endianness)?;
+    Ok((input, (interface_id, Some(timestamp), data)))
+}
+
+fn obsolete_packet(input: &[u8], endianness: Endianness) -> IResult<&[u8], PacketData<'_>> {
+    let (input, interface_id) = number::u16(endianness)(input)?;
+    let (input, _drops_count) = number::u16(endianness)(input)?;
+    let (input, (timestamp, data)) = packet_fields(input, endianness)?;
+    Ok((input, (u32::from(interface_id), Some(timestamp), data)))
+}
+
+/// The timestamp and captured data of the (Enhanced) Packet Block.
+fn packet_fields(input: &[u8], endianness: Endianness) -> IResult<&[u8], (u64, &[u8])> {
+    let (input, timestamp_high) = number::u32(endianness)(input)?;
+    let (input, timestamp_low) = number::u32(endianness)(input)?;
+    let (input, captured_length) = number::u32(endianness)(input)?;
+    let (input, _original_length) = number::u32(endianness)(input)?;
+    let (input, data) = take(captured_length)(input)?;
+    Ok((
+        input,
+        (
+            (u64::from(timestamp_high) << 32) | u64::from(timestamp_low),
+            data,
+        ),
+    ))
+}
+
+impl Interface {
+    fn time(&self, timestamp: u64) -> SystemTime {
+        let seconds = timestamp / self.units_per_second;
+        let fraction = u128::from(timestamp % self.units_per_second);
+        let nanoseconds =
+            fraction * u128::from(NANOSECONDS_PER_SECOND) / u128::from(self.units_per_second);
+        UNIX_EPOCH + Duration::from_secs(seconds) + Duration::from_nanos(nanoseconds as u64)
+    }
+}
@@ -134,7 +134,7 @@ fn silent_aggregate_system(input: &[u8]) -> IResult<&[u8], SilentAggregateSystem
     ))
 }
 
-fn silent_entity_system(input: &[u8]) -> IResult<&[u8], SilentEntitySystem> {
+pub(crate) fn silent_entity_system(input: &[u8]) -> IResult<&[u8], SilentEntitySystem> {
     let (input, number_of_entities) = be_u16(input)?;
     let (input, number_of_appearance_records) = be_u16(input)?;
     let (input, entity_type) = entity_type(input)?;
@@ -5,11 +5,16 @@
 
 #[cfg(test)]
 mod tests {
-    use crate::common::attribute::model::Attribute;
+    use crate::common::attribute::model::{
+        AngleDeception, Attribute, AttributeRecord, AttributeRecordFields, AttributeRecordKind,
+        AttributeRecordSet, AttributeStateIndicator, BlankingSector, CommunicationsNodeId,
+        EntityDestination, FalseTargets, GroupAssignment, GroupDestination,
+    };
     use crate::common::model::DisTimeStamp;
-    use crate::common::model::{Pdu, PduHeader, SimulationAddress};
+    use crate::common::model::{EntityId, Pdu, PduBody, PduHeader, SimulationAddress};
     use crate::common::parser::parse_pdu;
-    use crate::enumerations::{AttributeActionCode, PduType, ProtocolVersion};
+    use crate::enumerations::{AttributeActionCode, PduType, ProtocolVersion, VariableRecordType};
+    use crate::{parse_with_options, ParseOptions};
     use bytes::BytesMut;
 
     #[test]
@@ -44,4 +49,143 @@ fn attribute_internal_consistency() {
             }
         }
     }
+
+    #[test]
+    fn attribute_typed_records_internal_consistency() {
+        let header = PduHeader::new_v7(1, PduType::Attribute);
+
+        let record_set = AttributeRecordSet::new()
+            .with_entity_id(EntityId::new(10, 10, 1))
+            .with_attribute_record(
+                BlankingSector::default()
+                    .with_emitter_number(1)
+                    .with_beam_number(2)
+                    .with_state_indicator(AttributeStateIndicator::ChangedData)
+                    .with_left_azimuth(-0.5)
+                    .with_right_azimuth(0.5)
+                    .with_residual_power(-20.0)
+                    .to_attribute_record(),
+            )
+            .with_attribute_record(
+                AngleDeception::default()
+                    .with_emitter_number(1)
+                    .with_azimuth_offset(0.1)
+                    .with_elevation_pull_acceleration(0.01)
+                    .to_attribute_record(),
+            )
+            .with_attribute_record(
+                FalseTargets::default()
+                    .with_false_target_count(4)
+                    .with_echo_spacing(150.0)
+                    .to_attribute_record(),
+            )
+            .with_attribute_record(
+                AttributeRecord::new()
+                    .with_record_type(VariableRecordType::from(99_999))
+                    .with_specific_fields(AttributeRecordFields::Unspecified(vec![1; 10])),
+            );
+        let body = Attribute::builder()
+            .with_originating_simulation_address(SimulationAddress::new(10, 10))
+            .with_action_code(AttributeActionCode::NoStatement)
+            .with_record_protocol_version(ProtocolVersion::IEEE1278_12012)
+            .with_record_pdu_type(PduType::ElectromagneticEmission)
+            .with_attribute_record_set(record_set)
+            .build()
+            .into_pdu_body();
+        let original_pdu =
+            Pdu::finalize_from_parts(header, body, DisTimeStamp::new_absolute_from_secs(100));
+        let pdu_length = original_pdu.header.pdu_length;
+        let original_length = original_pdu.pdu_length();
+
+        let mut buf = BytesMut::with_capacity(pdu_length as usize);
+
+        let serialized_length = original_pdu.serialize(&mut buf).unwrap();
+
+        assert_eq!(original_length, serialized_length);
+
+        let parsed = parse_pdu(&buf);
+        match parsed {
+            Ok(ref pdu) => {
+                assert_eq!(&original_pdu, pdu);
+            }
+            Err(ref err) => {
+                panic!("Parse error: {err}");
+            }
+        }
+    }
+
+    #[test]
+    fn attribute_communications_records_internal_consistency() {
+        let entity_destination_type = VariableRecordType::from(500_000);
+        let group_destination_type = VariableRecordType::from(500_001);
+        let group_assignment_type = VariableRecordType::from(500_002);
+        let node_id = CommunicationsNodeId::new(EntityId::new(10, 10, 2), 1);
+
+        let record_set = AttributeRecordSet::new()
+            .with_entity_id(EntityId::new(10, 10, 1))
+            .with_attribute_record(
+                EntityDestination::default()
+                    .with_destination_communications_device_id(node_id)
+                    .with_destination_line_id(2)
+                    .with_destination_priority(3)
+                    .with_line_state_command(1)
+                    .to_attribute_record(entity_destination_type),
+            )
+            .with_attribute_record(
+                GroupDestination::default()
+                    .with_group_bit_field(0b1010)
+                    .with_destination_priority(1)
+                    .with_line_state_command(2)
+                    .to_attribute_record(group_destination_type),
+            )
+            .with_attribute_record(
+                GroupAssignment::default()
+                    .with_group_bit_field(0b0110)
+                    .with_destination_communications_device_id(node_id)
+                    .with_destination_line_id(4)
+                    .to_attribute_record(group_assignment_type),
+            );
+        let body = Attribute::builder()
+            .with_originating_simulation_address(SimulationAddress::new(10, 10))
+            .with_action_code(AttributeActionCode::NoStatement)
+            .with_record_protocol_version(ProtocolVersion::IEEE1278_12012)
+            .with_record_pdu_type(PduType::IntercomSignal)
+            .with_attribute_record_set(record_set)
+            .build()
+            .into_pdu_body();
+        let original_pdu = Pdu::finalize_from_parts(
+            PduHeader::new_v7(1, PduType::Attribute),
+            body,
+            DisTimeStamp::new_absolute_from_secs(100),
+        );
+
+        let mut buf = BytesMut::with_capacity(original_pdu.header.pdu_length as usize);
+        let serialized_length = original_pdu.serialize(&mut buf).unwrap();
+        assert_eq!(original_pdu.pdu_length(), serialized_length);
+
+        let options = ParseOptions::new()
+            .with_attribute_record_kind(
+                entity_destination_type,
+                AttributeRecordKind::EntityDestination,
+            )
+            .with_attribute_record_kind(
+                group_destination_type,
+                AttributeRecordKind::GroupDestination,
+            )
+            .with_attribute_record_kind(
+                group_assignment_type,
+                AttributeRecordKind::GroupAssignment,
+            );
+        let report = parse_with_options(&buf, &options).unwrap();
+        assert_eq!(report.pdus, vec![original_pdu]);
+
+        let unregistered = parse_pdu(&buf).unwrap();
+        let PduBody::Attribute(attribute) = unregistered.body else {
+            panic!("Expected an Attribute PDU body");
+        };
+        assert!(matches!(
+            attribute.attribute_record_sets[0].attribute_records[0].specific_fields,
+            AttributeRecordFields::Unspecified(_)
+        ));
+    }
 }
@@ -1,6 +1,7 @@
 use crate::common::attribute::builder::AttributeBuilder;
-use crate::common::model::{EntityId, PduBody, SimulationAddress};
+use crate::common::model::{length_padded_to_num, EntityId, PduBody, SimulationAddress};
 use crate::common::{BodyInfo, Interaction};
+use crate::constants::EIGHT_OCTETS;
 use crate::enumerations::{AttributeActionCode, PduType, ProtocolVersion, VariableRecordType};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -8,6 +9,12 @@
 pub const BASE_ATTRIBUTE_BODY_LENGTH: u16 = 20;
 pub const BASE_ATTRIBUTE_RECORD_SET_LENGTH: u16 = 8;
 pub const BASE_ATTRIBUTE_RECORD_LENGTH_OCTETS: u16 = 6;
+pub const BLANKING_SECTOR_FIELDS_LENGTH_OCTETS: u16 = 34;
+pub const ANGLE_DECEPTION_FIELDS_LENGTH_OCTETS: u16 = 42;
+pub const FALSE_TARGETS_FIELDS_LENGTH_OCTETS: u16 = 34;
+pub const ENTITY_DESTINATION_FIELDS_LENGTH_OCTETS: u16 = 12;
+pub const GROUP_DESTINATION_FIELDS_LENGTH_OCTETS: u16 = 8;
+pub const GROUP_ASSIGNMENT_FIELDS_LENGTH_OCTETS: u16 = 16;
 
 /// 5.3.6 Attribute PDU
 ///
@@ -51,10 +58,7 @@ fn body_length(&self) -> u16 {
                         + set
                             .attribute_records
                             .iter()
-                            .map(|record| {
-                                BASE_ATTRIBUTE_RECORD_LENGTH_OCTETS
-                                    + record.specific_fields.len() as u16
-                            })
+                            .map(AttributeRecord::record_length)
                             .sum::<u16>()
                 })
                 .sum::<u16>()
@@ -122,7 +126,7 @@ pub fn with_attribute_record(mut self, attribute_record: AttributeRecord) -> Sel
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct AttributeRecord {
     pub record_type: VariableRecordType,
-    pub specific_fields: Vec<u8>,
+    pub specific_fields: AttributeRecordFields,
 }
 
 impl Default for AttributeRecord {
@@ -136,7 +140,7 @@ impl AttributeRecord {
     pub fn new() -> Self {
         Self {
             record_type: VariableRecordType::default(),
-            specific_fields: vec![],
+            specific_fields: AttributeRecordFields::Unspecified(vec![]),
         }
     }
 
@@ -147,8 +151,482 @@ pub fn with_record_type(mut self, record_type: VariableRecordType) -> Self {
     }
 
     #[must_use]
-    pub fn with_specific_fields(mut self, specific_fields: Vec<u8>) -> Self {
+    pub fn with_specific_fields(mut self, specific_fields: AttributeRecordFields) -> Self {
         self.specific_fields = specific_fields;
         self
     }
+
+    /// The length of the record in octets, including padding to a 64-bit boundary.
+    #[must_use]
+    pub fn record_length(&self) -> u16 {
+        length_padded_to_num(
+            (BASE_ATTRIBUTE_RECORD_LENGTH_OCTETS + self.specific_fields.length()) as usize,
+            EIGHT_OCTETS,
+        )
+        .record_length as u16
+    }
+}
+
+/// The record specific fields of an Attribute record.
+///
+/// Record types that are not modelled by the library are kept as raw bytes in the `Unspecified` variant.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum AttributeRecordFields {
+    BlankingSector(BlankingSector),
+    AngleDeception(AngleDeception),
+    FalseTargets(FalseTargets),
+    EntityDestination(EntityDestination),
+    GroupDestination(GroupDestination),
+    GroupAssignment(GroupAssignment),
+    Unspecified(Vec<u8>),
+}
+
+impl AttributeRecordFields {
+    /// The length of the record specific fields in octets, excluding padding.
+    #[must_use]
+    pub fn length(&self) -> u16 {
+        match self {
+            AttributeRecordFields::BlankingSector(_) => BLANKING_SECTOR_FIELDS_LENGTH_OCTETS,
+            AttributeRecordFields::AngleDeception(_) => ANGLE_DECEPTION_FIELDS_LENGTH_OCTETS,
+            AttributeRecordFields::FalseTargets(_) => FALSE_TARGETS_FIELDS_LENGTH_OCTETS,
+            AttributeRecordFields::EntityDestination(_) => ENTITY_DESTINATION_FIELDS_LENGTH_OCTETS,
+            AttributeRecordFields::GroupDestination(_) => GROUP_DESTINATION_FIELDS_LENGTH_OCTETS,
+            AttributeRecordFields::GroupAssignment(_) => GROUP_ASSIGNMENT_FIELDS_LENGTH_OCTETS,
+            AttributeRecordFields::Unspecified(fields) => fields.len() as u16,
+        }
+    }
+}
+
+/// The communications and intercom records that can be carried as the record specific fields of an Attribute record.
+///
+/// The enumerations do not assign record types to these records, so the record types used for them are agreed
+/// per exercise. Attribute records of such a record type are parsed as the configured kind of record when the
+/// record type is registered using `ParseOptions::with_attribute_record_kind`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum AttributeRecordKind {
+    EntityDestination,
+    GroupDestination,
+    GroupAssignment,
+}
+
+/// EE Attribute State Indicator, used in the Electronic Warfare attribute records.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum AttributeStateIndicator {
+    #[default]
+    HeartbeatUpdate,
+    ChangedData,
+    HasCeased,
+    Unspecified(u8),
+}
+
+impl From<u8> for AttributeStateIndicator {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => AttributeStateIndicator::HeartbeatUpdate,
+            1 => AttributeStateIndicator::ChangedData,
+            2 => AttributeStateIndicator::HasCeased,
+            unspecified_value => AttributeStateIndicator::Unspecified(unspecified_value),
+        }
+    }
+}
+
+impl From<AttributeStateIndicator> for u8 {
+    fn from(value: AttributeStateIndicator) -> Self {
+        match value {
+            AttributeStateIndicator::HeartbeatUpdate => 0,
+            AttributeStateIndicator::ChangedData => 1,
+            AttributeStateIndicator::HasCeased => 2,
+            AttributeStateIndicator::Unspecified(unspecified_value) => unspecified_value,
+        }
+    }
+}
+
+/// 6.2.21.2 Blanking Sector attribute record
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct BlankingSector {
+    pub emitter_number: u8,
+    pub beam_number: u8,
+    pub state_indicator: AttributeStateIndicator,
+    pub left_azimuth: f32,
+    pub right_azimuth: f32,
+    pub lower_elevation: f32,
+    pub upper_elevation: f32,
+    pub residual_power: f32,
+}
+
+impl BlankingSector {
+    #[must_use]
+    pub fn with_emitter_number(mut self, emitter_number: u8) -> Self {
+        self.emitter_number = emitter_number;
+        self
+    }
+
+    #[must_use]
+    pub fn with_beam_number(mut self, beam_number: u8) -> Self {
+        self.beam_number = beam_number;
+        self
+    }
+
+    #[must_use]
+    pub fn with_state_indicator(mut self, state_indicator: AttributeStateIndicator) -> Self {
+        self.state_indicator = state_indicator;
+        self
+    }
+
+    #[must_use]
+    pub fn with_left_azimuth(mut self, left_azimuth: f32) -> Self {
+        self.left_azimuth = left_azimuth;
+        self
+    }
+
+    #[must_use]
+    pub fn with_right_azimuth(mut self, right_azimuth: f32) -> Self {
+        self.right_azimuth = right_azimuth;
+        self
+    }
+
+    #[must_use]
+    pub fn with_lower_elevation(mut self, lower_elevation: f32) -> Self {
+        self.lower_elevation = lower_elevation;
+        self
+    }
+
+    #[must_use]
+    pub fn with_upper_elevation(mut self, upper_elevation: f32) -> Self {
+        self.upper_elevation = upper_elevation;
+        self
+    }
+
+    #[must_use]
+    pub fn with_residual_power(mut self, residual_power: f32) -> Self {
+        self.residual_power = residual_power;
+        self
+    }
+
+    #[must_use]
+    pub fn to_attribute_record(self) -> AttributeRecord {
+        AttributeRecord::new()
+            .with_record_type(VariableRecordType::BlankingSectorAttributeRecord_3500)
+            .with_specific_fields(AttributeRecordFields::BlankingSector(self))
+    }
+}
+
+/// 6.2.21.3 Angle Deception attribute record
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AngleDeception {
+    pub emitter_number: u8,
+    pub beam_number: u8,
+    pub state_indicator: AttributeStateIndicator,
+    pub azimuth_offset: f32,
+    pub azimuth_width: f32,
+    pub azimuth_pull_rate: f32,
+    pub azimuth_pull_acceleration: f32,
+    pub elevation_offset: f32,
+    pub elevation_width: f32,
+    pub elevation_pull_rate: f32,
+    pub elevation_pull_acceleration: f32,
+}
+
+impl AngleDeception {
+    #[must_use]
+    pub fn with_emitter_number(mut self, emitter_number: u8) -> Self {
+        self.emitter_number = emitter_number;
+        self
+    }
+
+    #[must_use]
+    pub fn with_beam_number(mut self, beam_number: u8) -> Self {
+        self.beam_number = beam_number;
+        self
+    }
+
+    #[must_use]
+    pub fn with_state_indicator(mut self, state_indicator: AttributeStateIndicator) -> Self {
+        self.state_indicator = state_indicator;
+        self
+    }
+
+    #[must_use]
+    pub fn with_azimuth_offset(mut self, azimuth_offset: f32) -> Self {
+        self.azimuth_offset = azimuth_offset;
+        self
+    }
+
+    #[must_use]
+    pub fn with_azimuth_width(mut self, azimuth_width: f32) -> Self {
+        self.azimuth_width = azimuth_width;
+        self
+    }
+
+    #[must_use]
+    pub fn with_azimuth_pull_rate(mut self, azimuth_pull_rate: f32) -> Self {
+        self.azimuth_pull_rate = azimuth_pull_rate;
+        self
+    }
+
+    #[must_use]
+    pub fn with_azimuth_pull_acceleration(mut self, azimuth_pull_acceleration: f32) -> Self {
+        self.azimuth_pull_acceleration = azimuth_pull_acceleration;
+        self
+    }
+
+    #[must_use]
+    pub fn with_elevation_offset(mut self, elevation_offset: f32) -> Self {
+        self.elevation_offset = elevation_offset;
+        self
+    }
+
+    #[must_use]
+    pub fn with_elevation_width(mut self, elevation_width: f32) -> Self {
+        self.elevation_width = elevation_width;
+        self
+    }
+
+    #[must_use]
+    pub fn with_elevation_pull_rate(mut self, elevation_pull_rate: f32) -> Self {
+        self.elevation_pull_rate = elevation_pull_rate;
+        self
+    }
+
+    #[must_use]
+    pub fn with_elevation_pull_acceleration(mut self, elevation_pull_acceleration: f32) -> Self {
+        self.elevation_pull_acceleration = elevation_pull_acceleration;
+        self
+    }
+
+    #[must_use]
+    pub fn to_attribute_record(self) -> AttributeRecord {
+        AttributeRecord::new()
+            .with_record_type(VariableRecordType::AngleDeceptionAttributeRecord_3501)
+            .with_specific_fields(AttributeRecordFields::AngleDeception(self))
+    }
+}
+
+/// 6.2.21.4 False Targets attribute record
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct FalseTargets {
+    pub emitter_number: u8,
+    pub beam_number: u8,
+    pub state_indicator: AttributeStateIndicator,
+    pub false_target_count: u16,
+    pub walk_speed: f32,
+    pub walk_acceleration: f32,
+    pub maximum_walk_distance: f32,
+    pub keep_time: f32,
+    pub echo_spacing: f32,
+    pub first_target_offset: f32,
+}
+
+impl FalseTargets {
+    #[must_use]
+    pub fn with_emitter_number(mut self, emitter_number: u8) -> Self {
+        self.emitter_number = emitter_number;
+        self
+    }
+
+    #[must_use]
+    pub fn with_beam_number(mut self, beam_number: u8) -> Self {
+        self.beam_number = beam_number;
+        self
+    }
+
+    #[must_use]
+    pub fn with_state_indicator(mut self, state_indicator: AttributeStateIndicator) -> Self {
+        self.state_indicator = state_indicator;
+        self
+    }
+
+    #[must_use]
+    pub fn with_false_target_count(mut self, false_target_count: u16) -> Self {
+        self.false_target_count = false_target_count;
+        self
+    }
+
+    #[must_use]
+    pub fn with_walk_speed(mut self, walk_speed: f32) -> Self {
+        self.walk_speed = walk_speed;
+        self
+    }
+
+    #[must_use]
+    pub fn with_walk_acceleration(mut self, walk_acceleration: f32) -> Self {
+        self.walk_acceleration = walk_acceleration;
+        self
+    }
+
+    #[must_use]
+    pub fn with_maximum_walk_distance(mut self, maximum_walk_distance: f32) -> Self {
+        self.maximum_walk_distance = maximum_walk_distance;
+        self
+    }
+
+    #[must_use]
+    pub fn with_keep_time(mut self, keep_time: f32) -> Self {
+        self.keep_time = keep_time;
+        self
+    }
+
+    #[must_use]
+    pub fn with_echo_spacing(mut self, echo_spacing: f32) -> Self {
+        self.echo_spacing = echo_spacing;
+        self
+    }
+
+    #[must_use]
+    pub fn with_first_target_offset(mut self, first_target_offset: f32) -> Self {
+        self.first_target_offset = first_target_offset;
+        self
+    }
+
+    #[must_use]
+    pub fn to_attribute_record(self) -> AttributeRecord {
+        AttributeRecord::new()
+            .with_record_type(VariableRecordType::FalseTargetsAttributeRecord_3502)
+            .with_specific_fields(AttributeRecordFields::FalseTargets(self))
+    }
+}
+
+/// Communications Node ID record
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CommunicationsNodeId {
+    pub entity_id: EntityId,
+    pub element_id: u16,
+}
+
+impl CommunicationsNodeId {
+    #[must_use]
+    pub fn new(entity_id: EntityId, element_id: u16) -> Self {
+        Self {
+            entity_id,
+            element_id,
+        }
+    }
+}
+
+/// Entity Destination record of the Intercom Communications Parameters
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct EntityDestination {
+    pub destination_communications_device_id: CommunicationsNodeId,
+    pub destination_line_id: u8,
+    pub destination_priority: u8,
+    pub line_state_command: u8,
+}
+
+impl EntityDestination {
+    #[must_use]
+    pub fn with_destination_communications_device_id(
+        mut self,
+        destination_communications_device_id: CommunicationsNodeId,
+    ) -> Self {
+        self.destination_communications_device_id = destination_communications_device_id;
+        self
+    }
+
+    #[must_use]
+    pub fn with_destination_line_id(mut self, destination_line_id: u8) -> Self {
+        self.destination_line_id = destination_line_id;
+        self
+    }
+
+    #[must_use]
+    pub fn with_destination_priority(mut self, destination_priority: u8) -> Self {
+        self.destination_priority = destination_priority;
+        self
+    }
+
+    #[must_use]
+    pub fn with_line_state_command(mut self, line_state_command: u8) -> Self {
+        self.line_state_command = line_state_command;
+        self
+    }
+
+    #[must_use]
+    pub fn to_attribute_record(self, record_type: VariableRecordType) -> AttributeRecord {
+        AttributeRecord::new()
+            .with_record_type(record_type)
+            .with_specific_fields(AttributeRecordFields::EntityDestination(self))
+    }
+}
+
+/// Group Destination record of the Intercom Communications Parameters
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GroupDestination {
+    pub group_bit_field: u32,
+    pub destination_priority: u8,
+    pub line_state_command: u8,
+}
+
+impl GroupDestination {
+    #[must_use]
+    pub fn with_group_bit_field(mut self, group_bit_field: u32) -> Self {
+        self.group_bit_field = group_bit_field;
+        self
+    }
+
+    #[must_use]
+    pub fn with_destination_priority(mut self, destination_priority: u8) -> Self {
+        self.destination_priority = destination_priority;
+        self
+    }
+
+    #[must_use]
+    pub fn with_line_state_command(mut self, line_state_command: u8) -> Self {
+        self.line_state_command = line_state_command;
+        self
+    }
+
+    #[must_use]
+    pub fn to_attribute_record(self, record_type: VariableRecordType) -> AttributeRecord {
+        AttributeRecord::new()
+            .with_record_type(record_type)
+            .with_specific_fields(AttributeRecordFields::GroupDestination(self))
+    }
+}
+
+/// Group Assignment record of the Intercom Communications Parameters
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GroupAssignment {
+    pub group_bit_field: u32,
+    pub destination_communications_device_id: CommunicationsNodeId,
+    pub destination_line_id: u8,
+}
+
+impl GroupAssignment {
+    #[must_use]
+    pub fn with_group_bit_field(mut self, group_bit_field: u32) -> Self {
+        self.group_bit_field = group_bit_field;
+        self
+    }
+
+    #[must_use]
+    pub fn with_destination_communications_device_id(
+        mut self,
+        destination_communications_device_id: CommunicationsNodeId,
+    ) -> Self {
+        self.destination_communications_device_id = destination_communications_device_id;
+        self
+    }
+
+    #[must_use]
+    pub fn with_destination_line_id(mut self, destination_line_id: u8) -> Self {
+        self.destination_line_id = destination_line_id;
+        self
+    }
+
+    #[must_use]
+    pub fn to_attribute_record(self, record_type: VariableRecordType) -> AttributeRecord {
+        AttributeRecord::new()
+            .with_record_type(record_type)
+            .with_specific_fields(AttributeRecordFields::GroupAssignment(self))
+    }
 }
@@ -1,65 +1,300 @@
 use crate::common::attribute::model::{
-    Attribute, AttributeRecord, AttributeRecordSet, BASE_ATTRIBUTE_RECORD_LENGTH_OCTETS,
+    AngleDeception, Attribute, AttributeRecord, AttributeRecordFields, AttributeRecordKind,
+    AttributeRecordSet, AttributeStateIndicator, BlankingSector, CommunicationsNodeId,
+    EntityDestination, FalseTargets, GroupAssignment, GroupDestination,
+    ANGLE_DECEPTION_FIELDS_LENGTH_OCTETS, BASE_ATTRIBUTE_RECORD_LENGTH_OCTETS,
+    BLANKING_SECTOR_FIELDS_LENGTH_OCTETS, ENTITY_DESTINATION_FIELDS_LENGTH_OCTETS,
+    FALSE_TARGETS_FIELDS_LENGTH_OCTETS, GROUP_ASSIGNMENT_FIELDS_LENGTH_OCTETS,
+    GROUP_DESTINATION_FIELDS_LENGTH_OCTETS,
 };
 use crate::common::model::PduBody;
 use crate::common::parser::{entity_id, pdu_type, protocol_version, simulation_address};
+use crate::common::ParseOptions;
 use crate::enumerations::{AttributeActionCode, VariableRecordType};
 use nom::bytes::complete::take;
 use nom::multi::count;
-use nom::number::complete::{be_u16, be_u32, be_u8};
+use nom::number::complete::{be_f32, be_u16, be_u32, be_u8};
 use nom::IResult;
 
-pub(crate) fn attribute_body(input: &[u8]) -> IResult<&[u8], PduBody> {
-    let (input, origination_simulation_address) = simulation_address(input)?;
-    let (input, _padding) = be_u32(input)?;
+pub(crate) fn attribute_body(
+    options: &ParseOptions,
+) -> impl Fn(&[u8]) -> IResult<&[u8], PduBody> + '_ {
+    move |input: &[u8]| {
+        let (input, origination_simulation_address) = simulation_address(input)?;
+        let (input, _padding) = be_u32(input)?;
+        let (input, _padding) = be_u16(input)?;
+        let (input, record_pdu_type) = pdu_type(input)?;
+        let (input, record_protocol_version) = protocol_version(input)?;
+        let (input, master_attribute_record_type) = be_u32(input)?;
+        let master_attribute_record_type = VariableRecordType::from(master_attribute_record_type);
+        let (input, action_code) = be_u8(input)?;
+        let action_code = AttributeActionCode::from(action_code);
+        let (input, _padding) = be_u8(input)?;
+        let (input, number_of_record_sets) = be_u16(input)?;
+        let (input, attribute_record_sets) =
+            count(attribute_record_set(options), number_of_record_sets.into())(input)?;
+
+        let body = Attribute::builder()
+            .with_originating_simulation_address(origination_simulation_address)
+            .with_record_pdu_type(record_pdu_type)
+            .with_record_protocol_version(record_protocol_version)
+            .with_master_attribute_record_type(master_attribute_record_type)
+            .with_action_code(action_code)
+            .with_attribute_record_sets(attribute_record_sets)
+            .build();
+
+        Ok((input, body.into_pdu_body()))
+    }
+}
+
+pub(crate) fn attribute_record_set(
+    options: &ParseOptions,
+) -> impl Fn(&[u8]) -> IResult<&[u8], AttributeRecordSet> + '_ {
+    move |input: &[u8]| {
+        let (input, entity_id) = entity_id(input)?;
+        let (input, number_of_records) = be_u16(input)?;
+        let (input, attribute_records) =
+            count(attribute_record(options), number_of_records.into())(input)?;
+
+        Ok((
+            input,
+            AttributeRecordSet::new()
+                .with_entity_id(entity_id)
+                .with_attribute_records(attribute_records),
+        ))
+    }
+}
+
+pub(crate) fn attribute_record(
+    options: &ParseOptions,
+) -> impl Fn(&[u8]) -> IResult<&[u8], AttributeRecord> + '_ {
+    move |input: &[u8]| {
+        let (input, record_type) = be_u32(input)?;
+        let (input, record_length_octets) = be_u16(input)?;
+        let (input, fields) =
+            take(record_length_octets.saturating_sub(BASE_ATTRIBUTE_RECORD_LENGTH_OCTETS))(input)?;
+        let record_type = VariableRecordType::from(record_type);
+        let specific_fields = attribute_record_fields(
+            record_type,
+            options.attribute_record_kind(record_type),
+            fields,
+        );
+
+        Ok((
+            input,
+            AttributeRecord::new()
+                .with_record_type(record_type)
+                .with_specific_fields(specific_fields),
+        ))
+    }
+}
+
+/// Parses the record specific fields of an Attribute record, based on the record type,
+/// or on the `kind` of record registered for the record type.
+///
+/// Fields of record types that are not modelled, or that do not have the length defined for the record type,
+/// are kept as raw bytes.
+fn attribute_record_fields(
+    record_type: VariableRecordType,
+    kind: Option<AttributeRecordKind>,
+    fields: &[u8],
+) -> AttributeRecordFields {
+    let typed_fields = match (kind, fields.len() as u16) {
+        (
+            Some(AttributeRecordKind::EntityDestination),
+            ENTITY_DESTINATION_FIELDS_LENGTH_OCTETS..,
+        ) => entity_destination(fields).ok(),
+        (Some(AttributeRecordKind::GroupDestination), GROUP_DESTINATION_FIELDS_LENGTH_OCTETS..) => {
+            group_destination(fields).ok()
+        }
+        (Some(AttributeRecordKind::GroupAssignment), GROUP_ASSIGNMENT_FIELDS_LENGTH_OCTETS..) => {
+            group_assignment(fields).ok()
+        }
+        (Some(_), _) => None,
+        (None, length) => ew_attribute_record_fields(record_type, length, fields),
+    };
+
+    typed_fields.map_or_else(
+        || AttributeRecordFields::Unspecified(fields.to_vec()),
+        |(_input, typed_fields)| typed_fields,
+    )
+}
+
+fn ew_attribute_record_fields(
+    record_type: VariableRecordType,
+    length: u16,
+    fields: &[u8],
+) -> Option<(&[u8], AttributeRecordFields)> {
+    match (record_type, length) {
+        (
+            VariableRecordType::BlankingSectorAttributeRecord_3500,
+            BLANKING_SECTOR_FIELDS_LENGTH_OCTETS,
+        ) => blanking_sector(fields).ok(),
+        (
+            VariableRecordType::AngleDeceptionAttributeRecord_3501,
+            ANGLE_DECEPTION_FIELDS_LENGTH_OCTETS,
+        ) => angle_deception(fields).ok(),
+        (
+            VariableRecordType::FalseTargetsAttributeRecord_3502,
+            FALSE_TARGETS_FIELDS_LENGTH_OCTETS,
+        ) => false_targets(fields).ok(),
+        _ => None,
+    }
+}
+
+fn ew_attribute_header(input: &[u8]) -> IResult<&[u8], (u8, u8, AttributeStateIndicator)> {
     let (input, _padding) = be_u16(input)?;
-    let (input, record_pdu_type) = pdu_type(input)?;
-    let (input, record_protocol_version) = protocol_version(input)?;
-    let (input, master_attribute_record_type) = be_u32(input)?;
-    let master_attribute_record_type = VariableRecordType::from(master_attribute_record_type);
-    let (input, action_code) = be_u8(input)?;
-    let action_code = AttributeActionCode::from(action_code);
+    let (input, emitter_number) = be_u8(input)?;
+    let (input, beam_number) = be_u8(input)?;
+    let (input, state_indicator) = be_u8(input)?;
+    let state_indicator = AttributeStateIndicator::from(state_indicator);
     let (input, _padding) = be_u8(input)?;
-    let (input, number_of_record_sets) = be_u16(input)?;
-    let (input, attribute_record_sets) =
-        count(attribute_record_set, number_of_record_sets.into())(input)?;
-
-    let body = Attribute::builder()
-        .with_originating_simulation_address(origination_simulation_address)
-        .with_record_pdu_type(record_pdu_type)
-        .with_record_protocol_version(record_protocol_version)
-        .with_master_attribute_record_type(master_attribute_record_type)
-        .with_action_code(action_code)
-        .with_attribute_record_sets(attribute_record_sets)
-        .build();
-
-    Ok((input, body.into_pdu_body()))
+
+    Ok((input, (emitter_number, beam_number, state_indicator)))
+}
+
+fn blanking_sector(input: &[u8]) -> IResult<&[u8], AttributeRecordFields> {
+    let (input, (emitter_number, beam_number, state_indicator)) = ew_attribute_header(input)?;
+    let (input, left_azimuth) = be_f32(input)?;
+    let (input, right_azimuth) = be_f32(input)?;
+    let (input, lower_elevation) = be_f32(input)?;
+    let (input, upper_elevation) = be_f32(input)?;
+    let (input, residual_power) = be_f32(input)?;
+    let (input, _padding) = take(8usize)(input)?;
+
+    Ok((
+        input,
+        AttributeRecordFields::BlankingSector(
+            BlankingSector::default()
+                .with_emitter_number(emitter_number)
+                .with_beam_number(beam_number)
+                .with_state_indicator(state_indicator)
+                .with_left_azimuth(left_azimuth)
+                .with_right_azimuth(right_azimuth)
+                .with_lower_elevation(lower_elevation)
+                .with_upper_elevation(upper_elevation)
+                .with_residual_power(residual_power),
+        ),
+    ))
+}
+
+fn angle_deception(input: &[u8]) -> IResult<&[u8], AttributeRecordFields> {
+    let (input, (emitter_number, beam_number, state_indicator)) = ew_attribute_header(input)?;
+    let (input, azimuth_offset) = be_f32(input)?;
+    let (input, azimuth_width) = be_f32(input)?;
+    let (input, azimuth_pull_rate) = be_f32(input)?;
+    let (input, azimuth_pull_acceleration) = be_f32(input)?;
+    let (input, elevation_offset) = be_f32(input)?;
+    let (input, elevation_width) = be_f32(input)?;
+    let (input, elevation_pull_rate) = be_f32(input)?;
+    let (input, elevation_pull_acceleration) = be_f32(input)?;
+    let (input, _padding) = be_u32(input)?;
+
+    Ok((
+        input,
+        AttributeRecordFields::AngleDeception(
+            AngleDeception::default()
+                .with_emitter_number(emitter_number)
+                .with_beam_number(beam_number)
+                .with_state_indicator(state_indicator)
+                .with_azimuth_offset(azimuth_offset)
+                .with_azimuth_width(azimuth_width)
+                .with_azimuth_pull_rate(azimuth_pull_rate)
+                .with_azimuth_pull_acceleration(azimuth_pull_acceleration)
+                .with_elevation_offset(elevation_offset)
+                .with_elevation_width(elevation_width)
+                .with_elevation_pull_rate(elevation_pull_rate)
+                .with_elevation_pull_acceleration(elevation_pull_acceleration),
+        ),
+    ))
 }
 
-pub(crate) fn attribute_record_set(input: &[u8]) -> IResult<&[u8], AttributeRecordSet> {
+fn false_targets(input: &[u8]) -> IResult<&[u8], AttributeRecordFields> {
+    let (input, (emitter_number, beam_number, state_indicator)) = ew_attribute_header(input)?;
+    let (input, _padding) = be_u16(input)?;
+    let (input, false_target_count) = be_u16(input)?;
+    let (input, walk_speed) = be_f32(input)?;
+    let (input, walk_acceleration) = be_f32(input)?;
+    let (input, maximum_walk_distance) = be_f32(input)?;
+    let (input, keep_time) = be_f32(input)?;
+    let (input, echo_spacing) = be_f32(input)?;
+    let (input, first_target_offset) = be_f32(input)?;
+
+    Ok((
+        input,
+        AttributeRecordFields::FalseTargets(
+            FalseTargets::default()
+                .with_emitter_number(emitter_number)
+                .with_beam_number(beam_number)
+                .with_state_indicator(state_indicator)
+                .with_false_target_count(false_target_count)
+                .with_walk_speed(walk_speed)
+                .with_walk_acceleration(walk_acceleration)
+                .with_maximum_walk_distance(maximum_walk_distance)
+                .with_keep_time(keep_time)
+                .with_echo_spacing(echo_spacing)
+                .with_first_target_offset(first_target_offset),
+        ),
+    ))
+}
+
+fn communications_node_id(input: &[u8]) -> IResult<&[u8], CommunicationsNodeId> {
     let (input, entity_id) = entity_id(input)?;
-    let (input, number_of_records) = be_u16(input)?;
-    let (input, attribute_records) = count(attribute_record, number_of_records.into())(input)?;
+    let (input, element_id) = be_u16(input)?;
+
+    Ok((input, CommunicationsNodeId::new(entity_id, element_id)))
+}
+
+fn entity_destination(input: &[u8]) -> IResult<&[u8], AttributeRecordFields> {
+    let (input, destination_communications_device_id) = communications_node_id(input)?;
+    let (input, destination_line_id) = be_u8(input)?;
+    let (input, destination_priority) = be_u8(input)?;
+    let (input, line_state_command) = be_u8(input)?;
+    let (input, _padding) = be_u8(input)?;
+
+    Ok((
+        input,
+        AttributeRecordFields::EntityDestination(
+            EntityDestination::default()
+                .with_destination_communications_device_id(destination_communications_device_id)
+                .with_destination_line_id(destination_line_id)
+                .with_destination_priority(destination_priority)
+                .with_line_state_command(line_state_command),
+        ),
+    ))
+}
+
+fn group_destination(input: &[u8]) -> IResult<&[u8], AttributeRecordFields> {
+    let (input, group_bit_field) = be_u32(input)?;
+    let (input, destination_priority) = be_u8(input)?;
+    let (input, line_state_command) = be_u8(input)?;
+    let (input, _padding) = be_u16(input)?;
 
     Ok((
         input,
-        AttributeRecordSet::new()
-            .with_entity_id(entity_id)
-            .with_attribute_records(attribute_records),
+        AttributeRecordFields::GroupDestination(
+            GroupDestination::default()
+                .with_group_bit_field(group_bit_field)
+                .with_destination_priority(destination_priority)
+                .with_line_state_command(line_state_command),
+        ),
     ))
 }
 
-pub(crate) fn attribute_record(input: &[u8]) -> IResult<&[u8], AttributeRecord> {
-    let (input, record_type) = be_u32(input)?;
-    let record_type = VariableRecordType::from(record_type);
-    let (input, record_length_octets) = be_u16(input)?;
-    let (input, fields) =
-        take(record_length_octets.saturating_sub(BASE_ATTRIBUTE_RECORD_LENGTH_OCTETS))(input)?;
+fn group_assignment(input: &[u8]) -> IResult<&[u8], AttributeRecordFields> {
+    let (input, group_bit_field) = be_u32(input)?;
+    let (input, destination_communications_device_id) = communications_node_id(input)?;
+    let (input, destination_line_id) = be_u8(input)?;
+    let (input, _padding) = be_u8(input)?;
+    let (input, _padding) = be_u16(input)?;
 
     Ok((
         input,
-        AttributeRecord::new()
-            .with_record_type(record_type)
-            .with_specific_fields(fields.to_vec()),
+        AttributeRecordFields::GroupAssignment(
+            GroupAssignment::default()
+                .with_group_bit_field(group_bit_field)
+                .with_destination_communications_device_id(destination_communications_device_id)
+                .with_destination_line_id(destination_line_id),
+        ),
     ))
 }
@@ -1,5 +1,7 @@
 use crate::common::attribute::model::{
-    Attribute, AttributeRecord, AttributeRecordSet, BASE_ATTRIBUTE_RECORD_LENGTH_OCTETS,
+    AngleDeception, Attribute, AttributeRecord, AttributeRecordFields, AttributeRecordSet,
+    AttributeStateIndicator, BlankingSector, CommunicationsNodeId, EntityDestination, FalseTargets,
+    GroupAssignment, GroupDestination, BASE_ATTRIBUTE_RECORD_LENGTH_OCTETS,
 };
 use crate::common::model::length_padded_to_num;
 use crate::common::{Serialize, SerializePdu, SupportedVersion};
@@ -44,16 +46,154 @@ fn serialize(&self, buf: &mut BytesMut) -> u16 {
 impl Serialize for AttributeRecord {
     fn serialize(&self, buf: &mut BytesMut) -> u16 {
         let padded_record_lengths = length_padded_to_num(
-            BASE_ATTRIBUTE_RECORD_LENGTH_OCTETS as usize + self.specific_fields.len(),
+            (BASE_ATTRIBUTE_RECORD_LENGTH_OCTETS + self.specific_fields.length()) as usize,
             EIGHT_OCTETS,
         );
         let record_length_bytes = padded_record_lengths.record_length as u16;
 
         buf.put_u32(self.record_type.into());
         buf.put_u16(record_length_bytes);
-        buf.put(&*self.specific_fields);
+        self.specific_fields.serialize(buf);
         buf.put_bytes(0u8, padded_record_lengths.padding_length);
 
         record_length_bytes
     }
 }
+
+impl Serialize for AttributeRecordFields {
+    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+        match self {
+            AttributeRecordFields::BlankingSector(record) => record.serialize(buf),
+            AttributeRecordFields::AngleDeception(record) => record.serialize(buf),
+            AttributeRecordFields::FalseTargets(record) => record.serialize(buf),
+            AttributeRecordFields::EntityDestination(record) => record.serialize(buf),
+            AttributeRecordFields::GroupDestination(record) => record.serialize(buf),
+            AttributeRecordFields::GroupAssignment(record) => record.serialize(buf),
+            AttributeRecordFields::Unspecified(fields) => {
+                buf.put(fields.as_slice());
+                fields.len() as u16
+            }
+        }
+    }
+}
+
+fn serialize_ew_attribute_header(
+    emitter_number: u8,
+    beam_number: u8,
+    state_indicator: AttributeStateIndicator,
+    buf: &mut BytesMut,
+) -> u16 {
+    buf.put_u16(0u16);
+    buf.put_u8(emitter_number);
+    buf.put_u8(beam_number);
+    buf.put_u8(state_indicator.into());
+    buf.put_u8(0u8);
+
+    6
+}
+
+impl Serialize for BlankingSector {
+    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+        let header_bytes = serialize_ew_attribute_header(
+            self.emitter_number,
+            self.beam_number,
+            self.state_indicator,
+            buf,
+        );
+        buf.put_f32(self.left_azimuth);
+        buf.put_f32(self.right_azimuth);
+        buf.put_f32(self.lower_elevation);
+        buf.put_f32(self.upper_elevation);
+        buf.put_f32(self.residual_power);
+        buf.put_u64(0u64);
+
+        header_bytes + 28
+    }
+}
+
+impl Serialize for AngleDeception {
+    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+        let header_bytes = serialize_ew_attribute_header(
+            self.emitter_number,
+            self.beam_number,
+            self.state_indicator,
+            buf,
+        );
+        buf.put_f32(self.azimuth_offset);
+        buf.put_f32(self.azimuth_width);
+        buf.put_f32(self.azimuth_pull_rate);
+        buf.put_f32(self.azimuth_pull_acceleration);
+        buf.put_f32(self.elevation_offset);
+        buf.put_f32(self.elevation_width);
+        buf.put_f32(self.elevation_pull_rate);
+        buf.put_f32(self.elevation_pull_acceleration);
+        buf.put_u32(0u32);
+
+        header_bytes + 36
+    }
+}
+
+impl Serialize for FalseTargets {
+    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+        let header_bytes = serialize_ew_attribute_header(
+            self.emitter_number,
+            self.beam_number,
+            self.state_indicator,
+            buf,
+        );
+        buf.put_u16(0u16);
+        buf.put_u16(self.false_target_count);
+        buf.put_f32(self.walk_speed);
+        buf.put_f32(self.walk_acceleration);
+        buf.put_f32(self.maximum_walk_distance);
+        buf.put_f32(self.keep_time);
+        buf.put_f32(self.echo_spacing);
+        buf.put_f32(self.first_target_offset);
+
+        header_bytes + 28
+    }
+}
+
+impl Serialize for CommunicationsNodeId {
+    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+        let entity_id_bytes = self.entity_id.serialize(buf);
+        buf.put_u16(self.element_id);
+
+        entity_id_bytes + 2
+    }
+}
+
+impl Serialize for EntityDestination {
+    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+        let node_id_bytes = self.destination_communications_device_id.serialize(buf);
+        buf.put_u8(self.destination_line_id);
+        buf.put_u8(self.destination_priority);
+        buf.put_u8(self.line_state_command);
+        buf.put_u8(0u8);
+
+        node_id_bytes + 4
+    }
+}
+
+impl Serialize for GroupDestination {
+    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+        buf.put_u32(self.group_bit_field);
+        buf.put_u8(self.destination_priority);
+        buf.put_u8(self.line_state_command);
+        buf.put_u16(0u16);
+
+        8
+    }
+}
+
+impl Serialize for GroupAssignment {
+    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+        buf.put_u32(self.group_bit_field);
+        let node_id_bytes = self.destination_communications_device_id.serialize(buf);
+        buf.put_u8(self.destination_line_id);
+        buf.put_u8(0u8);
+        buf.put_u16(0u16);
+
+        4 + node_id_bytes + 4
+    }
+}
@@ -0,0 +1,354 @@
+use crate::common::iff::model::LayersPresenceApplicability;
+use crate::common::model::{
+    DescriptorRecord, MunitionDescriptor, Pdu, PduBody, PduStatus, VariableParameter,
+};
+use crate::enumerations::{EntityCapabilities as EntityCapabilitiesV7, ProtocolVersion};
+use crate::v6::entity_state::model::EntityCapabilities;
+use crate::v7::writer::serialize_pdu_status;
+use crate::DisError;
+
+/// The first PDU type that was introduced in IEEE 1278.1-2012 (DIS v7)
+const FIRST_V7_ONLY_PDU_TYPE: u8 = 68;
+
+/// The fields of a PDU that changed while converting it to another DIS version.
+///
+/// Fields are named by their path in the model, e.g. `header.pdu_status`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ConversionReport {
+    /// Fields of which the value cannot be represented in the target version, and that were dropped
+    pub lost: Vec<String>,
+    /// Fields that do not exist in the source version, and that were set to their default value
+    pub defaulted: Vec<String>,
+}
+
+impl ConversionReport {
+    /// Whether the conversion kept all information of the PDU.
+    #[must_use]
+    pub fn is_lossless(&self) -> bool {
+        self.lost.is_empty()
+    }
+
+    fn lose(&mut self, field: &str) {
+        self.lost.push(field.to_string());
+    }
+
+    fn default(&mut self, field: &str) {
+        self.defaulted.push(field.to_string());
+    }
+}
+
+impl Pdu {
+    /// Converts the PDU to the provided DIS version, reporting the fields that were lost or defaulted.
+    ///
+    /// Converting to the version of the PDU returns an unchanged copy of the PDU.
+    ///
+    /// # Errors
+    /// Returns a `DisError` when the target version is not supported, or when the PDU type does not exist
+    /// in the target version.
+    pub fn convert_to(
+        &self,
+        version: ProtocolVersion,
+    ) -> Result<(Pdu, ConversionReport), DisError> {
+        let mut report = ConversionReport::default();
+        let mut pdu = self.clone();
+        if version == self.header.protocol_version {
+            return Ok((pdu, report));
+        }
+
+        match version {
+            ProtocolVersion::IEEE1278_1A1998 => {
+                let pdu_type = u8::from(self.header.pdu_type);
+                if pdu_type >= FIRST_V7_ONLY_PDU_TYPE {
+                    return Err(DisError::PduTypeNotInVersion(pdu_type, version.into()));
+                }
+                to_v6(&mut pdu, &mut report);
+            }
+            ProtocolVersion::IEEE1278_12012 => to_v7(&mut pdu, &mut report),
+            _ => return Err(DisError::UnsupportedProtocolVersion(version.into())),
+        }
+        pdu.header.protocol_version = version;
+        pdu.header.pdu_length = pdu.pdu_length();
+
+        Ok((pdu, report))
+    }
+}
+
+fn to_v6(pdu: &mut Pdu, report: &mut ConversionReport) {
+    if let Some(status) = pdu.header.pdu_status.take() {
+        if serialize_pdu_status(&status, &pdu.header.pdu_type) != 0 {
+            report.lose("header.pdu_status");
+        }
+    }
+
+    match &mut pdu.body {
+        PduBody::EntityState(body) => {
+            // the model of the four v6 capabilities, as produced when parsing a v6 PDU
+            let capabilities =
+                EntityCapabilitiesV7::from(EntityCapabilities::from(body.entity_capabilities));
+            if u32::from(capabilities) != u32::from(body.entity_capabilities) {
+                report.lose("body.entity_capabilities");
+            }
+            body.entity_capabilities = capabilities;
+            retain_v6_variable_parameters(&mut body.variable_parameters, report);
+        }
+        PduBody::EntityStateUpdate(body) => {
+            retain_v6_variable_parameters(&mut body.variable_parameters, report);
+        }
+        PduBody::Fire(body) => to_v6_descriptor(&mut body.descriptor, report),
+        PduBody::Detonation(body) => {
+            to_v6_descriptor(&mut body.descriptor, report);
+            retain_v6_variable_parameters(&mut body.variable_parameters, report);
+        }
+        PduBody::IFF(body) => {
+            if body.layer_3.take().is_some() {
+                report.lose("body.layer_3");
+            }
+            if body.layer_4.take().is_some() {
+                report.lose("body.layer_4");
+            }
+            if body.layer_5.take().is_some() {
+                report.lose("body.layer_5");
+            }
+            let layers = &mut body.fundamental_operational_data.information_layers;
+            for layer in [
+                &mut layers.layer_3,
+                &mut layers.layer_4,
+                &mut layers.layer_5,
+                &mut layers.layer_6,
+                &mut layers.layer_7,
+            ] {
+                *layer = LayersPresenceApplicability::NotPresentApplicable;
+            }
+        }
+        PduBody::Transmitter(body) => {
+            if !body.variable_transmitter_parameters.is_empty() {
+                body.variable_transmitter_parameters.clear();
+                report.lose("body.variable_transmitter_parameters");
+            }
+        }
+        _ => {}
+    }
+}
+
+/// DIS v6 only defines the Articulated Part and Attached Part variable parameter records.
+fn retain_v6_variable_parameters(
+    variable_parameters: &mut Vec<VariableParameter>,
+    report: &mut ConversionReport,
+) {
+    let count = variable_parameters.len();
+    variable_parameters.retain(|parameter| {
+        matches!(
+            parameter,
+            VariableParameter::Articulated(_) | VariableParameter::Attached(_)
+        )
+    });
+    if variable_parameters.len() != count {
+        report.lose("body.variable_parameters");
+    }
+}
+
+/// DIS v6 only defines the Munition descriptor (the Burst Descriptor); other descriptors keep their entity type.
+fn to_v6_descriptor(descriptor: &mut DescriptorRecord, report: &mut ConversionReport) {
+    match descriptor {
+        DescriptorRecord::Munition { .. } => {}
+        DescriptorRecord::Expendable { entity_type }
+        | DescriptorRecord::Explosion { entity_type, .. } => {
+            *descriptor =
+                DescriptorRecord::new_munition(*entity_type, MunitionDescriptor::default());
+            report.lose("body.descriptor");
+        }
+    }
+}
+
+fn to_v7(pdu: &mut Pdu, report: &mut ConversionReport) {
+    if pdu.header.pdu_status.is_none() {
+        pdu.header.pdu_status = Some(PduStatus::default());
+        report.default("header.pdu_status");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::attribute::model::Attribute;
+    use crate::detonation::model::Detonation;
+    use crate::entity_state::model::EntityState;
+    use crate::enumerations::{
+        EntityKind, ExplosiveMaterialCategories, PduType, PlatformDomain, ProtocolVersion,
+        TransferredEntityIndicator,
+    };
+    use crate::iff::model::{
+        FundamentalOperationalData, Iff, IffLayer3, InformationLayers, LayersPresenceApplicability,
+    };
+    use crate::model::{
+        ArticulatedPart, DescriptorRecord, DisTimeStamp, EntityType, EntityTypeParameter,
+        MunitionDescriptor, Pdu, PduBody, PduHeader, PduStatus,
+    };
+    use crate::transmitter::model::{Transmitter, VariableTransmitterParameter};
+    use crate::v7::entity_state::entity_capabilities_from_bytes;
+    use crate::DisError;
+
+    #[test]
+    fn convert_v7_to_v6() {
+        let header = PduHeader::new_v7(1, PduType::EntityState).with_pdu_status(
+            PduStatus::default()
+                .with_transferred_entity_indicator(TransferredEntityIndicator::Difference),
+        );
+        let pdu = Pdu::finalize_from_parts(
+            header,
+            EntityState::builder().build().into_pdu_body(),
+            DisTimeStamp::new_absolute_from_secs(100),
+        );
+
+        let (converted, report) = pdu.convert_to(ProtocolVersion::IEEE1278_1A1998).unwrap();
+        assert_eq!(
+            converted.header.protocol_version,
+            ProtocolVersion::IEEE1278_1A1998
+        );
+        assert!(converted.header.pdu_status.is_none());
+        assert_eq!(report.lost, vec!["header.pdu_status".to_string()]);
+        assert!(!report.is_lossless());
+    }
+
+    #[test]
+    fn convert_transmitter_to_v6_drops_variable_transmitter_parameters() {
+        let pdu = Pdu::finalize_from_parts(
+            PduHeader::new_v7(1, PduType::Transmitter),
+            Transmitter::builder()
+                .with_variable_transmitter_parameter(VariableTransmitterParameter::default())
+                .build()
+                .into_pdu_body(),
+            DisTimeStamp::new_absolute_from_secs(100),
+        );
+
+        let (converted, report) = pdu.convert_to(ProtocolVersion::IEEE1278_1A1998).unwrap();
+        assert_eq!(
+            report.lost,
+            vec!["body.variable_transmitter_parameters".to_string()]
+        );
+        assert!(converted.pdu_length() < pdu.pdu_length());
+        assert_eq!(converted.header.pdu_length, converted.pdu_length());
+    }
+
+    #[test]
+    fn convert_entity_state_to_v6_masks_v7_content() {
+        let entity_type = EntityType::default()
+            .with_kind(EntityKind::Platform)
+            .with_domain(PlatformDomain::Land);
+        let pdu = Pdu::finalize_from_parts(
+            PduHeader::new_v7(1, PduType::EntityState),
+            EntityState::builder()
+                .with_entity_type(entity_type)
+                .with_capabilities(entity_capabilities_from_bytes(u32::MAX, &entity_type))
+                .with_variable_parameter(ArticulatedPart::default().to_variable_parameter())
+                .with_variable_parameter(EntityTypeParameter::default().to_variable_parameter())
+                .build()
+                .into_pdu_body(),
+            DisTimeStamp::new_absolute_from_secs(100),
+        );
+
+        let (converted, report) = pdu.convert_to(ProtocolVersion::IEEE1278_1A1998).unwrap();
+        assert_eq!(
+            report.lost,
+            vec![
+                "body.entity_capabilities".to_string(),
+                "body.variable_parameters".to_string()
+            ]
+        );
+        let PduBody::EntityState(body) = converted.body else {
+            panic!("Expected an Entity State PDU body");
+        };
+        assert_eq!(u32::from(body.entity_capabilities).count_ones(), 4);
+        assert_eq!(body.variable_parameters.len(), 1);
+    }
+
+    #[test]
+    fn convert_iff_and_detonation_to_v6_drop_v7_content() {
+        let pdu = Pdu::finalize_from_parts(
+            PduHeader::new_v7(1, PduType::IFF),
+            Iff::builder()
+                .with_fundamental_operational_data(
+                    FundamentalOperationalData::builder()
+                        .with_information_layers(
+                            InformationLayers::builder()
+                                .with_layer_1(LayersPresenceApplicability::PresentApplicable)
+                                .with_layer_3(LayersPresenceApplicability::PresentApplicable)
+                                .build(),
+                        )
+                        .build(),
+                )
+                .with_layer_3(IffLayer3::default())
+                .build()
+                .into_pdu_body(),
+            DisTimeStamp::new_absolute_from_secs(100),
+        );
+
+        let (converted, report) = pdu.convert_to(ProtocolVersion::IEEE1278_1A1998).unwrap();
+        assert_eq!(report.lost, vec!["body.layer_3".to_string()]);
+        let PduBody::IFF(body) = converted.body else {
+            panic!("Expected an IFF PDU body");
+        };
+        assert!(body.layer_3.is_none());
+        let layers = body.fundamental_operational_data.information_layers;
+        assert_eq!(
+            layers.layer_1,
+            LayersPresenceApplicability::PresentApplicable
+        );
+        assert_eq!(
+            layers.layer_3,
+            LayersPresenceApplicability::NotPresentApplicable
+        );
+
+        let entity_type = EntityType::default().with_kind(EntityKind::Munition);
+        let pdu = Pdu::finalize_from_parts(
+            PduHeader::new_v7(1, PduType::Detonation),
+            Detonation::builder()
+                .with_explosion_descriptor(entity_type, ExplosiveMaterialCategories::default(), 1.0)
+                .build()
+                .into_pdu_body(),
+            DisTimeStamp::new_absolute_from_secs(100),
+        );
+
+        let (converted, report) = pdu.convert_to(ProtocolVersion::IEEE1278_1A1998).unwrap();
+        assert_eq!(report.lost, vec!["body.descriptor".to_string()]);
+        let PduBody::Detonation(body) = converted.body else {
+            panic!("Expected a Detonation PDU body");
+        };
+        assert_eq!(
+            body.descriptor,
+            DescriptorRecord::new_munition(entity_type, MunitionDescriptor::default())
+        );
+    }
+
+    #[test]
+    fn convert_v6_to_v7() {
+        let pdu = Pdu::finalize_from_parts(
+            PduHeader::new_v6(1, PduType::EntityState),
+            EntityState::builder().build().into_pdu_body(),
+            DisTimeStamp::new_absolute_from_secs(100),
+        );
+
+        let (converted, report) = pdu.convert_to(ProtocolVersion::IEEE1278_12012).unwrap();
+        assert_eq!(converted.header.pdu_status, Some(PduStatus::default()));
+        assert!(report.is_lossless());
+        assert_eq!(report.defaulted, vec!["header.pdu_status".to_string()]);
+        assert_eq!(converted.body, pdu.body);
+    }
+
+    #[test]
+    fn v7_only_pdu_types_are_refused() {
+        let pdu = Pdu::finalize_from_parts(
+            PduHeader::new_v7(1, PduType::Attribute),
+            Attribute::default().into_pdu_body(),
+            DisTimeStamp::new_absolute_from_secs(100),
+        );
+
+        assert_eq!(
+            pdu.convert_to(ProtocolVersion::IEEE1278_1A1998),
+            Err(DisError::PduTypeNotInVersion(72, 6))
+        );
+        assert_eq!(
+            pdu.convert_to(ProtocolVersion::from(5)),
+            Err(DisError::UnsupportedProtocolVersion(5))
+        );
+    }
+}
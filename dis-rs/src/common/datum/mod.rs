@@ -0,0 +1,111 @@
+pub mod model;
+pub mod registry;
+
+#[cfg(test)]
+mod tests {
+    use crate::common::datum::model::{DatumCodec, DatumValue, StandardDatumCodec};
+    use crate::common::datum::registry::DatumRegistry;
+    use crate::common::model::{EntityId, FixedDatum, Location, VariableDatum};
+    use crate::enumerations::VariableRecordType;
+    use crate::DisError;
+
+    #[test]
+    fn decode_standard_fixed_datum() {
+        let registry = DatumRegistry::default();
+        let datum = FixedDatum::new(VariableRecordType::Azimuth_52340, 45.0f32.to_bits());
+
+        let value = registry.decode_fixed_datum(&datum).unwrap();
+        assert_eq!(value, DatumValue::Float32(45.0));
+    }
+
+    #[test]
+    fn fixed_datum_round_trip() {
+        let registry = DatumRegistry::default();
+        let value = DatumValue::UnsignedInteger(200);
+
+        let datum = registry
+            .encode_fixed_datum(VariableRecordType::_7_62mm_Quantity_24010, &value)
+            .unwrap();
+        assert_eq!(datum.datum_value, 200);
+        assert_eq!(registry.decode_fixed_datum(&datum).unwrap(), value);
+    }
+
+    #[test]
+    fn standard_codecs_round_trip() {
+        let registry = DatumRegistry::default();
+
+        let fuel = DatumValue::Float32(1250.5);
+        let datum = registry
+            .encode_fixed_datum(VariableRecordType::Quantity_23100, &fuel)
+            .unwrap();
+        assert_eq!(registry.decode_fixed_datum(&datum).unwrap(), fuel);
+
+        let location = DatumValue::Location(Location::new(3_900_000.0, 300_000.0, 5_000_000.0));
+        let datum = registry
+            .encode_variable_datum(VariableRecordType::GeocentricCoordinates_31200, &location)
+            .unwrap();
+        assert_eq!(datum.datum_value.len(), 24);
+        assert_eq!(registry.decode_variable_datum(&datum).unwrap(), location);
+    }
+
+    #[test]
+    fn fixed_datum_value_too_large() {
+        let registry = DatumRegistry::new().with_codec(
+            VariableRecordType::Azimuth_52340,
+            StandardDatumCodec::Float64,
+        );
+
+        let result = registry
+            .encode_fixed_datum(VariableRecordType::Azimuth_52340, &DatumValue::Float64(1.0));
+        assert!(matches!(result, Err(DisError::DatumValueMismatch(_))));
+    }
+
+    #[test]
+    fn unregistered_variable_datum_is_raw() {
+        let registry = DatumRegistry::new();
+        let datum = VariableDatum::new(VariableRecordType::Azimuth_52340, vec![1, 2, 3]);
+
+        assert_eq!(
+            registry.decode_variable_datum(&datum).unwrap(),
+            DatumValue::Raw(vec![1, 2, 3])
+        );
+    }
+
+    #[test]
+    fn custom_codecs_round_trip() {
+        let location_type = VariableRecordType::from(500_000);
+        let text_type = VariableRecordType::from(500_001);
+        let entity_type = VariableRecordType::from(500_002);
+        let registry = DatumRegistry::default()
+            .with_codec(location_type, StandardDatumCodec::Location)
+            .with_codec(text_type, StandardDatumCodec::Text)
+            .with_codec(entity_type, StandardDatumCodec::EntityId);
+
+        let values = [
+            (
+                location_type,
+                DatumValue::Location(Location::new(1.0, 2.0, 3.0)),
+            ),
+            (text_type, DatumValue::Text("Hello DIS".to_string())),
+            (entity_type, DatumValue::EntityId(EntityId::new(1, 2, 3))),
+        ];
+        for (record_type, value) in values {
+            let datum = registry.encode_variable_datum(record_type, &value).unwrap();
+            assert_eq!(registry.decode_variable_datum(&datum).unwrap(), value);
+        }
+    }
+
+    #[test]
+    fn codec_rejects_mismatching_value() {
+        let result = StandardDatumCodec::Location.encode(&DatumValue::Float32(1.0));
+        assert!(matches!(result, Err(DisError::DatumValueMismatch(_))));
+        let result = StandardDatumCodec::Float32.decode(&[0, 1]);
+        assert!(matches!(result, Err(DisError::DatumValueMismatch(_))));
+    }
+
+    #[test]
+    fn text_codec_trims_padding() {
+        let value = StandardDatumCodec::Text.decode(b"Alpha\0\0\0").unwrap();
+        assert_eq!(value, DatumValue::Text("Alpha".to_string()));
+    }
+}
@@ -0,0 +1,159 @@
+use crate::common::model::{ClockTime, EntityId, EntityType, Location};
+use crate::common::parser::{clock_time, entity_id, entity_type, location};
+use crate::common::Serialize as SerializeRecord;
+use crate::constants::FOUR_OCTETS;
+use crate::DisError;
+use bytes::BytesMut;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Typed representation of the value of a Fixed or Variable Datum record (6.2.37, 6.2.93).
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum DatumValue {
+    Float32(f32),
+    Float64(f64),
+    UnsignedInteger(u32),
+    SignedInteger(i32),
+    Text(String),
+    Location(Location),
+    EntityId(EntityId),
+    EntityType(EntityType),
+    ClockTime(ClockTime),
+    Raw(Vec<u8>),
+}
+
+/// Trait for codecs that decode the raw bytes of a datum into a `DatumValue`, and encode a `DatumValue` back into bytes.
+///
+/// Applications can implement this trait to register codecs for site-specific record types
+/// in a `DatumRegistry`.
+pub trait DatumCodec: Send + Sync {
+    /// Decodes the raw datum value into a `DatumValue`.
+    ///
+    /// # Errors
+    /// Returns a `DisError` when the bytes cannot be decoded by this codec.
+    fn decode(&self, bytes: &[u8]) -> Result<DatumValue, DisError>;
+
+    /// Encodes a `DatumValue` into the raw bytes of a datum value.
+    ///
+    /// # Errors
+    /// Returns a `DisError` when the `DatumValue` is not supported by this codec.
+    fn encode(&self, value: &DatumValue) -> Result<Vec<u8>, DisError>;
+}
+
+/// Codecs for the data types commonly used in datums.
+///
+/// All numeric values are encoded in network byte order (big endian).
+/// `Text` values are encoded as ASCII, without a terminating NUL character.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum StandardDatumCodec {
+    Float32,
+    Float64,
+    UnsignedInteger,
+    SignedInteger,
+    Text,
+    Location,
+    EntityId,
+    EntityType,
+    ClockTime,
+    Raw,
+}
+
+impl DatumCodec for StandardDatumCodec {
+    fn decode(&self, bytes: &[u8]) -> Result<DatumValue, DisError> {
+        match self {
+            StandardDatumCodec::Float32 => Ok(DatumValue::Float32(f32::from_be_bytes(
+                fixed_size_bytes(bytes)?,
+            ))),
+            StandardDatumCodec::Float64 => Ok(DatumValue::Float64(f64::from_be_bytes(
+                fixed_size_bytes(bytes)?,
+            ))),
+            StandardDatumCodec::UnsignedInteger => Ok(DatumValue::UnsignedInteger(
+                u32::from_be_bytes(fixed_size_bytes(bytes)?),
+            )),
+            StandardDatumCodec::SignedInteger => Ok(DatumValue::SignedInteger(i32::from_be_bytes(
+                fixed_size_bytes(bytes)?,
+            ))),
+            StandardDatumCodec::Text => {
+                if !bytes.is_ascii() {
+                    return Err(DisError::StringNotAsciiError);
+                }
+                let text = String::from_utf8_lossy(bytes)
+                    .trim_end_matches('\0')
+                    .to_string();
+                Ok(DatumValue::Text(text))
+            }
+            StandardDatumCodec::Location => location(bytes)
+                .map(|(_, value)| DatumValue::Location(value))
+                .map_err(|err| DisError::ParseError(err.to_string())),
+            StandardDatumCodec::EntityId => entity_id(bytes)
+                .map(|(_, value)| DatumValue::EntityId(value))
+                .map_err(|err| DisError::ParseError(err.to_string())),
+            StandardDatumCodec::EntityType => entity_type(bytes)
+                .map(|(_, value)| DatumValue::EntityType(value))
+                .map_err(|err| DisError::ParseError(err.to_string())),
+            StandardDatumCodec::ClockTime => clock_time(bytes)
+                .map(|(_, value)| DatumValue::ClockTime(value))
+                .map_err(|err| DisError::ParseError(err.to_string())),
+            StandardDatumCodec::Raw => Ok(DatumValue::Raw(bytes.to_vec())),
+        }
+    }
+
+    fn encode(&self, value: &DatumValue) -> Result<Vec<u8>, DisError> {
+        match (self, value) {
+            (StandardDatumCodec::Float32, DatumValue::Float32(value)) => {
+                Ok(value.to_be_bytes().to_vec())
+            }
+            (StandardDatumCodec::Float64, DatumValue::Float64(value)) => {
+                Ok(value.to_be_bytes().to_vec())
+            }
+            (StandardDatumCodec::UnsignedInteger, DatumValue::UnsignedInteger(value)) => {
+                Ok(value.to_be_bytes().to_vec())
+            }
+            (StandardDatumCodec::SignedInteger, DatumValue::SignedInteger(value)) => {
+                Ok(value.to_be_bytes().to_vec())
+            }
+            (StandardDatumCodec::Text, DatumValue::Text(value)) => {
+                if !value.is_ascii() {
+                    return Err(DisError::StringNotAsciiError);
+                }
+                Ok(value.as_bytes().to_vec())
+            }
+            (StandardDatumCodec::Location, DatumValue::Location(value)) => {
+                Ok(serialize_record(value))
+            }
+            (StandardDatumCodec::EntityId, DatumValue::EntityId(value)) => {
+                Ok(serialize_record(value))
+            }
+            (StandardDatumCodec::EntityType, DatumValue::EntityType(value)) => {
+                Ok(serialize_record(value))
+            }
+            (StandardDatumCodec::ClockTime, DatumValue::ClockTime(value)) => {
+                Ok(serialize_record(value))
+            }
+            (StandardDatumCodec::Raw, DatumValue::Raw(value)) => Ok(value.clone()),
+            (codec, value) => Err(DisError::DatumValueMismatch(format!(
+                "{codec:?} codec cannot encode {value:?}"
+            ))),
+        }
+    }
+}
+
+/// Copies the input to a fixed size array, failing when the input does not contain exactly `N` bytes.
+fn fixed_size_bytes<const N: usize>(bytes: &[u8]) -> Result<[u8; N], DisError> {
+    <[u8; N]>::try_from(bytes).map_err(|_| {
+        DisError::DatumValueMismatch(format!(
+            "Expected a datum value of {N} bytes, found {} bytes",
+            bytes.len()
+        ))
+    })
+}
+
+fn serialize_record(record: &impl SerializeRecord) -> Vec<u8> {
+    let mut buf = BytesMut::new();
+    record.serialize(&mut buf);
+    buf.to_vec()
+}
+
+/// Size of the value of a Fixed Datum record, in bytes.
+pub(crate) const FIXED_DATUM_VALUE_LENGTH: usize = FOUR_OCTETS;
@@ -0,0 +1,182 @@
+use crate::common::datum::model::{
+    DatumCodec, DatumValue, StandardDatumCodec, FIXED_DATUM_VALUE_LENGTH,
+};
+use crate::common::model::{FixedDatum, VariableDatum};
+use crate::enumerations::VariableRecordType;
+use crate::DisError;
+use std::collections::HashMap;
+
+/// A registry of `DatumCodec`s, keyed by the `VariableRecordType` (Datum ID) of a datum.
+///
+/// `DatumRegistry::default()` contains codecs for a set of standard record types.
+/// Applications can add codecs for other (site-specific) record types using `DatumRegistry::register`,
+/// which replaces any codec registered earlier for the same record type.
+pub struct DatumRegistry {
+    codecs: HashMap<VariableRecordType, Box<dyn DatumCodec>>,
+}
+
+impl Default for DatumRegistry {
+    fn default() -> Self {
+        Self::new_standard()
+    }
+}
+
+impl DatumRegistry {
+    /// Creates a registry without any registered codecs.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            codecs: HashMap::new(),
+        }
+    }
+
+    /// Creates a registry with codecs for the standard record types supported by the library.
+    #[must_use]
+    pub fn new_standard() -> Self {
+        Self::new()
+            .with_codec(
+                VariableRecordType::Azimuth_52340,
+                StandardDatumCodec::Float32,
+            )
+            .with_codec(
+                VariableRecordType::VehicleMass_26000,
+                StandardDatumCodec::Float32,
+            )
+            .with_codec(
+                VariableRecordType::Age_34100,
+                StandardDatumCodec::UnsignedInteger,
+            )
+            .with_codec(
+                VariableRecordType::Acceleration_37000,
+                StandardDatumCodec::Float32,
+            )
+            .with_codec(
+                VariableRecordType::AirSpeed_240054,
+                StandardDatumCodec::Float32,
+            )
+            .with_codec(
+                VariableRecordType::AngleOfAttack_610026,
+                StandardDatumCodec::Float32,
+            )
+            .with_codec(
+                VariableRecordType::Country_11130,
+                StandardDatumCodec::UnsignedInteger,
+            )
+            .with_codec(
+                VariableRecordType::_7_62mmM62_Quantity_24005,
+                StandardDatumCodec::UnsignedInteger,
+            )
+            .with_codec(
+                VariableRecordType::_7_62mmM80_Quantity_24008,
+                StandardDatumCodec::UnsignedInteger,
+            )
+            .with_codec(
+                VariableRecordType::_7_62mm_Quantity_24010,
+                StandardDatumCodec::UnsignedInteger,
+            )
+            .with_codec(
+                VariableRecordType::Quantity_23100,
+                StandardDatumCodec::Float32,
+            )
+            .with_codec(
+                VariableRecordType::GeocentricCoordinates_31200,
+                StandardDatumCodec::Location,
+            )
+    }
+
+    /// Registers `codec` for `record_type`, replacing a previously registered codec for that type,
+    /// and returns the registry for chaining.
+    #[must_use]
+    pub fn with_codec(
+        mut self,
+        record_type: VariableRecordType,
+        codec: impl DatumCodec + 'static,
+    ) -> Self {
+        self.register(record_type, codec);
+        self
+    }
+
+    /// Registers `codec` for `record_type`, replacing a previously registered codec for that type.
+    pub fn register(&mut self, record_type: VariableRecordType, codec: impl DatumCodec + 'static) {
+        self.codecs.insert(record_type, Box::new(codec));
+    }
+
+    /// Returns whether a codec is registered for `record_type`.
+    #[must_use]
+    pub fn contains(&self, record_type: VariableRecordType) -> bool {
+        self.codecs.contains_key(&record_type)
+    }
+
+    /// Decodes the value of a `VariableDatum`.
+    /// Values of record types without a registered codec are returned as `DatumValue::Raw`.
+    ///
+    /// # Errors
+    /// Returns a `DisError` when the registered codec fails to decode the value.
+    pub fn decode_variable_datum(&self, datum: &VariableDatum) -> Result<DatumValue, DisError> {
+        self.decode(datum.datum_id, &datum.datum_value)
+    }
+
+    /// Decodes the value of a `FixedDatum`.
+    /// Values of record types without a registered codec are returned as `DatumValue::UnsignedInteger`.
+    ///
+    /// # Errors
+    /// Returns a `DisError` when the registered codec fails to decode the value.
+    pub fn decode_fixed_datum(&self, datum: &FixedDatum) -> Result<DatumValue, DisError> {
+        match self.codecs.get(&datum.datum_id) {
+            Some(codec) => codec.decode(&datum.datum_value.to_be_bytes()),
+            None => Ok(DatumValue::UnsignedInteger(datum.datum_value)),
+        }
+    }
+
+    /// Encodes `value` into a `VariableDatum` of type `record_type`.
+    /// When no codec is registered for the record type, only `DatumValue::Raw` values can be encoded.
+    ///
+    /// # Errors
+    /// Returns a `DisError` when the value cannot be encoded for the record type.
+    pub fn encode_variable_datum(
+        &self,
+        record_type: VariableRecordType,
+        value: &DatumValue,
+    ) -> Result<VariableDatum, DisError> {
+        let bytes = match self.codecs.get(&record_type) {
+            Some(codec) => codec.encode(value)?,
+            None => StandardDatumCodec::Raw.encode(value)?,
+        };
+        Ok(VariableDatum::new(record_type, bytes))
+    }
+
+    /// Encodes `value` into a `FixedDatum` of type `record_type`.
+    /// The encoded value must be exactly 32 bits long.
+    /// When no codec is registered for the record type, only `DatumValue::UnsignedInteger` values can be encoded.
+    ///
+    /// # Errors
+    /// Returns a `DisError` when the value cannot be encoded for the record type, or does not fit a Fixed Datum.
+    pub fn encode_fixed_datum(
+        &self,
+        record_type: VariableRecordType,
+        value: &DatumValue,
+    ) -> Result<FixedDatum, DisError> {
+        let bytes = match self.codecs.get(&record_type) {
+            Some(codec) => codec.encode(value)?,
+            None => StandardDatumCodec::UnsignedInteger.encode(value)?,
+        };
+        let bytes = <[u8; FIXED_DATUM_VALUE_LENGTH]>::try_from(bytes.as_slice()).map_err(|_| {
+            DisError::DatumValueMismatch(format!(
+                "Fixed Datum values must be {FIXED_DATUM_VALUE_LENGTH} bytes, encoded value is {} bytes",
+                bytes.len()
+            ))
+        })?;
+        Ok(FixedDatum::new(record_type, u32::from_be_bytes(bytes)))
+    }
+
+    fn decode(
+        &self,
+        record_type: VariableRecordType,
+        bytes: &[u8],
+    ) -> Result<DatumValue, DisError> {
+        match self.codecs.get(&record_type) {
+            Some(codec) => codec.decode(bytes),
+            None => Ok(DatumValue::Raw(bytes.to_vec())),
+        }
+    }
+}
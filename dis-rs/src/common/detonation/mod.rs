@@ -7,9 +7,9 @@
 mod tests {
     use crate::common::detonation::model::Detonation;
     use crate::common::model::DisTimeStamp;
-    use crate::common::model::{Pdu, PduHeader};
+    use crate::common::model::{EntityType, Pdu, PduHeader, VectorF32};
     use crate::common::parser::parse_pdu;
-    use crate::enumerations::PduType;
+    use crate::enumerations::{ExplosiveMaterialCategories, PduType};
     use bytes::BytesMut;
 
     #[test]
@@ -38,4 +38,27 @@ fn detonation_internal_consistency() {
             }
         }
     }
+
+    #[test]
+    fn detonation_explosion_descriptor() {
+        let header = PduHeader::new_v7(1, PduType::Detonation);
+
+        let body = Detonation::builder()
+            .with_explosion_descriptor(
+                EntityType::default(),
+                ExplosiveMaterialCategories::Alcohol,
+                10.5,
+            )
+            .with_entity_location(VectorF32::new(1.0, 2.0, 3.0))
+            .build()
+            .into_pdu_body();
+        let original_pdu =
+            Pdu::finalize_from_parts(header, body, DisTimeStamp::new_absolute_from_secs(100));
+        let mut buf = BytesMut::with_capacity(original_pdu.header.pdu_length as usize);
+        original_pdu.serialize(&mut buf).unwrap();
+
+        let parsed = parse_pdu(&buf).unwrap();
+
+        assert_eq!(original_pdu, parsed);
+    }
 }
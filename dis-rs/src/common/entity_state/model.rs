@@ -16,6 +16,7 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use std::str::FromStr;
+use std::time::Duration;
 
 const BASE_ENTITY_STATE_BODY_LENGTH: u16 = 132;
 
@@ -60,6 +61,48 @@ pub fn into_builder(self) -> EntityStateBuilder {
     pub fn into_pdu_body(self) -> PduBody {
         PduBody::EntityState(self)
     }
+
+    /// Extrapolates the location of the entity `elapsed` time after this Entity State, using its dead reckoning parameters.
+    ///
+    /// Static entities do not move. The world coordinate algorithms FVW and RVW also apply the linear acceleration;
+    /// other algorithms only apply the linear velocity, which is a first order approximation for the body coordinate
+    /// algorithms.
+    #[must_use]
+    pub fn dead_reckoned_location(&self, elapsed: Duration) -> Location {
+        let seconds = elapsed.as_secs_f64();
+        let velocity = &self.entity_linear_velocity;
+        let acceleration = match self.dead_reckoning_parameters.algorithm {
+            DeadReckoningAlgorithm::StaticNonmovingEntity => return self.entity_location,
+            DeadReckoningAlgorithm::DRM_FVW_HighSpeedOrManeuveringEntity
+            | DeadReckoningAlgorithm::DRM_RVW_HighSpeedOrManeuveringEntityWithExtrapolationOfOrientation => {
+                self.dead_reckoning_parameters.linear_acceleration
+            }
+            _ => VectorF32::default(),
+        };
+        let extrapolate = |position: f64, velocity: f32, acceleration: f32| {
+            position
+                + f64::from(velocity) * seconds
+                + 0.5 * f64::from(acceleration) * seconds * seconds
+        };
+
+        Location::new(
+            extrapolate(
+                self.entity_location.x_coordinate,
+                velocity.first_vector_component,
+                acceleration.first_vector_component,
+            ),
+            extrapolate(
+                self.entity_location.y_coordinate,
+                velocity.second_vector_component,
+                acceleration.second_vector_component,
+            ),
+            extrapolate(
+                self.entity_location.z_coordinate,
+                velocity.third_vector_component,
+                acceleration.third_vector_component,
+            ),
+        )
+    }
 }
 
 impl BodyInfo for EntityState {
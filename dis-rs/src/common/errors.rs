@@ -1,10 +1,10 @@
 use thiserror::Error;
 
+use crate::common::model::EntityId;
 use crate::constants::PDU_HEADER_LEN_BYTES;
 
 #[derive(Debug, PartialEq, Eq, Error)]
 pub enum DisError {
-    // UnsupportedProtocolVersion,
     #[error("{0}")]
     ParseError(String), // the parsing of a PDU resulted in an error
     #[error("The buffer does not contain enough bytes for a valid DIS header. {0} bytes available, needed {PDU_HEADER_LEN_BYTES}")]
@@ -21,4 +21,32 @@ pub enum DisError {
     IffIncorrectSystemType, // the System Type in an IFF PDU is incorrect (to determine the type for parsing the basic data)
     #[error("IFF PDU - Undetermined System Time.")]
     IffUndeterminedSystemType, // the System Type in an IFF PDU does not determine whether it is an Interrogator or a Transponder
+    #[error("Datum value mismatch: {0}")]
+    DatumValueMismatch(String), // the value of a datum does not match the codec used to decode or encode it
+    #[error("Audio encoding {0} is not supported.")]
+    UnsupportedAudioEncoding(u16), // the (Signal PDU) audio encoding type is not supported by the audio codecs
+    #[error("Invalid audio data: {0}")]
+    InvalidAudioData(String), // the audio data or WAV file cannot be processed
+    #[error("PDU type {0} is not a reliable Simulation Management request.")]
+    NotAReliableRequest(u8), // the PDU provided to the SIMAN-R reliability service is not a request PDU of the reliable SIMAN family
+    #[error(
+        "Entity {0} is too far from the group reference point to be described in an IsGroupOf PDU."
+    )]
+    GroupedEntityOutOfRange(EntityId), // the offset of a grouped entity from the group reference point does not fit the GED record
+    #[error("Protocol version {0} is not supported.")]
+    UnsupportedProtocolVersion(u8), // the protocol version is not supported by the operation
+    #[error("PDU type {0} does not exist in protocol version {1}.")]
+    PduTypeNotInVersion(u8, u8), // the PDU type cannot be converted to the protocol version; (u8 PDU type, u8 protocol version)
+    #[error("Originator {0} does not belong to the simulation address of the exercise.")]
+    ForeignOriginator(EntityId), // the originating entity of a PDU body is not part of the simulation sending the PDU
+    #[error("Entity State Update for entity {0} cannot be applied to entity {1}.")]
+    EntityIdMismatch(EntityId, EntityId), // the update is about another entity than the stored state; (EntityId of the update, EntityId of the state)
+    #[error("Invalid filter expression at column {0}: {1}")]
+    InvalidFilter(usize, String), // the filter expression cannot be parsed; (usize column, String description of the problem)
+    #[error("Invalid capture: {0}")]
+    InvalidCapture(String), // the pcap or pcapng capture cannot be read, or the datagrams cannot be written as a capture
+    #[error("Invalid recording: {0}")]
+    InvalidRecording(String), // the data is not a valid DIS recording
+    #[error("I/O error: {0}")]
+    IoError(String), // reading or writing a file or stream failed
 }
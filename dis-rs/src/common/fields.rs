@@ -0,0 +1,970 @@
+//! The fields of the models, shared by the facilities that walk the models generically (`Diff`, `Reflect`).
+//!
+//! Each field is listed with how it is walked:
+//! - `leaf`: a value that is handled as a whole (primitives, enumerations, and records without a field listing)
+//! - `nested`: a record (or optional record) that is walked field by field
+//! - `list`: a list of records that are walked field by field
+//! - `leaves`: a list of values that are handled as a whole
+//!
+//! Listing a struct with a field missing fails to compile (see `reflect::value::impl_reflect`).
+//! The fields of the generated bitfield records are listed by the build script, in `with_bitfield_fields`.
+
+/// Invokes the callback macro with the field listing of the records and PDU bodies.
+macro_rules! with_model_fields {
+    ($callback:ident) => {
+        $callback! {
+            SimulationAddress {
+                site_id: leaf,
+                application_id: leaf,
+            }
+            EntityId {
+                simulation_address: nested,
+                entity_id: leaf,
+            }
+            EventId {
+                simulation_address: nested,
+                event_id: leaf,
+            }
+            EntityType {
+                kind: leaf,
+                domain: leaf,
+                country: leaf,
+                category: leaf,
+                subcategory: leaf,
+                specific: leaf,
+                extra: leaf,
+            }
+            Location {
+                x_coordinate: leaf,
+                y_coordinate: leaf,
+                z_coordinate: leaf,
+            }
+            Orientation {
+                psi: leaf,
+                theta: leaf,
+                phi: leaf,
+            }
+            VectorF32 {
+                first_vector_component: leaf,
+                second_vector_component: leaf,
+                third_vector_component: leaf,
+            }
+            ClockTime {
+                hour: leaf,
+                time_past_hour: leaf,
+            }
+            ArticulatedPart {
+                change_indicator: leaf,
+                attachment_id: leaf,
+                type_metric: leaf,
+                type_class: leaf,
+                parameter_value: leaf,
+            }
+            AttachedPart {
+                detached_indicator: leaf,
+                attachment_id: leaf,
+                parameter_type: leaf,
+                attached_part_type: nested,
+            }
+            EntityMarking {
+                marking_character_set: leaf,
+                marking_string: leaf,
+            }
+            MunitionDescriptor {
+                warhead: leaf,
+                fuse: leaf,
+                quantity: leaf,
+                rate: leaf,
+            }
+            FixedDatum {
+                datum_id: leaf,
+                datum_value: leaf,
+            }
+            VariableDatum {
+                datum_id: leaf,
+                datum_value: leaf,
+            }
+            BeamData {
+                azimuth_center: leaf,
+                azimuth_sweep: leaf,
+                elevation_center: leaf,
+                elevation_sweep: leaf,
+                sweep_sync: leaf,
+            }
+            DrParameters {
+                algorithm: leaf,
+                other_parameters: nested,
+                linear_acceleration: nested,
+                angular_velocity: nested,
+            }
+            DrEulerAngles {
+                local_yaw: leaf,
+                local_pitch: leaf,
+                local_roll: leaf,
+            }
+            DrWorldOrientationQuaternion {
+                nil: leaf,
+                x: leaf,
+                y: leaf,
+                z: leaf,
+            }
+            Other {
+                originating_entity_id: nested,
+                receiving_entity_id: nested,
+                body: leaf,
+            }
+            EntityState {
+                entity_id: nested,
+                force_id: leaf,
+                entity_type: nested,
+                alternative_entity_type: nested,
+                entity_linear_velocity: nested,
+                entity_location: nested,
+                entity_orientation: nested,
+                entity_appearance: nested,
+                dead_reckoning_parameters: nested,
+                entity_marking: nested,
+                entity_capabilities: leaf,
+                variable_parameters: list,
+            }
+            Fire {
+                firing_entity_id: nested,
+                target_entity_id: nested,
+                entity_id: nested,
+                event_id: nested,
+                fire_mission_index: leaf,
+                location_in_world: nested,
+                descriptor: nested,
+                velocity: nested,
+                range: leaf,
+            }
+            Detonation {
+                source_entity_id: nested,
+                target_entity_id: nested,
+                exploding_entity_id: nested,
+                event_id: nested,
+                velocity: nested,
+                location_in_world_coordinates: nested,
+                descriptor: nested,
+                location_in_entity_coordinates: nested,
+                detonation_result: leaf,
+                variable_parameters: list,
+            }
+            Collision {
+                issuing_entity_id: nested,
+                colliding_entity_id: nested,
+                event_id: nested,
+                collision_type: leaf,
+                velocity: nested,
+                mass: leaf,
+                location: nested,
+            }
+            ServiceRequest {
+                requesting_id: nested,
+                servicing_id: nested,
+                service_type_requested: leaf,
+                supplies: leaves,
+            }
+            ResupplyOffer {
+                requesting_id: nested,
+                servicing_id: nested,
+                supplies: leaves,
+            }
+            ResupplyReceived {
+                requesting_id: nested,
+                servicing_id: nested,
+                supplies: leaves,
+            }
+            ResupplyCancel {
+                requesting_id: nested,
+                servicing_id: nested,
+            }
+            RepairComplete {
+                receiving_id: nested,
+                repairing_id: nested,
+                repair: leaf,
+            }
+            RepairResponse {
+                receiving_id: nested,
+                repairing_id: nested,
+                repair_result: leaf,
+            }
+            CreateEntity {
+                originating_id: nested,
+                receiving_id: nested,
+                request_id: leaf,
+            }
+            RemoveEntity {
+                originating_id: nested,
+                receiving_id: nested,
+                request_id: leaf,
+            }
+            StartResume {
+                originating_id: nested,
+                receiving_id: nested,
+                real_world_time: nested,
+                simulation_time: nested,
+                request_id: leaf,
+            }
+            StopFreeze {
+                originating_id: nested,
+                receiving_id: nested,
+                real_world_time: nested,
+                reason: leaf,
+                frozen_behavior: leaf,
+                request_id: leaf,
+            }
+            Acknowledge {
+                originating_id: nested,
+                receiving_id: nested,
+                acknowledge_flag: leaf,
+                response_flag: leaf,
+                request_id: leaf,
+            }
+            ActionRequest {
+                originating_id: nested,
+                receiving_id: nested,
+                request_id: leaf,
+                action_id: leaf,
+                fixed_datum_records: list,
+                variable_datum_records: list,
+            }
+            ActionResponse {
+                originating_id: nested,
+                receiving_id: nested,
+                request_id: leaf,
+                request_status: leaf,
+                fixed_datum_records: list,
+                variable_datum_records: list,
+            }
+            DataQuery {
+                originating_id: nested,
+                receiving_id: nested,
+                request_id: leaf,
+                time_interval: leaf,
+                fixed_datum_records: list,
+                variable_datum_records: list,
+            }
+            SetData {
+                originating_id: nested,
+                receiving_id: nested,
+                request_id: leaf,
+                fixed_datum_records: list,
+                variable_datum_records: list,
+            }
+            Data {
+                originating_id: nested,
+                receiving_id: nested,
+                request_id: leaf,
+                fixed_datum_records: list,
+                variable_datum_records: list,
+            }
+            EventReport {
+                originating_id: nested,
+                receiving_id: nested,
+                event_type: leaf,
+                fixed_datum_records: list,
+                variable_datum_records: list,
+            }
+            Comment {
+                originating_id: nested,
+                receiving_id: nested,
+                variable_datum_records: list,
+            }
+            ElectromagneticEmission {
+                emitting_entity_id: nested,
+                event_id: nested,
+                state_update_indicator: leaf,
+                emitter_systems: list,
+            }
+            EmitterSystem {
+                name: leaf,
+                function: leaf,
+                number: leaf,
+                location: nested,
+                beams: list,
+            }
+            Beam {
+                number: leaf,
+                parameter_index: leaf,
+                parameter_data: nested,
+                beam_data: nested,
+                beam_function: leaf,
+                high_density_track_jam: leaf,
+                beam_status: leaf,
+                jamming_technique: nested,
+                track_jam_data: list,
+            }
+            FundamentalParameterData {
+                frequency: leaf,
+                frequency_range: leaf,
+                effective_power: leaf,
+                pulse_repetition_frequency: leaf,
+                pulse_width: leaf,
+            }
+            JammingTechnique {
+                kind: leaf,
+                category: leaf,
+                subcategory: leaf,
+                specific: leaf,
+            }
+            TrackJam {
+                entity_id: nested,
+                emitter: leaf,
+                beam: leaf,
+            }
+            Designator {
+                designating_entity_id: nested,
+                system_name: leaf,
+                designated_entity_id: nested,
+                code: leaf,
+                power: leaf,
+                wavelength: leaf,
+                spot_wrt_designated_entity: nested,
+                spot_location: nested,
+                dead_reckoning_algorithm: leaf,
+                linear_acceleration: nested,
+            }
+            Transmitter {
+                radio_reference_id: nested,
+                radio_number: leaf,
+                radio_type: nested,
+                transmit_state: leaf,
+                input_source: leaf,
+                antenna_location: nested,
+                relative_antenna_location: nested,
+                antenna_pattern_type: leaf,
+                frequency: leaf,
+                transmit_frequency_bandwidth: leaf,
+                power: leaf,
+                modulation_type: nested,
+                crypto_system: leaf,
+                crypto_key_id: nested,
+                modulation_parameters: nested,
+                antenna_pattern: nested,
+                variable_transmitter_parameters: list,
+            }
+            ModulationType {
+                spread_spectrum: nested,
+                major_modulation: leaf,
+                radio_system: leaf,
+            }
+            SpreadSpectrum {
+                frequency_hopping: leaf,
+                pseudo_noise: leaf,
+                time_hopping: leaf,
+            }
+            CryptoKeyId {
+                pseudo_crypto_key: leaf,
+                crypto_mode: leaf,
+            }
+            CcttSincgarsModulationParameters {
+                fh_net_id: leaf,
+                hop_set_id: leaf,
+                lockout_set_id: leaf,
+                start_of_message: leaf,
+                fh_synchronization_time_offset: leaf,
+                transmission_security_key: leaf,
+                clear_channel: leaf,
+            }
+            JtidsMidsModulationParameters {
+                time_slot_allocation_mode: leaf,
+                transmitting_terminal_primary_mode: leaf,
+                transmitting_terminal_secondary_mode: leaf,
+                synchronization_state: leaf,
+                network_sync_id: leaf,
+            }
+            BasicHaveQuickModulationParameters {
+                net_id: nested,
+                mwod_index: leaf,
+            }
+            HaveQuickNetId {
+                net_number: leaf,
+                frequency_table: leaf,
+                mode: leaf,
+            }
+            BeamAntennaPattern {
+                beam_direction: nested,
+                azimuth_beamwidth: leaf,
+                elevation_beamwidth: leaf,
+                reference_system: leaf,
+                e_z: leaf,
+                e_x: leaf,
+                phase: leaf,
+            }
+            SphericalHarmonicAntennaPattern {
+                order: leaf,
+                coefficients: leaves,
+                reference_system: leaf,
+            }
+            VariableTransmitterParameter {
+                record_type: leaf,
+                fields: nested,
+            }
+            HighFidelityHaveQuick {
+                net_id: nested,
+                tod_transmit_indicator: leaf,
+                tod_delta: leaf,
+                words_of_day: leaf,
+            }
+            Signal {
+                radio_reference_id: nested,
+                radio_number: leaf,
+                encoding_scheme: leaf,
+                tdl_type: leaf,
+                sample_rate: leaf,
+                samples: leaf,
+                data: leaf,
+            }
+            Receiver {
+                radio_reference_id: nested,
+                radio_number: leaf,
+                receiver_state: leaf,
+                received_power: leaf,
+                transmitter_radio_reference_id: nested,
+                transmitter_radio_number: leaf,
+            }
+            Iff {
+                emitting_entity_id: nested,
+                event_id: nested,
+                relative_antenna_location: nested,
+                system_id: nested,
+                system_designator: leaf,
+                system_specific_data: leaf,
+                fundamental_operational_data: nested,
+                layer_2: nested,
+                layer_3: nested,
+                layer_4: nested,
+                layer_5: nested,
+            }
+            SystemId {
+                system_type: leaf,
+                system_name: leaf,
+                system_mode: leaf,
+                change_options: nested,
+            }
+            ChangeOptionsRecord {
+                change_indicator: leaf,
+                system_specific_field_1: leaf,
+                system_specific_field_2: leaf,
+                heartbeat_indicator: leaf,
+                transponder_interrogator_indicator: leaf,
+                simulation_mode: leaf,
+                interactive_capable: leaf,
+                test_mode: leaf,
+            }
+            FundamentalOperationalData {
+                system_status: nested,
+                data_field_1: leaf,
+                information_layers: nested,
+                data_field_2: leaf,
+                parameter_1: leaf,
+                parameter_2: leaf,
+                parameter_3: leaf,
+                parameter_4: leaf,
+                parameter_5: leaf,
+                parameter_6: leaf,
+            }
+            SystemStatus {
+                system_on_off_status: leaf,
+                parameter_1_capable: leaf,
+                parameter_2_capable: leaf,
+                parameter_3_capable: leaf,
+                parameter_4_capable: leaf,
+                parameter_5_capable: leaf,
+                parameter_6_capable: leaf,
+                operational_status: leaf,
+            }
+            InformationLayers {
+                layer_1: leaf,
+                layer_2: leaf,
+                layer_3: leaf,
+                layer_4: leaf,
+                layer_5: leaf,
+                layer_6: leaf,
+                layer_7: leaf,
+            }
+            LayerHeader {
+                layer_number: leaf,
+                layer_specific_information: leaf,
+                length: leaf,
+            }
+            IffLayer2 {
+                layer_header: nested,
+                beam_data: nested,
+                operational_parameter_1: leaf,
+                operational_parameter_2: leaf,
+                iff_fundamental_parameters: list,
+            }
+            IffFundamentalParameterData {
+                erp: leaf,
+                frequency: leaf,
+                pgrf: leaf,
+                pulse_width: leaf,
+                burst_length: leaf,
+                applicable_modes: leaf,
+                system_specific_data: nested,
+            }
+            MarkXXIISystemSpecificData {
+                mode_1: leaf,
+                mode_2: leaf,
+                mode_3a: leaf,
+                mode_4: leaf,
+                mode_c: leaf,
+                mode_s: leaf,
+                mode_5: leaf,
+                mode_4_code: leaf,
+            }
+            SovietSystemSpecificData {
+                code: leaf,
+                mode: leaf,
+            }
+            RrbSystemSpecificData {
+                code: leaf,
+                power_reduction: leaf,
+                radar_enhancement: leaf,
+                delay: leaf,
+            }
+            ModeSSystemSpecificData {
+                aircraft_address: leaf,
+            }
+            IffLayer3 {
+                layer_header: nested,
+                reporting_simulation: nested,
+                mode_5_basic_data: leaf,
+                data_records: nested,
+            }
+            IffLayer4 {
+                layer_header: nested,
+                reporting_simulation: nested,
+                mode_s_basic_data: leaf,
+                data_records: nested,
+            }
+            IffLayer5 {
+                layer_header: nested,
+                reporting_simulation: nested,
+                applicable_layers: nested,
+                data_category: leaf,
+                data_records: nested,
+            }
+            IffDataSpecification {
+                iff_data_records: list,
+            }
+            IffDataRecord {
+                record_type: leaf,
+                record_specific_fields: leaf,
+            }
+            UnderwaterAcoustic {
+                emitting_entity_id: nested,
+                event_id: nested,
+                state_change_update_indicator: leaf,
+                passive_parameter_index: leaf,
+                propulsion_plant_configuration: leaf,
+                shafts: leaves,
+                apas: leaves,
+                emitter_systems: list,
+            }
+            UAEmitterSystem {
+                acoustic_emitter_system: nested,
+                location: nested,
+                beams: list,
+            }
+            AcousticEmitterSystem {
+                acoustic_system_name: leaf,
+                function: leaf,
+                acoustic_id_number: leaf,
+            }
+            UABeam {
+                beam_data_length: leaf,
+                beam_id_number: leaf,
+                fundamental_parameters: nested,
+            }
+            UAFundamentalParameterData {
+                active_emission_parameter_index: leaf,
+                scan_pattern: leaf,
+                beam_center_azimuth: leaf,
+                azimuthal_beamwidth: leaf,
+                beam_center_depression_elevation: leaf,
+                depression_elevation_beamwidth: leaf,
+            }
+            SEES {
+                originating_entity_id: nested,
+                infrared_signature_representation_index: leaf,
+                acoustic_signature_representation_index: leaf,
+                radar_cross_section_representation_index: leaf,
+                propulsion_systems: leaves,
+                vectoring_nozzle_systems: leaves,
+            }
+            AggregateState {
+                aggregate_id: nested,
+                force_id: leaf,
+                aggregate_state: leaf,
+                aggregate_type: leaf,
+                formation: leaf,
+                aggregate_marking: leaf,
+                dimensions: nested,
+                orientation: nested,
+                center_of_mass: nested,
+                velocity: nested,
+                aggregates: list,
+                entities: list,
+                silent_aggregate_systems: leaves,
+                silent_entity_systems: leaves,
+                variable_datums: list,
+            }
+            IsGroupOf {
+                group_id: nested,
+                grouped_entity_category: leaf,
+                group_reference_point: leaf,
+                descriptions: leaves,
+            }
+            TransferOwnership {
+                originating_id: nested,
+                receiving_id: nested,
+                request_id: leaf,
+                required_reliability_service: leaf,
+                transfer_type: leaf,
+                transfer_entity_id: nested,
+                record_specification: leaf,
+            }
+            IsPartOf {
+                originating_simulation_id: nested,
+                receiving_entity_id: nested,
+                relationship: leaf,
+                part_location: nested,
+                named_location_id: leaf,
+                part_type: nested,
+            }
+            CreateEntityR {
+                originating_id: nested,
+                receiving_id: nested,
+                required_reliability_service: leaf,
+                request_id: leaf,
+            }
+            RemoveEntityR {
+                originating_id: nested,
+                receiving_id: nested,
+                required_reliability_service: leaf,
+                request_id: leaf,
+            }
+            StartResumeR {
+                originating_id: nested,
+                receiving_id: nested,
+                real_world_time: nested,
+                simulation_time: nested,
+                required_reliability_service: leaf,
+                request_id: leaf,
+            }
+            StopFreezeR {
+                originating_id: nested,
+                receiving_id: nested,
+                real_world_time: nested,
+                reason: leaf,
+                frozen_behavior: leaf,
+                required_reliability_service: leaf,
+                request_id: leaf,
+            }
+            AcknowledgeR {
+                originating_id: nested,
+                receiving_id: nested,
+                acknowledge_flag: leaf,
+                response_flag: leaf,
+                request_id: leaf,
+            }
+            ActionRequestR {
+                originating_id: nested,
+                receiving_id: nested,
+                required_reliability_service: leaf,
+                request_id: leaf,
+                action_id: leaf,
+                fixed_datum_records: list,
+                variable_datum_records: list,
+            }
+            ActionResponseR {
+                originating_id: nested,
+                receiving_id: nested,
+                request_id: leaf,
+                request_status: leaf,
+                fixed_datum_records: list,
+                variable_datum_records: list,
+            }
+            DataQueryR {
+                originating_id: nested,
+                receiving_id: nested,
+                required_reliability_service: leaf,
+                request_id: leaf,
+                time_interval: leaf,
+                fixed_datum_records: list,
+                variable_datum_records: list,
+            }
+            SetDataR {
+                originating_id: nested,
+                receiving_id: nested,
+                required_reliability_service: leaf,
+                request_id: leaf,
+                fixed_datum_records: list,
+                variable_datum_records: list,
+            }
+            DataR {
+                originating_id: nested,
+                receiving_id: nested,
+                request_id: leaf,
+                required_reliability_service: leaf,
+                fixed_datum_records: list,
+                variable_datum_records: list,
+            }
+            EventReportR {
+                originating_id: nested,
+                receiving_id: nested,
+                event_type: leaf,
+                fixed_datum_records: list,
+                variable_datum_records: list,
+            }
+            CommentR {
+                originating_id: nested,
+                receiving_id: nested,
+                variable_datum_records: list,
+            }
+            RecordR {
+                originating_id: nested,
+                receiving_id: nested,
+                request_id: leaf,
+                required_reliability_service: leaf,
+                event_type: leaf,
+                response_serial_number: leaf,
+                record_specification: leaf,
+            }
+            SetRecordR {
+                originating_id: nested,
+                receiving_id: nested,
+                request_id: leaf,
+                required_reliability_service: leaf,
+                record_specification: leaf,
+            }
+            RecordQueryR {
+                originating_id: nested,
+                receiving_id: nested,
+                request_id: leaf,
+                required_reliability_service: leaf,
+                event_type: leaf,
+                time: leaf,
+                record_query_specification: leaf,
+            }
+            CollisionElastic {
+                issuing_entity_id: nested,
+                colliding_entity_id: nested,
+                event_id: nested,
+                velocity: nested,
+                mass: leaf,
+                location: nested,
+                intermediate_result_xx: leaf,
+                intermediate_result_xy: leaf,
+                intermediate_result_xz: leaf,
+                intermediate_result_yy: leaf,
+                intermediate_result_yz: leaf,
+                intermediate_result_zz: leaf,
+                unit_surface_normal: nested,
+                coefficient_of_restitution: leaf,
+            }
+            EntityStateUpdate {
+                entity_id: nested,
+                entity_linear_velocity: nested,
+                entity_location: nested,
+                entity_orientation: nested,
+                entity_appearance: nested,
+                variable_parameters: list,
+            }
+            Attribute {
+                originating_simulation_address: nested,
+                record_pdu_type: leaf,
+                record_protocol_version: leaf,
+                master_attribute_record_type: leaf,
+                action_code: leaf,
+                attribute_record_sets: list,
+            }
+            AttributeRecordSet {
+                entity_id: nested,
+                attribute_records: list,
+            }
+            AttributeRecord {
+                record_type: leaf,
+                specific_fields: nested,
+            }
+            BlankingSector {
+                emitter_number: leaf,
+                beam_number: leaf,
+                state_indicator: leaf,
+                left_azimuth: leaf,
+                right_azimuth: leaf,
+                lower_elevation: leaf,
+                upper_elevation: leaf,
+                residual_power: leaf,
+            }
+            AngleDeception {
+                emitter_number: leaf,
+                beam_number: leaf,
+                state_indicator: leaf,
+                azimuth_offset: leaf,
+                azimuth_width: leaf,
+                azimuth_pull_rate: leaf,
+                azimuth_pull_acceleration: leaf,
+                elevation_offset: leaf,
+                elevation_width: leaf,
+                elevation_pull_rate: leaf,
+                elevation_pull_acceleration: leaf,
+            }
+            FalseTargets {
+                emitter_number: leaf,
+                beam_number: leaf,
+                state_indicator: leaf,
+                false_target_count: leaf,
+                walk_speed: leaf,
+                walk_acceleration: leaf,
+                maximum_walk_distance: leaf,
+                keep_time: leaf,
+                echo_spacing: leaf,
+                first_target_offset: leaf,
+            }
+            CommunicationsNodeId {
+                entity_id: nested,
+                element_id: leaf,
+            }
+            EntityDestination {
+                destination_communications_device_id: nested,
+                destination_line_id: leaf,
+                destination_priority: leaf,
+                line_state_command: leaf,
+            }
+            GroupDestination {
+                group_bit_field: leaf,
+                destination_priority: leaf,
+                line_state_command: leaf,
+            }
+            GroupAssignment {
+                group_bit_field: leaf,
+                destination_communications_device_id: nested,
+                destination_line_id: leaf,
+            }
+        }
+    };
+}
+
+/// Invokes the callback macro with the enums of records that have single-value variants, and their field names.
+macro_rules! with_record_variants {
+    ($callback:ident) => {
+        $callback! {
+            EntityAppearance {
+                LandPlatform => land_platform,
+                AirPlatform => air_platform,
+                SurfacePlatform => surface_platform,
+                SubsurfacePlatform => subsurface_platform,
+                SpacePlatform => space_platform,
+                Munition => munition,
+                LifeForms => life_forms,
+                Environmental => environmental,
+                CulturalFeature => cultural_feature,
+                Supply => supply,
+                Radio => radio,
+                Expendable => expendable,
+                SensorEmitter => sensor_emitter,
+            }
+            DrOtherParameters {
+                LocalEulerAngles => local_euler_angles,
+                WorldOrientationQuaternion => world_orientation_quaternion,
+            }
+            ModulationParameters {
+                CcttSincgars => cctt_sincgars,
+                JtidsMids => jtids_mids,
+                BasicHaveQuick => basic_have_quick,
+            }
+            AntennaPattern {
+                Beam => beam,
+                SphericalHarmonic => spherical_harmonic,
+            }
+            VariableTransmitterParameterFields {
+                HighFidelityHaveQuick => high_fidelity_have_quick,
+            }
+            SystemSpecificData {
+                MarkXXII => mark_xxii,
+                Soviet => soviet,
+                Rrb => rrb,
+                ModeS => mode_s,
+            }
+            AttributeRecordFields {
+                BlankingSector => blanking_sector,
+                AngleDeception => angle_deception,
+                FalseTargets => false_targets,
+                EntityDestination => entity_destination,
+                GroupDestination => group_destination,
+                GroupAssignment => group_assignment,
+            }
+        }
+    };
+}
+
+/// Invokes the callback macro with the variants of `PduBody` that have a body, and their field names.
+macro_rules! with_body_variants {
+    ($callback:ident) => {
+        $callback! {
+            PduBody {
+                Other => other,
+                EntityState => entity_state,
+                Fire => fire,
+                Detonation => detonation,
+                Collision => collision,
+                ServiceRequest => service_request,
+                ResupplyOffer => resupply_offer,
+                ResupplyReceived => resupply_received,
+                ResupplyCancel => resupply_cancel,
+                RepairComplete => repair_complete,
+                RepairResponse => repair_response,
+                CreateEntity => create_entity,
+                RemoveEntity => remove_entity,
+                StartResume => start_resume,
+                StopFreeze => stop_freeze,
+                Acknowledge => acknowledge,
+                ActionRequest => action_request,
+                ActionResponse => action_response,
+                DataQuery => data_query,
+                SetData => set_data,
+                Data => data,
+                EventReport => event_report,
+                Comment => comment,
+                ElectromagneticEmission => electromagnetic_emission,
+                Designator => designator,
+                Transmitter => transmitter,
+                Signal => signal,
+                Receiver => receiver,
+                IFF => iff,
+                UnderwaterAcoustic => underwater_acoustic,
+                SupplementalEmissionEntityState => supplemental_emission_entity_state,
+                AggregateState => aggregate_state,
+                IsGroupOf => is_group_of,
+                TransferOwnership => transfer_ownership,
+                IsPartOf => is_part_of,
+                CreateEntityR => create_entity_r,
+                RemoveEntityR => remove_entity_r,
+                StartResumeR => start_resume_r,
+                StopFreezeR => stop_freeze_r,
+                AcknowledgeR => acknowledge_r,
+                ActionRequestR => action_request_r,
+                ActionResponseR => action_response_r,
+                DataQueryR => data_query_r,
+                SetDataR => set_data_r,
+                DataR => data_r,
+                EventReportR => event_report_r,
+                CommentR => comment_r,
+                RecordR => record_r,
+                SetRecordR => set_record_r,
+                RecordQueryR => record_query_r,
+                CollisionElastic => collision_elastic,
+                EntityStateUpdate => entity_state_update,
+                Attribute => attribute,
+            }
+        }
+    };
+}
+
+pub(crate) use with_body_variants;
+pub(crate) use with_model_fields;
+pub(crate) use with_record_variants;
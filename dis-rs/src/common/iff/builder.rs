@@ -3,12 +3,13 @@
     FundamentalOperationalData, Iff, IffDataRecord, IffDataSpecification,
     IffFundamentalParameterData, IffLayer2, IffLayer3, IffLayer4, IffLayer5, IffPresence,
     InformationLayers, LatLonAltSource, LayerHeader, LayersPresenceApplicability,
-    MalfunctionStatus, Mode5BasicData, Mode5InterrogatorBasicData, Mode5InterrogatorStatus,
-    Mode5MessageFormats, Mode5TransponderBasicData, Mode5TransponderStatus,
-    Mode5TransponderSupplementalData, ModeSAltitude, ModeSBasicData, ModeSInterrogatorBasicData,
-    ModeSInterrogatorStatus, ModeSLevelsPresent, ModeSTransponderBasicData, ModeSTransponderStatus,
-    OnOffStatus, OperationalStatus, ParameterCapable, SquitterStatus, SystemId, SystemSpecificData,
-    SystemStatus,
+    MalfunctionStatus, MarkXXIISystemSpecificData, Mode5BasicData, Mode5InterrogatorBasicData,
+    Mode5InterrogatorStatus, Mode5MessageFormats, Mode5TransponderBasicData,
+    Mode5TransponderStatus, Mode5TransponderSupplementalData, ModeSAltitude, ModeSBasicData,
+    ModeSInterrogatorBasicData, ModeSInterrogatorStatus, ModeSLevelsPresent,
+    ModeSSystemSpecificData, ModeSTransponderBasicData, ModeSTransponderStatus, OnOffStatus,
+    OperationalStatus, ParameterCapable, RrbSystemSpecificData, SovietSystemSpecificData,
+    SquitterStatus, SystemId, SystemSpecificData, SystemStatus,
 };
 use crate::common::model::{BeamData, EntityId, EventId, SimulationAddress, VectorF32};
 use crate::enumerations::{
@@ -39,6 +40,10 @@ pub fn new_from_body(body: Iff) -> Self {
         IffBuilder(body)
     }
 
+    /// Builds the `Iff` body.
+    ///
+    /// The System-Specific Data of the Layer 2 parameters is kept as provided. Use
+    /// `SystemSpecificData::interpreted_as` to convert it for the system type of the IFF system.
     #[must_use]
     pub fn build(self) -> Iff {
         self.0
@@ -636,83 +641,69 @@ pub fn with_system_specific_data(mut self, v: SystemSpecificData) -> Self {
     }
 
     #[must_use]
-    pub fn build(self) -> IffFundamentalParameterData {
-        self.0
-    }
-}
-
-pub struct LayerHeaderBuilder(LayerHeader);
-
-impl Default for LayerHeaderBuilder {
-    fn default() -> Self {
-        Self::new()
-    }
-}
-
-impl LayerHeaderBuilder {
-    #[must_use]
-    pub fn new() -> Self {
-        Self(LayerHeader::default())
+    pub fn with_mark_x_xii_system_specific_data(mut self, v: MarkXXIISystemSpecificData) -> Self {
+        self.0.system_specific_data = SystemSpecificData::MarkXXII(v);
+        self
     }
 
     #[must_use]
-    pub fn with_layer_number(mut self, v: u8) -> Self {
-        self.0.layer_number = v;
+    pub fn with_soviet_system_specific_data(mut self, v: SovietSystemSpecificData) -> Self {
+        self.0.system_specific_data = SystemSpecificData::Soviet(v);
         self
     }
 
     #[must_use]
-    pub fn with_layer_specific_information(mut self, v: u8) -> Self {
-        self.0.layer_specific_information = v;
+    pub fn with_rrb_system_specific_data(mut self, v: RrbSystemSpecificData) -> Self {
+        self.0.system_specific_data = SystemSpecificData::Rrb(v);
         self
     }
 
     #[must_use]
-    pub fn with_length(mut self, v: u16) -> Self {
-        self.0.length = v;
+    pub fn with_mode_s_system_specific_data(mut self, v: ModeSSystemSpecificData) -> Self {
+        self.0.system_specific_data = SystemSpecificData::ModeS(v);
         self
     }
 
     #[must_use]
-    pub fn build(self) -> LayerHeader {
+    pub fn build(self) -> IffFundamentalParameterData {
         self.0
     }
 }
 
-pub struct SystemSpecificDataBuilder(SystemSpecificData);
+pub struct LayerHeaderBuilder(LayerHeader);
 
-impl Default for SystemSpecificDataBuilder {
+impl Default for LayerHeaderBuilder {
     fn default() -> Self {
         Self::new()
     }
 }
 
-impl SystemSpecificDataBuilder {
+impl LayerHeaderBuilder {
     #[must_use]
     pub fn new() -> Self {
-        Self(SystemSpecificData::default())
+        Self(LayerHeader::default())
     }
 
     #[must_use]
-    pub fn with_part_1(mut self, v: u8) -> Self {
-        self.0.part_1 = v;
+    pub fn with_layer_number(mut self, v: u8) -> Self {
+        self.0.layer_number = v;
         self
     }
 
     #[must_use]
-    pub fn with_part_2(mut self, v: u8) -> Self {
-        self.0.part_2 = v;
+    pub fn with_layer_specific_information(mut self, v: u8) -> Self {
+        self.0.layer_specific_information = v;
         self
     }
 
     #[must_use]
-    pub fn with_part_3(mut self, v: u8) -> Self {
-        self.0.part_3 = v;
+    pub fn with_length(mut self, v: u16) -> Self {
+        self.0.length = v;
         self
     }
 
     #[must_use]
-    pub fn build(self) -> SystemSpecificData {
+    pub fn build(self) -> LayerHeader {
         self.0
     }
 }
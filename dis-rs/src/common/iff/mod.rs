@@ -6,16 +6,20 @@
 #[cfg(test)]
 mod tests {
     use crate::common::iff::model::{
-        FundamentalOperationalData, Iff, IffLayer2, InformationLayers, LayerHeader,
-        LayersPresenceApplicability, SystemId,
+        FundamentalOperationalData, Iff, IffFundamentalParameterData, IffLayer2, IffLayer3,
+        IffSystemRole, InformationLayers, LayerHeader, LayersPresenceApplicability,
+        MarkXXIISystemSpecificData, Mode5BasicData, Mode5TransponderBasicData,
+        ModeSSystemSpecificData, ModeSTransponderBasicData, SystemId, SystemSpecificData,
     };
-    use crate::common::model::{EntityId, EventId, Pdu, PduHeader};
+    use crate::common::iff::parser::mode_s_transponder_basic_data;
+    use crate::common::model::{EntityId, EventId, Pdu, PduBody, PduHeader};
     use crate::common::parser::parse_pdu;
     use crate::enumerations::{
         ActiveInterrogationIndicator, CoupledExtensionIndicator, IffSimulationMode, IffSystemType,
         LvcIndicator, PduType, TransferredEntityIndicator,
     };
     use crate::v7::model::PduStatus;
+    use crate::{parse_with_options, DisError, ParseDiagnostic, ParseOptions, Serialize};
     use bytes::BytesMut;
 
     #[test]
@@ -85,4 +89,199 @@ fn internal_consistency() {
             }
         }
     }
+
+    #[test]
+    fn system_specific_data_typed_round_trip() {
+        let header = PduHeader::new_v7(1, PduType::IFF);
+        let mark_x_xii = MarkXXIISystemSpecificData::new()
+            .with_mode_1(true)
+            .with_mode_3a(true)
+            .with_mode_c(true)
+            .with_mode_4_code(0x1234);
+        let iff_body = Iff::builder()
+            .with_emitting_entity_id(EntityId::new(1, 1, 1))
+            .with_event_id(EventId::new(15, 15, 15))
+            .with_fundamental_operational_data(
+                FundamentalOperationalData::builder()
+                    .with_information_layers(
+                        InformationLayers::builder()
+                            .with_layer_1(LayersPresenceApplicability::PresentApplicable)
+                            .with_layer_2(LayersPresenceApplicability::PresentApplicable)
+                            .build(),
+                    )
+                    .build(),
+            )
+            .with_system_id(
+                SystemId::builder()
+                    .with_system_type(IffSystemType::MarkXXIIATCRBSTransponder)
+                    .build(),
+            )
+            .with_layer_2(
+                IffLayer2::builder()
+                    .with_iff_fundamental_parameters(vec![IffFundamentalParameterData::builder()
+                        .with_mark_x_xii_system_specific_data(mark_x_xii)
+                        .build()])
+                    .build(),
+            )
+            .build()
+            .into_pdu_body();
+        let original_pdu = Pdu::finalize_from_parts(header, iff_body, 1);
+
+        let mut buf = BytesMut::with_capacity(original_pdu.header.pdu_length as usize);
+        original_pdu.serialize(&mut buf).unwrap();
+
+        let parsed = parse_pdu(&buf).unwrap();
+        assert_eq!(original_pdu, parsed);
+        if let PduBody::IFF(iff) = parsed.body {
+            assert_eq!(
+                iff.layer_2.unwrap().iff_fundamental_parameters[0].system_specific_data,
+                SystemSpecificData::MarkXXII(mark_x_xii)
+            );
+        } else {
+            panic!("Expected an IFF PDU body");
+        }
+    }
+
+    #[test]
+    fn system_specific_data_conversions() {
+        let bytes = [0xAB, 0xCD, 0xEF];
+        let mode_s = SystemSpecificData::from_bytes(IffSystemType::ModeSTransponder, bytes);
+        assert_eq!(
+            mode_s,
+            SystemSpecificData::ModeS(
+                ModeSSystemSpecificData::new().with_aircraft_address(0x00AB_CDEF)
+            )
+        );
+        assert_eq!(mode_s.to_bytes(), bytes);
+
+        // bit 7 of the modes octet is not covered by the Mark X/XII model
+        let bytes = [0x01, 0x00, 0x00];
+        let mark_x_xii = SystemSpecificData::from_bytes(IffSystemType::MarkXIIATransponder, bytes);
+        assert_eq!(mark_x_xii, SystemSpecificData::Unspecified(bytes));
+        assert_eq!(mark_x_xii.to_bytes(), bytes);
+    }
+
+    #[test]
+    fn builder_keeps_system_specific_data() {
+        let mark_x_xii = SystemSpecificData::MarkXXII(
+            MarkXXIISystemSpecificData::new()
+                .with_mode_1(true)
+                .with_mode_4_code(0x1234),
+        );
+        let iff = Iff::builder()
+            .with_system_id(
+                SystemId::builder()
+                    .with_system_type(IffSystemType::ModeSTransponder)
+                    .build(),
+            )
+            .with_layer_2(
+                IffLayer2::builder()
+                    .with_iff_fundamental_parameters(vec![IffFundamentalParameterData::builder()
+                        .with_system_specific_data(mark_x_xii)
+                        .build()])
+                    .build(),
+            )
+            .build();
+
+        assert_eq!(
+            iff.layer_2.unwrap().iff_fundamental_parameters[0].system_specific_data,
+            mark_x_xii
+        );
+    }
+
+    #[test]
+    fn undetermined_basic_data_is_kept_raw() {
+        let header = PduHeader::new_v7(1, PduType::IFF);
+        let basic_data = Mode5BasicData::new_transponder(
+            Mode5TransponderBasicData::builder()
+                .with_pin(1234)
+                .with_national_origin(42)
+                .with_figure_of_merit(3)
+                .build(),
+        );
+        let iff_body = Iff::builder()
+            .with_emitting_entity_id(EntityId::new(1, 1, 1))
+            .with_event_id(EventId::new(15, 15, 15))
+            .with_fundamental_operational_data(
+                FundamentalOperationalData::builder()
+                    .with_information_layers(
+                        InformationLayers::builder()
+                            .with_layer_1(LayersPresenceApplicability::PresentApplicable)
+                            .with_layer_3(LayersPresenceApplicability::PresentApplicable)
+                            .build(),
+                    )
+                    .build(),
+            )
+            .with_system_id(
+                SystemId::builder()
+                    .with_system_type(IffSystemType::MarkXIICombinedInterrogatorTransponder_CIT_)
+                    .build(),
+            )
+            .with_layer_3(
+                IffLayer3::builder()
+                    .with_mode_5_basic_data(basic_data.clone())
+                    .build(),
+            )
+            .build()
+            .into_pdu_body();
+        let original_pdu = Pdu::finalize_from_parts(header, iff_body, 1);
+
+        let mut buf = BytesMut::with_capacity(original_pdu.header.pdu_length as usize);
+        original_pdu.serialize(&mut buf).unwrap();
+
+        let parsed = parse_pdu(&buf).unwrap();
+        let PduBody::IFF(iff) = parsed.body.clone() else {
+            panic!("Expected an IFF PDU body");
+        };
+        assert!(matches!(
+            iff.layer_3.as_ref().unwrap().mode_5_basic_data,
+            Mode5BasicData::Undetermined(_)
+        ));
+        assert_eq!(iff.diagnostics(), vec![DisError::IffUndeterminedSystemType]);
+
+        // the raw basic data is serialized unchanged
+        let mut reserialized = BytesMut::with_capacity(parsed.header.pdu_length as usize);
+        parsed.serialize(&mut reserialized).unwrap();
+        assert_eq!(buf, reserialized);
+
+        let resolved = iff
+            .resolve_system_role(|_| Some(IffSystemRole::Transponder))
+            .unwrap();
+        assert_eq!(resolved.layer_3.unwrap().mode_5_basic_data, basic_data);
+        assert!(resolved.layer_4.is_none());
+
+        let report = parse_with_options(&buf, &ParseOptions::default()).unwrap();
+        assert_eq!(
+            report.diagnostics,
+            vec![ParseDiagnostic {
+                pdu_index: 0,
+                error: DisError::IffUndeterminedSystemType
+            }]
+        );
+
+        let options = ParseOptions::new().with_iff_system_role(
+            IffSystemType::MarkXIICombinedInterrogatorTransponder_CIT_,
+            IffSystemRole::Transponder,
+        );
+        let report = parse_with_options(&buf, &options).unwrap();
+        assert!(report.diagnostics.is_empty());
+        let PduBody::IFF(iff) = &report.pdus[0].body else {
+            panic!("Expected an IFF PDU body");
+        };
+        assert_eq!(iff.layer_3.as_ref().unwrap().mode_5_basic_data, basic_data);
+    }
+
+    #[test]
+    fn mode_s_transponder_basic_data_is_24_octets() {
+        let basic_data = ModeSTransponderBasicData::default();
+        let mut buf = BytesMut::with_capacity(24);
+
+        let written = basic_data.serialize(&mut buf);
+        assert_eq!(written, 24);
+        assert_eq!(buf.len(), 24);
+
+        let (input, parsed) = mode_s_transponder_basic_data(&buf).unwrap();
+        assert!(input.is_empty());
+        assert_eq!(parsed, basic_data);
+    }
 }
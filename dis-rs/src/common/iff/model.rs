@@ -8,7 +8,11 @@
     Mode5TransponderSupplementalDataBuilder, ModeSAltitudeBuilder,
     ModeSInterrogatorBasicDataBuilder, ModeSInterrogatorStatusBuilder, ModeSLevelsPresentBuilder,
     ModeSTransponderBasicDataBuilder, ModeSTransponderStatusBuilder, SystemIdBuilder,
-    SystemSpecificDataBuilder, SystemStatusBuilder,
+    SystemStatusBuilder,
+};
+use crate::common::iff::parser::{
+    mode_5_interrogator_basic_data, mode_5_transponder_basic_data, mode_s_interrogator_basic_data,
+    mode_s_transponder_basic_data,
 };
 use crate::common::model::{
     length_padded_to_num, BeamData, EntityId, EventId, PduBody, SimulationAddress, VectorF32,
@@ -26,11 +30,14 @@
     ModeSSquitterRecordSource, ModeSSquitterType, ModeSTransmitState, NavigationSource, PduType,
     VariableRecordType,
 };
+use crate::DisError;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 pub const IFF_PDU_LAYER_1_DATA_LENGTH_OCTETS: u16 = 48;
 pub const BASE_IFF_DATA_RECORD_LENGTH_OCTETS: u16 = 6;
+pub const MODE_5_BASIC_DATA_LENGTH_OCTETS: usize = 16;
+pub const MODE_S_BASIC_DATA_LENGTH_OCTETS: usize = 24;
 
 /// 7.6.5 Identification Friend or Foe (IFF) PDU
 ///
@@ -69,6 +76,56 @@ pub fn into_builder(self) -> IffBuilder {
     pub fn into_pdu_body(self) -> PduBody {
         PduBody::IFF(self)
     }
+
+    /// Reports the issues found while parsing the IFF PDU.
+    ///
+    /// When the system type does not determine whether the IFF system is an interrogator or a transponder,
+    /// the basic data of Layers 3 and 4 is kept as `Undetermined` raw bytes,
+    /// which is reported as `DisError::IffUndeterminedSystemType` (or `DisError::IffIncorrectSystemType`
+    /// for invalid system types).
+    #[must_use]
+    pub fn diagnostics(&self) -> Vec<DisError> {
+        let layer_3_undetermined = self.layer_3.as_ref().is_some_and(|layer| {
+            matches!(layer.mode_5_basic_data, Mode5BasicData::Undetermined(_))
+        });
+        let layer_4_undetermined = self.layer_4.as_ref().is_some_and(|layer| {
+            matches!(layer.mode_s_basic_data, ModeSBasicData::Undetermined(_))
+        });
+        let error = || match self.system_id.system_type {
+            IffSystemType::NotUsed_InvalidValue_ | IffSystemType::Unspecified(_) => {
+                DisError::IffIncorrectSystemType
+            }
+            _ => DisError::IffUndeterminedSystemType,
+        };
+
+        [layer_3_undetermined, layer_4_undetermined]
+            .iter()
+            .filter(|undetermined| **undetermined)
+            .map(|_| error())
+            .collect()
+    }
+
+    /// Resolves `Undetermined` basic data in Layers 3 and 4, using the role that `system_role`
+    /// provides for the system type of the IFF system.
+    /// When `system_role` returns `None` the basic data stays undetermined.
+    ///
+    /// # Errors
+    /// Returns a `DisError::ParseError` when the raw basic data cannot be parsed in the format of the provided role.
+    pub fn resolve_system_role(
+        mut self,
+        system_role: impl Fn(IffSystemType) -> Option<IffSystemRole>,
+    ) -> Result<Self, DisError> {
+        let Some(role) = system_role(self.system_id.system_type) else {
+            return Ok(self);
+        };
+        if let Some(layer_3) = self.layer_3.as_mut() {
+            layer_3.mode_5_basic_data = layer_3.mode_5_basic_data.clone().resolve(role)?;
+        }
+        if let Some(layer_4) = self.layer_4.as_mut() {
+            layer_4.mode_s_basic_data = layer_4.mode_s_basic_data.clone().resolve(role)?;
+        }
+        Ok(self)
+    }
 }
 
 impl BodyInfo for Iff {
@@ -215,6 +272,7 @@ pub fn finalize_layer_header_length(mut self) -> Self {
 pub enum Mode5BasicData {
     Interrogator(Mode5InterrogatorBasicData), // 7.6.5.4.2 Layer 3 Mode 5 Interrogator Format
     Transponder(Mode5TransponderBasicData),   // 7.6.5.4.3 Layer 3 Mode 5 Transponder Format
+    Undetermined([u8; MODE_5_BASIC_DATA_LENGTH_OCTETS]), // raw basic data, when the system type does not determine the format
 }
 
 impl Default for Mode5BasicData {
@@ -233,6 +291,26 @@ pub fn new_interrogator(basic_data: Mode5InterrogatorBasicData) -> Self {
     pub fn new_transponder(basic_data: Mode5TransponderBasicData) -> Self {
         Self::Transponder(basic_data)
     }
+
+    /// Parses `Undetermined` basic data in the format of the provided role.
+    /// Basic data that is already determined is returned unchanged.
+    ///
+    /// # Errors
+    /// Returns a `DisError::ParseError` when the raw basic data cannot be parsed in the format of `role`.
+    pub fn resolve(self, role: IffSystemRole) -> Result<Self, DisError> {
+        let Self::Undetermined(bytes) = self else {
+            return Ok(self);
+        };
+        let result = match role {
+            IffSystemRole::Interrogator => {
+                mode_5_interrogator_basic_data(&bytes).map(|(_, data)| Self::Interrogator(data))
+            }
+            IffSystemRole::Transponder => {
+                mode_5_transponder_basic_data(&bytes).map(|(_, data)| Self::Transponder(data))
+            }
+        };
+        result.map_err(|err| DisError::ParseError(err.to_string()))
+    }
 }
 
 /// 7.6.5.5 Layer 4 Mode S formats
@@ -286,6 +364,7 @@ pub fn finalize_layer_header_length(mut self) -> Self {
 pub enum ModeSBasicData {
     Interrogator(ModeSInterrogatorBasicData), // 7.6.5.5.2 Layer 4 Mode S Interrogator Format
     Transponder(ModeSTransponderBasicData),   // 7.6.5.5.3 Layer 4 Mode S Transponder Format
+    Undetermined([u8; MODE_S_BASIC_DATA_LENGTH_OCTETS]), // raw basic data, when the system type does not determine the format
 }
 
 impl Default for ModeSBasicData {
@@ -304,6 +383,60 @@ pub fn new_interrogator(basic_data: ModeSInterrogatorBasicData) -> Self {
     pub fn new_transponder(basic_data: ModeSTransponderBasicData) -> Self {
         Self::Transponder(basic_data)
     }
+
+    /// Parses `Undetermined` basic data in the format of the provided role.
+    /// Basic data that is already determined is returned unchanged.
+    ///
+    /// # Errors
+    /// Returns a `DisError::ParseError` when the raw basic data cannot be parsed in the format of `role`.
+    pub fn resolve(self, role: IffSystemRole) -> Result<Self, DisError> {
+        let Self::Undetermined(bytes) = self else {
+            return Ok(self);
+        };
+        let result = match role {
+            IffSystemRole::Interrogator => {
+                mode_s_interrogator_basic_data(&bytes).map(|(_, data)| Self::Interrogator(data))
+            }
+            IffSystemRole::Transponder => {
+                mode_s_transponder_basic_data(&bytes).map(|(_, data)| Self::Transponder(data))
+            }
+        };
+        result.map_err(|err| DisError::ParseError(err.to_string()))
+    }
+}
+
+/// The role of an IFF system, which determines the format of the basic data in Layers 3 and 4.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum IffSystemRole {
+    Interrogator,
+    Transponder,
+}
+
+impl IffSystemRole {
+    /// Returns the role of the IFF system for the given system type,
+    /// or `None` when the system type does not determine whether it is an interrogator or a transponder.
+    #[must_use]
+    pub fn from_system_type(system_type: IffSystemType) -> Option<Self> {
+        match system_type {
+            IffSystemType::MarkXXIIATCRBSTransponder
+            | IffSystemType::SovietTransponder
+            | IffSystemType::RRBTransponder
+            | IffSystemType::MarkXIIATransponder
+            | IffSystemType::Mode5Transponder
+            | IffSystemType::ModeSTransponder => Some(Self::Transponder),
+            IffSystemType::MarkXXIIATCRBSInterrogator
+            | IffSystemType::SovietInterrogator
+            | IffSystemType::MarkXIIAInterrogator
+            | IffSystemType::Mode5Interrogator
+            | IffSystemType::ModeSInterrogator => Some(Self::Interrogator),
+            IffSystemType::MarkXIIACombinedInterrogatorTransponder_CIT_
+            | IffSystemType::MarkXIICombinedInterrogatorTransponder_CIT_
+            | IffSystemType::TCASACASTransceiver
+            | IffSystemType::NotUsed_InvalidValue_
+            | IffSystemType::Unspecified(_) => None,
+        }
+    }
 }
 
 /// 7.6.5.6 Layer 5 data communications
@@ -672,13 +805,27 @@ pub fn builder() -> LayerHeaderBuilder {
     }
 }
 
-// TODO placeholder for 24-bits - See Annex B.
-#[derive(Copy, Clone, Default, Debug, PartialEq)]
+/// System-Specific Data field (24 bits) of the IFF Fundamental Parameter Data record (6.2.44), see Annex B.
+///
+/// The meaning of the bits is defined by the `IffSystemType` of the IFF system.
+/// Data of system types without a typed model, or containing bits that the typed model does not cover,
+/// is kept as raw bytes in `SystemSpecificData::Unspecified`.
+#[derive(Copy, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-pub struct SystemSpecificData {
-    pub part_1: u8,
-    pub part_2: u8,
-    pub part_3: u8,
+pub enum SystemSpecificData {
+    MarkXXII(MarkXXIISystemSpecificData),
+    Soviet(SovietSystemSpecificData),
+    Rrb(RrbSystemSpecificData),
+    ModeS(ModeSSystemSpecificData),
+    Unspecified([u8; SYSTEM_SPECIFIC_DATA_OCTETS]),
+}
+
+pub const SYSTEM_SPECIFIC_DATA_OCTETS: usize = 3;
+
+impl Default for SystemSpecificData {
+    fn default() -> Self {
+        Self::Unspecified([0; SYSTEM_SPECIFIC_DATA_OCTETS])
+    }
 }
 
 impl SystemSpecificData {
@@ -687,9 +834,319 @@ pub fn new() -> Self {
         SystemSpecificData::default()
     }
 
+    /// Interprets the raw System-Specific Data bytes for the given system type.
+    ///
+    /// The typed variant is only selected when it represents the bytes losslessly,
+    /// otherwise the bytes are returned as `SystemSpecificData::Unspecified`.
+    #[must_use]
+    pub fn from_bytes(
+        system_type: IffSystemType,
+        bytes: [u8; SYSTEM_SPECIFIC_DATA_OCTETS],
+    ) -> Self {
+        let typed = match system_type {
+            IffSystemType::MarkXXIIATCRBSTransponder
+            | IffSystemType::MarkXXIIATCRBSInterrogator
+            | IffSystemType::MarkXIIATransponder
+            | IffSystemType::MarkXIIAInterrogator
+            | IffSystemType::MarkXIIACombinedInterrogatorTransponder_CIT_
+            | IffSystemType::MarkXIICombinedInterrogatorTransponder_CIT_
+            | IffSystemType::TCASACASTransceiver
+            | IffSystemType::Mode5Interrogator
+            | IffSystemType::Mode5Transponder => {
+                Self::MarkXXII(MarkXXIISystemSpecificData::from(bytes))
+            }
+            IffSystemType::SovietTransponder | IffSystemType::SovietInterrogator => {
+                Self::Soviet(SovietSystemSpecificData::from(bytes))
+            }
+            IffSystemType::RRBTransponder => Self::Rrb(RrbSystemSpecificData::from(bytes)),
+            IffSystemType::ModeSInterrogator | IffSystemType::ModeSTransponder => {
+                Self::ModeS(ModeSSystemSpecificData::from(bytes))
+            }
+            _ => Self::Unspecified(bytes),
+        };
+        if typed.to_bytes() == bytes {
+            typed
+        } else {
+            Self::Unspecified(bytes)
+        }
+    }
+
+    /// Converts the System-Specific Data to the raw 24 bits.
+    #[must_use]
+    pub fn to_bytes(&self) -> [u8; SYSTEM_SPECIFIC_DATA_OCTETS] {
+        match self {
+            SystemSpecificData::MarkXXII(data) => (*data).into(),
+            SystemSpecificData::Soviet(data) => (*data).into(),
+            SystemSpecificData::Rrb(data) => (*data).into(),
+            SystemSpecificData::ModeS(data) => (*data).into(),
+            SystemSpecificData::Unspecified(bytes) => *bytes,
+        }
+    }
+
+    /// Re-interprets the System-Specific Data for the given system type.
+    #[must_use]
+    pub fn interpreted_as(&self, system_type: IffSystemType) -> Self {
+        Self::from_bytes(system_type, self.to_bytes())
+    }
+}
+
+/// System-Specific Data for Mark X/XII, ATCRBS, TCAS and Mode 5 systems.
+///
+/// The first octet holds a flag per mode (bits 0 to 6), the remaining two octets hold the Mode 4 code.
+#[derive(Copy, Clone, Default, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MarkXXIISystemSpecificData {
+    pub mode_1: bool,
+    pub mode_2: bool,
+    pub mode_3a: bool,
+    pub mode_4: bool,
+    pub mode_c: bool,
+    pub mode_s: bool,
+    pub mode_5: bool,
+    pub mode_4_code: u16,
+}
+
+impl MarkXXIISystemSpecificData {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn with_mode_1(mut self, mode_1: bool) -> Self {
+        self.mode_1 = mode_1;
+        self
+    }
+
+    #[must_use]
+    pub fn with_mode_2(mut self, mode_2: bool) -> Self {
+        self.mode_2 = mode_2;
+        self
+    }
+
+    #[must_use]
+    pub fn with_mode_3a(mut self, mode_3a: bool) -> Self {
+        self.mode_3a = mode_3a;
+        self
+    }
+
+    #[must_use]
+    pub fn with_mode_4(mut self, mode_4: bool) -> Self {
+        self.mode_4 = mode_4;
+        self
+    }
+
+    #[must_use]
+    pub fn with_mode_c(mut self, mode_c: bool) -> Self {
+        self.mode_c = mode_c;
+        self
+    }
+
+    #[must_use]
+    pub fn with_mode_s(mut self, mode_s: bool) -> Self {
+        self.mode_s = mode_s;
+        self
+    }
+
+    #[must_use]
+    pub fn with_mode_5(mut self, mode_5: bool) -> Self {
+        self.mode_5 = mode_5;
+        self
+    }
+
+    #[must_use]
+    pub fn with_mode_4_code(mut self, mode_4_code: u16) -> Self {
+        self.mode_4_code = mode_4_code;
+        self
+    }
+}
+
+impl From<[u8; SYSTEM_SPECIFIC_DATA_OCTETS]> for MarkXXIISystemSpecificData {
+    fn from(value: [u8; SYSTEM_SPECIFIC_DATA_OCTETS]) -> Self {
+        let modes = value[0];
+        Self {
+            mode_1: (modes & BIT_0_IN_BYTE) != 0,
+            mode_2: (modes & BIT_1_IN_BYTE) != 0,
+            mode_3a: (modes & BIT_2_IN_BYTE) != 0,
+            mode_4: (modes & BIT_3_IN_BYTE) != 0,
+            mode_c: (modes & BIT_4_IN_BYTE) != 0,
+            mode_s: (modes & BIT_5_IN_BYTE) != 0,
+            mode_5: (modes & BIT_6_IN_BYTE) != 0,
+            mode_4_code: u16::from_be_bytes([value[1], value[2]]),
+        }
+    }
+}
+
+impl From<MarkXXIISystemSpecificData> for [u8; SYSTEM_SPECIFIC_DATA_OCTETS] {
+    fn from(value: MarkXXIISystemSpecificData) -> Self {
+        let modes = [
+            (value.mode_1, BIT_0_IN_BYTE),
+            (value.mode_2, BIT_1_IN_BYTE),
+            (value.mode_3a, BIT_2_IN_BYTE),
+            (value.mode_4, BIT_3_IN_BYTE),
+            (value.mode_c, BIT_4_IN_BYTE),
+            (value.mode_s, BIT_5_IN_BYTE),
+            (value.mode_5, BIT_6_IN_BYTE),
+        ]
+        .iter()
+        .filter(|(enabled, _)| *enabled)
+        .fold(0u8, |modes, (_, bit)| modes | bit);
+        let [code_1, code_2] = value.mode_4_code.to_be_bytes();
+        [modes, code_1, code_2]
+    }
+}
+
+/// System-Specific Data for Soviet IFF systems.
+///
+/// The first two octets hold the Soviet IFF code, the third octet the mode.
+#[derive(Copy, Clone, Default, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SovietSystemSpecificData {
+    pub code: u16,
+    pub mode: u8,
+}
+
+impl SovietSystemSpecificData {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn with_code(mut self, code: u16) -> Self {
+        self.code = code;
+        self
+    }
+
+    #[must_use]
+    pub fn with_mode(mut self, mode: u8) -> Self {
+        self.mode = mode;
+        self
+    }
+}
+
+impl From<[u8; SYSTEM_SPECIFIC_DATA_OCTETS]> for SovietSystemSpecificData {
+    fn from(value: [u8; SYSTEM_SPECIFIC_DATA_OCTETS]) -> Self {
+        Self {
+            code: u16::from_be_bytes([value[0], value[1]]),
+            mode: value[2],
+        }
+    }
+}
+
+impl From<SovietSystemSpecificData> for [u8; SYSTEM_SPECIFIC_DATA_OCTETS] {
+    fn from(value: SovietSystemSpecificData) -> Self {
+        let [code_1, code_2] = value.code.to_be_bytes();
+        [code_1, code_2, value.mode]
+    }
+}
+
+/// System-Specific Data for Radar Beacon (RRB) transponders.
+///
+/// The first octet holds the RRB code (bits 0 to 4) and the Power Reduction and Radar Enhancement flags (bits 5 and 6),
+/// the remaining two octets hold the reply delay.
+#[derive(Copy, Clone, Default, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RrbSystemSpecificData {
+    pub code: u8,
+    pub power_reduction: bool,
+    pub radar_enhancement: bool,
+    pub delay: u16,
+}
+
+impl RrbSystemSpecificData {
     #[must_use]
-    pub fn builder() -> SystemSpecificDataBuilder {
-        SystemSpecificDataBuilder::new()
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn with_code(mut self, code: u8) -> Self {
+        self.code = code;
+        self
+    }
+
+    #[must_use]
+    pub fn with_power_reduction(mut self, power_reduction: bool) -> Self {
+        self.power_reduction = power_reduction;
+        self
+    }
+
+    #[must_use]
+    pub fn with_radar_enhancement(mut self, radar_enhancement: bool) -> Self {
+        self.radar_enhancement = radar_enhancement;
+        self
+    }
+
+    #[must_use]
+    pub fn with_delay(mut self, delay: u16) -> Self {
+        self.delay = delay;
+        self
+    }
+}
+
+impl From<[u8; SYSTEM_SPECIFIC_DATA_OCTETS]> for RrbSystemSpecificData {
+    fn from(value: [u8; SYSTEM_SPECIFIC_DATA_OCTETS]) -> Self {
+        Self {
+            code: (value[0] >> 3) & 0x1F,
+            power_reduction: (value[0] & BIT_5_IN_BYTE) != 0,
+            radar_enhancement: (value[0] & BIT_6_IN_BYTE) != 0,
+            delay: u16::from_be_bytes([value[1], value[2]]),
+        }
+    }
+}
+
+impl From<RrbSystemSpecificData> for [u8; SYSTEM_SPECIFIC_DATA_OCTETS] {
+    fn from(value: RrbSystemSpecificData) -> Self {
+        let flags = (value.code & 0x1F) << 3;
+        let flags = if value.power_reduction {
+            flags | BIT_5_IN_BYTE
+        } else {
+            flags
+        };
+        let flags = if value.radar_enhancement {
+            flags | BIT_6_IN_BYTE
+        } else {
+            flags
+        };
+        let [delay_1, delay_2] = value.delay.to_be_bytes();
+        [flags, delay_1, delay_2]
+    }
+}
+
+/// System-Specific Data for Mode S interrogators and transponders, holding the 24-bit aircraft address.
+#[derive(Copy, Clone, Default, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ModeSSystemSpecificData {
+    pub aircraft_address: u32,
+}
+
+impl ModeSSystemSpecificData {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the aircraft address. Only the 24 least significant bits are used.
+    #[must_use]
+    pub fn with_aircraft_address(mut self, aircraft_address: u32) -> Self {
+        self.aircraft_address = aircraft_address & 0x00FF_FFFF;
+        self
+    }
+}
+
+impl From<[u8; SYSTEM_SPECIFIC_DATA_OCTETS]> for ModeSSystemSpecificData {
+    fn from(value: [u8; SYSTEM_SPECIFIC_DATA_OCTETS]) -> Self {
+        Self {
+            aircraft_address: u32::from_be_bytes([0, value[0], value[1], value[2]]),
+        }
+    }
+}
+
+impl From<ModeSSystemSpecificData> for [u8; SYSTEM_SPECIFIC_DATA_OCTETS] {
+    fn from(value: ModeSSystemSpecificData) -> Self {
+        let [_, address_1, address_2, address_3] = value.aircraft_address.to_be_bytes();
+        [address_1, address_2, address_3]
     }
 }
 
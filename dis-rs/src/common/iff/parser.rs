@@ -2,17 +2,18 @@
     ChangeOptionsRecord, DamageStatus, DapSource, DapValue, EnabledStatus, EnhancedMode1Code,
     FundamentalOperationalData, Iff, IffDataRecord, IffDataSpecification,
     IffFundamentalParameterData, IffLayer2, IffLayer3, IffLayer4, IffLayer5, IffPresence,
-    InformationLayers, LatLonAltSource, LayerHeader, LayersPresenceApplicability,
+    IffSystemRole, InformationLayers, LatLonAltSource, LayerHeader, LayersPresenceApplicability,
     MalfunctionStatus, Mode5BasicData, Mode5InterrogatorBasicData, Mode5InterrogatorStatus,
     Mode5MessageFormats, Mode5TransponderBasicData, Mode5TransponderStatus,
     Mode5TransponderSupplementalData, ModeSAltitude, ModeSBasicData, ModeSInterrogatorBasicData,
     ModeSInterrogatorStatus, ModeSLevelsPresent, ModeSTransponderBasicData, ModeSTransponderStatus,
     OnOffStatus, OperationalStatus, ParameterCapable, SquitterStatus, SystemId, SystemSpecificData,
-    SystemStatus, BASE_IFF_DATA_RECORD_LENGTH_OCTETS,
+    SystemStatus, BASE_IFF_DATA_RECORD_LENGTH_OCTETS, MODE_5_BASIC_DATA_LENGTH_OCTETS,
+    MODE_S_BASIC_DATA_LENGTH_OCTETS, SYSTEM_SPECIFIC_DATA_OCTETS,
 };
 use crate::common::model::PduBody;
 use crate::common::parser::{beam_data, entity_id, event_id, simulation_address, vec3_f32};
-use crate::common::DisError;
+use crate::common::ParseOptions;
 use crate::constants::EIGHT_OCTETS;
 use crate::enumerations::{
     AircraftIdentificationType, AircraftPresentDomain, CapabilityReport, DataCategory,
@@ -24,88 +25,97 @@
 use nom::number::complete::{be_f32, be_u16, be_u32, be_u8};
 use nom::IResult;
 
-pub(crate) fn iff_body(input: &[u8]) -> IResult<&[u8], PduBody> {
-    let (input, entity_id) = entity_id(input)?;
-    let (input, event_id) = event_id(input)?;
-    let (input, antenna_location) = vec3_f32(input)?;
-    let (input, system_id) = system_id(input)?;
-    let (input, system_designator) = be_u8(input)?;
-    let (input, system_specific_data) = be_u8(input)?;
-    let (input, fundamental_data) = fundamental_operational_data(input)?;
-
-    let builder = Iff::builder();
-
-    let (input, builder) = if fundamental_data.information_layers.layer_2
-        == LayersPresenceApplicability::PresentApplicable
-    {
-        let (input, layer_2) = iff_layer_2(input)?;
-        (input, builder.with_layer_2(layer_2))
-    } else {
-        (input, builder)
-    };
-    let (input, builder) = if fundamental_data.information_layers.layer_3
-        == LayersPresenceApplicability::PresentApplicable
-    {
-        let (input, layer_3) = iff_layer_3(&system_id.system_type)(input)?;
-        (input, builder.with_layer_3(layer_3))
-    } else {
-        (input, builder)
-    };
-    let (input, builder) = if fundamental_data.information_layers.layer_4
-        == LayersPresenceApplicability::PresentApplicable
-    {
-        let (input, layer_4) = iff_layer_4(&system_id.system_type)(input)?;
-        (input, builder.with_layer_4(layer_4))
-    } else {
-        (input, builder)
-    };
-    let (input, builder) = if fundamental_data.information_layers.layer_5
-        == LayersPresenceApplicability::PresentApplicable
-    {
-        let (input, layer_5) = iff_layer_5(input)?;
-        (input, builder.with_layer_5(layer_5))
-    } else {
-        (input, builder)
-    };
-
-    let builder = builder
-        .with_emitting_entity_id(entity_id)
-        .with_event_id(event_id)
-        .with_relative_antenna_location(antenna_location)
-        .with_system_id(system_id)
-        .with_system_designator(system_designator)
-        .with_system_specific_data(system_specific_data)
-        .with_fundamental_operational_data(fundamental_data);
-
-    Ok((input, builder.build().into_pdu_body()))
-}
-
-fn iff_layer_2(input: &[u8]) -> IResult<&[u8], IffLayer2> {
-    let (input, layer_header) = layer_header(input)?;
-    let (input, beam_data) = beam_data(input)?;
-    let (input, operational_parameter_1) = be_u8(input)?;
-    let (input, operational_parameter_2) = be_u8(input)?;
-    let (input, num_params) = be_u16(input)?;
-    let (input, fundamental_parameters) =
-        count(iff_fundamental_parameter_data, num_params.into())(input)?;
+/// Parses an IFF PDU body, parsing the basic data in Layers 3 and 4 in the format for the role
+/// that `options` provides for the system type.
+pub(crate) fn iff_body(options: &ParseOptions) -> impl Fn(&[u8]) -> IResult<&[u8], PduBody> + '_ {
+    move |input: &[u8]| {
+        let (input, entity_id) = entity_id(input)?;
+        let (input, event_id) = event_id(input)?;
+        let (input, antenna_location) = vec3_f32(input)?;
+        let (input, system_id) = system_id(input)?;
+        let (input, system_designator) = be_u8(input)?;
+        let (input, system_specific_data) = be_u8(input)?;
+        let (input, fundamental_data) = fundamental_operational_data(input)?;
+        let system_role = options.iff_system_role(system_id.system_type);
+
+        let builder = Iff::builder();
+
+        let (input, builder) = if fundamental_data.information_layers.layer_2
+            == LayersPresenceApplicability::PresentApplicable
+        {
+            let (input, layer_2) = iff_layer_2(system_id.system_type)(input)?;
+            (input, builder.with_layer_2(layer_2))
+        } else {
+            (input, builder)
+        };
+        let (input, builder) = if fundamental_data.information_layers.layer_3
+            == LayersPresenceApplicability::PresentApplicable
+        {
+            let (input, layer_3) = iff_layer_3(system_role)(input)?;
+            (input, builder.with_layer_3(layer_3))
+        } else {
+            (input, builder)
+        };
+        let (input, builder) = if fundamental_data.information_layers.layer_4
+            == LayersPresenceApplicability::PresentApplicable
+        {
+            let (input, layer_4) = iff_layer_4(system_role)(input)?;
+            (input, builder.with_layer_4(layer_4))
+        } else {
+            (input, builder)
+        };
+        let (input, builder) = if fundamental_data.information_layers.layer_5
+            == LayersPresenceApplicability::PresentApplicable
+        {
+            let (input, layer_5) = iff_layer_5(input)?;
+            (input, builder.with_layer_5(layer_5))
+        } else {
+            (input, builder)
+        };
+
+        let builder = builder
+            .with_emitting_entity_id(entity_id)
+            .with_event_id(event_id)
+            .with_relative_antenna_location(antenna_location)
+            .with_system_id(system_id)
+            .with_system_designator(system_designator)
+            .with_system_specific_data(system_specific_data)
+            .with_fundamental_operational_data(fundamental_data);
 
-    Ok((
-        input,
-        IffLayer2::builder()
-            .with_header(layer_header)
-            .with_beam_data(beam_data)
-            .with_operational_parameter_1(operational_parameter_1)
-            .with_operational_parameter_2(operational_parameter_2)
-            .with_iff_fundamental_parameters(fundamental_parameters)
-            .build(),
-    ))
+        Ok((input, builder.build().into_pdu_body()))
+    }
+}
+
+fn iff_layer_2(system_type: IffSystemType) -> impl Fn(&[u8]) -> IResult<&[u8], IffLayer2> {
+    move |input: &[u8]| {
+        let (input, layer_header) = layer_header(input)?;
+        let (input, beam_data) = beam_data(input)?;
+        let (input, operational_parameter_1) = be_u8(input)?;
+        let (input, operational_parameter_2) = be_u8(input)?;
+        let (input, num_params) = be_u16(input)?;
+        let (input, fundamental_parameters) = count(
+            iff_fundamental_parameter_data(system_type),
+            num_params.into(),
+        )(input)?;
+
+        Ok((
+            input,
+            IffLayer2::builder()
+                .with_header(layer_header)
+                .with_beam_data(beam_data)
+                .with_operational_parameter_1(operational_parameter_1)
+                .with_operational_parameter_2(operational_parameter_2)
+                .with_iff_fundamental_parameters(fundamental_parameters)
+                .build(),
+        ))
+    }
 }
 
-fn iff_layer_3(system_type: &IffSystemType) -> impl Fn(&[u8]) -> IResult<&[u8], IffLayer3> + '_ {
+fn iff_layer_3(role: Option<IffSystemRole>) -> impl Fn(&[u8]) -> IResult<&[u8], IffLayer3> {
     move |input: &[u8]| {
         let (input, layer_header) = layer_header(input)?;
         let (input, reporting_simulation) = simulation_address(input)?;
-        let (input, basic_data) = mode_5_basic_data(system_type)(input)?;
+        let (input, basic_data) = mode_5_basic_data(role)(input)?;
         let (input, _padding) = be_u16(input)?;
         let (input, data_specification) = iff_data_specification(input)?;
 
@@ -114,21 +124,18 @@ fn iff_layer_3(system_type: &IffSystemType) -> impl Fn(&[u8]) -> IResult<&[u8],
             IffLayer3::builder()
                 .with_header(layer_header)
                 .with_reporting_simulation(reporting_simulation)
-                // TODO when we cannot match the system type, we insert the default Basic Data (transponder)
-                .with_mode_5_basic_data(basic_data.unwrap_or(Mode5BasicData::new_transponder(
-                    Mode5TransponderBasicData::default(),
-                )))
+                .with_mode_5_basic_data(basic_data)
                 .with_iff_data_specification(data_specification)
                 .build(),
         ))
     }
 }
 
-fn iff_layer_4(system_type: &IffSystemType) -> impl Fn(&[u8]) -> IResult<&[u8], IffLayer4> + '_ {
+fn iff_layer_4(role: Option<IffSystemRole>) -> impl Fn(&[u8]) -> IResult<&[u8], IffLayer4> {
     move |input: &[u8]| {
         let (input, layer_header) = layer_header(input)?;
         let (input, reporting_simulation) = simulation_address(input)?;
-        let (input, basic_data) = mode_s_basic_data(system_type)(input)?;
+        let (input, basic_data) = mode_s_basic_data(role)(input)?;
         let (input, _padding) = be_u16(input)?;
         let (input, data_specification) = iff_data_specification(input)?;
 
@@ -137,10 +144,7 @@ fn iff_layer_4(system_type: &IffSystemType) -> impl Fn(&[u8]) -> IResult<&[u8],
             IffLayer4::builder()
                 .with_header(layer_header)
                 .with_reporting_simulation(reporting_simulation)
-                // TODO when we cannot match the system type, we insert the default Basic Data (transponder)
-                .with_mode_s_basic_data(basic_data.unwrap_or(ModeSBasicData::Transponder(
-                    ModeSTransponderBasicData::default(),
-                )))
+                .with_mode_s_basic_data(basic_data)
                 .with_iff_data_specification(data_specification)
                 .build(),
         ))
@@ -238,28 +242,32 @@ fn information_layers(input: &[u8]) -> IResult<&[u8], InformationLayers> {
     Ok((input, InformationLayers::from(record)))
 }
 
-fn iff_fundamental_parameter_data(input: &[u8]) -> IResult<&[u8], IffFundamentalParameterData> {
-    let (input, erp) = be_f32(input)?;
-    let (input, frequency) = be_f32(input)?;
-    let (input, pgrf) = be_f32(input)?;
-    let (input, pulse_width) = be_f32(input)?;
-    let (input, burst_length) = be_f32(input)?;
-    let (input, applicable_modes) = be_u8(input)?;
-    let applicable_modes = IffApplicableModes::from(applicable_modes);
-    let (input, system_specific_data) = system_specific_data(input)?;
+fn iff_fundamental_parameter_data(
+    system_type: IffSystemType,
+) -> impl Fn(&[u8]) -> IResult<&[u8], IffFundamentalParameterData> {
+    move |input: &[u8]| {
+        let (input, erp) = be_f32(input)?;
+        let (input, frequency) = be_f32(input)?;
+        let (input, pgrf) = be_f32(input)?;
+        let (input, pulse_width) = be_f32(input)?;
+        let (input, burst_length) = be_f32(input)?;
+        let (input, applicable_modes) = be_u8(input)?;
+        let applicable_modes = IffApplicableModes::from(applicable_modes);
+        let (input, system_specific_data) = system_specific_data(system_type)(input)?;
 
-    Ok((
-        input,
-        IffFundamentalParameterData::builder()
-            .with_erp(erp)
-            .with_frequency(frequency)
-            .with_pgrf(pgrf)
-            .with_pulse_width(pulse_width)
-            .with_burst_length(burst_length)
-            .with_applicable_modes(applicable_modes)
-            .with_system_specific_data(system_specific_data)
-            .build(),
-    ))
+        Ok((
+            input,
+            IffFundamentalParameterData::builder()
+                .with_erp(erp)
+                .with_frequency(frequency)
+                .with_pgrf(pgrf)
+                .with_pulse_width(pulse_width)
+                .with_burst_length(burst_length)
+                .with_applicable_modes(applicable_modes)
+                .with_system_specific_data(system_specific_data)
+                .build(),
+        ))
+    }
 }
 
 fn layer_header(input: &[u8]) -> IResult<&[u8], LayerHeader> {
@@ -277,19 +285,16 @@ fn layer_header(input: &[u8]) -> IResult<&[u8], LayerHeader> {
     ))
 }
 
-fn system_specific_data(input: &[u8]) -> IResult<&[u8], SystemSpecificData> {
-    let (input, part_1) = be_u8(input)?;
-    let (input, part_2) = be_u8(input)?;
-    let (input, part_3) = be_u8(input)?;
+fn system_specific_data(
+    system_type: IffSystemType,
+) -> impl Fn(&[u8]) -> IResult<&[u8], SystemSpecificData> {
+    move |input: &[u8]| {
+        let (input, bytes) = take(SYSTEM_SPECIFIC_DATA_OCTETS)(input)?;
+        let mut raw = [0u8; SYSTEM_SPECIFIC_DATA_OCTETS];
+        raw.copy_from_slice(bytes);
 
-    Ok((
-        input,
-        SystemSpecificData::builder()
-            .with_part_1(part_1)
-            .with_part_2(part_2)
-            .with_part_3(part_3)
-            .build(),
-    ))
+        Ok((input, SystemSpecificData::from_bytes(system_type, raw)))
+    }
 }
 
 fn system_id(input: &[u8]) -> IResult<&[u8], SystemId> {
@@ -339,40 +344,32 @@ fn system_status(input: &[u8]) -> IResult<&[u8], SystemStatus> {
     Ok((input, SystemStatus::from(record)))
 }
 
-// TODO This bit of error handling the correct system type to parse is not that nice.
-#[allow(clippy::match_same_arms)]
+/// Parses the Mode 5 basic data in the format for the role of the IFF system.
+/// When the role is not known, the basic data is kept as raw bytes.
 fn mode_5_basic_data(
-    system_type: &IffSystemType,
-) -> impl Fn(&[u8]) -> IResult<&[u8], Result<Mode5BasicData, DisError>> + '_ {
-    move |input: &[u8]| match system_type {
-        IffSystemType::MarkXXIIATCRBSTransponder
-        | IffSystemType::SovietTransponder
-        | IffSystemType::RRBTransponder
-        | IffSystemType::MarkXIIATransponder
-        | IffSystemType::Mode5Transponder
-        | IffSystemType::ModeSTransponder => {
+    role: Option<IffSystemRole>,
+) -> impl Fn(&[u8]) -> IResult<&[u8], Mode5BasicData> {
+    move |input: &[u8]| match role {
+        Some(IffSystemRole::Transponder) => {
             let (input, basic_data) = mode_5_transponder_basic_data(input)?;
-            Ok((input, Ok(Mode5BasicData::Transponder(basic_data))))
+            Ok((input, Mode5BasicData::Transponder(basic_data)))
         }
-        IffSystemType::MarkXXIIATCRBSInterrogator
-        | IffSystemType::SovietInterrogator
-        | IffSystemType::MarkXIIAInterrogator
-        | IffSystemType::Mode5Interrogator
-        | IffSystemType::ModeSInterrogator => {
+        Some(IffSystemRole::Interrogator) => {
             let (input, basic_data) = mode_5_interrogator_basic_data(input)?;
-            Ok((input, Ok(Mode5BasicData::Interrogator(basic_data))))
+            Ok((input, Mode5BasicData::Interrogator(basic_data)))
         }
-        IffSystemType::MarkXIIACombinedInterrogatorTransponder_CIT_
-        | IffSystemType::MarkXIICombinedInterrogatorTransponder_CIT_
-        | IffSystemType::TCASACASTransceiver => {
-            Ok((input, Err(DisError::IffUndeterminedSystemType)))
+        None => {
+            let (input, bytes) = take(MODE_5_BASIC_DATA_LENGTH_OCTETS)(input)?;
+            let mut raw = [0u8; MODE_5_BASIC_DATA_LENGTH_OCTETS];
+            raw.copy_from_slice(bytes);
+            Ok((input, Mode5BasicData::Undetermined(raw)))
         }
-        IffSystemType::NotUsed_InvalidValue_ => Ok((input, Err(DisError::IffIncorrectSystemType))),
-        IffSystemType::Unspecified(_) => Ok((input, Err(DisError::IffIncorrectSystemType))),
     }
 }
 
-fn mode_5_interrogator_basic_data(input: &[u8]) -> IResult<&[u8], Mode5InterrogatorBasicData> {
+pub(crate) fn mode_5_interrogator_basic_data(
+    input: &[u8],
+) -> IResult<&[u8], Mode5InterrogatorBasicData> {
     let (input, status) = mode_5_interrogator_status(input)?;
     let (input, _padding) = be_u8(input)?;
     let (input, _padding) = be_u16(input)?;
@@ -402,7 +399,9 @@ fn mode_5_message_formats(input: &[u8]) -> IResult<&[u8], Mode5MessageFormats> {
     Ok((input, Mode5MessageFormats::from(record)))
 }
 
-fn mode_5_transponder_basic_data(input: &[u8]) -> IResult<&[u8], Mode5TransponderBasicData> {
+pub(crate) fn mode_5_transponder_basic_data(
+    input: &[u8],
+) -> IResult<&[u8], Mode5TransponderBasicData> {
     let (input, status) = mode_5_transponder_status(input)?;
     let (input, pin) = be_u16(input)?;
     let (input, message_formats_present) = mode_5_message_formats(input)?;
@@ -449,40 +448,32 @@ fn mode_s_altitude(input: &[u8]) -> IResult<&[u8], ModeSAltitude> {
     Ok((input, ModeSAltitude::from(record)))
 }
 
-// TODO This bit of error handling the correct system type to parse is not that nice.
-#[allow(clippy::match_same_arms)]
+/// Parses the Mode S basic data in the format for the role of the IFF system.
+/// When the role is not known, the basic data is kept as raw bytes.
 fn mode_s_basic_data(
-    system_type: &IffSystemType,
-) -> impl Fn(&[u8]) -> IResult<&[u8], Result<ModeSBasicData, DisError>> + '_ {
-    move |input: &[u8]| match system_type {
-        IffSystemType::MarkXXIIATCRBSTransponder
-        | IffSystemType::SovietTransponder
-        | IffSystemType::RRBTransponder
-        | IffSystemType::MarkXIIATransponder
-        | IffSystemType::Mode5Transponder
-        | IffSystemType::ModeSTransponder => {
+    role: Option<IffSystemRole>,
+) -> impl Fn(&[u8]) -> IResult<&[u8], ModeSBasicData> {
+    move |input: &[u8]| match role {
+        Some(IffSystemRole::Transponder) => {
             let (input, basic_data) = mode_s_transponder_basic_data(input)?;
-            Ok((input, Ok(ModeSBasicData::Transponder(basic_data))))
+            Ok((input, ModeSBasicData::Transponder(basic_data)))
         }
-        IffSystemType::MarkXXIIATCRBSInterrogator
-        | IffSystemType::SovietInterrogator
-        | IffSystemType::MarkXIIAInterrogator
-        | IffSystemType::Mode5Interrogator
-        | IffSystemType::ModeSInterrogator => {
+        Some(IffSystemRole::Interrogator) => {
             let (input, basic_data) = mode_s_interrogator_basic_data(input)?;
-            Ok((input, Ok(ModeSBasicData::Interrogator(basic_data))))
+            Ok((input, ModeSBasicData::Interrogator(basic_data)))
         }
-        IffSystemType::MarkXIIACombinedInterrogatorTransponder_CIT_
-        | IffSystemType::MarkXIICombinedInterrogatorTransponder_CIT_
-        | IffSystemType::TCASACASTransceiver => {
-            Ok((input, Err(DisError::IffUndeterminedSystemType)))
+        None => {
+            let (input, bytes) = take(MODE_S_BASIC_DATA_LENGTH_OCTETS)(input)?;
+            let mut raw = [0u8; MODE_S_BASIC_DATA_LENGTH_OCTETS];
+            raw.copy_from_slice(bytes);
+            Ok((input, ModeSBasicData::Undetermined(raw)))
         }
-        IffSystemType::NotUsed_InvalidValue_ => Ok((input, Err(DisError::IffIncorrectSystemType))),
-        IffSystemType::Unspecified(_) => Ok((input, Err(DisError::IffIncorrectSystemType))),
     }
 }
 
-fn mode_s_interrogator_basic_data(input: &[u8]) -> IResult<&[u8], ModeSInterrogatorBasicData> {
+pub(crate) fn mode_s_interrogator_basic_data(
+    input: &[u8],
+) -> IResult<&[u8], ModeSInterrogatorBasicData> {
     const PAD_168_BITS_IN_OCTETS: usize = 21;
 
     let (input, status) = mode_s_interrogator_status(input)?;
@@ -511,7 +502,11 @@ fn mode_s_levels_present(input: &[u8]) -> IResult<&[u8], ModeSLevelsPresent> {
     Ok((input, ModeSLevelsPresent::from(record)))
 }
 
-fn mode_s_transponder_basic_data(input: &[u8]) -> IResult<&[u8], ModeSTransponderBasicData> {
+pub(crate) fn mode_s_transponder_basic_data(
+    input: &[u8],
+) -> IResult<&[u8], ModeSTransponderBasicData> {
+    const PAD_24_BITS_IN_OCTETS: usize = 3;
+
     let (input, status) = mode_s_transponder_status(input)?;
     let (input, levels_present) = mode_s_levels_present(input)?;
     let (input, aircraft_present_domain) = be_u8(input)?;
@@ -536,6 +531,7 @@ fn mode_s_transponder_basic_data(input: &[u8]) -> IResult<&[u8], ModeSTransponde
     let (input, altitude) = mode_s_altitude(input)?;
     let (input, capability_report) = be_u8(input)?;
     let capability_report = CapabilityReport::from(capability_report);
+    let (input, _padding_3_octets) = take(PAD_24_BITS_IN_OCTETS)(input)?;
 
     Ok((
         input,
@@ -8,7 +8,7 @@
     Mode5TransponderSupplementalData, ModeSAltitude, ModeSBasicData, ModeSInterrogatorBasicData,
     ModeSInterrogatorStatus, ModeSLevelsPresent, ModeSTransponderBasicData, ModeSTransponderStatus,
     OnOffStatus, OperationalStatus, ParameterCapable, SquitterStatus, SystemId, SystemSpecificData,
-    SystemStatus,
+    SystemStatus, MODE_5_BASIC_DATA_LENGTH_OCTETS, MODE_S_BASIC_DATA_LENGTH_OCTETS,
 };
 use crate::common::model::length_padded_to_num;
 use crate::common::{Serialize, SerializePdu, SupportedVersion};
@@ -86,6 +86,10 @@ fn serialize(&self, buf: &mut BytesMut) -> u16 {
         let basic_data_bytes = match &self.mode_5_basic_data {
             Mode5BasicData::Interrogator(data) => data.serialize(buf),
             Mode5BasicData::Transponder(data) => data.serialize(buf),
+            Mode5BasicData::Undetermined(bytes) => {
+                buf.put_slice(bytes);
+                MODE_5_BASIC_DATA_LENGTH_OCTETS as u16
+            }
         };
         buf.put_u16(0u16);
         let iff_data_specification_bytes = self.data_records.serialize(buf);
@@ -105,6 +109,10 @@ fn serialize(&self, buf: &mut BytesMut) -> u16 {
         let basic_data_bytes = match &self.mode_s_basic_data {
             ModeSBasicData::Interrogator(data) => data.serialize(buf),
             ModeSBasicData::Transponder(data) => data.serialize(buf),
+            ModeSBasicData::Undetermined(bytes) => {
+                buf.put_slice(bytes);
+                MODE_S_BASIC_DATA_LENGTH_OCTETS as u16
+            }
         };
         buf.put_u16(0u16);
         let iff_data_records_bytes = self.data_records.serialize(buf);
@@ -252,9 +260,7 @@ fn serialize(&self, buf: &mut BytesMut) -> u16 {
 
 impl Serialize for SystemSpecificData {
     fn serialize(&self, buf: &mut BytesMut) -> u16 {
-        buf.put_u8(self.part_1);
-        buf.put_u8(self.part_2);
-        buf.put_u8(self.part_3);
+        buf.put_slice(&self.to_bytes());
 
         THREE_OCTETS as u16
     }
@@ -480,6 +486,7 @@ fn from(value: &IffPresence) -> Self {
 
 impl Serialize for ModeSTransponderBasicData {
     fn serialize(&self, buf: &mut BytesMut) -> u16 {
+        const PAD_24_BITS_IN_OCTETS: usize = 3;
         let _status_bytes = self.status.serialize(buf);
         let _levels_present_bytes = self.levels_present.serialize(buf);
         buf.put_u8(self.aircraft_present_domain.into());
@@ -496,6 +503,7 @@ fn serialize(&self, buf: &mut BytesMut) -> u16 {
         self.dap_source.serialize(buf);
         self.altitude.serialize(buf);
         buf.put_u8(self.capability_report.into());
+        buf.put_bytes(0u8, PAD_24_BITS_IN_OCTETS);
 
         24
     }
@@ -47,7 +47,7 @@ fn group_reference_point(input: &[u8]) -> IResult<&[u8], GroupReferencePoint> {
     ))
 }
 
-fn group_entity_description(
+pub(crate) fn group_entity_description(
     category: &IsGroupOfGroupedEntityCategory,
 ) -> impl Fn(&[u8]) -> IResult<&[u8], GroupEntityDescription> + '_ {
     move |input: &[u8]| {
@@ -13,12 +13,14 @@
 pub mod collision_elastic;
 pub mod comment;
 pub mod comment_r;
+pub mod conversion;
 pub mod create_entity;
 pub mod create_entity_r;
 pub mod data;
 pub mod data_query;
 pub mod data_query_r;
 pub mod data_r;
+pub mod datum;
 pub mod designator;
 pub mod detonation;
 pub mod electromagnetic_emission;
@@ -56,13 +58,17 @@
 pub mod underwater_acoustic;
 
 pub mod errors;
+pub(crate) mod fields;
 mod writer;
 
+use crate::common::attribute::model::AttributeRecordKind;
 use crate::common::errors::DisError;
-use crate::common::model::Pdu;
+use crate::common::iff::model::IffSystemRole;
+use crate::common::model::{Pdu, PduBody};
 use crate::common::parser::parse_multiple_pdu;
-use crate::enumerations::{PduType, ProtocolVersion};
+use crate::enumerations::{IffSystemType, PduType, ProtocolVersion, VariableRecordType};
 use bytes::BytesMut;
+use std::collections::HashMap;
 
 #[allow(dead_code)]
 pub enum SupportedVersion {
@@ -127,7 +133,7 @@ pub trait Serialize {
 /// # Errors
 /// Returns a `DisError` when parsing fails
 pub fn parse(input: &[u8]) -> Result<Vec<Pdu>, DisError> {
-    parse_multiple_pdu(input)
+    parse_multiple_pdu(input, &ParseOptions::default())
 }
 
 /// Parses the contents of the input as DIS version 6.
@@ -139,7 +145,7 @@ pub fn parse(input: &[u8]) -> Result<Vec<Pdu>, DisError> {
 /// # Errors
 /// Returns a `DisError` when parsing fails
 pub fn parse_v6(input: &[u8]) -> Result<Vec<Pdu>, DisError> {
-    let pdus = parse_multiple_pdu(input)?
+    let pdus = parse_multiple_pdu(input, &ParseOptions::default())?
         .into_iter()
         .filter(|pdu| pdu.header.protocol_version == ProtocolVersion::IEEE1278_1A1998)
         .collect();
@@ -155,9 +161,104 @@ pub fn parse_v6(input: &[u8]) -> Result<Vec<Pdu>, DisError> {
 /// # Errors
 /// Returns a `DisError` when parsing fails
 pub fn parse_v7(input: &[u8]) -> Result<Vec<Pdu>, DisError> {
-    let pdus = parse_multiple_pdu(input)?
+    let pdus = parse_multiple_pdu(input, &ParseOptions::default())?
         .into_iter()
         .filter(|pdu| pdu.header.protocol_version == ProtocolVersion::IEEE1278_12012)
         .collect();
     Ok(pdus)
 }
+
+/// Options that control how PDUs are parsed by [`parse_with_options`].
+#[derive(Clone, Debug, Default)]
+pub struct ParseOptions {
+    iff_system_roles: HashMap<IffSystemType, IffSystemRole>,
+    attribute_record_kinds: HashMap<VariableRecordType, AttributeRecordKind>,
+}
+
+impl ParseOptions {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parses the basic data in Layers 3 and 4 of IFF systems of `system_type` in the format of `role`,
+    /// for system types that do not determine the role (such as CIT and TCAS systems),
+    /// or to override the role that follows from the system type.
+    #[must_use]
+    pub fn with_iff_system_role(mut self, system_type: IffSystemType, role: IffSystemRole) -> Self {
+        self.iff_system_roles.insert(system_type, role);
+        self
+    }
+
+    /// Returns the role used to parse the basic data of IFF systems of `system_type`:
+    /// the supplied role, or else the role that follows from the system type.
+    #[must_use]
+    pub fn iff_system_role(&self, system_type: IffSystemType) -> Option<IffSystemRole> {
+        self.iff_system_roles
+            .get(&system_type)
+            .copied()
+            .or_else(|| IffSystemRole::from_system_type(system_type))
+    }
+
+    /// Parses the record specific fields of Attribute records of `record_type` as the given `kind` of record.
+    #[must_use]
+    pub fn with_attribute_record_kind(
+        mut self,
+        record_type: VariableRecordType,
+        kind: AttributeRecordKind,
+    ) -> Self {
+        self.attribute_record_kinds.insert(record_type, kind);
+        self
+    }
+
+    /// Returns the kind of record registered for Attribute records of `record_type`, if any.
+    #[must_use]
+    pub fn attribute_record_kind(
+        &self,
+        record_type: VariableRecordType,
+    ) -> Option<AttributeRecordKind> {
+        self.attribute_record_kinds.get(&record_type).copied()
+    }
+}
+
+/// The PDUs parsed by [`parse_with_options`], together with the issues found while parsing them.
+#[derive(Debug, Default, PartialEq)]
+pub struct ParseReport {
+    pub pdus: Vec<Pdu>,
+    pub diagnostics: Vec<ParseDiagnostic>,
+}
+
+/// An issue found while parsing the PDU at position `pdu_index` in the input.
+#[derive(Debug, PartialEq)]
+pub struct ParseDiagnostic {
+    pub pdu_index: usize,
+    pub error: DisError,
+}
+
+/// Parses the contents of the input like [`parse`], using the provided `ParseOptions`.
+///
+/// Issues that do not prevent parsing a PDU are reported as diagnostics:
+/// IFF basic data that is kept as `Undetermined` raw bytes, because the system type does not determine
+/// whether the system is an interrogator or a transponder, is reported as `DisError::IffUndeterminedSystemType`
+/// (or `DisError::IffIncorrectSystemType` for invalid system types).
+///
+/// # Errors
+/// Returns a `DisError` when parsing fails
+pub fn parse_with_options(input: &[u8], options: &ParseOptions) -> Result<ParseReport, DisError> {
+    let pdus = parse_multiple_pdu(input, options)?;
+    let diagnostics = pdus
+        .iter()
+        .enumerate()
+        .flat_map(|(pdu_index, pdu)| {
+            let errors = match &pdu.body {
+                PduBody::IFF(iff) => iff.diagnostics(),
+                _ => Vec::new(),
+            };
+            errors
+                .into_iter()
+                .map(move |error| ParseDiagnostic { pdu_index, error })
+        })
+        .collect();
+
+    Ok(ParseReport { pdus, diagnostics })
+}
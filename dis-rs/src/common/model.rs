@@ -74,6 +74,7 @@
 use serde::{Deserialize, Serialize};
 use std::fmt::Display;
 use std::str::FromStr;
+use std::time::Duration;
 
 pub use crate::v7::model::PduStatus;
 
@@ -851,7 +852,7 @@ fn try_from(value: String) -> Result<Self, Self::Error> {
     }
 }
 
-#[derive(Copy, Clone, Debug, PartialEq)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct EventId {
     pub simulation_address: SimulationAddress,
@@ -1402,6 +1403,47 @@ pub fn new_relative_from_units(units_past_the_hour: u32) -> Self {
         }
     }
 
+    /// Creates an absolute `DisTimeStamp` for the time past the hour of `since_reference`,
+    /// the time elapsed since a reference time such as the Unix epoch.
+    #[must_use]
+    pub fn new_absolute_from_duration(since_reference: Duration) -> Self {
+        Self::new_absolute_from_units(Self::duration_to_dis_time_units(since_reference))
+    }
+
+    /// Creates a relative `DisTimeStamp` for the time past the hour of `since_reference`,
+    /// the time elapsed since a reference time such as the start of the simulation.
+    #[must_use]
+    pub fn new_relative_from_duration(since_reference: Duration) -> Self {
+        Self::new_relative_from_units(Self::duration_to_dis_time_units(since_reference))
+    }
+
+    /// The time past the hour represented by the timestamp.
+    #[must_use]
+    pub fn duration_past_the_hour(&self) -> Duration {
+        let (DisTimeStamp::Absolute {
+            units_past_the_hour,
+            ..
+        }
+        | DisTimeStamp::Relative {
+            units_past_the_hour,
+            ..
+        }) = self;
+        let nanoseconds = (u128::from(*units_past_the_hour) * u128::from(NANOSECONDS_IN_AN_HOUR))
+            >> DIS_TIME_UNITS_PER_HOUR_BITS;
+
+        Duration::from_nanos(u64::try_from(nanoseconds).unwrap_or(u64::MAX))
+    }
+
+    /// Helper function to convert the time past the hour of a duration to DIS Time Units past the hour.
+    fn duration_to_dis_time_units(since_reference: Duration) -> u32 {
+        let nanoseconds_past_the_hour =
+            since_reference.as_nanos() % u128::from(NANOSECONDS_IN_AN_HOUR);
+        let units = (nanoseconds_past_the_hour << DIS_TIME_UNITS_PER_HOUR_BITS)
+            / u128::from(NANOSECONDS_IN_AN_HOUR);
+
+        u32::try_from(units).unwrap_or(u32::MAX >> 1)
+    }
+
     /// Helper function to convert seconds to nanoseconds
     fn seconds_to_nanoseconds(seconds: u32) -> u32 {
         seconds * 1_000_000
@@ -1468,8 +1510,34 @@ pub fn new(hour: i32, time_past_hour: u32) -> Self {
             time_past_hour,
         }
     }
+
+    /// Creates a `ClockTime` from a duration since a reference time,
+    /// such as the Unix epoch for real-world time. The time past the hour is an absolute timestamp.
+    #[must_use]
+    pub fn from_duration(duration: Duration) -> Self {
+        let hour = duration.as_secs() / SECONDS_PER_HOUR;
+
+        Self {
+            hour: i32::try_from(hour).unwrap_or(i32::MAX),
+            time_past_hour: DisTimeStamp::new_absolute_from_duration(duration).into(),
+        }
+    }
+
+    /// Converts the `ClockTime` to a duration since the reference time. Negative hours are treated as zero.
+    #[must_use]
+    pub fn to_duration(&self) -> Duration {
+        let hours =
+            Duration::from_secs(u64::from(self.hour.max(0).unsigned_abs()) * SECONDS_PER_HOUR);
+
+        hours + DisTimeStamp::from(self.time_past_hour).duration_past_the_hour()
+    }
 }
 
+const SECONDS_PER_HOUR: u64 = 3600;
+const NANOSECONDS_IN_AN_HOUR: u64 = SECONDS_PER_HOUR * 1_000_000_000;
+/// An hour is divided in 2^31 DIS time units (6.2.88)
+const DIS_TIME_UNITS_PER_HOUR_BITS: u32 = 31;
+
 /// 6.2.18 Datum Specification record
 #[derive(Clone, Default, Debug, PartialEq)]
 pub struct DatumSpecification {
@@ -2311,4 +2379,36 @@ fn event_id_try_from_string() {
         assert!(matches!(err, Err(DisError::ParseError(_))));
         assert_eq!(err.unwrap_err().to_string(), "Invalid event id digit");
     }
+
+    #[test]
+    fn clock_time_duration_round_trip() {
+        let duration = Duration::from_millis(1_700_000_123_456);
+        let clock_time = ClockTime::from_duration(duration);
+        assert_eq!(clock_time.hour, 472_222);
+        assert_eq!(clock_time.time_past_hour & 1, 1);
+
+        let converted = clock_time.to_duration();
+        let difference = converted.max(duration) - converted.min(duration);
+        assert!(difference < Duration::from_micros(5), "{difference:?}");
+    }
+
+    #[test]
+    fn dis_time_stamp_duration_conversion() {
+        let since_epoch = Duration::from_secs(7200 + 900);
+        let time_stamp = DisTimeStamp::new_absolute_from_duration(since_epoch);
+        assert!(matches!(
+            time_stamp,
+            DisTimeStamp::Absolute {
+                units_past_the_hour: 536_870_912,
+                ..
+            }
+        ));
+        assert_eq!(
+            time_stamp.duration_past_the_hour(),
+            Duration::from_secs(900)
+        );
+
+        let time_stamp = DisTimeStamp::new_relative_from_units((1 << 31) - 1);
+        assert!(time_stamp.duration_past_the_hour() < Duration::from_secs(3600));
+    }
 }
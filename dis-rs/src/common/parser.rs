@@ -36,6 +36,7 @@
 use crate::common::start_resume::parser::start_resume_body;
 use crate::common::stop_freeze::parser::stop_freeze_body;
 use crate::common::transmitter::parser::transmitter_body;
+use crate::common::ParseOptions;
 use crate::constants::{
     EIGHT_OCTETS, FIVE_LEAST_SIGNIFICANT_BITS, ONE_BYTE_IN_BITS, PDU_HEADER_LEN_BYTES,
 };
@@ -84,8 +85,11 @@
 use nom::Err;
 use nom::IResult;
 
-pub(crate) fn parse_multiple_pdu(input: &[u8]) -> Result<Vec<Pdu>, DisError> {
-    match many1(pdu)(input) {
+pub(crate) fn parse_multiple_pdu(
+    input: &[u8],
+    options: &ParseOptions,
+) -> Result<Vec<Pdu>, DisError> {
+    match many1(bundled_pdu(options))(input) {
         Ok((_, pdus)) => Ok(pdus),
         Err(err) => Err(DisError::ParseError(err.to_string())), // TODO not very descriptive / error means we can not match any PDUs
     }
@@ -93,7 +97,7 @@ pub(crate) fn parse_multiple_pdu(input: &[u8]) -> Result<Vec<Pdu>, DisError> {
 
 #[allow(dead_code)]
 pub(crate) fn parse_pdu(input: &[u8]) -> Result<Pdu, DisError> {
-    match pdu(input) {
+    match pdu(&ParseOptions::default())(input) {
         Ok((_, pdu)) => Ok(pdu),
         Err(err) => Err(DisError::ParseError(err.to_string())), // TODO not very descriptive / error means we can not match any PDUs
     }
@@ -145,23 +149,43 @@ pub(crate) fn parse_header(input: &[u8]) -> Result<PduHeader, DisError> {
     }
 }
 
-fn pdu(input: &[u8]) -> IResult<&[u8], Pdu> {
-    // parse the header
-    let (input, header) = pdu_header(input)?;
+fn pdu(options: &ParseOptions) -> impl Fn(&[u8]) -> IResult<&[u8], Pdu> + '_ {
+    move |input: &[u8]| {
+        // parse the header
+        let (input, header) = pdu_header(input)?;
 
-    // if (header.pdu_length - PDU_HEADER_LEN_BYTES) as usize > input.len() {
-    //     // FIXME signal correct sort of error when the input is too small for the indicated PDU length
-    //     return nom::error::make_error(input, nom::error::ErrorKind::Eof);
-    // }
+        // if (header.pdu_length - PDU_HEADER_LEN_BYTES) as usize > input.len() {
+        //     // FIXME signal correct sort of error when the input is too small for the indicated PDU length
+        //     return nom::error::make_error(input, nom::error::ErrorKind::Eof);
+        // }
 
-    // parse the body based on the type
-    // and produce the final pdu combined with the header
-    let (input, body) = pdu_body(&header)(input)?;
+        // parse the body based on the type
+        // and produce the final pdu combined with the header
+        let (input, body) = pdu_body(&header, options)(input)?;
 
-    Ok((input, Pdu { header, body }))
+        Ok((input, Pdu { header, body }))
+    }
 }
 
-fn pdu_header(input: &[u8]) -> IResult<&[u8], PduHeader> {
+/// Parses a PDU, and skips the zero-octet padding that aligns the next PDU in a bundle on an 8-octet boundary.
+///
+/// Bundles without padding are accepted as well, as a PDU never starts with a zero octet (the protocol version).
+fn bundled_pdu(options: &ParseOptions) -> impl Fn(&[u8]) -> IResult<&[u8], Pdu> + '_ {
+    move |input: &[u8]| {
+        let (rest, pdu) = pdu(options)(input)?;
+        let consumed = input.len() - rest.len();
+        let padding = (EIGHT_OCTETS - consumed % EIGHT_OCTETS) % EIGHT_OCTETS;
+        let padding = rest
+            .iter()
+            .take(padding)
+            .take_while(|octet| **octet == 0)
+            .count();
+
+        Ok((&rest[padding..], pdu))
+    }
+}
+
+pub(crate) fn pdu_header(input: &[u8]) -> IResult<&[u8], PduHeader> {
     let protocol_version = protocol_version;
     let exercise_id = be_u8;
     let pdu_type = pdu_type;
@@ -219,7 +243,10 @@ fn pdu_header_skip_body(input: &[u8]) -> IResult<&[u8], PduHeader> {
     Ok((input, header))
 }
 
-fn pdu_body(header: &PduHeader) -> impl Fn(&[u8]) -> IResult<&[u8], PduBody> + '_ {
+pub(crate) fn pdu_body<'a>(
+    header: &'a PduHeader,
+    options: &'a ParseOptions,
+) -> impl Fn(&[u8]) -> IResult<&[u8], PduBody> + 'a {
     move |input: &[u8]| {
         // parse the body of the PDU based on the type
         // NOTE only processes supported PduTypes; process others as 'Other'
@@ -252,7 +279,7 @@ fn pdu_body(header: &PduHeader) -> impl Fn(&[u8]) -> IResult<&[u8], PduBody> + '
             PduType::Transmitter => transmitter_body(header)(input)?,
             PduType::Signal => signal_body(input)?,
             PduType::Receiver => receiver_body(input)?,
-            PduType::IFF => iff_body(input)?,
+            PduType::IFF => iff_body(options)(input)?,
             PduType::UnderwaterAcoustic => underwater_acoustic_body(input)?,
             PduType::SupplementalEmissionEntityState => sees_body(input)?,
             // PduType::IntercomSignal => {}
@@ -296,7 +323,7 @@ fn pdu_body(header: &PduHeader) -> impl Fn(&[u8]) -> IResult<&[u8], PduBody> + '
             // PduType::EntityDamageStatus => {}
             // PduType::InformationOperationsAction => {}
             // PduType::InformationOperationsReport => {}
-            PduType::Attribute => attribute_body(input)?,
+            PduType::Attribute => attribute_body(options)(input)?,
             PduType::Unspecified(_type_number) => other_body(header)(input)?, // TODO Log unsupported type number?
             _ => other_body(header)(input)?,
         };
@@ -541,6 +568,7 @@ pub(crate) fn descriptor_record_dti(
             DetonationTypeIndicator::NonmunitionExplosion => {
                 let (input, explosive_material) = be_u16(input)?;
                 let explosive_material = ExplosiveMaterialCategories::from(explosive_material);
+                let (input, _padding) = be_u16(input)?;
                 let (input, explosive_force) = be_f32(input)?;
 
                 Ok((
@@ -748,6 +776,7 @@ fn entity_type_variable_parameter(input: &[u8]) -> IResult<&[u8], VariableParame
 fn separation(input: &[u8]) -> IResult<&[u8], VariableParameter> {
     let (input, reason) = be_u8(input)?;
     let (input, pre_entity_indicator) = be_u8(input)?;
+    let (input, _pad_8) = be_u8(input)?;
     let (input, parent_entity_id) = entity_id(input)?;
     let (input, _pad_16) = be_u16(input)?;
     let (input, station_name) = be_u16(input)?;
@@ -859,7 +888,8 @@ fn ceil_bits_to_bytes(bits: u16) -> u16 {
 #[cfg(test)]
 mod tests {
     use crate::common::errors::DisError;
-    use crate::common::parser::{parse_multiple_header, skip_body};
+    use crate::common::model::{EntityId, VariableParameter};
+    use crate::common::parser::{parse_multiple_header, skip_body, variable_parameter};
     use crate::constants::PDU_HEADER_LEN_BYTES;
     use crate::enumerations::{PduType, ProtocolFamily, ProtocolVersion};
 
@@ -979,4 +1009,21 @@ fn skip_body_total_length_cannot_contain_a_header() {
         assert_eq!(input, [0x00, 0x00]);
         assert_eq!(skipped, []);
     }
+
+    #[test]
+    fn parse_separation_variable_parameter() {
+        let bytes: [u8; 16] = [
+            0x02, 0x01, 0x02, 0x00, 0x00, 0x01, 0x00, 0x02, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x04,
+        ];
+
+        let (input, parameter) = variable_parameter(&bytes).unwrap();
+        assert!(input.is_empty());
+        if let VariableParameter::Separation(separation) = parameter {
+            assert_eq!(separation.parent_entity_id, EntityId::new(1, 2, 3));
+            assert_eq!(separation.station_number, 4);
+        } else {
+            panic!("Expected a Separation VP record, got {parameter:?}");
+        }
+    }
 }
@@ -0,0 +1,392 @@
+use crate::common::model::EntityId;
+use crate::common::signal::gsm::{GsmDecoder, GsmEncoder, GSM_FRAME_OCTETS, GSM_FRAME_SAMPLES};
+use crate::common::signal::model::{EncodingScheme, Signal, BASE_SIGNAL_BODY_LENGTH};
+use crate::constants::{ONE_BYTE_IN_BITS, PDU_HEADER_LEN_BYTES};
+use crate::enumerations::{SignalEncodingClass, SignalEncodingType};
+use crate::fixed_parameters::MAX_PDU_SIZE_OCTETS;
+use crate::DisError;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Maximum number of octets of audio data that fits in a single Signal PDU.
+pub const MAX_SIGNAL_DATA_OCTETS: usize =
+    MAX_PDU_SIZE_OCTETS as usize - PDU_HEADER_LEN_BYTES as usize - BASE_SIGNAL_BODY_LENGTH as usize;
+
+/// The audio encodings of the Signal PDU (`SignalEncodingType`) supported by the audio codecs.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum AudioEncoding {
+    /// 8-bit µ-law (ITU-T G.711)
+    MuLaw,
+    /// CVSD (MIL-STD-188-113), one bit per sample
+    Cvsd,
+    /// 16-bit linear PCM, two's complement, big endian
+    LinearPcm16BigEndian,
+    /// 8-bit linear PCM, unsigned
+    LinearPcm8Unsigned,
+    /// 16-bit linear PCM, two's complement, little endian
+    LinearPcm16LittleEndian,
+    /// GSM full-rate (ETSI GSM 06.10), 33-octet frames of 160 samples
+    GsmFullRate,
+}
+
+impl AudioEncoding {
+    /// Returns the `AudioEncoding` for the given encoding type,
+    /// or `None` when the encoding type is not supported.
+    #[must_use]
+    pub fn from_encoding_type(encoding_type: SignalEncodingType) -> Option<Self> {
+        match encoding_type {
+            SignalEncodingType::_8bitMulaw_ITUTG_711_1 => Some(Self::MuLaw),
+            SignalEncodingType::CVSD_MILSTD188113_2 => Some(Self::Cvsd),
+            SignalEncodingType::_16bitLinearPCM2sComplement_BigEndian_4 => {
+                Some(Self::LinearPcm16BigEndian)
+            }
+            SignalEncodingType::_8bitLinearPCM_Unsigned_5 => Some(Self::LinearPcm8Unsigned),
+            SignalEncodingType::_16bitLinearPCM2sComplement_LittleEndian_100 => {
+                Some(Self::LinearPcm16LittleEndian)
+            }
+            SignalEncodingType::GSMFullRate_ETSI06_10_8 => Some(Self::GsmFullRate),
+            _ => None,
+        }
+    }
+
+    /// Returns the `AudioEncoding` used by the Signal PDU.
+    ///
+    /// # Errors
+    /// Returns `DisError::UnsupportedAudioEncoding` when the Signal does not contain audio in a supported encoding.
+    pub fn from_signal(signal: &Signal) -> Result<Self, DisError> {
+        match signal.encoding_scheme {
+            EncodingScheme::EncodedAudio { encoding_type, .. } => {
+                Self::from_encoding_type(encoding_type)
+                    .ok_or(DisError::UnsupportedAudioEncoding(encoding_type.into()))
+            }
+            _ => Err(DisError::InvalidAudioData(
+                "Signal does not contain encoded audio".to_string(),
+            )),
+        }
+    }
+
+    #[must_use]
+    pub fn encoding_type(&self) -> SignalEncodingType {
+        match self {
+            AudioEncoding::MuLaw => SignalEncodingType::_8bitMulaw_ITUTG_711_1,
+            AudioEncoding::Cvsd => SignalEncodingType::CVSD_MILSTD188113_2,
+            AudioEncoding::LinearPcm16BigEndian => {
+                SignalEncodingType::_16bitLinearPCM2sComplement_BigEndian_4
+            }
+            AudioEncoding::LinearPcm8Unsigned => SignalEncodingType::_8bitLinearPCM_Unsigned_5,
+            AudioEncoding::LinearPcm16LittleEndian => {
+                SignalEncodingType::_16bitLinearPCM2sComplement_LittleEndian_100
+            }
+            AudioEncoding::GsmFullRate => SignalEncodingType::GSMFullRate_ETSI06_10_8,
+        }
+    }
+
+    #[must_use]
+    pub fn encoding_scheme(&self) -> EncodingScheme {
+        EncodingScheme::EncodedAudio {
+            encoding_class: SignalEncodingClass::EncodedAudio,
+            encoding_type: self.encoding_type(),
+        }
+    }
+
+    /// The number of samples that are encoded together in a whole number of octets.
+    #[must_use]
+    pub fn samples_per_frame(&self) -> usize {
+        match self {
+            AudioEncoding::Cvsd => ONE_BYTE_IN_BITS,
+            AudioEncoding::MuLaw
+            | AudioEncoding::LinearPcm8Unsigned
+            | AudioEncoding::LinearPcm16BigEndian
+            | AudioEncoding::LinearPcm16LittleEndian => 1,
+            AudioEncoding::GsmFullRate => GSM_FRAME_SAMPLES,
+        }
+    }
+
+    /// The number of octets of an encoded frame of `samples_per_frame` samples.
+    #[must_use]
+    pub fn octets_per_frame(&self) -> usize {
+        match self {
+            AudioEncoding::MuLaw | AudioEncoding::Cvsd | AudioEncoding::LinearPcm8Unsigned => 1,
+            AudioEncoding::LinearPcm16BigEndian | AudioEncoding::LinearPcm16LittleEndian => 2,
+            AudioEncoding::GsmFullRate => GSM_FRAME_OCTETS,
+        }
+    }
+
+    /// The maximum number of samples that fits in a single Signal PDU.
+    #[must_use]
+    pub fn max_samples_per_pdu(&self) -> usize {
+        MAX_SIGNAL_DATA_OCTETS / self.octets_per_frame() * self.samples_per_frame()
+    }
+}
+
+/// Decoder for audio in Signal PDUs.
+///
+/// The decoder keeps state between calls for encodings that need it (CVSD, GSM),
+/// so a single decoder should be used for consecutive Signal PDUs of the same transmission.
+#[derive(Clone, Debug)]
+pub struct AudioDecoder {
+    encoding: AudioEncoding,
+    cvsd: Cvsd,
+    gsm: GsmDecoder,
+}
+
+impl AudioDecoder {
+    #[must_use]
+    pub fn new(encoding: AudioEncoding) -> Self {
+        Self {
+            encoding,
+            cvsd: Cvsd::default(),
+            gsm: GsmDecoder::default(),
+        }
+    }
+
+    #[must_use]
+    pub fn encoding(&self) -> AudioEncoding {
+        self.encoding
+    }
+
+    /// Decodes at most `samples` samples from the encoded `data`.
+    #[must_use]
+    pub fn decode(&mut self, data: &[u8], samples: usize) -> Vec<i16> {
+        match self.encoding {
+            AudioEncoding::MuLaw => data
+                .iter()
+                .take(samples)
+                .map(|b| mu_law_decode(*b))
+                .collect(),
+            AudioEncoding::Cvsd => data
+                .iter()
+                .flat_map(|byte| {
+                    (0..ONE_BYTE_IN_BITS)
+                        .rev()
+                        .map(move |bit| (byte >> bit) & 1 != 0)
+                })
+                .take(samples)
+                .map(|bit| self.cvsd.next(bit))
+                .collect(),
+            AudioEncoding::LinearPcm16BigEndian => data
+                .chunks_exact(2)
+                .take(samples)
+                .map(|pair| i16::from_be_bytes([pair[0], pair[1]]))
+                .collect(),
+            AudioEncoding::LinearPcm8Unsigned => data
+                .iter()
+                .take(samples)
+                .map(|b| (i16::from(*b) - 128) << 8)
+                .collect(),
+            AudioEncoding::LinearPcm16LittleEndian => data
+                .chunks_exact(2)
+                .take(samples)
+                .map(|pair| i16::from_le_bytes([pair[0], pair[1]]))
+                .collect(),
+            AudioEncoding::GsmFullRate => data
+                .chunks_exact(GSM_FRAME_OCTETS)
+                .flat_map(|frame| self.gsm.decode_frame(frame))
+                .take(samples)
+                .collect(),
+        }
+    }
+
+    /// Decodes the audio samples of a Signal PDU.
+    ///
+    /// # Errors
+    /// Returns a `DisError` when the Signal does not contain audio in the encoding of this decoder.
+    pub fn decode_signal(&mut self, signal: &Signal) -> Result<Vec<i16>, DisError> {
+        let encoding = AudioEncoding::from_signal(signal)?;
+        if encoding != self.encoding {
+            return Err(DisError::InvalidAudioData(format!(
+                "Signal is encoded as {encoding:?}, decoder expects {:?}",
+                self.encoding
+            )));
+        }
+        Ok(self.decode(&signal.data, signal.samples as usize))
+    }
+}
+
+/// Encoder for audio in Signal PDUs.
+///
+/// The encoder keeps state between calls for encodings that need it (CVSD, GSM),
+/// so a single encoder should be used for consecutive Signal PDUs of the same transmission.
+#[derive(Clone, Debug)]
+pub struct AudioEncoder {
+    encoding: AudioEncoding,
+    cvsd: Cvsd,
+    gsm: GsmEncoder,
+}
+
+impl AudioEncoder {
+    #[must_use]
+    pub fn new(encoding: AudioEncoding) -> Self {
+        Self {
+            encoding,
+            cvsd: Cvsd::default(),
+            gsm: GsmEncoder::default(),
+        }
+    }
+
+    #[must_use]
+    pub fn encoding(&self) -> AudioEncoding {
+        self.encoding
+    }
+
+    /// Encodes the samples. For CVSD the last octet is padded with zero bits when
+    /// the number of samples is not a multiple of 8, and for GSM the last frame is padded with silence when
+    /// the number of samples is not a multiple of 160.
+    #[must_use]
+    pub fn encode(&mut self, samples: &[i16]) -> Vec<u8> {
+        match self.encoding {
+            AudioEncoding::MuLaw => samples.iter().map(|s| mu_law_encode(*s)).collect(),
+            AudioEncoding::Cvsd => samples
+                .chunks(ONE_BYTE_IN_BITS)
+                .map(|chunk| {
+                    chunk.iter().enumerate().fold(0u8, |byte, (i, sample)| {
+                        let bit = f32::from(*sample) >= self.cvsd.integrator;
+                        let _ = self.cvsd.next(bit);
+                        byte | (u8::from(bit) << (ONE_BYTE_IN_BITS - 1 - i))
+                    })
+                })
+                .collect(),
+            AudioEncoding::LinearPcm16BigEndian => {
+                samples.iter().flat_map(|s| s.to_be_bytes()).collect()
+            }
+            AudioEncoding::LinearPcm8Unsigned => {
+                samples.iter().map(|s| ((s >> 8) + 128) as u8).collect()
+            }
+            AudioEncoding::LinearPcm16LittleEndian => {
+                samples.iter().flat_map(|s| s.to_le_bytes()).collect()
+            }
+            AudioEncoding::GsmFullRate => samples
+                .chunks(GSM_FRAME_SAMPLES)
+                .flat_map(|frame| self.gsm.encode_frame(frame))
+                .collect(),
+        }
+    }
+}
+
+/// Splits `audio` into a sequence of Signal PDU bodies containing at most `samples_per_pdu` samples each.
+///
+/// # Errors
+/// Returns `DisError::InvalidAudioData` when `samples_per_pdu` is zero, does not fit in a single PDU,
+/// or is not a multiple of the samples per frame of the encoding (8 for CVSD, 160 for GSM).
+pub fn signals_from_audio(
+    radio_reference_id: EntityId,
+    radio_number: u16,
+    encoding: AudioEncoding,
+    sample_rate: u32,
+    audio: &[i16],
+    samples_per_pdu: usize,
+) -> Result<Vec<Signal>, DisError> {
+    if samples_per_pdu == 0 || samples_per_pdu > encoding.max_samples_per_pdu() {
+        return Err(DisError::InvalidAudioData(format!(
+            "{samples_per_pdu} samples per PDU is not in the range 1..={} for {encoding:?}",
+            encoding.max_samples_per_pdu()
+        )));
+    }
+    if samples_per_pdu % encoding.samples_per_frame() != 0 {
+        return Err(DisError::InvalidAudioData(format!(
+            "{samples_per_pdu} samples per PDU do not fill whole octets for {encoding:?}"
+        )));
+    }
+
+    let mut encoder = AudioEncoder::new(encoding);
+    Ok(audio
+        .chunks(samples_per_pdu)
+        .map(|chunk| {
+            Signal::builder()
+                .with_radio_reference_id(radio_reference_id)
+                .with_radio_number(radio_number)
+                .with_encoding_scheme(encoding.encoding_scheme())
+                .with_sample_rate(sample_rate)
+                .with_samples(chunk.len() as u16)
+                .with_data(encoder.encode(chunk))
+                .build()
+        })
+        .collect())
+}
+
+const MU_LAW_BIAS: i32 = 0x84;
+const MU_LAW_CLIP: i32 = 32635;
+
+/// Encodes a linear sample to 8-bit µ-law (ITU-T G.711).
+#[must_use]
+pub fn mu_law_encode(sample: i16) -> u8 {
+    let sample = i32::from(sample);
+    let (sign, magnitude) = if sample < 0 {
+        (0x80, -sample)
+    } else {
+        (0x00, sample)
+    };
+    let magnitude = magnitude.min(MU_LAW_CLIP) + MU_LAW_BIAS;
+
+    let mut exponent = 7;
+    let mut mask = 0x4000;
+    while exponent > 0 && (magnitude & mask) == 0 {
+        exponent -= 1;
+        mask >>= 1;
+    }
+    let mantissa = (magnitude >> (exponent + 3)) & 0x0F;
+
+    !((sign | (exponent << 4) | mantissa) as u8)
+}
+
+/// Decodes an 8-bit µ-law (ITU-T G.711) value to a linear sample.
+#[must_use]
+pub fn mu_law_decode(value: u8) -> i16 {
+    let value = !value;
+    let exponent = i32::from((value >> 4) & 0x07);
+    let mantissa = i32::from(value & 0x0F);
+    let magnitude = (((mantissa << 3) + MU_LAW_BIAS) << exponent) - MU_LAW_BIAS;
+
+    if value & 0x80 != 0 {
+        -magnitude as i16
+    } else {
+        magnitude as i16
+    }
+}
+
+const CVSD_MIN_STEP: f32 = 10.0;
+const CVSD_MAX_STEP: f32 = 1280.0;
+const CVSD_RUN_LENGTH_MASK: u8 = 0b111;
+/// Syllabic filter decay, for a time constant of about 4 ms at 16 kHz
+const CVSD_SYLLABIC_DECAY: f32 = 0.9845;
+/// Principal integrator leak, for a time constant of about 1 ms at 16 kHz
+const CVSD_INTEGRATOR_LEAK: f32 = 0.968_75;
+
+/// State of a CVSD encoder or decoder, with a run length of three bits for detecting slope overload.
+#[derive(Clone, Debug)]
+struct Cvsd {
+    integrator: f32,
+    step: f32,
+    history: u8,
+}
+
+impl Default for Cvsd {
+    fn default() -> Self {
+        Self {
+            integrator: 0.0,
+            step: CVSD_MIN_STEP,
+            history: 0,
+        }
+    }
+}
+
+impl Cvsd {
+    /// Processes the next bit and returns the reconstructed sample.
+    fn next(&mut self, bit: bool) -> i16 {
+        self.history = ((self.history << 1) | u8::from(bit)) & CVSD_RUN_LENGTH_MASK;
+        let coincidence = self.history == 0 || self.history == CVSD_RUN_LENGTH_MASK;
+        let target_step = if coincidence {
+            CVSD_MAX_STEP
+        } else {
+            CVSD_MIN_STEP
+        };
+        self.step = self.step * CVSD_SYLLABIC_DECAY + target_step * (1.0 - CVSD_SYLLABIC_DECAY);
+
+        let delta = if bit { self.step } else { -self.step };
+        self.integrator = (self.integrator * CVSD_INTEGRATOR_LEAK + delta)
+            .clamp(f32::from(i16::MIN), f32::from(i16::MAX));
+
+        self.integrator as i16
+    }
+}
@@ -0,0 +1,693 @@
+//! GSM 06.10 full-rate speech codec (RPE-LTP), in the 33-octet frame format that is commonly used to carry
+//! GSM full-rate encoded audio (a 4-bit signature followed by the 260 bits of the frame).
+//!
+//! The arithmetic follows the fixed-point description of ETSI GSM 06.10.
+
+/// Number of samples (8 kHz) in a single GSM full-rate frame.
+pub const GSM_FRAME_SAMPLES: usize = 160;
+/// Number of octets of an encoded GSM full-rate frame.
+pub const GSM_FRAME_OCTETS: usize = 33;
+
+const GSM_SIGNATURE: u8 = 0xD;
+const SUBFRAMES: usize = 4;
+const SUBFRAME_SAMPLES: usize = 40;
+const RPE_PULSES: usize = 13;
+const LPC_ORDER: usize = 8;
+const MIN_LAG: i16 = 40;
+const MAX_LAG: i16 = 120;
+
+/// Number of bits per coded Log Area Ratio
+const LAR_BITS: [usize; LPC_ORDER] = [6, 6, 5, 5, 4, 4, 3, 3];
+const LAR_A: [i16; LPC_ORDER] = [20480, 20480, 20480, 20480, 13964, 15360, 8534, 9036];
+const LAR_B: [i16; LPC_ORDER] = [0, 0, 2048, -2560, 94, -1792, -341, -1144];
+const LAR_MIC: [i16; LPC_ORDER] = [-32, -32, -16, -16, -8, -8, -4, -4];
+const LAR_MAC: [i16; LPC_ORDER] = [31, 31, 15, 15, 7, 7, 3, 3];
+const LAR_INVA: [i16; LPC_ORDER] = [13107, 13107, 13107, 13107, 19223, 17476, 31454, 29708];
+/// Decision levels of the LTP gain
+const DLB: [i16; 4] = [6554, 16384, 26214, 32767];
+/// Quantization levels of the LTP gain
+const QLB: [i16; 4] = [3277, 11469, 21299, 32767];
+/// Impulse response of the RPE weighting filter
+const H: [i16; 11] = [-134, -374, 0, 2054, 5741, 8192, 5741, 2054, 0, -374, -134];
+/// Normalized inverse mantissa, used for the APCM quantization
+const NRFAC: [i16; 8] = [29128, 26215, 23832, 21846, 20165, 18725, 17476, 16384];
+/// Normalized direct mantissa, used for the APCM inverse quantization
+const FAC: [i16; 8] = [18431, 20479, 22527, 24575, 26623, 28671, 30719, 32767];
+
+/// The parameters of a single GSM full-rate frame.
+#[derive(Clone, Debug, Default, PartialEq)]
+struct Frame {
+    lar: [i16; LPC_ORDER],
+    lag: [i16; SUBFRAMES],
+    gain: [i16; SUBFRAMES],
+    grid: [i16; SUBFRAMES],
+    block_amplitude: [i16; SUBFRAMES],
+    pulses: [[i16; RPE_PULSES]; SUBFRAMES],
+}
+
+impl Frame {
+    fn to_bytes(&self) -> [u8; GSM_FRAME_OCTETS] {
+        let mut writer = BitWriter {
+            bytes: [0; GSM_FRAME_OCTETS],
+            cursor: 0,
+        };
+        writer.put(i16::from(GSM_SIGNATURE), 4);
+        for (lar, bits) in self.lar.iter().zip(LAR_BITS) {
+            writer.put(*lar, bits);
+        }
+        for subframe in 0..SUBFRAMES {
+            writer.put(self.lag[subframe], 7);
+            writer.put(self.gain[subframe], 2);
+            writer.put(self.grid[subframe], 2);
+            writer.put(self.block_amplitude[subframe], 6);
+            for pulse in self.pulses[subframe] {
+                writer.put(pulse, 3);
+            }
+        }
+        writer.bytes
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Self {
+        let mut reader = BitReader { bytes, cursor: 0 };
+        let _signature = reader.take(4);
+        let mut frame = Frame::default();
+        for (lar, bits) in frame.lar.iter_mut().zip(LAR_BITS) {
+            *lar = reader.take(bits);
+        }
+        for subframe in 0..SUBFRAMES {
+            frame.lag[subframe] = reader.take(7);
+            frame.gain[subframe] = reader.take(2);
+            frame.grid[subframe] = reader.take(2);
+            frame.block_amplitude[subframe] = reader.take(6);
+            for pulse in &mut frame.pulses[subframe] {
+                *pulse = reader.take(3);
+            }
+        }
+        frame
+    }
+}
+
+struct BitWriter {
+    bytes: [u8; GSM_FRAME_OCTETS],
+    cursor: usize,
+}
+
+impl BitWriter {
+    fn put(&mut self, value: i16, bits: usize) {
+        for bit in (0..bits).rev() {
+            if (value >> bit) & 1 != 0 {
+                self.bytes[self.cursor / 8] |= 0x80 >> (self.cursor % 8);
+            }
+            self.cursor += 1;
+        }
+    }
+}
+
+struct BitReader<'a> {
+    bytes: &'a [u8],
+    cursor: usize,
+}
+
+impl BitReader<'_> {
+    fn take(&mut self, bits: usize) -> i16 {
+        (0..bits).fold(0, |value, _| {
+            let bit = self
+                .bytes
+                .get(self.cursor / 8)
+                .map_or(0, |byte| (byte >> (7 - self.cursor % 8)) & 1);
+            self.cursor += 1;
+            (value << 1) | i16::from(bit)
+        })
+    }
+}
+
+/// Encoder state of the GSM full-rate codec.
+#[derive(Clone, Debug)]
+pub(crate) struct GsmEncoder {
+    z1: i16,
+    l_z2: i32,
+    mp: i16,
+    u: [i16; LPC_ORDER],
+    larpp: [[i16; LPC_ORDER]; 2],
+    j: usize,
+    dp: [i16; 280],
+    e: [i16; 50],
+}
+
+impl Default for GsmEncoder {
+    fn default() -> Self {
+        Self {
+            z1: 0,
+            l_z2: 0,
+            mp: 0,
+            u: [0; LPC_ORDER],
+            larpp: [[0; LPC_ORDER]; 2],
+            j: 0,
+            dp: [0; 280],
+            e: [0; 50],
+        }
+    }
+}
+
+impl GsmEncoder {
+    /// Encodes a frame of 160 samples. Missing samples are treated as silence.
+    pub(crate) fn encode_frame(&mut self, samples: &[i16]) -> [u8; GSM_FRAME_OCTETS] {
+        let mut s = [0i16; GSM_FRAME_SAMPLES];
+        for (target, sample) in s.iter_mut().zip(samples) {
+            *target = *sample;
+        }
+
+        self.preprocess(&mut s);
+        let mut frame = Frame {
+            lar: lpc_analysis(&mut s),
+            ..Default::default()
+        };
+        self.short_term_analysis(&frame.lar, &mut s);
+
+        for subframe in 0..SUBFRAMES {
+            let start = subframe * SUBFRAME_SAMPLES;
+            let d = &s[start..start + SUBFRAME_SAMPLES];
+            // dp[120 + start + k] is the current subframe, dp[k - lag] the reconstructed past signal
+            let past = 120 + start;
+
+            let (lag, gain) = ltp_parameters(d, &self.dp, past);
+            let mut dpp = [0i16; SUBFRAME_SAMPLES];
+            for (k, dpp) in dpp.iter_mut().enumerate() {
+                *dpp = mult_r(QLB[gain as usize], self.dp[past + k - lag as usize]);
+                self.e[5 + k] = sub(d[k], *dpp);
+            }
+
+            let (grid, block_amplitude, pulses) = self.rpe_encoding();
+            for (k, dpp) in dpp.iter().enumerate() {
+                self.dp[past + k] = add(self.e[5 + k], *dpp);
+            }
+
+            frame.lag[subframe] = lag;
+            frame.gain[subframe] = gain;
+            frame.grid[subframe] = grid;
+            frame.block_amplitude[subframe] = block_amplitude;
+            frame.pulses[subframe] = pulses;
+        }
+        self.dp.copy_within(GSM_FRAME_SAMPLES.., 0);
+
+        frame.to_bytes()
+    }
+
+    /// Downscaling, offset compensation and preemphasis of the input signal.
+    fn preprocess(&mut self, s: &mut [i16; GSM_FRAME_SAMPLES]) {
+        for sample in s.iter_mut() {
+            let so = (*sample >> 3) << 2;
+            let s1 = so.wrapping_sub(self.z1);
+            self.z1 = so;
+
+            let mut l_s2 = i32::from(s1) << 15;
+            let msp = (self.l_z2 >> 15) as i16;
+            let lsp = self.l_z2.wrapping_sub(i32::from(msp) << 15) as i16;
+            l_s2 = l_s2.wrapping_add(i32::from(mult_r(lsp, 32735)));
+            self.l_z2 = (i32::from(msp) * 32735).saturating_add(l_s2);
+
+            let l_temp = self.l_z2.saturating_add(16384);
+            let msp = mult_r(self.mp, -28180);
+            self.mp = (l_temp >> 15) as i16;
+            *sample = add(self.mp, msp);
+        }
+    }
+
+    fn short_term_analysis(&mut self, lar: &[i16; LPC_ORDER], s: &mut [i16; GSM_FRAME_SAMPLES]) {
+        let (previous, current) = next_larpp(&mut self.larpp, &mut self.j, lar);
+        for (range, rp) in interpolated_rp(&previous, &current) {
+            for sample in &mut s[range] {
+                let mut di = *sample;
+                let mut sav = di;
+                for (u, rp) in self.u.iter_mut().zip(rp) {
+                    let ui = *u;
+                    *u = sav;
+                    sav = add(ui, mult_r(rp, di));
+                    di = add(di, mult_r(rp, ui));
+                }
+                *sample = di;
+            }
+        }
+    }
+
+    /// Regular pulse excitation encoding of the residual in `e[5..45]`;
+    /// replaces the residual by its quantized version.
+    fn rpe_encoding(&mut self) -> (i16, i16, [i16; RPE_PULSES]) {
+        let mut x = [0i16; SUBFRAME_SAMPLES];
+        for (k, x) in x.iter_mut().enumerate() {
+            let sum = H.iter().enumerate().fold(4096i32, |sum, (i, h)| {
+                sum.wrapping_add(i32::from(self.e[k + i]) * i32::from(*h))
+            });
+            *x = saturate(sum >> 13);
+        }
+
+        let grid = (0..4)
+            .map(|m| {
+                let energy = (0..RPE_PULSES)
+                    .map(|i| i32::from(x[m + 3 * i] >> 2).pow(2))
+                    .sum::<i32>();
+                (m, energy)
+            })
+            .fold(
+                (0, 0),
+                |best, (m, energy)| {
+                    if energy > best.1 {
+                        (m, energy)
+                    } else {
+                        best
+                    }
+                },
+            )
+            .0;
+        let mut x_m = [0i16; RPE_PULSES];
+        for (i, x_m) in x_m.iter_mut().enumerate() {
+            *x_m = x[grid + 3 * i];
+        }
+
+        // APCM quantization
+        let x_max = x_m.iter().map(|x| x.saturating_abs()).max().unwrap_or(0);
+        let mut exponent = 0i16;
+        let mut temp = x_max >> 9;
+        let mut itest = false;
+        for _ in 0..=5 {
+            itest |= temp <= 0;
+            temp >>= 1;
+            if !itest {
+                exponent += 1;
+            }
+        }
+        let block_amplitude = add(x_max >> (exponent + 5), exponent << 3);
+        let (exponent, mantissa) = exponent_and_mantissa(block_amplitude);
+
+        let mut pulses = [0i16; RPE_PULSES];
+        for (pulse, x_m) in pulses.iter_mut().zip(x_m) {
+            let temp = x_m.wrapping_shl((6 - exponent) as u32);
+            *pulse = (mult(temp, NRFAC[mantissa as usize]) >> 12) + 4;
+        }
+
+        let x_mp = apcm_inverse_quantization(&pulses, exponent, mantissa);
+        self.e[5..5 + SUBFRAME_SAMPLES].fill(0);
+        for (i, x_mp) in x_mp.iter().enumerate() {
+            self.e[5 + grid + 3 * i] = *x_mp;
+        }
+
+        (grid as i16, block_amplitude, pulses)
+    }
+}
+
+/// Decoder state of the GSM full-rate codec.
+#[derive(Clone, Debug)]
+pub(crate) struct GsmDecoder {
+    larpp: [[i16; LPC_ORDER]; 2],
+    j: usize,
+    v: [i16; LPC_ORDER + 1],
+    dp: [i16; 160],
+    nrp: i16,
+    msr: i16,
+}
+
+impl Default for GsmDecoder {
+    fn default() -> Self {
+        Self {
+            larpp: [[0; LPC_ORDER]; 2],
+            j: 0,
+            v: [0; LPC_ORDER + 1],
+            dp: [0; 160],
+            nrp: MIN_LAG,
+            msr: 0,
+        }
+    }
+}
+
+impl GsmDecoder {
+    /// Decodes a 33-octet frame into 160 samples. Missing octets are treated as zero.
+    pub(crate) fn decode_frame(&mut self, bytes: &[u8]) -> [i16; GSM_FRAME_SAMPLES] {
+        let frame = Frame::from_bytes(bytes);
+        let mut wt = [0i16; GSM_FRAME_SAMPLES];
+
+        for subframe in 0..SUBFRAMES {
+            let (exponent, mantissa) = exponent_and_mantissa(frame.block_amplitude[subframe]);
+            let x_mp = apcm_inverse_quantization(&frame.pulses[subframe], exponent, mantissa);
+            let mut erp = [0i16; SUBFRAME_SAMPLES];
+            for (i, x_mp) in x_mp.iter().enumerate() {
+                erp[frame.grid[subframe] as usize + 3 * i] = *x_mp;
+            }
+
+            // long term synthesis; dp[120 + k] is the current subframe
+            let lag = frame.lag[subframe];
+            let lag = if (MIN_LAG..=MAX_LAG).contains(&lag) {
+                lag
+            } else {
+                self.nrp
+            };
+            self.nrp = lag;
+            let gain = QLB[frame.gain[subframe] as usize];
+            for (k, erp) in erp.iter().enumerate() {
+                let drpp = mult_r(gain, self.dp[120 + k - lag as usize]);
+                self.dp[120 + k] = add(*erp, drpp);
+            }
+            wt[subframe * SUBFRAME_SAMPLES..(subframe + 1) * SUBFRAME_SAMPLES]
+                .copy_from_slice(&self.dp[120..]);
+            self.dp.copy_within(SUBFRAME_SAMPLES.., 0);
+        }
+
+        let mut s = self.short_term_synthesis(&frame.lar, &wt);
+        self.postprocess(&mut s);
+        s
+    }
+
+    fn short_term_synthesis(
+        &mut self,
+        lar: &[i16; LPC_ORDER],
+        wt: &[i16; GSM_FRAME_SAMPLES],
+    ) -> [i16; GSM_FRAME_SAMPLES] {
+        let mut s = [0i16; GSM_FRAME_SAMPLES];
+        let (previous, current) = next_larpp(&mut self.larpp, &mut self.j, lar);
+        for (range, rrp) in interpolated_rp(&previous, &current) {
+            for k in range {
+                let mut sri = wt[k];
+                for i in (0..LPC_ORDER).rev() {
+                    sri = sub(sri, mult_r(rrp[i], self.v[i]));
+                    self.v[i + 1] = add(self.v[i], mult_r(rrp[i], sri));
+                }
+                self.v[0] = sri;
+                s[k] = sri;
+            }
+        }
+        s
+    }
+
+    /// Deemphasis, upscaling and output truncation.
+    fn postprocess(&mut self, s: &mut [i16; GSM_FRAME_SAMPLES]) {
+        for sample in s.iter_mut() {
+            self.msr = add(*sample, mult_r(self.msr, 28180));
+            *sample = add(self.msr, self.msr) & !0x7;
+        }
+    }
+}
+
+/// Computes the coded Log Area Ratios of the (preprocessed) frame.
+fn lpc_analysis(s: &mut [i16; GSM_FRAME_SAMPLES]) -> [i16; LPC_ORDER] {
+    let acf = autocorrelation(s);
+    let mut lar = reflection_coefficients(&acf);
+
+    // transformation to Log Area Ratios
+    for lar in &mut lar {
+        let temp = lar.saturating_abs();
+        let temp = if temp < 22118 {
+            temp >> 1
+        } else if temp < 31130 {
+            temp - 11059
+        } else {
+            (temp - 26112) << 2
+        };
+        *lar = if *lar < 0 { -temp } else { temp };
+    }
+
+    // quantization and coding
+    for i in 0..LPC_ORDER {
+        let temp = add(add(mult(LAR_A[i], lar[i]), LAR_B[i]), 256) >> 9;
+        lar[i] = if temp > LAR_MAC[i] {
+            LAR_MAC[i] - LAR_MIC[i]
+        } else if temp < LAR_MIC[i] {
+            0
+        } else {
+            temp - LAR_MIC[i]
+        };
+    }
+    lar
+}
+
+fn autocorrelation(s: &mut [i16; GSM_FRAME_SAMPLES]) -> [i32; LPC_ORDER + 1] {
+    let s_max = s.iter().map(|s| s.saturating_abs()).max().unwrap_or(0);
+    let scale = if s_max == 0 {
+        0
+    } else {
+        4 - norm(i32::from(s_max) << 16) as i16
+    };
+    if scale > 0 {
+        for sample in s.iter_mut() {
+            *sample = mult_r(*sample, 16384 >> (scale - 1));
+        }
+    }
+
+    let mut acf = [0i32; LPC_ORDER + 1];
+    for (k, acf) in acf.iter_mut().enumerate() {
+        *acf = (k..GSM_FRAME_SAMPLES)
+            .fold(0i32, |sum, i| {
+                sum.wrapping_add(i32::from(s[i]) * i32::from(s[i - k]))
+            })
+            .wrapping_shl(1);
+    }
+
+    if scale > 0 {
+        for sample in s.iter_mut() {
+            *sample = sample.wrapping_shl(scale as u32);
+        }
+    }
+    acf
+}
+
+/// Computes the reflection coefficients using the Schur recursion.
+fn reflection_coefficients(l_acf: &[i32; LPC_ORDER + 1]) -> [i16; LPC_ORDER] {
+    let mut r = [0i16; LPC_ORDER];
+    if l_acf[0] == 0 {
+        return r;
+    }
+
+    let shift = norm(l_acf[0]);
+    let acf = l_acf.map(|acf| (acf.wrapping_shl(shift) >> 16) as i16);
+    let mut k = acf;
+    let mut p = acf;
+
+    for n in 0..LPC_ORDER {
+        let temp = p[1].saturating_abs();
+        if p[0] < temp {
+            return r;
+        }
+        r[n] = div(temp, p[0]);
+        if p[1] > 0 {
+            r[n] = -r[n];
+        }
+        if n == LPC_ORDER - 1 {
+            return r;
+        }
+
+        p[0] = add(p[0], mult_r(p[1], r[n]));
+        for m in 1..LPC_ORDER - n {
+            p[m] = add(p[m + 1], mult_r(k[m], r[n]));
+            k[m] = add(k[m], mult_r(p[m + 1], r[n]));
+        }
+    }
+    r
+}
+
+/// Determines the LTP lag and the coded LTP gain for the subframe `d`,
+/// given the past reconstructed signal `dp` in which the subframe starts at `start`.
+fn ltp_parameters(d: &[i16], dp: &[i16], start: usize) -> (i16, i16) {
+    let d_max = d.iter().map(|d| d.saturating_abs()).max().unwrap_or(0);
+    let temp = if d_max == 0 {
+        0
+    } else {
+        norm(i32::from(d_max) << 16) as i16
+    };
+    let scale = if temp > 6 { 0 } else { 6 - temp };
+    let wt: Vec<i16> = d.iter().map(|d| d >> scale).collect();
+
+    let mut lag = MIN_LAG;
+    let mut l_max = 0i32;
+    for lambda in MIN_LAG..=MAX_LAG {
+        let l_result = wt.iter().enumerate().fold(0i32, |sum, (k, wt)| {
+            sum.wrapping_add(i32::from(*wt) * i32::from(dp[start + k - lambda as usize]))
+        });
+        if l_result > l_max {
+            lag = lambda;
+            l_max = l_result;
+        }
+    }
+    let l_max = l_max.wrapping_shl(1) >> (6 - scale);
+
+    let l_power = (0..SUBFRAME_SAMPLES)
+        .fold(0i32, |sum, k| {
+            let temp = i32::from(dp[start + k - lag as usize] >> 3);
+            sum.wrapping_add(temp * temp)
+        })
+        .wrapping_shl(1);
+
+    if l_max <= 0 {
+        return (lag, 0);
+    }
+    if l_max >= l_power {
+        return (lag, 3);
+    }
+    let shift = norm(l_power);
+    let r = (l_max.wrapping_shl(shift) >> 16) as i16;
+    let s = (l_power.wrapping_shl(shift) >> 16) as i16;
+    let gain = DLB[..3]
+        .iter()
+        .position(|dlb| r <= mult(s, *dlb))
+        .unwrap_or(3);
+
+    (lag, gain as i16)
+}
+
+/// Decodes the coded Log Area Ratios into `larpp`, and returns the previous and the current decoded ratios.
+fn next_larpp(
+    larpp: &mut [[i16; LPC_ORDER]; 2],
+    j: &mut usize,
+    lar: &[i16; LPC_ORDER],
+) -> ([i16; LPC_ORDER], [i16; LPC_ORDER]) {
+    let previous = larpp[*j];
+    *j ^= 1;
+    for i in 0..LPC_ORDER {
+        let temp = add(lar[i], LAR_MIC[i]) << 10;
+        let temp = mult_r(LAR_INVA[i], sub(temp, LAR_B[i] << 1));
+        larpp[*j][i] = add(temp, temp);
+    }
+    (previous, larpp[*j])
+}
+
+/// Interpolates the Log Area Ratios of the previous and the current frame for the four segments of a frame,
+/// and converts them to reflection coefficients.
+fn interpolated_rp(
+    previous: &[i16; LPC_ORDER],
+    current: &[i16; LPC_ORDER],
+) -> [(std::ops::Range<usize>, [i16; LPC_ORDER]); 4] {
+    let mut segments = [
+        (0..13, [0i16; LPC_ORDER]),
+        (13..27, [0i16; LPC_ORDER]),
+        (27..40, [0i16; LPC_ORDER]),
+        (40..GSM_FRAME_SAMPLES, *current),
+    ];
+    for i in 0..LPC_ORDER {
+        segments[0].1[i] = add(add(previous[i] >> 2, current[i] >> 2), previous[i] >> 1);
+        segments[1].1[i] = add(previous[i] >> 1, current[i] >> 1);
+        segments[2].1[i] = add(add(previous[i] >> 2, current[i] >> 2), current[i] >> 1);
+    }
+    for (_, lar) in &mut segments {
+        for lar in lar.iter_mut() {
+            let temp = lar.saturating_abs();
+            let rp = if temp < 11059 {
+                temp << 1
+            } else if temp < 20070 {
+                temp + 11059
+            } else {
+                add(temp >> 2, 26112)
+            };
+            *lar = if *lar < 0 { -rp } else { rp };
+        }
+    }
+    segments
+}
+
+/// Splits the coded block amplitude into its exponent and mantissa.
+fn exponent_and_mantissa(block_amplitude: i16) -> (i16, i16) {
+    let mut exponent = if block_amplitude > 15 {
+        (block_amplitude >> 3) - 1
+    } else {
+        0
+    };
+    let mut mantissa = block_amplitude - (exponent << 3);
+    if mantissa == 0 {
+        return (-4, 7);
+    }
+    while mantissa <= 7 {
+        mantissa = (mantissa << 1) | 1;
+        exponent -= 1;
+    }
+    (exponent, mantissa - 8)
+}
+
+fn apcm_inverse_quantization(
+    pulses: &[i16; RPE_PULSES],
+    exponent: i16,
+    mantissa: i16,
+) -> [i16; RPE_PULSES] {
+    let fac = FAC[mantissa as usize];
+    let shift = sub(6, exponent);
+    let rounding = asl(1, sub(shift, 1));
+    pulses.map(|pulse| {
+        let temp = ((pulse << 1) - 7) << 12;
+        asr(add(mult_r(fac, temp), rounding), shift)
+    })
+}
+
+fn saturate(value: i32) -> i16 {
+    value.clamp(i32::from(i16::MIN), i32::from(i16::MAX)) as i16
+}
+
+fn add(a: i16, b: i16) -> i16 {
+    a.saturating_add(b)
+}
+
+fn sub(a: i16, b: i16) -> i16 {
+    a.saturating_sub(b)
+}
+
+fn mult(a: i16, b: i16) -> i16 {
+    if a == i16::MIN && b == i16::MIN {
+        i16::MAX
+    } else {
+        ((i32::from(a) * i32::from(b)) >> 15) as i16
+    }
+}
+
+fn mult_r(a: i16, b: i16) -> i16 {
+    if a == i16::MIN && b == i16::MIN {
+        i16::MAX
+    } else {
+        ((i32::from(a) * i32::from(b) + 16384) >> 15) as i16
+    }
+}
+
+/// Number of left shifts needed to normalize the 32-bit value.
+fn norm(value: i32) -> u32 {
+    if value <= -1_073_741_824 {
+        return 0;
+    }
+    let value = if value < 0 { !value } else { value };
+    value.leading_zeros() - 1
+}
+
+/// Divides `numerator` by `denominator` in Q15, for `0 <= numerator <= denominator`.
+fn div(numerator: i16, denominator: i16) -> i16 {
+    if numerator == 0 {
+        return 0;
+    }
+    let mut l_numerator = i32::from(numerator);
+    let denominator = i32::from(denominator);
+    let mut quotient = 0i16;
+    for _ in 0..15 {
+        quotient <<= 1;
+        l_numerator <<= 1;
+        if l_numerator >= denominator {
+            l_numerator -= denominator;
+            quotient += 1;
+        }
+    }
+    quotient
+}
+
+fn asl(a: i16, n: i16) -> i16 {
+    if n >= 16 {
+        0
+    } else if n <= -16 {
+        -i16::from(a < 0)
+    } else if n < 0 {
+        asr(a, -n)
+    } else {
+        a.wrapping_shl(n as u32)
+    }
+}
+
+fn asr(a: i16, n: i16) -> i16 {
+    if n >= 16 {
+        -i16::from(a < 0)
+    } else if n <= -16 {
+        0
+    } else if n < 0 {
+        a.wrapping_shl(-n as u32)
+    } else {
+        a >> n
+    }
+}
@@ -1,6 +1,9 @@
+pub mod audio;
 pub mod builder;
+mod gsm;
 pub mod model;
 pub mod parser;
+pub mod wav;
 pub mod writer;
 
 #[cfg(test)]
@@ -8,11 +11,28 @@ mod tests {
     use crate::common::model::DisTimeStamp;
     use crate::common::model::{Pdu, PduHeader};
     use crate::common::parser::parse_pdu;
-    use crate::enumerations::{PduType, SignalEncodingClass, SignalEncodingType};
-    use crate::model::EntityId;
+    use crate::common::signal::parser::signal_body;
+    use crate::constants::PDU_HEADER_LEN_BYTES;
+    use crate::enumerations::{
+        PduType, SignalEncodingClass, SignalEncodingType, SignalUserProtocolIdentificationNumber,
+    };
+    use crate::model::{EntityId, PduBody};
+    use crate::signal::audio::{
+        mu_law_decode, mu_law_encode, signals_from_audio, AudioDecoder, AudioEncoder, AudioEncoding,
+    };
     use crate::signal::model::{EncodingScheme, Signal};
+    use crate::signal::wav::{read_wav, write_wav, WavAudio};
     use bytes::BytesMut;
 
+    fn sine(frequency: f32, amplitude: f32, sample_rate: u32, samples: usize) -> Vec<i16> {
+        (0..samples)
+            .map(|i| {
+                let t = i as f32 / sample_rate as f32;
+                (amplitude * (2.0 * std::f32::consts::PI * frequency * t).sin()) as i16
+            })
+            .collect()
+    }
+
     #[test]
     fn signal_internal_consistency() {
         let header = PduHeader::new_v6(1, PduType::Signal);
@@ -50,4 +70,228 @@ fn signal_internal_consistency() {
             }
         }
     }
+
+    fn serialized(signal: Signal) -> BytesMut {
+        let pdu = Pdu::finalize_from_parts(
+            PduHeader::new_v6(1, PduType::Signal),
+            signal.into_pdu_body(),
+            DisTimeStamp::new_absolute_from_secs(100),
+        );
+        let mut buf = BytesMut::with_capacity(pdu.header.pdu_length as usize);
+        pdu.serialize(&mut buf).unwrap();
+        buf
+    }
+
+    #[test]
+    fn signal_body_consumes_data_padding() {
+        let buf = serialized(Signal::builder().with_data(vec![0x10, 0x10, 0x10]).build());
+
+        let (input, _body) = signal_body(&buf[PDU_HEADER_LEN_BYTES as usize..]).unwrap();
+
+        assert!(input.is_empty());
+    }
+
+    #[test]
+    fn signal_data_too_short_for_encoding_scheme() {
+        for encoding_scheme in [
+            EncodingScheme::ApplicationSpecificData {
+                encoding_class: SignalEncodingClass::ApplicationSpecificData,
+                user_protocol_id: SignalUserProtocolIdentificationNumber::default(),
+            },
+            EncodingScheme::DatabaseIndex {
+                encoding_class: SignalEncodingClass::DatabaseIndex,
+                index: 1,
+                offset_milli_secs: 2,
+                duration_milli_secs: 3,
+            },
+        ] {
+            let buf = serialized(
+                Signal::builder()
+                    .with_encoding_scheme(encoding_scheme)
+                    .with_data(vec![0x00, 0x01])
+                    .build(),
+            );
+
+            let pdu = parse_pdu(&buf).unwrap();
+
+            let PduBody::Signal(signal) = pdu.body else {
+                panic!("Expected a Signal PDU, got {:?}", pdu.body);
+            };
+            assert!(matches!(
+                signal.encoding_scheme,
+                EncodingScheme::Unspecified { .. }
+            ));
+        }
+    }
+
+    #[test]
+    fn mu_law_round_trip() {
+        for sample in [
+            0i16, 1, -1, 100, -100, 1000, -1000, 8000, -8000, 32000, -32000,
+        ] {
+            let decoded = mu_law_decode(mu_law_encode(sample));
+            let tolerance = (i32::from(sample).abs() / 16).max(8);
+            assert!(
+                (i32::from(decoded) - i32::from(sample)).abs() <= tolerance,
+                "{sample} decoded as {decoded}"
+            );
+        }
+        assert_eq!(mu_law_encode(0), 0xFF);
+    }
+
+    #[test]
+    fn linear_pcm_round_trip() {
+        let samples = vec![0i16, 1, -1, 0x1234, -0x1234, i16::MAX, i16::MIN];
+
+        let mut encoder = AudioEncoder::new(AudioEncoding::LinearPcm16BigEndian);
+        let data = encoder.encode(&samples);
+        assert_eq!(&data[6..8], &[0x12, 0x34]);
+        let mut decoder = AudioDecoder::new(AudioEncoding::LinearPcm16BigEndian);
+        assert_eq!(decoder.decode(&data, samples.len()), samples);
+
+        let mut encoder = AudioEncoder::new(AudioEncoding::LinearPcm16LittleEndian);
+        let data = encoder.encode(&samples);
+        assert_eq!(&data[6..8], &[0x34, 0x12]);
+        let mut decoder = AudioDecoder::new(AudioEncoding::LinearPcm16LittleEndian);
+        assert_eq!(decoder.decode(&data, samples.len()), samples);
+    }
+
+    #[test]
+    fn cvsd_tracks_sine() {
+        let samples = sine(250.0, 8000.0, 16000, 1600);
+
+        let mut encoder = AudioEncoder::new(AudioEncoding::Cvsd);
+        let data = encoder.encode(&samples);
+        assert_eq!(data.len(), samples.len() / 8);
+
+        let mut decoder = AudioDecoder::new(AudioEncoding::Cvsd);
+        let decoded = decoder.decode(&data, samples.len());
+        assert_eq!(decoded.len(), samples.len());
+
+        let settled = 160;
+        let mean_error = samples[settled..]
+            .iter()
+            .zip(&decoded[settled..])
+            .map(|(original, decoded)| (i32::from(*original) - i32::from(*decoded)).abs())
+            .sum::<i32>()
+            / (samples.len() - settled) as i32;
+        assert!(mean_error < 1000, "mean error {mean_error}");
+    }
+
+    #[test]
+    fn gsm_full_rate_tracks_speech_band_signal() {
+        let samples: Vec<i16> = sine(300.0, 8000.0, 8000, 1600)
+            .iter()
+            .zip(sine(1100.0, 3000.0, 8000, 1600))
+            .map(|(low, high)| low + high)
+            .collect();
+
+        let mut encoder = AudioEncoder::new(AudioEncoding::GsmFullRate);
+        let data = encoder.encode(&samples);
+        assert_eq!(data.len(), 10 * 33);
+        assert_eq!(data[0] >> 4, 0xD);
+
+        let mut decoder = AudioDecoder::new(AudioEncoding::GsmFullRate);
+        let decoded = decoder.decode(&data, samples.len());
+        assert_eq!(decoded.len(), samples.len());
+
+        let settled = 320;
+        let (signal, noise) = samples[settled..].iter().zip(&decoded[settled..]).fold(
+            (0f64, 0f64),
+            |(signal, noise), (original, decoded)| {
+                (
+                    signal + f64::from(*original).powi(2),
+                    noise + (f64::from(*original) - f64::from(*decoded)).powi(2),
+                )
+            },
+        );
+        let snr = 10.0 * (signal / noise).log10();
+        assert!(snr > 15.0, "signal to noise ratio {snr} dB");
+
+        assert_eq!(
+            AudioEncoding::from_encoding_type(AudioEncoding::GsmFullRate.encoding_type()),
+            Some(AudioEncoding::GsmFullRate)
+        );
+        assert_eq!(AudioEncoding::GsmFullRate.max_samples_per_pdu() % 160, 0);
+    }
+
+    #[test]
+    fn wav_round_trip() {
+        let audio = WavAudio::new_mono(8000, sine(440.0, 10000.0, 8000, 800));
+
+        let bytes = write_wav(&audio);
+        assert_eq!(&bytes[0..4], b"RIFF");
+        assert_eq!(&bytes[8..12], b"WAVE");
+        assert_eq!(bytes.len(), 44 + audio.samples.len() * 2);
+
+        let parsed = read_wav(&bytes).unwrap();
+        assert_eq!(parsed, audio);
+
+        let stereo = WavAudio::new(8000, 2, vec![100, 200, -100, -300]);
+        assert_eq!(stereo.to_mono(), vec![150, -200]);
+        assert!(read_wav(b"RIFX").is_err());
+    }
+
+    #[test]
+    fn signals_from_audio_splits_audio() {
+        let audio = sine(300.0, 5000.0, 8000, 1000);
+        let radio = EntityId::new(1, 2, 3);
+
+        let signals =
+            signals_from_audio(radio, 1, AudioEncoding::MuLaw, 8000, &audio, 480).unwrap();
+        assert_eq!(signals.len(), 3);
+        assert_eq!(signals[0].samples, 480);
+        assert_eq!(signals[0].data.len(), 480);
+        assert_eq!(signals[2].samples, 40);
+
+        let mut decoder = AudioDecoder::new(AudioEncoding::MuLaw);
+        let decoded: Vec<i16> = signals
+            .iter()
+            .flat_map(|signal| decoder.decode_signal(signal).unwrap())
+            .collect();
+        assert_eq!(decoded.len(), audio.len());
+
+        assert!(signals_from_audio(radio, 1, AudioEncoding::MuLaw, 8000, &audio, 0).is_err());
+        assert!(signals_from_audio(radio, 1, AudioEncoding::Cvsd, 16000, &audio, 100).is_err());
+        assert!(
+            signals_from_audio(radio, 1, AudioEncoding::GsmFullRate, 8000, &audio, 480).is_ok()
+        );
+        assert!(
+            signals_from_audio(radio, 1, AudioEncoding::GsmFullRate, 8000, &audio, 400).is_err()
+        );
+        assert!(signals_from_audio(
+            radio,
+            1,
+            AudioEncoding::LinearPcm16BigEndian,
+            8000,
+            &audio,
+            AudioEncoding::LinearPcm16BigEndian.max_samples_per_pdu() + 1
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn audio_signal_internal_consistency() {
+        let header = PduHeader::new_v6(1, PduType::Signal);
+        let audio = sine(300.0, 5000.0, 16000, 320);
+        let body = signals_from_audio(
+            EntityId::new(1, 2, 3),
+            1,
+            AudioEncoding::Cvsd,
+            16000,
+            &audio,
+            320,
+        )
+        .unwrap()
+        .remove(0)
+        .into_pdu_body();
+        let original_pdu =
+            Pdu::finalize_from_parts(header, body, DisTimeStamp::new_absolute_from_secs(100));
+
+        let mut buf = BytesMut::with_capacity(original_pdu.header.pdu_length as usize);
+        original_pdu.serialize(&mut buf).unwrap();
+
+        let parsed = parse_pdu(&buf).unwrap();
+        assert_eq!(parsed, original_pdu);
+    }
 }
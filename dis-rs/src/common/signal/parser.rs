@@ -1,7 +1,7 @@
-use crate::common::model::PduBody;
+use crate::common::model::{length_padded_to_num, PduBody};
 use crate::common::parser::entity_id;
 use crate::common::signal::model::{EncodingScheme, Signal};
-use crate::constants::ONE_BYTE_IN_BITS;
+use crate::constants::{FOUR_OCTETS, ONE_BYTE_IN_BITS};
 use crate::enumerations::{
     SignalEncodingClass, SignalEncodingType, SignalTdlType, SignalUserProtocolIdentificationNumber,
 };
@@ -20,6 +20,8 @@ pub(crate) fn signal_body(input: &[u8]) -> IResult<&[u8], PduBody> {
     let (input, samples) = be_u16(input)?;
     let (input, data) =
         nom::bytes::complete::take(data_length_in_bits / ONE_BYTE_IN_BITS as u16)(input)?;
+    let padded_data_length = length_padded_to_num(data.len(), FOUR_OCTETS);
+    let (input, _padding) = nom::bytes::complete::take(padded_data_length.padding_length)(input)?;
 
     let encoding_scheme = parse_encoding_scheme(encoding_scheme, data);
 
@@ -53,28 +55,28 @@ fn parse_encoding_scheme(encoding_scheme_bytes: u16, data: &[u8]) -> EncodingSch
             encoding_class,
             nr_of_messages: low_bits,
         },
-        SignalEncodingClass::ApplicationSpecificData => {
-            let mut bytes = [0u8, 0u8, 0u8, 0u8];
-            bytes.clone_from_slice(&data[0..4]);
-            let user_protocol_id = u32::from_be_bytes(bytes);
-            let user_protocol_id = SignalUserProtocolIdentificationNumber::from(user_protocol_id);
-            EncodingScheme::ApplicationSpecificData {
+        SignalEncodingClass::ApplicationSpecificData => match data_field(data, 0) {
+            Some(user_protocol_id) => EncodingScheme::ApplicationSpecificData {
                 encoding_class,
-                user_protocol_id,
-            }
-        }
+                user_protocol_id: SignalUserProtocolIdentificationNumber::from(user_protocol_id),
+            },
+            None => EncodingScheme::Unspecified { encoding_class },
+        },
         SignalEncodingClass::DatabaseIndex => {
-            let mut index_bytes = [0u8, 0u8, 0u8, 0u8];
-            index_bytes.clone_from_slice(&data[0..4]);
-            let mut offset_bytes = [0u8, 0u8, 0u8, 0u8];
-            offset_bytes.clone_from_slice(&data[4..8]);
-            let mut duration_bytes = [0u8, 0u8, 0u8, 0u8];
-            duration_bytes.clone_from_slice(&data[8..12]);
-            EncodingScheme::DatabaseIndex {
-                encoding_class,
-                index: u32::from_be_bytes(index_bytes),
-                offset_milli_secs: u32::from_be_bytes(offset_bytes),
-                duration_milli_secs: u32::from_be_bytes(duration_bytes),
+            match (
+                data_field(data, 0),
+                data_field(data, 1),
+                data_field(data, 2),
+            ) {
+                (Some(index), Some(offset_milli_secs), Some(duration_milli_secs)) => {
+                    EncodingScheme::DatabaseIndex {
+                        encoding_class,
+                        index,
+                        offset_milli_secs,
+                        duration_milli_secs,
+                    }
+                }
+                _ => EncodingScheme::Unspecified { encoding_class },
             }
         }
         SignalEncodingClass::Unspecified(_) => {
@@ -84,3 +86,12 @@ fn parse_encoding_scheme(encoding_scheme_bytes: u16, data: &[u8]) -> EncodingSch
         }
     }
 }
+
+/// Reads the `index`-th 32-bit field from the signal data, if the data is long enough.
+fn data_field(data: &[u8], index: usize) -> Option<u32> {
+    let start = index * FOUR_OCTETS;
+    let bytes = data.get(start..start + FOUR_OCTETS)?;
+    let mut field = [0u8; FOUR_OCTETS];
+    field.copy_from_slice(bytes);
+    Some(u32::from_be_bytes(field))
+}
@@ -0,0 +1,194 @@
+use crate::common::signal::audio::mu_law_decode;
+use crate::DisError;
+use bytes::{BufMut, BytesMut};
+use nom::bytes::complete::{tag, take};
+use nom::number::complete::{le_u16, le_u32};
+use nom::IResult;
+
+const WAVE_FORMAT_PCM: u16 = 1;
+const WAVE_FORMAT_MU_LAW: u16 = 7;
+const RIFF_HEADER_OCTETS: usize = 12;
+const CHUNK_HEADER_OCTETS: usize = 8;
+const FMT_CHUNK_OCTETS: u32 = 16;
+const BITS_PER_SAMPLE: u16 = 16;
+const BYTES_PER_SAMPLE: u16 = BITS_PER_SAMPLE / 8;
+
+/// Audio read from or written to a WAV (RIFF WAVE) file.
+///
+/// Samples of multichannel audio are interleaved.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct WavAudio {
+    pub sample_rate: u32,
+    pub channels: u16,
+    pub samples: Vec<i16>,
+}
+
+impl WavAudio {
+    #[must_use]
+    pub fn new(sample_rate: u32, channels: u16, samples: Vec<i16>) -> Self {
+        Self {
+            sample_rate,
+            channels,
+            samples,
+        }
+    }
+
+    /// Creates single channel audio.
+    #[must_use]
+    pub fn new_mono(sample_rate: u32, samples: Vec<i16>) -> Self {
+        Self::new(sample_rate, 1, samples)
+    }
+
+    /// Returns the audio as a single channel, averaging the channels of multichannel audio.
+    #[must_use]
+    pub fn to_mono(&self) -> Vec<i16> {
+        if self.channels <= 1 {
+            return self.samples.clone();
+        }
+        self.samples
+            .chunks(self.channels as usize)
+            .map(|frame| {
+                let sum: i32 = frame.iter().map(|sample| i32::from(*sample)).sum();
+                (sum / frame.len() as i32) as i16
+            })
+            .collect()
+    }
+}
+
+/// Reads a WAV file containing 8-bit or 16-bit linear PCM, or 8-bit µ-law audio.
+///
+/// # Errors
+/// Returns `DisError::InvalidAudioData` when the input is not a valid WAV file, or uses an unsupported audio format.
+pub fn read_wav(input: &[u8]) -> Result<WavAudio, DisError> {
+    let (mut input, ()) = riff_header(input).map_err(invalid_wav)?;
+
+    let mut format = None;
+    while !input.is_empty() {
+        let (rest, (chunk_id, chunk_data)) = chunk(input).map_err(invalid_wav)?;
+        input = rest;
+        match chunk_id {
+            b"fmt " => {
+                let (_, fmt) = format_chunk(chunk_data).map_err(invalid_wav)?;
+                format = Some(fmt);
+            }
+            b"data" => {
+                let fmt = format.ok_or_else(|| {
+                    DisError::InvalidAudioData("WAV data chunk precedes the fmt chunk".to_string())
+                })?;
+                return Ok(WavAudio::new(
+                    fmt.sample_rate,
+                    fmt.channels,
+                    decode_wav_samples(&fmt, chunk_data)?,
+                ));
+            }
+            _ => {}
+        }
+    }
+
+    Err(DisError::InvalidAudioData(
+        "WAV file does not contain a data chunk".to_string(),
+    ))
+}
+
+/// Writes the audio as a WAV file with 16-bit linear PCM samples.
+#[must_use]
+pub fn write_wav(audio: &WavAudio) -> Vec<u8> {
+    let data_length = (audio.samples.len() * BYTES_PER_SAMPLE as usize) as u32;
+    let riff_length = 4
+        + (CHUNK_HEADER_OCTETS as u32 + FMT_CHUNK_OCTETS)
+        + (CHUNK_HEADER_OCTETS as u32 + data_length);
+    let block_align = audio.channels * BYTES_PER_SAMPLE;
+
+    let mut buf = BytesMut::with_capacity(RIFF_HEADER_OCTETS + riff_length as usize - 4);
+    buf.put_slice(b"RIFF");
+    buf.put_u32_le(riff_length);
+    buf.put_slice(b"WAVE");
+
+    buf.put_slice(b"fmt ");
+    buf.put_u32_le(FMT_CHUNK_OCTETS);
+    buf.put_u16_le(WAVE_FORMAT_PCM);
+    buf.put_u16_le(audio.channels);
+    buf.put_u32_le(audio.sample_rate);
+    buf.put_u32_le(audio.sample_rate * u32::from(block_align));
+    buf.put_u16_le(block_align);
+    buf.put_u16_le(BITS_PER_SAMPLE);
+
+    buf.put_slice(b"data");
+    buf.put_u32_le(data_length);
+    audio
+        .samples
+        .iter()
+        .for_each(|sample| buf.put_i16_le(*sample));
+
+    buf.to_vec()
+}
+
+#[derive(Copy, Clone, Debug)]
+struct WavFormat {
+    format: u16,
+    channels: u16,
+    sample_rate: u32,
+    bits_per_sample: u16,
+}
+
+fn decode_wav_samples(format: &WavFormat, data: &[u8]) -> Result<Vec<i16>, DisError> {
+    match (format.format, format.bits_per_sample) {
+        (WAVE_FORMAT_PCM, 16) => Ok(data
+            .chunks_exact(2)
+            .map(|pair| i16::from_le_bytes([pair[0], pair[1]]))
+            .collect()),
+        (WAVE_FORMAT_PCM, 8) => Ok(data
+            .iter()
+            .map(|sample| (i16::from(*sample) - 128) << 8)
+            .collect()),
+        (WAVE_FORMAT_MU_LAW, 8) => Ok(data.iter().map(|sample| mu_law_decode(*sample)).collect()),
+        (format, bits) => Err(DisError::InvalidAudioData(format!(
+            "Unsupported WAV audio format {format} with {bits} bits per sample"
+        ))),
+    }
+}
+
+fn invalid_wav(error: nom::Err<nom::error::Error<&[u8]>>) -> DisError {
+    DisError::InvalidAudioData(format!("Invalid WAV file: {error}"))
+}
+
+fn riff_header(input: &[u8]) -> IResult<&[u8], ()> {
+    let (input, _) = tag(&b"RIFF"[..])(input)?;
+    let (input, _riff_length) = le_u32(input)?;
+    let (input, _) = tag(&b"WAVE"[..])(input)?;
+
+    Ok((input, ()))
+}
+
+/// Parses a chunk, returning the chunk id and the chunk data. Chunks are padded to an even length.
+fn chunk(input: &[u8]) -> IResult<&[u8], (&[u8], &[u8])> {
+    let (input, chunk_id) = take(4usize)(input)?;
+    let (input, chunk_length) = le_u32(input)?;
+    let (input, chunk_data) = take(chunk_length)(input)?;
+    let input = if chunk_length % 2 == 1 && !input.is_empty() {
+        &input[1..]
+    } else {
+        input
+    };
+
+    Ok((input, (chunk_id, chunk_data)))
+}
+
+fn format_chunk(input: &[u8]) -> IResult<&[u8], WavFormat> {
+    let (input, format) = le_u16(input)?;
+    let (input, channels) = le_u16(input)?;
+    let (input, sample_rate) = le_u32(input)?;
+    let (input, _byte_rate) = le_u32(input)?;
+    let (input, _block_align) = le_u16(input)?;
+    let (input, bits_per_sample) = le_u16(input)?;
+
+    Ok((
+        input,
+        WavFormat {
+            format,
+            channels,
+            sample_rate,
+            bits_per_sample,
+        },
+    ))
+}
@@ -1,10 +1,11 @@
 use crate::enumerations::{
     TransmitterAntennaPatternType, TransmitterCryptoSystem, TransmitterInputSource,
-    TransmitterTransmitState,
+    TransmitterModulationTypeSystem, TransmitterTransmitState,
 };
 use crate::model::{EntityId, EntityType, Location, VectorF32};
 use crate::transmitter::model::{
-    BeamAntennaPattern, CryptoKeyId, ModulationType, Transmitter, VariableTransmitterParameter,
+    AntennaPattern, CryptoKeyId, ModulationParameters, ModulationType, Transmitter,
+    VariableTransmitterParameter,
 };
 
 pub struct TransmitterBuilder(Transmitter);
@@ -116,14 +117,40 @@ pub fn with_crypto_key_id(mut self, crypto_key_id: CryptoKeyId) -> Self {
         self.0.crypto_key_id = crypto_key_id;
         self
     }
+    /// Sets the Modulation Parameters record, and the radio system of the `ModulationType` that selects the record.
+    /// Basic HAVE QUICK parameters keep a HAVE QUICK I, HAVE QUICK II or SATURN radio system, and default to HAVE QUICK I.
+    /// For `ModulationParameters::Unspecified` only the record is set.
     #[must_use]
-    pub fn with_modulation_parameters(mut self, modulation_parameters: Vec<u8>) -> Self {
+    pub fn with_modulation_parameters(
+        mut self,
+        modulation_parameters: ModulationParameters,
+    ) -> Self {
+        let radio_system = &mut self.0.modulation_type.radio_system;
+        match modulation_parameters {
+            ModulationParameters::CcttSincgars(_) => {
+                *radio_system = TransmitterModulationTypeSystem::CCTTSINCGARS;
+            }
+            ModulationParameters::JtidsMids(_) => {
+                *radio_system = TransmitterModulationTypeSystem::JTIDSMIDS;
+            }
+            ModulationParameters::BasicHaveQuick(_) => {
+                if !matches!(
+                    radio_system,
+                    TransmitterModulationTypeSystem::HAVEQUICKI
+                        | TransmitterModulationTypeSystem::HAVEQUICKII
+                        | TransmitterModulationTypeSystem::SATURN
+                ) {
+                    *radio_system = TransmitterModulationTypeSystem::HAVEQUICKI;
+                }
+            }
+            ModulationParameters::Unspecified(_) => {}
+        }
         self.0.modulation_parameters = Some(modulation_parameters);
         self
     }
 
     #[must_use]
-    pub fn with_antenna_pattern(mut self, antenna_pattern: BeamAntennaPattern) -> Self {
+    pub fn with_antenna_pattern(mut self, antenna_pattern: AntennaPattern) -> Self {
         self.0.antenna_pattern = Some(antenna_pattern);
         self
     }
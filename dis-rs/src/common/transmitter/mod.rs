@@ -6,14 +6,20 @@
 #[cfg(test)]
 mod tests {
     use crate::common::model::DisTimeStamp;
-    use crate::common::model::{Pdu, PduHeader};
+    use crate::common::model::{Pdu, PduBody, PduHeader};
     use crate::common::parser::parse_pdu;
     use crate::enumerations::{
         CoupledExtensionIndicator, LvcIndicator, PduType, RadioAttachedIndicator,
-        TransferredEntityIndicator, TransmitterTransmitState, VariableRecordType,
+        TransferredEntityIndicator, TransmitterAntennaPatternType, TransmitterModulationTypeSystem,
+        TransmitterTransmitState, VariableRecordType,
     };
     use crate::model::{Location, VectorF32};
-    use crate::transmitter::model::{Transmitter, VariableTransmitterParameter};
+    use crate::transmitter::model::{
+        AntennaPattern, CcttSincgarsModulationParameters, HaveQuickNetId, HighFidelityHaveQuick,
+        JtidsMidsModulationParameters, ModulationParameters, ModulationType,
+        SphericalHarmonicAntennaPattern, Transmitter, VariableTransmitterParameter,
+        VariableTransmitterParameterFields,
+    };
     use crate::v7::model::PduStatus;
     use bytes::BytesMut;
 
@@ -70,7 +76,9 @@ fn transmitter_internal_consistency_v7() {
             .with_variable_transmitter_parameter(
                 VariableTransmitterParameter::default()
                     .with_record_type(VariableRecordType::Age_34100)
-                    .with_fields(vec![0xFF, 0xEE]),
+                    .with_fields(VariableTransmitterParameterFields::Unspecified(vec![
+                        0xFF, 0xEE,
+                    ])),
             )
             .build()
             .into_pdu_body();
@@ -92,4 +100,153 @@ fn transmitter_internal_consistency_v7() {
             }
         }
     }
+
+    #[test]
+    fn transmitter_typed_parameters_internal_consistency() {
+        let header = PduHeader::new_v7(1, PduType::Transmitter);
+
+        let sincgars = CcttSincgarsModulationParameters::new()
+            .with_fh_net_id(1234)
+            .with_hop_set_id(56)
+            .with_lockout_set_id(7)
+            .with_start_of_message(true)
+            .with_fh_synchronization_time_offset(89_000)
+            .with_transmission_security_key(0xABCD)
+            .to_modulation_parameters();
+        let have_quick = HighFidelityHaveQuick::new()
+            .with_net_id(HaveQuickNetId::new(250, 1, 2))
+            .with_tod_transmit_indicator(true)
+            .with_tod_delta(600)
+            .with_words_of_day([1, 2, 3, 4, 5, 6]);
+
+        let spherical_harmonic = SphericalHarmonicAntennaPattern::new()
+            .with_order(1)
+            .with_coefficients(vec![1.0, 0.5, 0.25, 0.125])
+            .to_antenna_pattern();
+
+        let body = Transmitter::builder()
+            .with_frequency(30_000_000)
+            .with_transmit_state(TransmitterTransmitState::OnButNotTransmitting)
+            .with_modulation_parameters(sincgars.clone())
+            .with_antenna_pattern_type(TransmitterAntennaPatternType::SphericalHarmonic)
+            .with_antenna_pattern(spherical_harmonic.clone())
+            .with_variable_transmitter_parameter(have_quick.to_variable_transmitter_parameter())
+            .build()
+            .into_pdu_body();
+        let original_pdu =
+            Pdu::finalize_from_parts(header, body, DisTimeStamp::new_absolute_from_secs(100));
+        let pdu_length = original_pdu.header.pdu_length;
+
+        let mut buf = BytesMut::with_capacity(pdu_length as usize);
+
+        let serialized_length = original_pdu.serialize(&mut buf).unwrap();
+        assert_eq!(original_pdu.pdu_length(), serialized_length);
+
+        let parsed = parse_pdu(&buf).unwrap();
+        assert_eq!(original_pdu, parsed);
+
+        if let PduBody::Transmitter(transmitter) = parsed.body {
+            assert_eq!(
+                transmitter.modulation_type.radio_system,
+                TransmitterModulationTypeSystem::CCTTSINCGARS
+            );
+            assert_eq!(transmitter.modulation_parameters, Some(sincgars));
+            assert_eq!(transmitter.antenna_pattern, Some(spherical_harmonic));
+            assert_eq!(
+                transmitter.variable_transmitter_parameters[0].fields,
+                VariableTransmitterParameterFields::HighFidelityHaveQuick(have_quick)
+            );
+        } else {
+            panic!("Expected a Transmitter PDU body");
+        }
+    }
+
+    #[test]
+    fn transmitter_parameters_mismatching_system_are_unspecified() {
+        let params = JtidsMidsModulationParameters::new()
+            .with_network_sync_id(42)
+            .to_modulation_parameters();
+        let sincgars =
+            ModulationType::new().with_radio_system(TransmitterModulationTypeSystem::CCTTSINCGARS);
+
+        assert_eq!(
+            ModulationParameters::from_bytes(&sincgars, &params.to_bytes()),
+            ModulationParameters::Unspecified(params.to_bytes())
+        );
+
+        let have_quick = HighFidelityHaveQuick::new()
+            .with_tod_delta(600)
+            .to_variable_transmitter_parameter();
+        assert_eq!(
+            VariableTransmitterParameterFields::from_bytes(
+                VariableRecordType::Age_34100,
+                &have_quick.fields.to_bytes()
+            ),
+            VariableTransmitterParameterFields::Unspecified(have_quick.fields.to_bytes())
+        );
+    }
+
+    #[test]
+    fn vtp_record_length_below_base_length() {
+        let header = PduHeader::new_v6(1, PduType::Transmitter);
+        let body = Transmitter::builder()
+            .with_variable_transmitter_parameter(
+                VariableTransmitterParameter::new()
+                    .with_record_type(VariableRecordType::Azimuth_52340)
+                    .with_fields(VariableTransmitterParameterFields::Unspecified(vec![
+                        0x01, 0x02,
+                    ])),
+            )
+            .build()
+            .into_pdu_body();
+        let pdu = Pdu::finalize_from_parts(header, body, DisTimeStamp::new_absolute_from_secs(100));
+        let mut buf = BytesMut::with_capacity(pdu.header.pdu_length as usize);
+        pdu.serialize(&mut buf).unwrap();
+
+        // The last record is a VTP record of 8 octets; set its record length to 2 octets.
+        let record_length_offset = buf.len() - 4;
+        buf[record_length_offset..record_length_offset + 2].copy_from_slice(&[0x00, 0x02]);
+        let parsed = parse_pdu(&buf).unwrap();
+
+        if let PduBody::Transmitter(transmitter) = parsed.body {
+            assert_eq!(
+                transmitter.variable_transmitter_parameters[0].fields,
+                VariableTransmitterParameterFields::Unspecified(vec![])
+            );
+        } else {
+            panic!("Expected a Transmitter PDU, got {:?}", parsed.body);
+        }
+    }
+
+    #[test]
+    fn spherical_harmonic_coefficients_follow_order() {
+        let pattern = SphericalHarmonicAntennaPattern::new()
+            .with_order(1)
+            .with_coefficients(vec![1.0, 0.5])
+            .to_antenna_pattern();
+        let bytes = pattern.to_bytes();
+        assert_eq!(bytes.len(), pattern.length());
+
+        let AntennaPattern::SphericalHarmonic(parsed) =
+            AntennaPattern::from_bytes(TransmitterAntennaPatternType::SphericalHarmonic, &bytes)
+        else {
+            panic!("Expected a Spherical Harmonic Antenna Pattern");
+        };
+        assert_eq!(parsed.order, 1);
+        assert_eq!(parsed.coefficients, vec![1.0, 0.5, 0.0, 0.0]);
+
+        let truncated = SphericalHarmonicAntennaPattern::new()
+            .with_coefficients(vec![1.0, 0.5, 0.25])
+            .to_antenna_pattern();
+        assert_eq!(truncated.to_bytes().len(), truncated.length());
+        assert_eq!(
+            AntennaPattern::from_bytes(
+                TransmitterAntennaPatternType::SphericalHarmonic,
+                &truncated.to_bytes()
+            ),
+            SphericalHarmonicAntennaPattern::new()
+                .with_coefficients(vec![1.0])
+                .to_antenna_pattern()
+        );
+    }
 }
@@ -1,8 +1,11 @@
 use crate::common::model::{
     length_padded_to_num, EntityId, EntityType, Location, Orientation, PduBody, VectorF32,
 };
-use crate::common::{BodyInfo, Interaction};
-use crate::constants::{EIGHT_OCTETS, ZERO_OCTETS};
+use crate::common::transmitter::parser::{
+    antenna_pattern, modulation_parameters, variable_transmitter_parameter_fields,
+};
+use crate::common::{BodyInfo, Interaction, Serialize as SerializeRecord};
+use crate::constants::{EIGHT_OCTETS, FOUR_OCTETS, ZERO_OCTETS};
 use crate::enumerations::{
     PduType, TransmitterAntennaPatternReferenceSystem, TransmitterAntennaPatternType,
     TransmitterCryptoSystem, TransmitterDetailAmplitudeAngleModulation,
@@ -13,12 +16,15 @@
     TransmitterModulationTypeSystem, TransmitterTransmitState, VariableRecordType,
 };
 use crate::transmitter::builder::TransmitterBuilder;
+use bytes::BytesMut;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 const BASE_TRANSMITTER_BODY_LENGTH: u16 = 92;
 pub const BEAM_ANTENNA_PATTERN_OCTETS: u16 = 40;
 pub const BASE_VTP_RECORD_LENGTH: u16 = 6;
+/// Length of the Order and Reference System fields of a Spherical Harmonic Antenna Pattern record.
+pub const SPHERICAL_HARMONIC_ANTENNA_PATTERN_BASE_OCTETS: usize = 2;
 
 /// 5.8.3 Transmitter PDU
 ///
@@ -40,8 +46,8 @@ pub struct Transmitter {
     pub modulation_type: ModulationType,
     pub crypto_system: TransmitterCryptoSystem,
     pub crypto_key_id: CryptoKeyId,
-    pub modulation_parameters: Option<Vec<u8>>,
-    pub antenna_pattern: Option<BeamAntennaPattern>,
+    pub modulation_parameters: Option<ModulationParameters>,
+    pub antenna_pattern: Option<AntennaPattern>,
     pub variable_transmitter_parameters: Vec<VariableTransmitterParameter>,
 }
 
@@ -68,17 +74,17 @@ fn body_length(&self) -> u16 {
             + self
                 .modulation_parameters
                 .as_ref()
-                .map_or(ZERO_OCTETS as u16, |params| params.len() as u16)
+                .map_or(ZERO_OCTETS as u16, |params| params.length() as u16)
             + self
                 .antenna_pattern
                 .as_ref()
-                .map_or(ZERO_OCTETS as u16, |_| BEAM_ANTENNA_PATTERN_OCTETS)
+                .map_or(ZERO_OCTETS as u16, |pattern| pattern.length() as u16)
             + self
                 .variable_transmitter_parameters
                 .iter()
                 .map(|vtp| {
                     length_padded_to_num(
-                        BASE_VTP_RECORD_LENGTH as usize + vtp.fields.len(),
+                        BASE_VTP_RECORD_LENGTH as usize + vtp.fields.length(),
                         EIGHT_OCTETS,
                     )
                     .record_length as u16
@@ -299,6 +305,45 @@ fn from(value: bool) -> Self {
     }
 }
 
+/// Typed representation of the Antenna Pattern field of the Transmitter PDU.
+///
+/// The record contained in the field is determined by the `antenna_pattern_type` of the Transmitter.
+/// Patterns of types that are not modelled, or that have an unexpected length, are kept as raw bytes.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum AntennaPattern {
+    Beam(BeamAntennaPattern),
+    SphericalHarmonic(SphericalHarmonicAntennaPattern),
+    Unspecified(Vec<u8>),
+}
+
+impl AntennaPattern {
+    /// Decodes the raw Antenna Pattern field of a Transmitter with the given `pattern_type`,
+    /// as the parser does.
+    #[must_use]
+    pub fn from_bytes(pattern_type: TransmitterAntennaPatternType, bytes: &[u8]) -> Self {
+        antenna_pattern(pattern_type, bytes)
+    }
+
+    /// Encodes the Antenna Pattern record into the raw bytes of the Antenna Pattern field.
+    #[must_use]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut buf = BytesMut::with_capacity(self.length());
+        self.serialize(&mut buf);
+        buf.to_vec()
+    }
+
+    #[must_use]
+    pub fn length(&self) -> usize {
+        match self {
+            AntennaPattern::Beam(_) => BEAM_ANTENNA_PATTERN_OCTETS as usize,
+            AntennaPattern::SphericalHarmonic(pattern) => pattern.length(),
+            AntennaPattern::Unspecified(bytes) => bytes.len(),
+        }
+    }
+}
+
+/// Beam Antenna Pattern record
 #[derive(Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct BeamAntennaPattern {
@@ -375,13 +420,95 @@ pub fn with_phase(mut self, phase: f32) -> Self {
         self.phase = phase;
         self
     }
+
+    #[must_use]
+    pub fn to_antenna_pattern(self) -> AntennaPattern {
+        AntennaPattern::Beam(self)
+    }
+}
+
+/// Spherical Harmonic Antenna Pattern record
+///
+/// A pattern of order N holds (N+1)^2 coefficients. The number of coefficients in the record follows from the order:
+/// missing coefficients are written as zero, and coefficients beyond that number are not written.
+/// The record is padded to a 64-bit boundary.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SphericalHarmonicAntennaPattern {
+    pub order: u8,
+    pub coefficients: Vec<f32>,
+    pub reference_system: TransmitterAntennaPatternReferenceSystem,
+}
+
+impl SphericalHarmonicAntennaPattern {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the order of the pattern, and resizes the coefficients to the (N+1)^2 coefficients of that order.
+    #[must_use]
+    pub fn with_order(mut self, order: u8) -> Self {
+        self.order = order;
+        self.coefficients
+            .resize(Self::number_of_coefficients(order), 0.0);
+        self
+    }
+
+    /// Sets the coefficients of the pattern. The number of coefficients should match the order of the pattern;
+    /// see `SphericalHarmonicAntennaPattern::coefficients_of_order`.
+    #[must_use]
+    pub fn with_coefficients(mut self, coefficients: Vec<f32>) -> Self {
+        self.coefficients = coefficients;
+        self
+    }
+
+    #[must_use]
+    pub fn with_reference_system(
+        mut self,
+        reference_system: TransmitterAntennaPatternReferenceSystem,
+    ) -> Self {
+        self.reference_system = reference_system;
+        self
+    }
+
+    #[must_use]
+    pub fn to_antenna_pattern(self) -> AntennaPattern {
+        AntennaPattern::SphericalHarmonic(self)
+    }
+
+    /// The number of coefficients of a pattern of the given order, (N+1)^2.
+    #[must_use]
+    pub fn number_of_coefficients(order: u8) -> usize {
+        (usize::from(order) + 1).pow(2)
+    }
+
+    /// The (N+1)^2 coefficients of the order of the pattern, padded with zeroes when the pattern holds fewer.
+    pub fn coefficients_of_order(&self) -> impl Iterator<Item = f32> + '_ {
+        self.coefficients
+            .iter()
+            .copied()
+            .chain(std::iter::repeat(0.0))
+            .take(Self::number_of_coefficients(self.order))
+    }
+
+    /// Length of the record in octets, including padding.
+    #[must_use]
+    pub fn length(&self) -> usize {
+        length_padded_to_num(
+            SPHERICAL_HARMONIC_ANTENNA_PATTERN_BASE_OCTETS
+                + Self::number_of_coefficients(self.order) * FOUR_OCTETS,
+            EIGHT_OCTETS,
+        )
+        .record_length
+    }
 }
 
 #[derive(Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct VariableTransmitterParameter {
     pub record_type: VariableRecordType,
-    pub fields: Vec<u8>,
+    pub fields: VariableTransmitterParameterFields,
 }
 
 impl Default for VariableTransmitterParameter {
@@ -395,7 +522,7 @@ impl VariableTransmitterParameter {
     pub fn new() -> Self {
         Self {
             record_type: VariableRecordType::default(),
-            fields: Vec::new(),
+            fields: VariableTransmitterParameterFields::Unspecified(Vec::new()),
         }
     }
 
@@ -406,12 +533,351 @@ pub fn with_record_type(mut self, record_type: VariableRecordType) -> Self {
     }
 
     #[must_use]
-    pub fn with_fields(mut self, fields: Vec<u8>) -> Self {
+    pub fn with_fields(mut self, fields: VariableTransmitterParameterFields) -> Self {
         self.fields = fields;
         self
     }
 }
 
+pub const CCTT_SINCGARS_MODULATION_PARAMETERS_OCTETS: usize = 16;
+pub const JTIDS_MIDS_MODULATION_PARAMETERS_OCTETS: usize = 8;
+pub const BASIC_HAVE_QUICK_MODULATION_PARAMETERS_OCTETS: usize = 8;
+
+/// Length of the record specific fields of a High Fidelity HAVE QUICK/SATURN VTP record, excluding the record type and length fields.
+pub const HIGH_FIDELITY_HAVE_QUICK_FIELDS_OCTETS: usize = 34;
+pub const NUMBER_OF_WORDS_OF_DAY: usize = 6;
+
+/// Typed representation of the Modulation Parameters field of the Transmitter PDU (6.2.59).
+///
+/// The record contained in the field is determined by the `ModulationType::radio_system` of the Transmitter.
+/// Parameters of radio systems that are not modelled, or that have an unexpected length, are kept as raw bytes.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ModulationParameters {
+    CcttSincgars(CcttSincgarsModulationParameters),
+    JtidsMids(JtidsMidsModulationParameters),
+    BasicHaveQuick(BasicHaveQuickModulationParameters),
+    Unspecified(Vec<u8>),
+}
+
+impl ModulationParameters {
+    /// Decodes the raw Modulation Parameters field of a Transmitter with the given `modulation_type`,
+    /// as the parser does.
+    #[must_use]
+    pub fn from_bytes(modulation_type: &ModulationType, bytes: &[u8]) -> Self {
+        modulation_parameters(modulation_type, bytes)
+    }
+
+    /// Encodes the Modulation Parameters record into the raw bytes of the Modulation Parameters field.
+    #[must_use]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut buf = BytesMut::with_capacity(self.length());
+        self.serialize(&mut buf);
+        buf.to_vec()
+    }
+
+    #[must_use]
+    pub fn length(&self) -> usize {
+        match self {
+            ModulationParameters::CcttSincgars(_) => CCTT_SINCGARS_MODULATION_PARAMETERS_OCTETS,
+            ModulationParameters::JtidsMids(_) => JTIDS_MIDS_MODULATION_PARAMETERS_OCTETS,
+            ModulationParameters::BasicHaveQuick(_) => {
+                BASIC_HAVE_QUICK_MODULATION_PARAMETERS_OCTETS
+            }
+            ModulationParameters::Unspecified(bytes) => bytes.len(),
+        }
+    }
+}
+
+/// 6.2.59.2 CCTT SINCGARS Modulation Parameters record
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CcttSincgarsModulationParameters {
+    pub fh_net_id: u16,
+    pub hop_set_id: u16,
+    pub lockout_set_id: u16,
+    pub start_of_message: bool,
+    pub fh_synchronization_time_offset: u32,
+    pub transmission_security_key: u16,
+    pub clear_channel: bool,
+}
+
+impl CcttSincgarsModulationParameters {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn with_fh_net_id(mut self, fh_net_id: u16) -> Self {
+        self.fh_net_id = fh_net_id;
+        self
+    }
+
+    #[must_use]
+    pub fn with_hop_set_id(mut self, hop_set_id: u16) -> Self {
+        self.hop_set_id = hop_set_id;
+        self
+    }
+
+    #[must_use]
+    pub fn with_lockout_set_id(mut self, lockout_set_id: u16) -> Self {
+        self.lockout_set_id = lockout_set_id;
+        self
+    }
+
+    #[must_use]
+    pub fn with_start_of_message(mut self, start_of_message: bool) -> Self {
+        self.start_of_message = start_of_message;
+        self
+    }
+
+    #[must_use]
+    pub fn with_fh_synchronization_time_offset(mut self, time_offset: u32) -> Self {
+        self.fh_synchronization_time_offset = time_offset;
+        self
+    }
+
+    #[must_use]
+    pub fn with_transmission_security_key(mut self, transmission_security_key: u16) -> Self {
+        self.transmission_security_key = transmission_security_key;
+        self
+    }
+
+    #[must_use]
+    pub fn with_clear_channel(mut self, clear_channel: bool) -> Self {
+        self.clear_channel = clear_channel;
+        self
+    }
+
+    #[must_use]
+    pub fn to_modulation_parameters(self) -> ModulationParameters {
+        ModulationParameters::CcttSincgars(self)
+    }
+}
+
+/// 6.2.59.3 JTIDS/MIDS Modulation Parameters record
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct JtidsMidsModulationParameters {
+    pub time_slot_allocation_mode: u8,
+    pub transmitting_terminal_primary_mode: u8,
+    pub transmitting_terminal_secondary_mode: u8,
+    pub synchronization_state: u8,
+    pub network_sync_id: u32,
+}
+
+impl JtidsMidsModulationParameters {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn with_time_slot_allocation_mode(mut self, time_slot_allocation_mode: u8) -> Self {
+        self.time_slot_allocation_mode = time_slot_allocation_mode;
+        self
+    }
+
+    #[must_use]
+    pub fn with_transmitting_terminal_primary_mode(mut self, primary_mode: u8) -> Self {
+        self.transmitting_terminal_primary_mode = primary_mode;
+        self
+    }
+
+    #[must_use]
+    pub fn with_transmitting_terminal_secondary_mode(mut self, secondary_mode: u8) -> Self {
+        self.transmitting_terminal_secondary_mode = secondary_mode;
+        self
+    }
+
+    #[must_use]
+    pub fn with_synchronization_state(mut self, synchronization_state: u8) -> Self {
+        self.synchronization_state = synchronization_state;
+        self
+    }
+
+    #[must_use]
+    pub fn with_network_sync_id(mut self, network_sync_id: u32) -> Self {
+        self.network_sync_id = network_sync_id;
+        self
+    }
+
+    #[must_use]
+    pub fn to_modulation_parameters(self) -> ModulationParameters {
+        ModulationParameters::JtidsMids(self)
+    }
+}
+
+/// 6.2.59.4 Basic HAVE QUICK Modulation Parameters record
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct BasicHaveQuickModulationParameters {
+    pub net_id: HaveQuickNetId,
+    pub mwod_index: u16,
+}
+
+impl BasicHaveQuickModulationParameters {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn with_net_id(mut self, net_id: HaveQuickNetId) -> Self {
+        self.net_id = net_id;
+        self
+    }
+
+    #[must_use]
+    pub fn with_mwod_index(mut self, mwod_index: u16) -> Self {
+        self.mwod_index = mwod_index;
+        self
+    }
+
+    #[must_use]
+    pub fn to_modulation_parameters(self) -> ModulationParameters {
+        ModulationParameters::BasicHaveQuick(self)
+    }
+}
+
+/// Net ID record of the HAVE QUICK radio systems.
+///
+/// Encoded in 16 bits: the Net Number in the 10 most significant bits,
+/// followed by the Frequency Table (2 bits), the Mode (2 bits) and 2 bits of padding.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct HaveQuickNetId {
+    pub net_number: u16,
+    pub frequency_table: u8,
+    pub mode: u8,
+}
+
+impl HaveQuickNetId {
+    #[must_use]
+    pub fn new(net_number: u16, frequency_table: u8, mode: u8) -> Self {
+        Self {
+            net_number,
+            frequency_table,
+            mode,
+        }
+    }
+}
+
+impl From<u16> for HaveQuickNetId {
+    fn from(value: u16) -> Self {
+        Self {
+            net_number: value >> 6,
+            frequency_table: ((value >> 4) & 0x03) as u8,
+            mode: ((value >> 2) & 0x03) as u8,
+        }
+    }
+}
+
+impl From<HaveQuickNetId> for u16 {
+    fn from(value: HaveQuickNetId) -> Self {
+        ((value.net_number & 0x03FF) << 6)
+            | ((u16::from(value.frequency_table) & 0x03) << 4)
+            | ((u16::from(value.mode) & 0x03) << 2)
+    }
+}
+
+/// Typed representation of the record specific fields of a `VariableTransmitterParameter`.
+///
+/// The record is selected by the `VariableTransmitterParameter::record_type`.
+/// Record types that are not modelled, or that have an unexpected length, are kept as raw bytes.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum VariableTransmitterParameterFields {
+    HighFidelityHaveQuick(HighFidelityHaveQuick),
+    Unspecified(Vec<u8>),
+}
+
+impl Default for VariableTransmitterParameterFields {
+    fn default() -> Self {
+        Self::Unspecified(Vec::new())
+    }
+}
+
+impl VariableTransmitterParameterFields {
+    /// Decodes the raw record specific fields of a VTP record of the given `record_type`, as the parser does.
+    #[must_use]
+    pub fn from_bytes(record_type: VariableRecordType, bytes: &[u8]) -> Self {
+        variable_transmitter_parameter_fields(record_type, bytes)
+    }
+
+    /// Encodes the record specific fields into raw bytes, excluding padding.
+    #[must_use]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut buf = BytesMut::with_capacity(self.length());
+        self.serialize(&mut buf);
+        buf.to_vec()
+    }
+
+    /// The length of the record specific fields in octets, excluding padding.
+    #[must_use]
+    pub fn length(&self) -> usize {
+        match self {
+            VariableTransmitterParameterFields::HighFidelityHaveQuick(_) => {
+                HIGH_FIDELITY_HAVE_QUICK_FIELDS_OCTETS
+            }
+            VariableTransmitterParameterFields::Unspecified(bytes) => bytes.len(),
+        }
+    }
+}
+
+/// High Fidelity HAVE QUICK/SATURN Transmitter Parameters record (VTP record type 3000)
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct HighFidelityHaveQuick {
+    pub net_id: HaveQuickNetId,
+    pub tod_transmit_indicator: bool,
+    pub tod_delta: u32,
+    pub words_of_day: [u32; NUMBER_OF_WORDS_OF_DAY],
+}
+
+impl HighFidelityHaveQuick {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn with_net_id(mut self, net_id: HaveQuickNetId) -> Self {
+        self.net_id = net_id;
+        self
+    }
+
+    #[must_use]
+    pub fn with_tod_transmit_indicator(mut self, tod_transmit_indicator: bool) -> Self {
+        self.tod_transmit_indicator = tod_transmit_indicator;
+        self
+    }
+
+    #[must_use]
+    pub fn with_tod_delta(mut self, tod_delta: u32) -> Self {
+        self.tod_delta = tod_delta;
+        self
+    }
+
+    #[must_use]
+    pub fn with_words_of_day(mut self, words_of_day: [u32; NUMBER_OF_WORDS_OF_DAY]) -> Self {
+        self.words_of_day = words_of_day;
+        self
+    }
+
+    #[must_use]
+    pub fn to_variable_transmitter_parameter(self) -> VariableTransmitterParameter {
+        VariableTransmitterParameter::new()
+            .with_record_type(
+                VariableRecordType::HighFidelityHAVEQUICKSATURNTransmitterParameters_3000,
+            )
+            .with_fields(VariableTransmitterParameterFields::HighFidelityHaveQuick(
+                self,
+            ))
+    }
+}
+
 impl TransmitterMajorModulation {
     #[must_use]
     pub fn new_from_bytes_with_detail(major_modulation: u16, detail: u16) -> Self {
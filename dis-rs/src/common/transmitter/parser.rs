@@ -1,8 +1,14 @@
 use crate::common::model::{PduBody, PduHeader};
 use crate::common::parser::{entity_id, entity_type, location, orientation, vec3_f32};
 use crate::common::transmitter::model::{
-    BeamAntennaPattern, CryptoKeyId, ModulationType, SpreadSpectrum, Transmitter,
-    VariableTransmitterParameter, BASE_VTP_RECORD_LENGTH,
+    AntennaPattern, BasicHaveQuickModulationParameters, BeamAntennaPattern,
+    CcttSincgarsModulationParameters, CryptoKeyId, HaveQuickNetId, HighFidelityHaveQuick,
+    JtidsMidsModulationParameters, ModulationParameters, ModulationType,
+    SphericalHarmonicAntennaPattern, SpreadSpectrum, Transmitter, VariableTransmitterParameter,
+    VariableTransmitterParameterFields, BASE_VTP_RECORD_LENGTH,
+    BASIC_HAVE_QUICK_MODULATION_PARAMETERS_OCTETS, BEAM_ANTENNA_PATTERN_OCTETS,
+    CCTT_SINCGARS_MODULATION_PARAMETERS_OCTETS, HIGH_FIDELITY_HAVE_QUICK_FIELDS_OCTETS,
+    JTIDS_MIDS_MODULATION_PARAMETERS_OCTETS, NUMBER_OF_WORDS_OF_DAY,
 };
 use crate::enumerations::{
     ProtocolVersion, TransmitterAntennaPatternReferenceSystem, TransmitterAntennaPatternType,
@@ -53,13 +59,13 @@ pub(crate) fn transmitter_body(
 
         let (input, modulation_parameters) = if length_of_modulation_parameters > 0 {
             let (input, params) = take(length_of_modulation_parameters)(input)?;
-            (input, Some(params))
+            (input, Some(modulation_parameters(&modulation_type, params)))
         } else {
             (input, None)
         };
         let (input, antenna_pattern) = if antenna_pattern_length > 0 {
-            let (input, pattern) = beam_antenna_pattern(input)?;
-            (input, Some(pattern))
+            let (input, pattern) = take(antenna_pattern_length)(input)?;
+            (input, Some(antenna_pattern(antenna_pattern_type, pattern)))
         } else {
             (input, None)
         };
@@ -89,7 +95,7 @@ pub(crate) fn transmitter_body(
             body
         };
         let body = if let Some(modulation_parameters) = modulation_parameters {
-            body.with_modulation_parameters(modulation_parameters.to_vec())
+            body.with_modulation_parameters(modulation_parameters)
         } else {
             body
         };
@@ -129,6 +135,33 @@ fn crypto_key_id(input: &[u8]) -> IResult<&[u8], CryptoKeyId> {
     Ok((input, CryptoKeyId::from(value)))
 }
 
+/// Parses the Antenna Pattern field, based on the antenna pattern type.
+///
+/// Patterns of types that are not modelled, or that do not have the length defined for the pattern,
+/// are kept as raw bytes.
+pub(crate) fn antenna_pattern(
+    pattern_type: TransmitterAntennaPatternType,
+    pattern: &[u8],
+) -> AntennaPattern {
+    let typed = match pattern_type {
+        TransmitterAntennaPatternType::Beam
+            if pattern.len() == BEAM_ANTENNA_PATTERN_OCTETS as usize =>
+        {
+            beam_antenna_pattern(pattern)
+                .ok()
+                .map(|(_, pattern)| AntennaPattern::Beam(pattern))
+        }
+        TransmitterAntennaPatternType::SphericalHarmonic => {
+            spherical_harmonic_antenna_pattern(pattern)
+                .ok()
+                .filter(|(_, typed)| typed.length() == pattern.len())
+                .map(|(_, typed)| AntennaPattern::SphericalHarmonic(typed))
+        }
+        _ => None,
+    };
+    typed.unwrap_or_else(|| AntennaPattern::Unspecified(pattern.to_vec()))
+}
+
 fn beam_antenna_pattern(input: &[u8]) -> IResult<&[u8], BeamAntennaPattern> {
     let (input, beam_direction) = orientation(input)?;
     let (input, azimuth_beamwidth) = be_f32(input)?;
@@ -155,17 +188,182 @@ fn beam_antenna_pattern(input: &[u8]) -> IResult<&[u8], BeamAntennaPattern> {
     ))
 }
 
-fn variable_transmitter_parameter(input: &[u8]) -> IResult<&[u8], VariableTransmitterParameter> {
+fn spherical_harmonic_antenna_pattern(
+    input: &[u8],
+) -> IResult<&[u8], SphericalHarmonicAntennaPattern> {
+    let (input, order) = be_u8(input)?;
+    let (input, coefficients) = count(
+        be_f32,
+        SphericalHarmonicAntennaPattern::number_of_coefficients(order),
+    )(input)?;
+    let (input, reference_system) = be_u8(input)?;
+    let reference_system = TransmitterAntennaPatternReferenceSystem::from(reference_system);
+
+    Ok((
+        input,
+        SphericalHarmonicAntennaPattern::new()
+            .with_order(order)
+            .with_coefficients(coefficients)
+            .with_reference_system(reference_system),
+    ))
+}
+
+pub(crate) fn variable_transmitter_parameter(
+    input: &[u8],
+) -> IResult<&[u8], VariableTransmitterParameter> {
     let (input, record_type) = be_u32(input)?;
     let record_type = VariableRecordType::from(record_type);
     let (input, record_length) = be_u16(input)?;
-    let fields_length_bytes = record_length - BASE_VTP_RECORD_LENGTH;
+    let fields_length_bytes = record_length.saturating_sub(BASE_VTP_RECORD_LENGTH);
     let (input, specific_fields) = take(fields_length_bytes)(input)?;
 
     Ok((
         input,
         VariableTransmitterParameter::new()
             .with_record_type(record_type)
-            .with_fields(specific_fields.to_vec()),
+            .with_fields(variable_transmitter_parameter_fields(
+                record_type,
+                specific_fields,
+            )),
     ))
 }
+
+/// Parses the Modulation Parameters field of a Transmitter PDU, based on the `ModulationType` of the Transmitter.
+///
+/// Parameters of radio systems that are not modelled, or that do not have the length defined for the record,
+/// are kept as raw bytes.
+pub(crate) fn modulation_parameters(
+    modulation_type: &ModulationType,
+    bytes: &[u8],
+) -> ModulationParameters {
+    let typed = match (modulation_type.radio_system, bytes.len()) {
+        (
+            TransmitterModulationTypeSystem::CCTTSINCGARS,
+            CCTT_SINCGARS_MODULATION_PARAMETERS_OCTETS,
+        ) => cctt_sincgars_modulation_parameters(bytes)
+            .ok()
+            .map(|(_, params)| ModulationParameters::CcttSincgars(params)),
+        (TransmitterModulationTypeSystem::JTIDSMIDS, JTIDS_MIDS_MODULATION_PARAMETERS_OCTETS) => {
+            jtids_mids_modulation_parameters(bytes)
+                .ok()
+                .map(|(_, params)| ModulationParameters::JtidsMids(params))
+        }
+        (
+            TransmitterModulationTypeSystem::HAVEQUICKI
+            | TransmitterModulationTypeSystem::HAVEQUICKII
+            | TransmitterModulationTypeSystem::SATURN,
+            BASIC_HAVE_QUICK_MODULATION_PARAMETERS_OCTETS,
+        ) => basic_have_quick_modulation_parameters(bytes)
+            .ok()
+            .map(|(_, params)| ModulationParameters::BasicHaveQuick(params)),
+        _ => None,
+    };
+    typed.unwrap_or_else(|| ModulationParameters::Unspecified(bytes.to_vec()))
+}
+
+/// Parses the record specific fields of a Variable Transmitter Parameter record, based on the record type.
+///
+/// Fields of record types that are not modelled, or that do not have the length defined for the record type,
+/// are kept as raw bytes.
+pub(crate) fn variable_transmitter_parameter_fields(
+    record_type: VariableRecordType,
+    fields: &[u8],
+) -> VariableTransmitterParameterFields {
+    let typed = match (record_type, fields.len()) {
+        (
+            VariableRecordType::HighFidelityHAVEQUICKSATURNTransmitterParameters_3000,
+            HIGH_FIDELITY_HAVE_QUICK_FIELDS_OCTETS,
+        ) => high_fidelity_have_quick(fields)
+            .ok()
+            .map(|(_, record)| VariableTransmitterParameterFields::HighFidelityHaveQuick(record)),
+        _ => None,
+    };
+    typed.unwrap_or_else(|| VariableTransmitterParameterFields::Unspecified(fields.to_vec()))
+}
+
+fn cctt_sincgars_modulation_parameters(
+    input: &[u8],
+) -> IResult<&[u8], CcttSincgarsModulationParameters> {
+    let (input, fh_net_id) = be_u16(input)?;
+    let (input, hop_set_id) = be_u16(input)?;
+    let (input, lockout_set_id) = be_u16(input)?;
+    let (input, start_of_message) = be_u8(input)?;
+    let (input, _reserved) = be_u8(input)?;
+    let (input, fh_synchronization_time_offset) = be_u32(input)?;
+    let (input, transmission_security_key) = be_u16(input)?;
+    let (input, clear_channel) = be_u8(input)?;
+    let (input, _padding) = be_u8(input)?;
+
+    Ok((
+        input,
+        CcttSincgarsModulationParameters::new()
+            .with_fh_net_id(fh_net_id)
+            .with_hop_set_id(hop_set_id)
+            .with_lockout_set_id(lockout_set_id)
+            .with_start_of_message(start_of_message != 0)
+            .with_fh_synchronization_time_offset(fh_synchronization_time_offset)
+            .with_transmission_security_key(transmission_security_key)
+            .with_clear_channel(clear_channel != 0),
+    ))
+}
+
+fn jtids_mids_modulation_parameters(input: &[u8]) -> IResult<&[u8], JtidsMidsModulationParameters> {
+    let (input, time_slot_allocation_mode) = be_u8(input)?;
+    let (input, primary_mode) = be_u8(input)?;
+    let (input, secondary_mode) = be_u8(input)?;
+    let (input, synchronization_state) = be_u8(input)?;
+    let (input, network_sync_id) = be_u32(input)?;
+
+    Ok((
+        input,
+        JtidsMidsModulationParameters::new()
+            .with_time_slot_allocation_mode(time_slot_allocation_mode)
+            .with_transmitting_terminal_primary_mode(primary_mode)
+            .with_transmitting_terminal_secondary_mode(secondary_mode)
+            .with_synchronization_state(synchronization_state)
+            .with_network_sync_id(network_sync_id),
+    ))
+}
+
+fn basic_have_quick_modulation_parameters(
+    input: &[u8],
+) -> IResult<&[u8], BasicHaveQuickModulationParameters> {
+    let (input, net_id) = have_quick_net_id(input)?;
+    let (input, mwod_index) = be_u16(input)?;
+    let (input, _reserved) = be_u16(input)?;
+    let (input, _reserved) = be_u8(input)?;
+    let (input, _padding) = be_u8(input)?;
+
+    Ok((
+        input,
+        BasicHaveQuickModulationParameters::new()
+            .with_net_id(net_id)
+            .with_mwod_index(mwod_index),
+    ))
+}
+
+fn high_fidelity_have_quick(input: &[u8]) -> IResult<&[u8], HighFidelityHaveQuick> {
+    let (input, _padding) = be_u16(input)?;
+    let (input, net_id) = have_quick_net_id(input)?;
+    let (input, tod_transmit_indicator) = be_u8(input)?;
+    let (input, _padding) = be_u8(input)?;
+    let (input, tod_delta) = be_u32(input)?;
+    let (input, words) = count(be_u32, NUMBER_OF_WORDS_OF_DAY)(input)?;
+    let mut words_of_day = [0u32; NUMBER_OF_WORDS_OF_DAY];
+    words_of_day.copy_from_slice(&words);
+
+    Ok((
+        input,
+        HighFidelityHaveQuick::new()
+            .with_net_id(net_id)
+            .with_tod_transmit_indicator(tod_transmit_indicator != 0)
+            .with_tod_delta(tod_delta)
+            .with_words_of_day(words_of_day),
+    ))
+}
+
+fn have_quick_net_id(input: &[u8]) -> IResult<&[u8], HaveQuickNetId> {
+    let (input, net_id) = be_u16(input)?;
+
+    Ok((input, HaveQuickNetId::from(net_id)))
+}
@@ -1,10 +1,16 @@
 use crate::common::model::length_padded_to_num;
 use crate::common::transmitter::model::{
-    BeamAntennaPattern, CryptoKeyId, CryptoMode, ModulationType, SpreadSpectrum, Transmitter,
-    VariableTransmitterParameter, BASE_VTP_RECORD_LENGTH, BEAM_ANTENNA_PATTERN_OCTETS,
+    AntennaPattern, BasicHaveQuickModulationParameters, BeamAntennaPattern,
+    CcttSincgarsModulationParameters, CryptoKeyId, CryptoMode, HighFidelityHaveQuick,
+    JtidsMidsModulationParameters, ModulationParameters, ModulationType,
+    SphericalHarmonicAntennaPattern, SpreadSpectrum, Transmitter, VariableTransmitterParameter,
+    VariableTransmitterParameterFields, BASE_VTP_RECORD_LENGTH,
+    BASIC_HAVE_QUICK_MODULATION_PARAMETERS_OCTETS, BEAM_ANTENNA_PATTERN_OCTETS,
+    CCTT_SINCGARS_MODULATION_PARAMETERS_OCTETS, HIGH_FIDELITY_HAVE_QUICK_FIELDS_OCTETS,
+    JTIDS_MIDS_MODULATION_PARAMETERS_OCTETS, SPHERICAL_HARMONIC_ANTENNA_PATTERN_BASE_OCTETS,
 };
 use crate::common::{Serialize, SerializePdu, SupportedVersion};
-use crate::constants::{EIGHT_OCTETS, ZERO_OCTETS};
+use crate::constants::{EIGHT_OCTETS, FOUR_OCTETS, ZERO_OCTETS};
 use bytes::{BufMut, BytesMut};
 
 impl SerializePdu for Transmitter {
@@ -18,8 +24,8 @@ fn serialize_pdu(&self, _version: SupportedVersion, buf: &mut BytesMut) -> u16 {
         self.antenna_location.serialize(buf);
         self.relative_antenna_location.serialize(buf);
         buf.put_u16(self.antenna_pattern_type.into());
-        if self.antenna_pattern.is_some() {
-            buf.put_u16(BEAM_ANTENNA_PATTERN_OCTETS);
+        if let Some(antenna_pattern) = &self.antenna_pattern {
+            buf.put_u16(antenna_pattern.length() as u16);
         } else {
             buf.put_u16(ZERO_OCTETS as u16);
         }
@@ -30,7 +36,7 @@ fn serialize_pdu(&self, _version: SupportedVersion, buf: &mut BytesMut) -> u16 {
         buf.put_u16(self.crypto_system.into());
         self.crypto_key_id.serialize(buf);
         if let Some(modulation_parameters) = &self.modulation_parameters {
-            buf.put_u8(modulation_parameters.len() as u8);
+            buf.put_u8(modulation_parameters.length() as u8);
         } else {
             buf.put_u8(ZERO_OCTETS as u8);
         }
@@ -39,20 +45,13 @@ fn serialize_pdu(&self, _version: SupportedVersion, buf: &mut BytesMut) -> u16 {
 
         let modulation_parameters_bytes =
             if let Some(modulation_parameters) = &self.modulation_parameters {
-                modulation_parameters
-                    .iter()
-                    .map(|field| {
-                        buf.put_u8(*field);
-                        1u16
-                    })
-                    .sum::<u16>()
+                modulation_parameters.serialize(buf)
             } else {
-                0u16
+                ZERO_OCTETS as u16
             };
 
         let antenna_pattern_bytes = if let Some(antenna_pattern) = &self.antenna_pattern {
-            antenna_pattern.serialize(buf);
-            BEAM_ANTENNA_PATTERN_OCTETS
+            antenna_pattern.serialize(buf)
         } else {
             ZERO_OCTETS as u16
         };
@@ -98,6 +97,19 @@ fn serialize(&self, buf: &mut BytesMut) -> u16 {
     }
 }
 
+impl Serialize for AntennaPattern {
+    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+        match self {
+            AntennaPattern::Beam(pattern) => pattern.serialize(buf),
+            AntennaPattern::SphericalHarmonic(pattern) => pattern.serialize(buf),
+            AntennaPattern::Unspecified(bytes) => {
+                buf.put(bytes.as_slice());
+                bytes.len() as u16
+            }
+        }
+    }
+}
+
 impl Serialize for BeamAntennaPattern {
     fn serialize(&self, buf: &mut BytesMut) -> u16 {
         self.beam_direction.serialize(buf);
@@ -115,19 +127,118 @@ fn serialize(&self, buf: &mut BytesMut) -> u16 {
     }
 }
 
+impl Serialize for SphericalHarmonicAntennaPattern {
+    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+        let padded_lengths = length_padded_to_num(
+            SPHERICAL_HARMONIC_ANTENNA_PATTERN_BASE_OCTETS
+                + SphericalHarmonicAntennaPattern::number_of_coefficients(self.order) * FOUR_OCTETS,
+            EIGHT_OCTETS,
+        );
+
+        buf.put_u8(self.order);
+        self.coefficients_of_order()
+            .for_each(|coefficient| buf.put_f32(coefficient));
+        buf.put_u8(self.reference_system.into());
+        buf.put_bytes(0u8, padded_lengths.padding_length);
+
+        padded_lengths.record_length as u16
+    }
+}
+
 impl Serialize for VariableTransmitterParameter {
     fn serialize(&self, buf: &mut BytesMut) -> u16 {
         let record_padded_lengths = length_padded_to_num(
-            BASE_VTP_RECORD_LENGTH as usize + self.fields.len(),
+            BASE_VTP_RECORD_LENGTH as usize + self.fields.length(),
             EIGHT_OCTETS,
         );
         let record_length_bytes = record_padded_lengths.record_length as u16;
 
         buf.put_u32(self.record_type.into());
         buf.put_u16(record_length_bytes);
-        buf.put(&*self.fields);
+        self.fields.serialize(buf);
         buf.put_bytes(0u8, record_padded_lengths.padding_length);
 
         record_length_bytes
     }
 }
+
+impl Serialize for ModulationParameters {
+    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+        match self {
+            ModulationParameters::CcttSincgars(params) => params.serialize(buf),
+            ModulationParameters::JtidsMids(params) => params.serialize(buf),
+            ModulationParameters::BasicHaveQuick(params) => params.serialize(buf),
+            ModulationParameters::Unspecified(bytes) => {
+                buf.put(bytes.as_slice());
+                bytes.len() as u16
+            }
+        }
+    }
+}
+
+impl Serialize for CcttSincgarsModulationParameters {
+    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+        buf.put_u16(self.fh_net_id);
+        buf.put_u16(self.hop_set_id);
+        buf.put_u16(self.lockout_set_id);
+        buf.put_u8(u8::from(self.start_of_message));
+        buf.put_u8(0u8);
+        buf.put_u32(self.fh_synchronization_time_offset);
+        buf.put_u16(self.transmission_security_key);
+        buf.put_u8(u8::from(self.clear_channel));
+        buf.put_u8(0u8);
+
+        CCTT_SINCGARS_MODULATION_PARAMETERS_OCTETS as u16
+    }
+}
+
+impl Serialize for JtidsMidsModulationParameters {
+    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+        buf.put_u8(self.time_slot_allocation_mode);
+        buf.put_u8(self.transmitting_terminal_primary_mode);
+        buf.put_u8(self.transmitting_terminal_secondary_mode);
+        buf.put_u8(self.synchronization_state);
+        buf.put_u32(self.network_sync_id);
+
+        JTIDS_MIDS_MODULATION_PARAMETERS_OCTETS as u16
+    }
+}
+
+impl Serialize for BasicHaveQuickModulationParameters {
+    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+        buf.put_u16(self.net_id.into());
+        buf.put_u16(self.mwod_index);
+        buf.put_u16(0u16);
+        buf.put_u8(0u8);
+        buf.put_u8(0u8);
+
+        BASIC_HAVE_QUICK_MODULATION_PARAMETERS_OCTETS as u16
+    }
+}
+
+impl Serialize for VariableTransmitterParameterFields {
+    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+        match self {
+            VariableTransmitterParameterFields::HighFidelityHaveQuick(record) => {
+                record.serialize(buf)
+            }
+            VariableTransmitterParameterFields::Unspecified(bytes) => {
+                buf.put(bytes.as_slice());
+                bytes.len() as u16
+            }
+        }
+    }
+}
+
+impl Serialize for HighFidelityHaveQuick {
+    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+        buf.put_u16(0u16);
+        buf.put_u16(self.net_id.into());
+        buf.put_u8(u8::from(self.tod_transmit_indicator));
+        buf.put_u8(0u8);
+        buf.put_u32(self.tod_delta);
+        self.words_of_day.iter().for_each(|word| buf.put_u32(*word));
+
+        HIGH_FIDELITY_HAVE_QUICK_FIELDS_OCTETS as u16
+    }
+}
@@ -8,9 +8,10 @@ mod tests {
     use crate::common::model::DisTimeStamp;
     use crate::common::model::{Pdu, PduHeader};
     use crate::common::parser::parse_pdu;
+    use crate::common::Serialize;
     use crate::enumerations::{
-        PduType, UAPassiveParameterIndex, UAPropulsionPlantConfiguration,
-        UAStateChangeUpdateIndicator,
+        APAStatus, PduType, UAAdditionalPassiveActivityParameterIndex, UAPassiveParameterIndex,
+        UAPropulsionPlantConfiguration, UAStateChangeUpdateIndicator,
     };
     use crate::model::{EntityId, EventId};
     use crate::underwater_acoustic::model::{
@@ -75,4 +76,17 @@ fn underwater_acoustic_internal_consistency() {
             }
         }
     }
+
+    #[test]
+    fn serialize_apa_combines_parameter_index_and_status() {
+        let apa = APA::default()
+            .with_parameter(UAAdditionalPassiveActivityParameterIndex::from(5))
+            .with_status(APAStatus::from(1))
+            .with_value(-2);
+        let mut buf = BytesMut::with_capacity(4);
+
+        apa.serialize(&mut buf);
+
+        assert_eq!(buf.as_ref(), [0x00, 0x15, 0xff, 0xfe].as_ref());
+    }
 }
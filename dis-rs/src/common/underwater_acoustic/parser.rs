@@ -95,7 +95,7 @@ fn apa(input: &[u8]) -> IResult<&[u8], APA> {
     ))
 }
 
-fn ua_emitter_system(input: &[u8]) -> IResult<&[u8], UAEmitterSystem> {
+pub(crate) fn ua_emitter_system(input: &[u8]) -> IResult<&[u8], UAEmitterSystem> {
     let (input, _data_length) = be_u8(input)?;
     let (input, number_of_beams) = be_u8(input)?;
     let (input, _padding) = be_u16(input)?;
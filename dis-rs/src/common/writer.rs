@@ -253,7 +253,7 @@ fn serialize(&self, buf: &mut BytesMut) -> u16 {
         let padded_record_bits = length_padded_to_num(data_length_bits, SIXTY_FOUR_BITS);
         let record_length_bits = padded_record_bits.record_length as u16;
         let record_length_bytes = record_length_bits / 8;
-        let padding_length_bytes = padded_record_bits.padding_length * 8;
+        let padding_length_bytes = padded_record_bits.padding_length / 8;
 
         buf.put_u32(self.datum_id.into());
         buf.put_u32(data_length_bits as u32);
@@ -420,10 +420,10 @@ fn serialize(&self, buf: &mut BytesMut) -> u16 {
 
 #[cfg(test)]
 mod tests {
-    use crate::common::model::PduHeader;
+    use crate::common::model::{PduHeader, VariableDatum};
     use crate::common::Serialize;
     use crate::constants::PDU_HEADER_LEN_BYTES;
-    use crate::enumerations::{LvcIndicator, PduType};
+    use crate::enumerations::{LvcIndicator, PduType, VariableRecordType};
     use crate::v7::model::PduStatus;
     use bytes::BytesMut;
 
@@ -472,4 +472,19 @@ fn serialize_header_v7_with_status() {
         ];
         assert_eq!(buf.as_ref(), expected.as_ref());
     }
+
+    #[test]
+    fn serialize_variable_datum_padded_to_64_bits() {
+        let datum = VariableDatum::new(VariableRecordType::Azimuth_52340, vec![0x01, 0x02, 0x03]);
+        let mut buf = BytesMut::with_capacity(16);
+
+        let written = datum.serialize(&mut buf);
+
+        let expected: [u8; 16] = [
+            0x00, 0x00, 0xcc, 0x74, 0x00, 0x00, 0x00, 0x18, 0x01, 0x02, 0x03, 0x00, 0x00, 0x00,
+            0x00, 0x00,
+        ];
+        assert_eq!(written, 16);
+        assert_eq!(buf.as_ref(), expected.as_ref());
+    }
 }
@@ -0,0 +1,184 @@
+use crate::common::acknowledge::model::Acknowledge;
+use crate::common::acknowledge_r::model::AcknowledgeR;
+use crate::common::action_request::model::ActionRequest;
+use crate::common::action_request_r::model::ActionRequestR;
+use crate::common::action_response::model::ActionResponse;
+use crate::common::action_response_r::model::ActionResponseR;
+use crate::common::aggregate_state::model::AggregateState;
+use crate::common::attribute::model::{
+    AngleDeception, Attribute, AttributeRecord, AttributeRecordFields, AttributeRecordSet,
+    BlankingSector, CommunicationsNodeId, EntityDestination, FalseTargets, GroupAssignment,
+    GroupDestination,
+};
+use crate::common::collision::model::Collision;
+use crate::common::collision_elastic::model::CollisionElastic;
+use crate::common::comment::model::Comment;
+use crate::common::comment_r::model::CommentR;
+use crate::common::create_entity::model::CreateEntity;
+use crate::common::create_entity_r::model::CreateEntityR;
+use crate::common::data::model::Data;
+use crate::common::data_query::model::DataQuery;
+use crate::common::data_query_r::model::DataQueryR;
+use crate::common::data_r::model::DataR;
+use crate::common::designator::model::Designator;
+use crate::common::detonation::model::Detonation;
+use crate::common::electromagnetic_emission::model::{
+    Beam, ElectromagneticEmission, EmitterSystem, FundamentalParameterData, JammingTechnique,
+    TrackJam,
+};
+use crate::common::entity_state::model::{
+    DrEulerAngles, DrOtherParameters, DrParameters, DrWorldOrientationQuaternion, EntityAppearance,
+    EntityMarking, EntityState,
+};
+use crate::common::entity_state_update::model::EntityStateUpdate;
+use crate::common::event_report::model::EventReport;
+use crate::common::event_report_r::model::EventReportR;
+use crate::common::fields::{with_body_variants, with_model_fields, with_record_variants};
+use crate::common::fire::model::Fire;
+use crate::common::iff::model::{
+    ChangeOptionsRecord, FundamentalOperationalData, Iff, IffDataRecord, IffDataSpecification,
+    IffFundamentalParameterData, IffLayer2, IffLayer3, IffLayer4, IffLayer5, InformationLayers,
+    LayerHeader, MarkXXIISystemSpecificData, ModeSSystemSpecificData, RrbSystemSpecificData,
+    SovietSystemSpecificData, SystemId, SystemSpecificData, SystemStatus,
+};
+use crate::common::is_group_of::model::IsGroupOf;
+use crate::common::is_part_of::model::IsPartOf;
+use crate::common::model::{
+    ArticulatedPart, AttachedPart, BeamData, ClockTime, DescriptorRecord, EntityId, EntityType,
+    EventId, FixedDatum, Location, MunitionDescriptor, Orientation, PduBody, SimulationAddress,
+    VariableDatum, VariableParameter, VectorF32,
+};
+use crate::common::other::model::Other;
+use crate::common::receiver::model::Receiver;
+use crate::common::record_query_r::model::RecordQueryR;
+use crate::common::record_r::model::RecordR;
+use crate::common::remove_entity::model::RemoveEntity;
+use crate::common::remove_entity_r::model::RemoveEntityR;
+use crate::common::repair_complete::model::RepairComplete;
+use crate::common::repair_response::model::RepairResponse;
+use crate::common::resupply_cancel::model::ResupplyCancel;
+use crate::common::resupply_offer::model::ResupplyOffer;
+use crate::common::resupply_received::model::ResupplyReceived;
+use crate::common::sees::model::SEES;
+use crate::common::service_request::model::ServiceRequest;
+use crate::common::set_data::model::SetData;
+use crate::common::set_data_r::model::SetDataR;
+use crate::common::set_record_r::model::SetRecordR;
+use crate::common::signal::model::Signal;
+use crate::common::start_resume::model::StartResume;
+use crate::common::start_resume_r::model::StartResumeR;
+use crate::common::stop_freeze::model::StopFreeze;
+use crate::common::stop_freeze_r::model::StopFreezeR;
+use crate::common::transfer_ownership::model::TransferOwnership;
+use crate::common::transmitter::model::{
+    AntennaPattern, BasicHaveQuickModulationParameters, BeamAntennaPattern,
+    CcttSincgarsModulationParameters, CryptoKeyId, HaveQuickNetId, HighFidelityHaveQuick,
+    JtidsMidsModulationParameters, ModulationParameters, ModulationType,
+    SphericalHarmonicAntennaPattern, SpreadSpectrum, Transmitter, VariableTransmitterParameter,
+    VariableTransmitterParameterFields,
+};
+use crate::common::underwater_acoustic::model::{
+    AcousticEmitterSystem, UABeam, UAEmitterSystem, UAFundamentalParameterData, UnderwaterAcoustic,
+};
+use crate::diff::field::{
+    field_path, impl_diff, impl_diff_variants, leaf, leaves, list, nested, Diff, FieldChange,
+};
+#[allow(clippy::wildcard_imports)] // the generated bitfield records
+use crate::enumerations::*;
+use crate::with_bitfield_fields;
+
+with_model_fields!(impl_diff);
+with_bitfield_fields!(impl_diff);
+
+impl Diff for VariableParameter {
+    fn diff_into(&self, other: &Self, path: &str, changes: &mut Vec<FieldChange>) {
+        match (self, other) {
+            (VariableParameter::Articulated(old), VariableParameter::Articulated(new)) => {
+                old.diff_into(new, path, changes);
+            }
+            (VariableParameter::Attached(old), VariableParameter::Attached(new)) => {
+                old.diff_into(new, path, changes);
+            }
+            _ => leaf(path, self, other, changes),
+        }
+    }
+}
+
+/// The fields of descriptors of the same kind are compared field by field.
+impl Diff for DescriptorRecord {
+    fn diff_into(&self, other: &Self, path: &str, changes: &mut Vec<FieldChange>) {
+        match (self, other) {
+            (
+                DescriptorRecord::Munition {
+                    entity_type: old_type,
+                    munition: old_munition,
+                },
+                DescriptorRecord::Munition {
+                    entity_type: new_type,
+                    munition: new_munition,
+                },
+            ) => {
+                nested(
+                    &field_path(path, "entity_type"),
+                    old_type,
+                    new_type,
+                    changes,
+                );
+                nested(
+                    &field_path(path, "munition"),
+                    old_munition,
+                    new_munition,
+                    changes,
+                );
+            }
+            (
+                DescriptorRecord::Expendable {
+                    entity_type: old_type,
+                },
+                DescriptorRecord::Expendable {
+                    entity_type: new_type,
+                },
+            ) => nested(
+                &field_path(path, "entity_type"),
+                old_type,
+                new_type,
+                changes,
+            ),
+            (
+                DescriptorRecord::Explosion {
+                    entity_type: old_type,
+                    explosive_material: old_material,
+                    explosive_force: old_force,
+                },
+                DescriptorRecord::Explosion {
+                    entity_type: new_type,
+                    explosive_material: new_material,
+                    explosive_force: new_force,
+                },
+            ) => {
+                nested(
+                    &field_path(path, "entity_type"),
+                    old_type,
+                    new_type,
+                    changes,
+                );
+                leaf(
+                    &field_path(path, "explosive_material"),
+                    old_material,
+                    new_material,
+                    changes,
+                );
+                leaf(
+                    &field_path(path, "explosive_force"),
+                    old_force,
+                    new_force,
+                    changes,
+                );
+            }
+            _ => leaf(path, self, other, changes),
+        }
+    }
+}
+
+with_record_variants!(impl_diff_variants);
+with_body_variants!(impl_diff_variants);
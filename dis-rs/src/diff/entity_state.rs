@@ -0,0 +1,137 @@
+use crate::common::entity_state::model::EntityState;
+use crate::common::entity_state_update::model::EntityStateUpdate;
+use crate::common::model::VariableParameter;
+use crate::diff::field::Diff;
+use crate::DisError;
+
+/// The fields of an `EntityState` that an `EntityStateUpdate` carries.
+const UPDATE_FIELDS: [&str; 5] = [
+    "entity_linear_velocity",
+    "entity_location",
+    "entity_orientation",
+    "entity_appearance",
+    "variable_parameters",
+];
+
+/// What a simulation has to send to communicate the change between two states of an entity.
+#[derive(Clone, Debug, PartialEq)]
+pub enum EntityStateChange {
+    /// Both states are the same.
+    Unchanged,
+    /// The change can be sent as an Entity State Update PDU.
+    Update(EntityStateUpdate),
+    /// Fields changed that an Entity State Update PDU does not carry, so a full Entity State PDU is needed.
+    FullState,
+}
+
+impl EntityStateUpdate {
+    /// Determines the minimal update from the `previous` to the `current` state of an entity.
+    ///
+    /// The update contains only the variable parameters that changed or were added. A full Entity State is needed
+    /// when other fields than the location, velocity, orientation, appearance or variable parameters changed,
+    /// or when a variable parameter was removed.
+    #[must_use]
+    pub fn from_changes(previous: &EntityState, current: &EntityState) -> EntityStateChange {
+        let changes = previous.diff(current);
+        if changes.is_empty() {
+            return EntityStateChange::Unchanged;
+        }
+
+        let only_update_fields = changes.iter().all(|change| {
+            let field = change
+                .path
+                .split(|c| c == '.' || c == '[')
+                .next()
+                .unwrap_or_default();
+            UPDATE_FIELDS.contains(&field)
+        });
+        let parameters_removed = previous.variable_parameters.iter().any(|old| {
+            !current
+                .variable_parameters
+                .iter()
+                .any(|new| same_part(old, new))
+        });
+        if !only_update_fields || parameters_removed {
+            return EntityStateChange::FullState;
+        }
+
+        let variable_parameters = current
+            .variable_parameters
+            .iter()
+            .filter(|new| {
+                !previous
+                    .variable_parameters
+                    .iter()
+                    .any(|old| same_part(old, new) && old == *new)
+            })
+            .cloned()
+            .collect();
+
+        EntityStateChange::Update(EntityStateUpdate {
+            entity_id: current.entity_id,
+            entity_linear_velocity: current.entity_linear_velocity,
+            entity_location: current.entity_location,
+            entity_orientation: current.entity_orientation,
+            entity_appearance: current.entity_appearance,
+            variable_parameters,
+        })
+    }
+}
+
+impl EntityState {
+    /// Applies an `EntityStateUpdate` onto the stored state of the entity.
+    ///
+    /// Variable parameters in the update replace the parameters describing the same part, and are added otherwise.
+    ///
+    /// # Errors
+    /// Returns `DisError::EntityIdMismatch` when the update is about another entity.
+    pub fn apply_update(&mut self, update: &EntityStateUpdate) -> Result<(), DisError> {
+        if update.entity_id != self.entity_id {
+            return Err(DisError::EntityIdMismatch(update.entity_id, self.entity_id));
+        }
+
+        self.entity_linear_velocity = update.entity_linear_velocity;
+        self.entity_location = update.entity_location;
+        self.entity_orientation = update.entity_orientation;
+        self.entity_appearance = update.entity_appearance;
+        for new in &update.variable_parameters {
+            if let Some(old) = self
+                .variable_parameters
+                .iter_mut()
+                .find(|old| same_part(old, new))
+            {
+                *old = new.clone();
+            } else {
+                self.variable_parameters.push(new.clone());
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Whether both variable parameters describe the same part of the entity.
+fn same_part(a: &VariableParameter, b: &VariableParameter) -> bool {
+    match (a, b) {
+        (VariableParameter::Articulated(a), VariableParameter::Articulated(b)) => {
+            a.attachment_id == b.attachment_id
+                && a.type_class == b.type_class
+                && a.type_metric == b.type_metric
+        }
+        (VariableParameter::Attached(a), VariableParameter::Attached(b)) => {
+            a.attachment_id == b.attachment_id && a.parameter_type == b.parameter_type
+        }
+        (VariableParameter::Separation(a), VariableParameter::Separation(b)) => {
+            a.parent_entity_id == b.parent_entity_id
+                && a.station_name == b.station_name
+                && a.station_number == b.station_number
+        }
+        (VariableParameter::EntityType(a), VariableParameter::EntityType(b)) => {
+            a.entity_type == b.entity_type
+        }
+        (VariableParameter::EntityAssociation(a), VariableParameter::EntityAssociation(b)) => {
+            a.entity_id == b.entity_id && a.own_station_location == b.own_station_location
+        }
+        (VariableParameter::Unspecified(a, _), VariableParameter::Unspecified(b, _)) => a == b,
+        _ => false,
+    }
+}
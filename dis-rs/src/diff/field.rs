@@ -0,0 +1,158 @@
+use std::fmt::Debug;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A field that differs between two values of the same type.
+///
+/// The `path` names the field in the model, e.g. `entity_appearance` or `variable_parameters[2].parameter_value`.
+/// The values are formatted using their `Debug` representation. A value is `None` when the field does not exist
+/// on that side, which happens for elements that were added to or removed from a list.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct FieldChange {
+    pub path: String,
+    pub old: Option<String>,
+    pub new: Option<String>,
+}
+
+/// Field-level comparison of two values of the same type.
+pub trait Diff {
+    /// Appends the changes between `self` and `other` to `changes`, prefixing the field paths with `path`.
+    fn diff_into(&self, other: &Self, path: &str, changes: &mut Vec<FieldChange>);
+
+    /// The fields that changed from `self` (old) to `other` (new).
+    #[must_use]
+    fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        self.diff_into(other, "", &mut changes);
+        changes
+    }
+}
+
+/// Optional values are compared field by field when present on both sides, and as a whole otherwise.
+impl<T: Diff + PartialEq + Debug> Diff for Option<T> {
+    fn diff_into(&self, other: &Self, path: &str, changes: &mut Vec<FieldChange>) {
+        match (self, other) {
+            (Some(old), Some(new)) => old.diff_into(new, path, changes),
+            _ => leaf(path, self, other, changes),
+        }
+    }
+}
+
+pub(crate) fn field_path(path: &str, field: &str) -> String {
+    if path.is_empty() {
+        field.to_string()
+    } else {
+        format!("{path}.{field}")
+    }
+}
+
+fn element_path(path: &str, index: usize) -> String {
+    format!("{path}[{index}]")
+}
+
+/// Compares a value as a whole.
+pub(crate) fn leaf<T: PartialEq + Debug>(
+    path: &str,
+    old: &T,
+    new: &T,
+    changes: &mut Vec<FieldChange>,
+) {
+    if old != new {
+        changes.push(FieldChange {
+            path: path.to_string(),
+            old: Some(format!("{old:?}")),
+            new: Some(format!("{new:?}")),
+        });
+    }
+}
+
+/// Compares a value field by field.
+pub(crate) fn nested<T: Diff>(path: &str, old: &T, new: &T, changes: &mut Vec<FieldChange>) {
+    old.diff_into(new, path, changes);
+}
+
+/// Compares a list element by element, comparing the elements field by field.
+pub(crate) fn list<T: Diff + Debug>(
+    path: &str,
+    old: &[T],
+    new: &[T],
+    changes: &mut Vec<FieldChange>,
+) {
+    for (index, (old, new)) in old.iter().zip(new).enumerate() {
+        old.diff_into(new, &element_path(path, index), changes);
+    }
+    added_and_removed(path, old, new, changes);
+}
+
+/// Compares a list element by element, comparing the elements as a whole.
+pub(crate) fn leaves<T: PartialEq + Debug>(
+    path: &str,
+    old: &[T],
+    new: &[T],
+    changes: &mut Vec<FieldChange>,
+) {
+    for (index, (old, new)) in old.iter().zip(new).enumerate() {
+        leaf(&element_path(path, index), old, new, changes);
+    }
+    added_and_removed(path, old, new, changes);
+}
+
+fn added_and_removed<T: Debug>(path: &str, old: &[T], new: &[T], changes: &mut Vec<FieldChange>) {
+    let common = old.len().min(new.len());
+    changes.extend(
+        old.iter()
+            .enumerate()
+            .skip(common)
+            .map(|(index, old)| FieldChange {
+                path: element_path(path, index),
+                old: Some(format!("{old:?}")),
+                new: None,
+            }),
+    );
+    changes.extend(
+        new.iter()
+            .enumerate()
+            .skip(common)
+            .map(|(index, new)| FieldChange {
+                path: element_path(path, index),
+                old: None,
+                new: Some(format!("{new:?}")),
+            }),
+    );
+}
+
+/// Implements `Diff` for structs, comparing each listed field with the named strategy
+/// (`leaf`, `nested`, `list` or `leaves`).
+macro_rules! impl_diff {
+    ($($ty:ident { $($field:ident: $kind:ident),* $(,)? })*) => {
+        $(
+            impl Diff for $ty {
+                fn diff_into(&self, other: &Self, path: &str, changes: &mut Vec<FieldChange>) {
+                    $( $kind(&field_path(path, stringify!($field)), &self.$field, &other.$field, changes); )*
+                }
+            }
+        )*
+    };
+}
+
+/// Implements `Diff` for enums with single-value variants, comparing the values field by field
+/// when both sides are the same variant, and as a whole otherwise.
+macro_rules! impl_diff_variants {
+    ($($ty:ident { $($variant:ident => $name:ident),* $(,)? })*) => {
+        $(
+            impl Diff for $ty {
+                fn diff_into(&self, other: &Self, path: &str, changes: &mut Vec<FieldChange>) {
+                    match (self, other) {
+                        $( ($ty::$variant(old), $ty::$variant(new)) => old.diff_into(new, path, changes), )*
+                        _ => leaf(path, self, other, changes),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+pub(crate) use impl_diff;
+pub(crate) use impl_diff_variants;
@@ -0,0 +1,191 @@
+mod bodies;
+pub mod entity_state;
+pub mod field;
+
+#[cfg(test)]
+mod tests {
+    use crate::diff::entity_state::EntityStateChange;
+    use crate::diff::field::{Diff, FieldChange};
+    use crate::entity_state::model::{EntityMarking, EntityState};
+    use crate::entity_state_update::model::EntityStateUpdate;
+    use crate::enumerations::{ArticulatedPartsTypeClass, ArticulatedPartsTypeMetric};
+    use crate::fire::model::Fire;
+    use crate::model::{
+        ArticulatedPart, EntityAssociationParameter, EntityId, EntityType, Location,
+        MunitionDescriptor, PduBody, SeparationParameter, VariableParameter,
+    };
+    use crate::DisError;
+
+    fn turret(class: ArticulatedPartsTypeClass, azimuth: f32) -> VariableParameter {
+        ArticulatedPart::default()
+            .with_type_class(class)
+            .with_type_metric(ArticulatedPartsTypeMetric::Azimuth)
+            .with_parameter_value(azimuth)
+            .to_variable_parameter()
+    }
+
+    fn tank(x: f64, azimuth: f32) -> EntityState {
+        EntityState::builder()
+            .with_entity_id(EntityId::new(1, 1, 1))
+            .with_location(Location::new(x, 2.0, 3.0))
+            .with_variable_parameters(vec![
+                turret(ArticulatedPartsTypeClass::PrimaryTurretNumber1, 0.0),
+                turret(ArticulatedPartsTypeClass::PrimaryGunNumber1, azimuth),
+            ])
+            .build()
+    }
+
+    #[test]
+    fn diff_field_paths() {
+        let previous = tank(1.0, 0.0);
+        let current = tank(5.0, 0.5);
+
+        let changes = previous.diff(&current);
+        assert_eq!(
+            changes,
+            vec![
+                FieldChange {
+                    path: "entity_location.x_coordinate".to_string(),
+                    old: Some("1.0".to_string()),
+                    new: Some("5.0".to_string()),
+                },
+                FieldChange {
+                    path: "variable_parameters[1].parameter_value".to_string(),
+                    old: Some("0.0".to_string()),
+                    new: Some("0.5".to_string()),
+                },
+            ]
+        );
+        assert!(previous.diff(&previous).is_empty());
+
+        let body = previous.clone().into_pdu_body();
+        assert_eq!(body.diff(&current.into_pdu_body()).len(), 2);
+        assert_eq!(body.diff(&PduBody::IntercomSignal).len(), 1);
+    }
+
+    #[test]
+    fn entity_state_update_from_changes() {
+        let previous = tank(1.0, 0.0);
+        assert_eq!(
+            EntityStateUpdate::from_changes(&previous, &previous),
+            EntityStateChange::Unchanged
+        );
+
+        let current = tank(5.0, 0.5);
+        let EntityStateChange::Update(update) =
+            EntityStateUpdate::from_changes(&previous, &current)
+        else {
+            panic!("expected an update");
+        };
+        assert_eq!(update.entity_location, current.entity_location);
+        assert_eq!(
+            update.variable_parameters,
+            vec![current.variable_parameters[1].clone()]
+        );
+
+        let mut renamed = current.clone();
+        renamed.entity_marking = EntityMarking::new_ascii("TANK");
+        assert_eq!(
+            EntityStateUpdate::from_changes(&previous, &renamed),
+            EntityStateChange::FullState
+        );
+        let mut removed = current;
+        removed.variable_parameters.pop();
+        assert_eq!(
+            EntityStateUpdate::from_changes(&previous, &removed),
+            EntityStateChange::FullState
+        );
+    }
+
+    #[test]
+    fn apply_entity_state_update() {
+        let mut stored = tank(1.0, 0.0);
+        let current = tank(5.0, 0.5);
+        let EntityStateChange::Update(update) = EntityStateUpdate::from_changes(&stored, &current)
+        else {
+            panic!("expected an update");
+        };
+
+        stored.apply_update(&update).unwrap();
+        assert_eq!(stored, current);
+
+        let mut other = tank(1.0, 0.0);
+        other.entity_id = EntityId::new(1, 1, 2);
+        assert_eq!(
+            other.apply_update(&update),
+            Err(DisError::EntityIdMismatch(
+                EntityId::new(1, 1, 1),
+                EntityId::new(1, 1, 2)
+            ))
+        );
+    }
+
+    #[test]
+    fn apply_update_matches_parts_by_identifying_fields() {
+        let association = |entity_id: EntityId, group_number: u16| {
+            EntityAssociationParameter::default()
+                .with_entity_id(entity_id)
+                .with_group_number(group_number)
+                .to_variable_parameter()
+        };
+        let mut stored = EntityState::builder()
+            .with_entity_id(EntityId::new(1, 1, 1))
+            .with_variable_parameters(vec![
+                association(EntityId::new(1, 1, 2), 1),
+                SeparationParameter::default()
+                    .with_parent_entity_id(EntityId::new(1, 1, 5))
+                    .to_variable_parameter(),
+            ])
+            .build();
+        let update = EntityStateUpdate::builder()
+            .with_entity_id(EntityId::new(1, 1, 1))
+            .with_variable_parameters(vec![
+                association(EntityId::new(1, 1, 2), 2),
+                association(EntityId::new(1, 1, 3), 1),
+                SeparationParameter::default()
+                    .with_parent_entity_id(EntityId::new(1, 1, 6))
+                    .to_variable_parameter(),
+            ])
+            .build();
+
+        stored.apply_update(&update).unwrap();
+        assert_eq!(
+            stored.variable_parameters,
+            vec![
+                association(EntityId::new(1, 1, 2), 2),
+                SeparationParameter::default()
+                    .with_parent_entity_id(EntityId::new(1, 1, 5))
+                    .to_variable_parameter(),
+                association(EntityId::new(1, 1, 3), 1),
+                SeparationParameter::default()
+                    .with_parent_entity_id(EntityId::new(1, 1, 6))
+                    .to_variable_parameter(),
+            ]
+        );
+    }
+
+    #[test]
+    fn diff_nested_records() {
+        let salvo = |quantity| {
+            Fire::builder()
+                .with_munition_descriptor(
+                    EntityType::default(),
+                    MunitionDescriptor::default().with_quantity(quantity),
+                )
+                .build()
+        };
+
+        assert_eq!(
+            salvo(1).diff(&salvo(4)),
+            vec![FieldChange {
+                path: "descriptor.munition.quantity".to_string(),
+                old: Some("1".to_string()),
+                new: Some("4".to_string()),
+            }]
+        );
+        let expendable = Fire::builder()
+            .with_expendable_descriptor(EntityType::default())
+            .build();
+        assert_eq!(salvo(1).diff(&expendable)[0].path, "descriptor");
+    }
+}
@@ -0,0 +1,1443 @@
+use crate::common::aggregate_state::model::aggregate_state_intermediate_length_padding;
+use crate::common::aggregate_state::parser::silent_entity_system;
+use crate::common::attribute::parser::attribute_record_set;
+use crate::common::electromagnetic_emission::parser::emitter_system;
+use crate::common::entity_state::model::EntityAppearance;
+use crate::common::entity_state::parser::dr_other_parameters;
+use crate::common::iff::model::{InformationLayers, LayersPresenceApplicability, SystemStatus};
+use crate::common::is_group_of::parser::group_entity_description;
+use crate::common::model::{
+    length_padded_to_num, DisTimeStamp, EntityId, EntityType, PduBody, PduHeader,
+};
+use crate::common::parser::{
+    clock_time, descriptor_record_dti, descriptor_record_fti, entity_id, entity_type, event_id,
+    fixed_datum, location, orientation, pdu_body, pdu_header, record_specification,
+    sanitize_marking, simulation_address, supply_quantity, variable_datum, variable_parameter,
+    vec3_f32,
+};
+use crate::common::record_query_r::parser::record_query_specification;
+use crate::common::transmitter::parser::variable_transmitter_parameter;
+use crate::common::underwater_acoustic::parser::ua_emitter_system;
+use crate::common::ParseOptions;
+use crate::constants::{
+    EIGHT_OCTETS, FIVE_LEAST_SIGNIFICANT_BITS, FOUR_OCTETS, ONE_BYTE_IN_BITS, PDU_HEADER_LEN_BYTES,
+};
+use crate::dissect::node::{DissectedNode, Dissection, Divergence};
+use crate::enumerations::{
+    AcknowledgeFlag, ActionId, AggregateStateAggregateState, AggregateStateFormation,
+    ArticulatedPartsTypeClass, ArticulatedPartsTypeMetric, AttributeActionCode, ChangeIndicator,
+    CollisionType, Country, DeadReckoningAlgorithm, DesignatorCode, DesignatorSystemName,
+    DetonationResult, DetonationTypeIndicator, ElectromagneticEmissionStateUpdateIndicator,
+    EntityKind, EntityMarkingCharacterSet, EventType, ExplosiveMaterialCategories,
+    FireTypeIndicator, ForceId, IsGroupOfGroupedEntityCategory, MunitionDescriptorFuse,
+    MunitionDescriptorWarhead, PduType, PlatformDomain, ProtocolFamily, ProtocolVersion,
+    ReceiverState, RecordQueryREventType, RepairCompleteRepair, RepairResponseRepairResult,
+    RequestStatus, RequiredReliabilityService, ResponseFlag, ServiceRequestServiceTypeRequested,
+    SignalTdlType, StopFreezeFrozenBehavior, StopFreezeReason, TransferControlTransferType,
+    TransmitterAntennaPatternType, TransmitterCryptoSystem, TransmitterInputSource,
+    TransmitterTransmitState, UAPassiveParameterIndex, UAStateChangeUpdateIndicator,
+    VariableParameterRecordType, VariableRecordType,
+};
+use crate::reflect::value::{Reflect, Value};
+use crate::v7::entity_state::entity_capabilities_from_bytes;
+use crate::v7::parser::parse_pdu_status_fields;
+use nom::bytes::complete::take;
+use nom::error::ErrorKind;
+use nom::number::complete::{be_f32, be_f64, be_i32, be_u16, be_u32, be_u64, be_u8};
+use nom::IResult;
+use std::fmt::Display;
+
+const PDU_TYPE_OFFSET: usize = 2;
+const PDU_LENGTH_OFFSET: usize = 8;
+const VARIABLE_PARAMETER_FIELDS_OCTETS: usize = 15;
+const MODULATION_TYPE_OCTETS: usize = 8;
+const IFF_SYSTEM_ID_OCTETS: usize = 6;
+const IFF_LAYER_HEADER_OCTETS: usize = 4;
+const AGGREGATE_TYPE_OCTETS: usize = 8;
+const AGGREGATE_MARKING_OCTETS: usize = 32;
+const SILENT_AGGREGATE_SYSTEM_OCTETS: usize = 12;
+const GROUP_REFERENCE_POINT_OCTETS: usize = 16;
+const SHAFT_OCTETS: usize = 8;
+const APA_OCTETS: usize = 4;
+const SEES_SYSTEM_OCTETS: usize = 8;
+
+/// Dissects the PDUs in `data` into a tree of fields per PDU, with the offset, length, raw octets
+/// and decoded value of each field, like a packet dissector does.
+///
+/// The header and the bodies of all modelled PDUs are dissected field by field, using the field names of the models.
+/// Fields that the models handle as a whole (such as IFF layers or emitter systems) are a single node,
+/// of which the value is taken from the parsed model through `Reflect`.
+/// The bodies of Other PDUs and of PDU types that are not modelled are a single node.
+/// Dissection stops at the first point where parsing diverges from the length declared in the header of a PDU,
+/// which is reported in `Dissection::divergence`.
+#[must_use]
+pub fn dissect(data: &[u8]) -> Dissection {
+    let mut dissection = Dissection::default();
+    let mut offset = 0;
+    while offset < data.len() && dissection.divergence.is_none() {
+        let (pdu, divergence, next) = dissect_pdu(data, offset);
+        dissection.pdus.push(pdu);
+        dissection.divergence = divergence;
+        // skip the padding that aligns the next PDU of a bundle
+        let padding = (EIGHT_OCTETS - (next - offset) % EIGHT_OCTETS) % EIGHT_OCTETS;
+        offset = next
+            + data[next..]
+                .iter()
+                .take(padding)
+                .take_while(|octet| **octet == 0)
+                .count();
+    }
+    dissection
+}
+
+/// Dissects the PDU at `offset`, returning its node, the divergence from its declared length if any,
+/// and the offset where the PDU ends according to its declared length.
+fn dissect_pdu(data: &[u8], offset: usize) -> (DissectedNode, Option<Divergence>, usize) {
+    let declared_length = data
+        .get(offset + PDU_LENGTH_OFFSET..offset + PDU_LENGTH_OFFSET + 2)
+        .map(|octets| u16::from_be_bytes([octets[0], octets[1]]) as usize);
+    let header_length = PDU_HEADER_LEN_BYTES as usize;
+    let end = match declared_length {
+        Some(length) => (offset + length.max(header_length)).min(data.len()),
+        None => data.len(),
+    };
+
+    let mut cursor = Cursor::new(&data[..end], offset);
+    let result = cursor.pdu();
+    let position = cursor.position;
+    let mut children = cursor.into_nodes();
+
+    if result.is_ok() && position < end {
+        children.push(DissectedNode {
+            name: "unparsed".to_string(),
+            offset: position,
+            length: end - position,
+            raw: data[position..end].to_vec(),
+            ..Default::default()
+        });
+    }
+    let declared_end = offset + declared_length.unwrap_or(header_length);
+    let reason = divergence_reason(result, declared_length, offset, position, end);
+    let divergence = reason.map(|(at, reason)| Divergence {
+        pdu_offset: offset,
+        offset: at,
+        declared_length: declared_end - offset,
+        parsed_length: position - offset,
+        reason,
+    });
+
+    let length = children
+        .last()
+        .map_or(0, |last| last.offset + last.length - offset);
+    let pdu_type = data.get(offset + PDU_TYPE_OFFSET).copied();
+    let pdu = DissectedNode {
+        name: "pdu".to_string(),
+        offset,
+        length,
+        raw: data[offset..offset + length].to_vec(),
+        value: pdu_type.map(|pdu_type| pdu_type.to_string()),
+        description: pdu_type.map(|pdu_type| PduType::from(pdu_type).to_string()),
+        children,
+    };
+    let next = if divergence.is_none() {
+        declared_end
+    } else {
+        data.len()
+    };
+    (pdu, divergence, next)
+}
+
+/// The offset and reason of the divergence of a dissected PDU from its declared length, if it diverges.
+fn divergence_reason(
+    result: Result<(), Stop>,
+    declared_length: Option<usize>,
+    offset: usize,
+    position: usize,
+    end: usize,
+) -> Option<(usize, String)> {
+    let header_length = PDU_HEADER_LEN_BYTES as usize;
+    let Some(declared_length) = declared_length else {
+        return Some((
+            end,
+            "The data ends before the PDU header is complete".to_string(),
+        ));
+    };
+    let declared_end = offset + declared_length;
+    match result {
+        _ if declared_length < header_length => Some((
+            offset + PDU_LENGTH_OFFSET,
+            format!(
+                "The declared length of {declared_length} octets is shorter than the PDU header"
+            ),
+        )),
+        Err(Stop {
+            offset: at,
+            path,
+            invalid,
+        }) => {
+            let reason = match invalid {
+                Some(description) => format!("`{path}` cannot be parsed: {description}"),
+                None if end < declared_end => format!(
+                    "`{path}` does not fit in the data, which ends {} octets before the declared length of {declared_length} octets",
+                    declared_end - end
+                ),
+                None => format!(
+                    "`{path}` does not fit in the declared length of {declared_length} octets"
+                ),
+            };
+            Some((at, reason))
+        }
+        Ok(()) if position < end => Some((
+            position,
+            format!(
+                "Parsing ends {} octets before the declared length of {declared_length} octets",
+                declared_end - position
+            ),
+        )),
+        Ok(()) if end < declared_end => Some((
+            end,
+            format!(
+                "The data ends {} octets before the declared length of {declared_length} octets",
+                declared_end - end
+            ),
+        )),
+        Ok(()) => None,
+    }
+}
+
+/// The model of a parsed PDU body, of which the fields are reflected through the field listing of the models.
+fn body_model(body: &PduBody) -> Option<&dyn Reflect> {
+    body.fields()
+        .into_iter()
+        .find_map(|(_, value)| match value {
+            Value::Record(model) => Some(model),
+            _ => None,
+        })
+}
+
+/// Formats a reflected value as the value of a node.
+fn reflected_value(value: &Value<'_>) -> String {
+    match value {
+        Value::Leaf(leaf) => format!("{leaf:?}"),
+        Value::Record(record) => format!("{record:?}"),
+        Value::List(items) => format!(
+            "[{}]",
+            items
+                .iter()
+                .map(reflected_value)
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        Value::Fields(fields) => format!(
+            "{{ {} }}",
+            fields
+                .iter()
+                .map(|(name, value)| format!("{name}: {}", reflected_value(value)))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+    }
+}
+
+/// The point where dissecting stops, because a field does not fit in the data (`invalid` is `None`) or is invalid.
+struct Stop {
+    offset: usize,
+    path: String,
+    invalid: Option<String>,
+}
+
+/// The nodes of a record that is being dissected.
+struct Frame {
+    name: String,
+    nodes: Vec<DissectedNode>,
+}
+
+/// Walks through the fields of a PDU, running the parser of each field and recording the octets it consumes.
+struct Cursor<'a> {
+    data: &'a [u8],
+    position: usize,
+    frames: Vec<Frame>,
+}
+
+impl<'a> Cursor<'a> {
+    fn new(data: &'a [u8], position: usize) -> Self {
+        Self {
+            data,
+            position,
+            frames: vec![Frame {
+                name: String::new(),
+                nodes: Vec::new(),
+            }],
+        }
+    }
+
+    fn into_nodes(mut self) -> Vec<DissectedNode> {
+        self.frames.pop().map_or_else(Vec::new, |frame| frame.nodes)
+    }
+
+    /// Adds a field decoded by `parser`, described by `describe` as a value and an optional description.
+    fn field<T>(
+        &mut self,
+        name: &str,
+        parser: impl FnOnce(&'a [u8]) -> IResult<&'a [u8], T>,
+        describe: impl FnOnce(&T) -> (String, Option<String>),
+    ) -> Result<T, Stop> {
+        let start = self.position;
+        let value = self.run(name, parser)?;
+        let (text, description) = describe(&value);
+        self.push(name, start, Some(text), description, Vec::new());
+        Ok(value)
+    }
+
+    fn number<T: Display>(
+        &mut self,
+        name: &str,
+        parser: impl FnOnce(&'a [u8]) -> IResult<&'a [u8], T>,
+    ) -> Result<T, Stop> {
+        self.field(name, parser, |value| (value.to_string(), None))
+    }
+
+    fn enumeration<R: Display + Copy, E: From<R> + Display>(
+        &mut self,
+        name: &str,
+        parser: impl FnOnce(&'a [u8]) -> IResult<&'a [u8], R>,
+    ) -> Result<E, Stop> {
+        let value = self.field(name, parser, |value| {
+            (value.to_string(), Some(E::from(*value).to_string()))
+        })?;
+        Ok(E::from(value))
+    }
+
+    /// Adds a field of `length` octets that is not decoded.
+    fn bytes(&mut self, name: &str, length: usize) -> Result<(), Stop> {
+        let start = self.position;
+        self.run(name, take(length))?;
+        self.push(name, start, None, None, Vec::new());
+        Ok(())
+    }
+
+    /// Adds a record, of which `layout` adds the fields.
+    ///
+    /// The record as a whole is decoded by `parser`, which determines where the record ends;
+    /// `describe` optionally provides the value of the record.
+    fn record<T>(
+        &mut self,
+        name: &str,
+        parser: impl FnOnce(&'a [u8]) -> IResult<&'a [u8], T>,
+        describe: impl FnOnce(&T) -> Option<String>,
+        layout: impl FnOnce(&mut Self) -> Result<(), Stop>,
+    ) -> Result<T, Stop> {
+        let data = self.data;
+        let start = self.position;
+        let parsed = parser(&data[start..]);
+        let (laid_out, children) = self.nest(name, layout);
+        let result = match (laid_out, parsed) {
+            (Err(stop), _) => Err(stop),
+            (Ok(()), Ok((rest, value))) => {
+                self.position = data.len() - rest.len();
+                Ok(value)
+            }
+            (Ok(()), Err(error)) => Err(self.stop(start, name, &error)),
+        };
+        let value = result.as_ref().ok().and_then(describe);
+        self.push(name, start, value, None, children);
+        result
+    }
+
+    /// Adds a record without a value of its own, of which `layout` adds the fields and determines where the record ends.
+    fn group(
+        &mut self,
+        name: &str,
+        layout: impl FnOnce(&mut Self) -> Result<(), Stop>,
+    ) -> Result<(), Stop> {
+        let start = self.position;
+        let (laid_out, children) = self.nest(name, layout);
+        self.push(name, start, None, None, children);
+        laid_out
+    }
+
+    /// Adds a field decoded by `parser` that the models handle as a whole,
+    /// of which the value is the value at the path `name` of the parsed `model`, if the body could be parsed.
+    fn reflected<T>(
+        &mut self,
+        name: &str,
+        parser: impl FnOnce(&'a [u8]) -> IResult<&'a [u8], T>,
+        model: Option<&dyn Reflect>,
+    ) -> Result<(), Stop> {
+        let start = self.position;
+        self.run(name, parser)?;
+        let value = model
+            .and_then(|model| model.get(name))
+            .map(|value| reflected_value(&value));
+        self.push(name, start, value, None, Vec::new());
+        Ok(())
+    }
+
+    /// Runs `layout` in a new frame named `name`, returning its result and the nodes it added.
+    fn nest(
+        &mut self,
+        name: &str,
+        layout: impl FnOnce(&mut Self) -> Result<(), Stop>,
+    ) -> (Result<(), Stop>, Vec<DissectedNode>) {
+        self.frames.push(Frame {
+            name: name.to_string(),
+            nodes: Vec::new(),
+        });
+        let laid_out = layout(self);
+        let children = self.frames.pop().map_or_else(Vec::new, |frame| frame.nodes);
+        (laid_out, children)
+    }
+
+    fn run<T>(
+        &mut self,
+        name: &str,
+        parser: impl FnOnce(&'a [u8]) -> IResult<&'a [u8], T>,
+    ) -> Result<T, Stop> {
+        let data = self.data;
+        match parser(&data[self.position..]) {
+            Ok((rest, value)) => {
+                self.position = data.len() - rest.len();
+                Ok(value)
+            }
+            Err(error) => Err(self.stop(self.position, name, &error)),
+        }
+    }
+
+    fn push(
+        &mut self,
+        name: &str,
+        offset: usize,
+        value: Option<String>,
+        description: Option<String>,
+        children: Vec<DissectedNode>,
+    ) {
+        let node = DissectedNode {
+            name: name.to_string(),
+            offset,
+            length: self.position - offset,
+            raw: self.data[offset..self.position].to_vec(),
+            value,
+            description,
+            children,
+        };
+        if let Some(frame) = self.frames.last_mut() {
+            frame.nodes.push(node);
+        }
+    }
+
+    fn stop(&self, offset: usize, name: &str, error: &nom::Err<nom::error::Error<&[u8]>>) -> Stop {
+        let path = self
+            .frames
+            .iter()
+            .skip(1)
+            .map(|frame| frame.name.as_str())
+            .chain([name])
+            .collect::<Vec<_>>()
+            .join(".");
+        let invalid = match error {
+            nom::Err::Error(error) | nom::Err::Failure(error) if error.code != ErrorKind::Eof => {
+                Some(error.code.description().to_string())
+            }
+            _ => None,
+        };
+        Stop {
+            offset,
+            path,
+            invalid,
+        }
+    }
+
+    fn pdu(&mut self) -> Result<(), Stop> {
+        let header = self.record("header", pdu_header, |_| None, Self::header)?;
+        let data = self.data;
+        let options = ParseOptions::default();
+        let parsed = pdu_body(&header, &options)(&data[self.position..]);
+        let model = parsed.as_ref().ok().and_then(|(_, body)| body_model(body));
+        self.record(
+            "body",
+            |_| parsed.clone(),
+            |_| None,
+            |cursor| cursor.body(&header, model),
+        )?;
+        Ok(())
+    }
+
+    fn body(&mut self, header: &PduHeader, model: Option<&dyn Reflect>) -> Result<(), Stop> {
+        match header.pdu_type {
+            PduType::EntityState => self.entity_state(header),
+            PduType::Fire => self.fire(header),
+            PduType::Detonation => self.detonation(header),
+            PduType::Collision => self.collision(),
+            PduType::ServiceRequest => self.service_request(),
+            PduType::ResupplyOffer | PduType::ResupplyReceived => self.resupply(),
+            PduType::ResupplyCancel => {
+                self.entity_id_record("requesting_id")?;
+                self.entity_id_record("servicing_id")?;
+                Ok(())
+            }
+            PduType::RepairComplete => self.repair_complete(),
+            PduType::RepairResponse => self.repair_response(),
+            PduType::CreateEntity | PduType::RemoveEntity => self.create_remove_entity(false),
+            PduType::CreateEntityR | PduType::RemoveEntityR => self.create_remove_entity(true),
+            PduType::StartResume => self.start_resume(false),
+            PduType::StartResumeR => self.start_resume(true),
+            PduType::StopFreeze => self.stop_freeze(false),
+            PduType::StopFreezeR => self.stop_freeze(true),
+            PduType::Acknowledge | PduType::AcknowledgeR => self.acknowledge(),
+            PduType::ActionRequest => self.action_request(false),
+            PduType::ActionRequestR => self.action_request(true),
+            PduType::ActionResponse | PduType::ActionResponseR => self.action_response(),
+            PduType::DataQuery => self.data_query(false),
+            PduType::DataQueryR => self.data_query(true),
+            PduType::SetData => self.set_data(false),
+            PduType::SetDataR => self.set_data(true),
+            PduType::Data => self.data_pdu(false),
+            PduType::DataR => self.data_pdu(true),
+            PduType::EventReport | PduType::EventReportR => self.event_report(),
+            PduType::Comment | PduType::CommentR => {
+                self.originating_receiving_ids()?;
+                self.datum_specification()
+            }
+            PduType::ElectromagneticEmission => self.electromagnetic_emission(model),
+            PduType::Designator => self.designator(),
+            PduType::Transmitter => self.transmitter(header, model),
+            PduType::Signal => self.signal(model),
+            PduType::Receiver => self.receiver(),
+            PduType::IFF => self.iff(model),
+            PduType::UnderwaterAcoustic => self.underwater_acoustic(model),
+            PduType::SupplementalEmissionEntityState => self.sees(model),
+            PduType::AggregateState => self.aggregate_state(model),
+            PduType::IsGroupOf => self.is_group_of(model),
+            PduType::TransferOwnership => self.transfer_ownership(model),
+            PduType::IsPartOf => self.is_part_of(model),
+            PduType::RecordR => self.record_r(model),
+            PduType::SetRecordR => self.set_record_r(model),
+            PduType::RecordQueryR => self.record_query_r(model),
+            PduType::CollisionElastic => self.collision_elastic(),
+            PduType::EntityStateUpdate => self.entity_state_update(),
+            PduType::Attribute => self.attribute(model),
+            // The bodies of Other PDUs and of PDU types that are not modelled are handled as a whole
+            _ => Ok(()),
+        }
+    }
+
+    fn header(&mut self) -> Result<(), Stop> {
+        let protocol_version = self.enumeration::<_, ProtocolVersion>("protocol_version", be_u8)?;
+        self.number("exercise_id", be_u8)?;
+        let pdu_type = self.enumeration::<_, PduType>("pdu_type", be_u8)?;
+        self.enumeration::<_, ProtocolFamily>("protocol_family", be_u8)?;
+        self.field("time_stamp", be_u32, |time_stamp| {
+            (
+                time_stamp.to_string(),
+                Some(format!("{:?}", DisTimeStamp::from(*time_stamp))),
+            )
+        })?;
+        self.number("pdu_length", be_u16)?;
+        if (1..=6).contains(&u8::from(protocol_version)) {
+            self.number("padding", be_u16)?;
+        } else {
+            self.field("pdu_status", be_u8, |status| {
+                (
+                    format!("{status:#04x}"),
+                    Some(format!(
+                        "{:?}",
+                        parse_pdu_status_fields(u8::from(pdu_type), *status)
+                    )),
+                )
+            })?;
+            self.number("padding", be_u8)?;
+        }
+        Ok(())
+    }
+
+    fn entity_state(&mut self, header: &PduHeader) -> Result<(), Stop> {
+        self.entity_id_record("entity_id")?;
+        self.enumeration::<_, ForceId>("force_id", be_u8)?;
+        let variable_parameters = self.number("number_of_variable_parameters", be_u8)?;
+        let entity_type = self.entity_type_record("entity_type")?;
+        self.entity_type_record("alternative_entity_type")?;
+        self.vector_record("entity_linear_velocity")?;
+        self.location_record("entity_location")?;
+        self.orientation_record("entity_orientation")?;
+        self.field("entity_appearance", be_u32, |appearance| {
+            (
+                format!("{appearance:#010x}"),
+                Some(format!(
+                    "{:?}",
+                    EntityAppearance::from_bytes(*appearance, &entity_type)
+                )),
+            )
+        })?;
+        self.group("dead_reckoning_parameters", |cursor| {
+            let algorithm = cursor.enumeration::<_, DeadReckoningAlgorithm>("algorithm", be_u8)?;
+            cursor.field(
+                "other_parameters",
+                |input| dr_other_parameters(input, algorithm),
+                |parameters| (format!("{parameters:?}"), None),
+            )?;
+            cursor.vector_record("linear_acceleration")?;
+            cursor.vector_record("angular_velocity")?;
+            Ok(())
+        })?;
+        self.group("entity_marking", |cursor| {
+            cursor.enumeration::<_, EntityMarkingCharacterSet>("marking_character_set", be_u8)?;
+            cursor.field("marking_string", take(11usize), |marking| {
+                (sanitize_marking(marking), None)
+            })?;
+            Ok(())
+        })?;
+        let protocol_version = header.protocol_version;
+        self.field("entity_capabilities", be_u32, |capabilities| {
+            let description = if protocol_version == ProtocolVersion::IEEE1278_12012 {
+                Some(format!(
+                    "{:?}",
+                    entity_capabilities_from_bytes(*capabilities, &entity_type)
+                ))
+            } else {
+                crate::v6::entity_state::parser::entity_capabilities(&capabilities.to_be_bytes())
+                    .ok()
+                    .map(|(_, capabilities)| format!("{capabilities:?}"))
+            };
+            (format!("{capabilities:#010x}"), description)
+        })?;
+        self.variable_parameters(variable_parameters)
+    }
+
+    fn fire(&mut self, header: &PduHeader) -> Result<(), Stop> {
+        let fire_type = header
+            .pdu_status
+            .unwrap_or_default()
+            .fire_type_indicator
+            .unwrap_or(FireTypeIndicator::Munition);
+        self.entity_id_record("firing_entity_id")?;
+        self.entity_id_record("target_entity_id")?;
+        self.entity_id_record("entity_id")?;
+        self.event_id_record("event_id")?;
+        self.number("fire_mission_index", be_u32)?;
+        self.location_record("location_in_world")?;
+        self.record(
+            "descriptor",
+            descriptor_record_fti(fire_type),
+            |_| None,
+            |cursor| {
+                cursor.entity_type_record("entity_type")?;
+                match fire_type {
+                    FireTypeIndicator::Munition => cursor.munition_descriptor(),
+                    _ => cursor.bytes("padding", 8),
+                }
+            },
+        )?;
+        self.vector_record("velocity")?;
+        self.number("range", be_f32)?;
+        Ok(())
+    }
+
+    fn detonation(&mut self, header: &PduHeader) -> Result<(), Stop> {
+        let detonation_type = header
+            .pdu_status
+            .unwrap_or_default()
+            .detonation_type_indicator
+            .unwrap_or(DetonationTypeIndicator::Munition);
+        self.entity_id_record("source_entity_id")?;
+        self.entity_id_record("target_entity_id")?;
+        self.entity_id_record("exploding_entity_id")?;
+        self.event_id_record("event_id")?;
+        self.vector_record("velocity")?;
+        self.location_record("location_in_world_coordinates")?;
+        self.record(
+            "descriptor",
+            descriptor_record_dti(detonation_type),
+            |_| None,
+            |cursor| {
+                cursor.entity_type_record("entity_type")?;
+                match detonation_type {
+                    DetonationTypeIndicator::Munition => cursor.munition_descriptor(),
+                    DetonationTypeIndicator::NonmunitionExplosion => {
+                        cursor.enumeration::<_, ExplosiveMaterialCategories>(
+                            "explosive_material",
+                            be_u16,
+                        )?;
+                        cursor.bytes("padding", 2)?;
+                        cursor.number("explosive_force", be_f32)?;
+                        Ok(())
+                    }
+                    _ => cursor.bytes("padding", 8),
+                }
+            },
+        )?;
+        self.vector_record("location_in_entity_coordinates")?;
+        self.enumeration::<_, DetonationResult>("detonation_result", be_u8)?;
+        let variable_parameters = self.number("number_of_variable_parameters", be_u8)?;
+        self.bytes("padding", 2)?;
+        self.variable_parameters(variable_parameters)
+    }
+
+    fn collision(&mut self) -> Result<(), Stop> {
+        self.entity_id_record("issuing_entity_id")?;
+        self.entity_id_record("colliding_entity_id")?;
+        self.event_id_record("event_id")?;
+        self.enumeration::<_, CollisionType>("collision_type", be_u8)?;
+        self.bytes("padding", 1)?;
+        self.vector_record("velocity")?;
+        self.number("mass", be_f32)?;
+        self.vector_record("location")
+    }
+
+    fn collision_elastic(&mut self) -> Result<(), Stop> {
+        self.entity_id_record("issuing_entity_id")?;
+        self.entity_id_record("colliding_entity_id")?;
+        self.event_id_record("event_id")?;
+        self.bytes("padding", 2)?;
+        self.vector_record("velocity")?;
+        self.number("mass", be_f32)?;
+        self.vector_record("location")?;
+        for component in ["xx", "xy", "xz", "yy", "yz", "zz"] {
+            self.number(&format!("intermediate_result_{component}"), be_f32)?;
+        }
+        self.vector_record("unit_surface_normal")?;
+        self.number("coefficient_of_restitution", be_f32)?;
+        Ok(())
+    }
+
+    fn service_request(&mut self) -> Result<(), Stop> {
+        self.entity_id_record("requesting_id")?;
+        self.entity_id_record("servicing_id")?;
+        self.enumeration::<_, ServiceRequestServiceTypeRequested>("service_type_requested", be_u8)?;
+        let supplies = self.number("number_of_supplies", be_u8)?;
+        self.bytes("padding", 2)?;
+        self.supplies(supplies)
+    }
+
+    fn resupply(&mut self) -> Result<(), Stop> {
+        self.entity_id_record("requesting_id")?;
+        self.entity_id_record("servicing_id")?;
+        let supplies = self.number("number_of_supplies", be_u8)?;
+        self.bytes("padding", 3)?;
+        self.supplies(supplies)
+    }
+
+    fn repair_complete(&mut self) -> Result<(), Stop> {
+        self.entity_id_record("receiving_id")?;
+        self.entity_id_record("repairing_id")?;
+        self.enumeration::<_, RepairCompleteRepair>("repair", be_u16)?;
+        self.bytes("padding", 2)
+    }
+
+    fn repair_response(&mut self) -> Result<(), Stop> {
+        self.entity_id_record("receiving_id")?;
+        self.entity_id_record("repairing_id")?;
+        self.enumeration::<_, RepairResponseRepairResult>("repair_result", be_u8)?;
+        self.bytes("padding", 3)
+    }
+
+    /// Create Entity and Remove Entity PDUs, or their reliable counterparts when `reliable` is set.
+    fn create_remove_entity(&mut self, reliable: bool) -> Result<(), Stop> {
+        self.originating_receiving_ids()?;
+        if reliable {
+            self.required_reliability_service(3)?;
+        }
+        self.number("request_id", be_u32)?;
+        Ok(())
+    }
+
+    fn start_resume(&mut self, reliable: bool) -> Result<(), Stop> {
+        self.originating_receiving_ids()?;
+        self.clock_time_record("real_world_time")?;
+        self.clock_time_record("simulation_time")?;
+        if reliable {
+            self.required_reliability_service(3)?;
+        }
+        self.number("request_id", be_u32)?;
+        Ok(())
+    }
+
+    fn stop_freeze(&mut self, reliable: bool) -> Result<(), Stop> {
+        self.originating_receiving_ids()?;
+        self.clock_time_record("real_world_time")?;
+        self.enumeration::<_, StopFreezeReason>("reason", be_u8)?;
+        self.enumeration::<_, StopFreezeFrozenBehavior>("frozen_behavior", be_u8)?;
+        if reliable {
+            self.required_reliability_service(1)?;
+        } else {
+            self.bytes("padding", 2)?;
+        }
+        self.number("request_id", be_u32)?;
+        Ok(())
+    }
+
+    fn acknowledge(&mut self) -> Result<(), Stop> {
+        self.originating_receiving_ids()?;
+        self.enumeration::<_, AcknowledgeFlag>("acknowledge_flag", be_u16)?;
+        self.enumeration::<_, ResponseFlag>("response_flag", be_u16)?;
+        self.number("request_id", be_u32)?;
+        Ok(())
+    }
+
+    fn action_request(&mut self, reliable: bool) -> Result<(), Stop> {
+        self.originating_receiving_ids()?;
+        if reliable {
+            self.required_reliability_service(3)?;
+        }
+        self.number("request_id", be_u32)?;
+        self.enumeration::<_, ActionId>("action_id", be_u32)?;
+        if reliable {
+            self.bytes("padding", 4)?;
+        }
+        self.datum_specification()
+    }
+
+    fn action_response(&mut self) -> Result<(), Stop> {
+        self.originating_receiving_ids()?;
+        self.number("request_id", be_u32)?;
+        self.enumeration::<_, RequestStatus>("request_status", be_u32)?;
+        self.datum_specification()
+    }
+
+    fn data_query(&mut self, reliable: bool) -> Result<(), Stop> {
+        self.originating_receiving_ids()?;
+        if reliable {
+            self.required_reliability_service(3)?;
+        }
+        self.number("request_id", be_u32)?;
+        self.number("time_interval", be_u32)?;
+        let fixed_datums = self.number("number_of_fixed_datum_records", be_u32)?;
+        let variable_datums = self.number("number_of_variable_datum_records", be_u32)?;
+        for index in 0..fixed_datums {
+            self.enumeration::<_, VariableRecordType>(
+                &format!("fixed_datum_records[{index}]"),
+                be_u32,
+            )?;
+        }
+        for index in 0..variable_datums {
+            self.enumeration::<_, VariableRecordType>(
+                &format!("variable_datum_records[{index}]"),
+                be_u32,
+            )?;
+        }
+        Ok(())
+    }
+
+    fn set_data(&mut self, reliable: bool) -> Result<(), Stop> {
+        self.originating_receiving_ids()?;
+        if reliable {
+            self.required_reliability_service(3)?;
+        }
+        self.number("request_id", be_u32)?;
+        if !reliable {
+            self.bytes("padding", 4)?;
+        }
+        self.datum_specification()
+    }
+
+    fn data_pdu(&mut self, reliable: bool) -> Result<(), Stop> {
+        self.originating_receiving_ids()?;
+        self.number("request_id", be_u32)?;
+        if reliable {
+            self.required_reliability_service(3)?;
+        } else {
+            self.bytes("padding", 4)?;
+        }
+        self.datum_specification()
+    }
+
+    fn event_report(&mut self) -> Result<(), Stop> {
+        self.originating_receiving_ids()?;
+        self.enumeration::<_, EventType>("event_type", be_u32)?;
+        self.bytes("padding", 4)?;
+        self.datum_specification()
+    }
+
+    fn record_r(&mut self, model: Option<&dyn Reflect>) -> Result<(), Stop> {
+        self.originating_receiving_ids()?;
+        self.number("request_id", be_u32)?;
+        self.required_reliability_service(1)?;
+        self.enumeration::<_, EventType>("event_type", be_u32)?;
+        self.number("response_serial_number", be_u32)?;
+        self.reflected("record_specification", record_specification, model)
+    }
+
+    fn set_record_r(&mut self, model: Option<&dyn Reflect>) -> Result<(), Stop> {
+        self.originating_receiving_ids()?;
+        self.number("request_id", be_u32)?;
+        self.required_reliability_service(7)?;
+        self.reflected("record_specification", record_specification, model)
+    }
+
+    fn record_query_r(&mut self, model: Option<&dyn Reflect>) -> Result<(), Stop> {
+        self.originating_receiving_ids()?;
+        self.number("request_id", be_u32)?;
+        self.required_reliability_service(1)?;
+        self.enumeration::<_, RecordQueryREventType>("event_type", be_u16)?;
+        self.number("time", be_u32)?;
+        self.reflected(
+            "record_query_specification",
+            record_query_specification,
+            model,
+        )
+    }
+
+    fn electromagnetic_emission(&mut self, model: Option<&dyn Reflect>) -> Result<(), Stop> {
+        self.entity_id_record("emitting_entity_id")?;
+        self.event_id_record("event_id")?;
+        self.enumeration::<_, ElectromagneticEmissionStateUpdateIndicator>(
+            "state_update_indicator",
+            be_u8,
+        )?;
+        let systems = self.number("number_of_systems", be_u8)?;
+        self.bytes("padding", 2)?;
+        for index in 0..systems {
+            self.reflected(&format!("emitter_systems[{index}]"), emitter_system, model)?;
+        }
+        Ok(())
+    }
+
+    fn designator(&mut self) -> Result<(), Stop> {
+        self.entity_id_record("designating_entity_id")?;
+        self.enumeration::<_, DesignatorSystemName>("system_name", be_u16)?;
+        self.entity_id_record("designated_entity_id")?;
+        self.enumeration::<_, DesignatorCode>("code", be_u16)?;
+        self.number("power", be_f32)?;
+        self.number("wavelength", be_f32)?;
+        self.vector_record("spot_wrt_designated_entity")?;
+        self.location_record("spot_location")?;
+        self.enumeration::<_, DeadReckoningAlgorithm>("dead_reckoning_algorithm", be_u8)?;
+        self.bytes("padding", 3)?;
+        self.vector_record("linear_acceleration")
+    }
+
+    fn transmitter(&mut self, header: &PduHeader, model: Option<&dyn Reflect>) -> Result<(), Stop> {
+        self.entity_id_record("radio_reference_id")?;
+        self.number("radio_number", be_u16)?;
+        self.entity_type_record("radio_type")?;
+        self.enumeration::<_, TransmitterTransmitState>("transmit_state", be_u8)?;
+        self.enumeration::<_, TransmitterInputSource>("input_source", be_u8)?;
+        // IEEE 1278.1a-1998 defines padding where IEEE 1278.1-2012 defines the number of VTP records
+        let parameters = if header.protocol_version == ProtocolVersion::IEEE1278_12012 {
+            self.number("number_of_variable_transmitter_parameters", be_u16)?
+        } else {
+            self.bytes("padding", 2)?;
+            0
+        };
+        self.location_record("antenna_location")?;
+        self.vector_record("relative_antenna_location")?;
+        self.enumeration::<_, TransmitterAntennaPatternType>("antenna_pattern_type", be_u16)?;
+        let antenna_pattern_length = self.number("antenna_pattern_length", be_u16)?;
+        self.number("frequency", be_u64)?;
+        self.number("transmit_frequency_bandwidth", be_f32)?;
+        self.number("power", be_f32)?;
+        self.reflected("modulation_type", take(MODULATION_TYPE_OCTETS), model)?;
+        self.enumeration::<_, TransmitterCryptoSystem>("crypto_system", be_u16)?;
+        self.reflected("crypto_key_id", be_u16, model)?;
+        let modulation_parameters_length = self.number("length_of_modulation_parameters", be_u8)?;
+        self.bytes("padding", 3)?;
+        if modulation_parameters_length > 0 {
+            self.reflected(
+                "modulation_parameters",
+                take(modulation_parameters_length),
+                model,
+            )?;
+        }
+        if antenna_pattern_length > 0 {
+            self.reflected("antenna_pattern", take(antenna_pattern_length), model)?;
+        }
+        for index in 0..parameters {
+            self.reflected(
+                &format!("variable_transmitter_parameters[{index}]"),
+                variable_transmitter_parameter,
+                model,
+            )?;
+        }
+        Ok(())
+    }
+
+    fn signal(&mut self, model: Option<&dyn Reflect>) -> Result<(), Stop> {
+        self.entity_id_record("radio_reference_id")?;
+        self.number("radio_number", be_u16)?;
+        self.reflected("encoding_scheme", be_u16, model)?;
+        self.enumeration::<_, SignalTdlType>("tdl_type", be_u16)?;
+        self.number("sample_rate", be_u32)?;
+        let data_length_bits = self.number("data_length", be_u16)?;
+        self.number("samples", be_u16)?;
+        let data_length = length_padded_to_num(
+            usize::from(data_length_bits) / ONE_BYTE_IN_BITS,
+            FOUR_OCTETS,
+        );
+        self.bytes("data", data_length.data_length)?;
+        self.bytes("padding", data_length.padding_length)
+    }
+
+    fn receiver(&mut self) -> Result<(), Stop> {
+        self.entity_id_record("radio_reference_id")?;
+        self.number("radio_number", be_u16)?;
+        self.enumeration::<_, ReceiverState>("receiver_state", be_u16)?;
+        self.bytes("padding", 2)?;
+        self.number("received_power", be_f32)?;
+        self.entity_id_record("transmitter_radio_reference_id")?;
+        self.number("transmitter_radio_number", be_u16)?;
+        Ok(())
+    }
+
+    fn iff(&mut self, model: Option<&dyn Reflect>) -> Result<(), Stop> {
+        self.entity_id_record("emitting_entity_id")?;
+        self.event_id_record("event_id")?;
+        self.vector_record("relative_antenna_location")?;
+        self.reflected("system_id", take(IFF_SYSTEM_ID_OCTETS), model)?;
+        self.number("system_designator", be_u8)?;
+        self.number("system_specific_data", be_u8)?;
+        let mut information_layers = InformationLayers::default();
+        self.group("fundamental_operational_data", |cursor| {
+            cursor.field("system_status", be_u8, |status| {
+                (
+                    format!("{status:#04x}"),
+                    Some(format!("{:?}", SystemStatus::from(*status))),
+                )
+            })?;
+            cursor.number("data_field_1", be_u8)?;
+            let layers = cursor.field("information_layers", be_u8, |layers| {
+                (
+                    format!("{layers:#04x}"),
+                    Some(format!("{:?}", InformationLayers::from(*layers))),
+                )
+            })?;
+            information_layers = InformationLayers::from(layers);
+            cursor.number("data_field_2", be_u8)?;
+            for parameter in 1..=6 {
+                cursor.number(&format!("parameter_{parameter}"), be_u16)?;
+            }
+            Ok(())
+        })?;
+        let layers = [
+            ("layer_2", information_layers.layer_2),
+            ("layer_3", information_layers.layer_3),
+            ("layer_4", information_layers.layer_4),
+            ("layer_5", information_layers.layer_5),
+        ];
+        for (name, presence) in layers {
+            if presence == LayersPresenceApplicability::PresentApplicable {
+                self.iff_layer(name, model)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Adds an IFF layer, which extends as far as the length in its layer header.
+    fn iff_layer(&mut self, name: &str, model: Option<&dyn Reflect>) -> Result<(), Stop> {
+        self.record(
+            name,
+            |input| {
+                let length = input
+                    .get(2..IFF_LAYER_HEADER_OCTETS)
+                    .map_or(0, |octets| u16::from_be_bytes([octets[0], octets[1]]));
+                take(length)(input)
+            },
+            |_| {
+                model
+                    .and_then(|model| model.get(name))
+                    .map(|value| reflected_value(&value))
+            },
+            |cursor| {
+                cursor.number("layer_number", be_u8)?;
+                cursor.number("layer_specific_information", be_u8)?;
+                let length = cursor.number("length", be_u16)?;
+                cursor.bytes(
+                    "data",
+                    usize::from(length).saturating_sub(IFF_LAYER_HEADER_OCTETS),
+                )
+            },
+        )?;
+        Ok(())
+    }
+
+    fn underwater_acoustic(&mut self, model: Option<&dyn Reflect>) -> Result<(), Stop> {
+        self.entity_id_record("emitting_entity_id")?;
+        self.event_id_record("event_id")?;
+        self.enumeration::<_, UAStateChangeUpdateIndicator>(
+            "state_change_update_indicator",
+            be_u8,
+        )?;
+        self.bytes("padding", 1)?;
+        self.enumeration::<_, UAPassiveParameterIndex>("passive_parameter_index", be_u16)?;
+        self.reflected("propulsion_plant_configuration", be_u8, model)?;
+        let shafts = self.number("number_of_shafts", be_u8)?;
+        let apas = self.number("number_of_apas", be_u8)?;
+        let emitter_systems = self.number("number_of_emitter_systems", be_u8)?;
+        for index in 0..shafts {
+            self.reflected(&format!("shafts[{index}]"), take(SHAFT_OCTETS), model)?;
+        }
+        for index in 0..apas {
+            self.reflected(&format!("apas[{index}]"), take(APA_OCTETS), model)?;
+        }
+        for index in 0..emitter_systems {
+            self.reflected(
+                &format!("emitter_systems[{index}]"),
+                ua_emitter_system,
+                model,
+            )?;
+        }
+        Ok(())
+    }
+
+    fn sees(&mut self, model: Option<&dyn Reflect>) -> Result<(), Stop> {
+        self.entity_id_record("originating_entity_id")?;
+        self.number("infrared_signature_representation_index", be_u16)?;
+        self.number("acoustic_signature_representation_index", be_u16)?;
+        self.number("radar_cross_section_representation_index", be_u16)?;
+        let propulsion_systems = self.number("number_of_propulsion_systems", be_u16)?;
+        let nozzle_systems = self.number("number_of_vectoring_nozzle_systems", be_u16)?;
+        for index in 0..propulsion_systems {
+            self.reflected(
+                &format!("propulsion_systems[{index}]"),
+                take(SEES_SYSTEM_OCTETS),
+                model,
+            )?;
+        }
+        for index in 0..nozzle_systems {
+            self.reflected(
+                &format!("vectoring_nozzle_systems[{index}]"),
+                take(SEES_SYSTEM_OCTETS),
+                model,
+            )?;
+        }
+        Ok(())
+    }
+
+    fn aggregate_state(&mut self, model: Option<&dyn Reflect>) -> Result<(), Stop> {
+        self.entity_id_record("aggregate_id")?;
+        self.enumeration::<_, ForceId>("force_id", be_u8)?;
+        self.enumeration::<_, AggregateStateAggregateState>("aggregate_state", be_u8)?;
+        self.reflected("aggregate_type", take(AGGREGATE_TYPE_OCTETS), model)?;
+        self.enumeration::<_, AggregateStateFormation>("formation", be_u32)?;
+        self.reflected("aggregate_marking", take(AGGREGATE_MARKING_OCTETS), model)?;
+        self.vector_record("dimensions")?;
+        self.orientation_record("orientation")?;
+        self.location_record("center_of_mass")?;
+        self.vector_record("velocity")?;
+        let number_of_aggregates = self.number("number_of_aggregates", be_u16)?;
+        let number_of_entities = self.number("number_of_entities", be_u16)?;
+        let silent_aggregates = self.number("number_of_silent_aggregate_systems", be_u16)?;
+        let silent_entities = self.number("number_of_silent_entity_systems", be_u16)?;
+        let aggregates = (0..number_of_aggregates)
+            .map(|index| self.entity_id_record(&format!("aggregates[{index}]")))
+            .collect::<Result<Vec<_>, _>>()?;
+        let entities = (0..number_of_entities)
+            .map(|index| self.entity_id_record(&format!("entities[{index}]")))
+            .collect::<Result<Vec<_>, _>>()?;
+        let (_, padding) = aggregate_state_intermediate_length_padding(&aggregates, &entities);
+        self.bytes("padding", padding.into())?;
+        for index in 0..silent_aggregates {
+            self.reflected(
+                &format!("silent_aggregate_systems[{index}]"),
+                take(SILENT_AGGREGATE_SYSTEM_OCTETS),
+                model,
+            )?;
+        }
+        for index in 0..silent_entities {
+            self.reflected(
+                &format!("silent_entity_systems[{index}]"),
+                silent_entity_system,
+                model,
+            )?;
+        }
+        let variable_datums = self.number("number_of_variable_datum_records", be_u32)?;
+        for index in 0..variable_datums {
+            self.variable_datum_record(&format!("variable_datums[{index}]"))?;
+        }
+        Ok(())
+    }
+
+    fn is_group_of(&mut self, model: Option<&dyn Reflect>) -> Result<(), Stop> {
+        self.entity_id_record("group_id")?;
+        let category = self
+            .enumeration::<_, IsGroupOfGroupedEntityCategory>("grouped_entity_category", be_u8)?;
+        let descriptions = self.number("number_of_group_entity_descriptions", be_u8)?;
+        self.bytes("padding", 4)?;
+        self.reflected(
+            "group_reference_point",
+            take(GROUP_REFERENCE_POINT_OCTETS),
+            model,
+        )?;
+        for index in 0..descriptions {
+            self.reflected(
+                &format!("descriptions[{index}]"),
+                group_entity_description(&category),
+                model,
+            )?;
+        }
+        Ok(())
+    }
+
+    fn transfer_ownership(&mut self, model: Option<&dyn Reflect>) -> Result<(), Stop> {
+        self.originating_receiving_ids()?;
+        self.number("request_id", be_u32)?;
+        self.enumeration::<_, RequiredReliabilityService>("required_reliability_service", be_u8)?;
+        self.enumeration::<_, TransferControlTransferType>("transfer_type", be_u8)?;
+        self.entity_id_record("transfer_entity_id")?;
+        self.reflected("record_specification", record_specification, model)
+    }
+
+    fn is_part_of(&mut self, model: Option<&dyn Reflect>) -> Result<(), Stop> {
+        self.entity_id_record("originating_simulation_id")?;
+        self.entity_id_record("receiving_entity_id")?;
+        self.reflected("relationship", take(FOUR_OCTETS), model)?;
+        self.vector_record("part_location")?;
+        self.reflected("named_location_id", take(FOUR_OCTETS), model)?;
+        self.entity_type_record("part_type")?;
+        Ok(())
+    }
+
+    fn entity_state_update(&mut self) -> Result<(), Stop> {
+        self.entity_id_record("entity_id")?;
+        self.bytes("padding", 1)?;
+        let variable_parameters = self.number("number_of_variable_parameters", be_u8)?;
+        self.vector_record("entity_linear_velocity")?;
+        self.location_record("entity_location")?;
+        self.orientation_record("entity_orientation")?;
+        self.field("entity_appearance", be_u32, |appearance| {
+            (format!("{appearance:#010x}"), None)
+        })?;
+        self.variable_parameters(variable_parameters)
+    }
+
+    fn attribute(&mut self, model: Option<&dyn Reflect>) -> Result<(), Stop> {
+        self.record(
+            "originating_simulation_address",
+            simulation_address,
+            |_| None,
+            |cursor| {
+                cursor.number("site_id", be_u16)?;
+                cursor.number("application_id", be_u16)?;
+                Ok(())
+            },
+        )?;
+        self.bytes("padding", 6)?;
+        self.enumeration::<_, PduType>("record_pdu_type", be_u8)?;
+        self.enumeration::<_, ProtocolVersion>("record_protocol_version", be_u8)?;
+        self.enumeration::<_, VariableRecordType>("master_attribute_record_type", be_u32)?;
+        self.enumeration::<_, AttributeActionCode>("action_code", be_u8)?;
+        self.bytes("padding", 1)?;
+        let record_sets = self.number("number_of_record_sets", be_u16)?;
+        let options = ParseOptions::default();
+        for index in 0..record_sets {
+            self.reflected(
+                &format!("attribute_record_sets[{index}]"),
+                attribute_record_set(&options),
+                model,
+            )?;
+        }
+        Ok(())
+    }
+
+    fn originating_receiving_ids(&mut self) -> Result<(), Stop> {
+        self.entity_id_record("originating_id")?;
+        self.entity_id_record("receiving_id")?;
+        Ok(())
+    }
+
+    /// Adds the Required Reliability Service field of reliable PDUs, followed by `padding` octets.
+    fn required_reliability_service(&mut self, padding: usize) -> Result<(), Stop> {
+        self.enumeration::<_, RequiredReliabilityService>("required_reliability_service", be_u8)?;
+        self.bytes("padding", padding)
+    }
+
+    fn supplies(&mut self, count: u8) -> Result<(), Stop> {
+        for index in 0..count {
+            self.record(
+                &format!("supplies[{index}]"),
+                supply_quantity,
+                |supply| Some(format!("{supply:?}")),
+                |cursor| {
+                    cursor.entity_type_record("supply_type")?;
+                    cursor.number("quantity", be_f32)?;
+                    Ok(())
+                },
+            )?;
+        }
+        Ok(())
+    }
+
+    fn datum_specification(&mut self) -> Result<(), Stop> {
+        let fixed_datums = self.number("number_of_fixed_datum_records", be_u32)?;
+        let variable_datums = self.number("number_of_variable_datum_records", be_u32)?;
+        for index in 0..fixed_datums {
+            self.record(
+                &format!("fixed_datum_records[{index}]"),
+                fixed_datum,
+                |datum| Some(format!("{datum:?}")),
+                |cursor| {
+                    cursor.enumeration::<_, VariableRecordType>("datum_id", be_u32)?;
+                    cursor.number("datum_value", be_u32)?;
+                    Ok(())
+                },
+            )?;
+        }
+        for index in 0..variable_datums {
+            self.variable_datum_record(&format!("variable_datum_records[{index}]"))?;
+        }
+        Ok(())
+    }
+
+    fn variable_datum_record(&mut self, name: &str) -> Result<(), Stop> {
+        self.record(
+            name,
+            variable_datum,
+            |datum| Some(format!("{datum:?}")),
+            |cursor| {
+                cursor.enumeration::<_, VariableRecordType>("datum_id", be_u32)?;
+                let length_bits = cursor.number("datum_length", be_u32)?;
+                let length =
+                    length_padded_to_num(length_bits as usize / ONE_BYTE_IN_BITS, EIGHT_OCTETS);
+                cursor.bytes("datum_value", length.data_length)?;
+                cursor.bytes("padding", length.padding_length)
+            },
+        )?;
+        Ok(())
+    }
+
+    fn entity_id_record(&mut self, name: &str) -> Result<EntityId, Stop> {
+        self.record(
+            name,
+            entity_id,
+            |entity_id| Some(entity_id.to_string()),
+            |cursor| {
+                cursor.number("site_id", be_u16)?;
+                cursor.number("application_id", be_u16)?;
+                cursor.number("entity_id", be_u16)?;
+                Ok(())
+            },
+        )
+    }
+
+    fn clock_time_record(&mut self, name: &str) -> Result<(), Stop> {
+        self.record(
+            name,
+            clock_time,
+            |_| None,
+            |cursor| {
+                cursor.number("hour", be_i32)?;
+                cursor.number("time_past_hour", be_u32)?;
+                Ok(())
+            },
+        )?;
+        Ok(())
+    }
+
+    fn event_id_record(&mut self, name: &str) -> Result<(), Stop> {
+        self.record(
+            name,
+            event_id,
+            |event_id| Some(event_id.to_string()),
+            |cursor| {
+                cursor.number("site_id", be_u16)?;
+                cursor.number("application_id", be_u16)?;
+                cursor.number("event_id", be_u16)?;
+                Ok(())
+            },
+        )?;
+        Ok(())
+    }
+
+    fn entity_type_record(&mut self, name: &str) -> Result<EntityType, Stop> {
+        self.record(
+            name,
+            entity_type,
+            |entity_type| Some(entity_type.to_string()),
+            |cursor| {
+                cursor.enumeration::<_, EntityKind>("kind", be_u8)?;
+                cursor.enumeration::<_, PlatformDomain>("domain", be_u8)?;
+                cursor.enumeration::<_, Country>("country", be_u16)?;
+                cursor.number("category", be_u8)?;
+                cursor.number("subcategory", be_u8)?;
+                cursor.number("specific", be_u8)?;
+                cursor.number("extra", be_u8)?;
+                Ok(())
+            },
+        )
+    }
+
+    fn vector_record(&mut self, name: &str) -> Result<(), Stop> {
+        self.record(
+            name,
+            vec3_f32,
+            |_| None,
+            |cursor| {
+                cursor.number("first_vector_component", be_f32)?;
+                cursor.number("second_vector_component", be_f32)?;
+                cursor.number("third_vector_component", be_f32)?;
+                Ok(())
+            },
+        )?;
+        Ok(())
+    }
+
+    fn location_record(&mut self, name: &str) -> Result<(), Stop> {
+        self.record(
+            name,
+            location,
+            |_| None,
+            |cursor| {
+                cursor.number("x_coordinate", be_f64)?;
+                cursor.number("y_coordinate", be_f64)?;
+                cursor.number("z_coordinate", be_f64)?;
+                Ok(())
+            },
+        )?;
+        Ok(())
+    }
+
+    fn orientation_record(&mut self, name: &str) -> Result<(), Stop> {
+        self.record(
+            name,
+            orientation,
+            |_| None,
+            |cursor| {
+                cursor.number("psi", be_f32)?;
+                cursor.number("theta", be_f32)?;
+                cursor.number("phi", be_f32)?;
+                Ok(())
+            },
+        )?;
+        Ok(())
+    }
+
+    fn munition_descriptor(&mut self) -> Result<(), Stop> {
+        self.enumeration::<_, MunitionDescriptorWarhead>("warhead", be_u16)?;
+        self.enumeration::<_, MunitionDescriptorFuse>("fuse", be_u16)?;
+        self.number("quantity", be_u16)?;
+        self.number("rate", be_u16)?;
+        Ok(())
+    }
+
+    fn variable_parameters(&mut self, count: u8) -> Result<(), Stop> {
+        for index in 0..count {
+            self.record(
+                &format!("variable_parameters[{index}]"),
+                variable_parameter,
+                |parameter| Some(format!("{parameter:?}")),
+                |cursor| {
+                    let record_type = cursor
+                        .enumeration::<_, VariableParameterRecordType>("record_type", be_u8)?;
+                    if record_type == VariableParameterRecordType::ArticulatedPart {
+                        cursor.enumeration::<_, ChangeIndicator>("change_indicator", be_u8)?;
+                        cursor.number("attachment_id", be_u16)?;
+                        cursor.field("parameter_type", be_u32, |parameter_type| {
+                            let type_metric = parameter_type & FIVE_LEAST_SIGNIFICANT_BITS;
+                            (
+                                parameter_type.to_string(),
+                                Some(format!(
+                                    "{} - {}",
+                                    ArticulatedPartsTypeClass::from(parameter_type - type_metric),
+                                    ArticulatedPartsTypeMetric::from(type_metric)
+                                )),
+                            )
+                        })?;
+                        cursor.number("parameter_value", be_f32)?;
+                        cursor.bytes("padding", 4)
+                    } else {
+                        cursor.bytes("fields", VARIABLE_PARAMETER_FIELDS_OCTETS)
+                    }
+                },
+            )?;
+        }
+        Ok(())
+    }
+}
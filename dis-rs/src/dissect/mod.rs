@@ -0,0 +1,248 @@
+pub mod dissector;
+pub mod node;
+mod render;
+
+#[cfg(test)]
+mod tests {
+    use crate::action_request::model::ActionRequest;
+    use crate::collision::model::Collision;
+    use crate::dissect::dissector::dissect;
+    use crate::dissect::node::DissectedNode;
+    use crate::entity_state::model::{EntityMarking, EntityState};
+    use crate::enumerations::{
+        ArticulatedPartsTypeClass, ArticulatedPartsTypeMetric, ForceId, PduType, VariableRecordType,
+    };
+    use crate::model::{
+        ArticulatedPart, DisTimeStamp, EntityId, EntityType, FixedDatum, Location, Pdu, PduHeader,
+        VariableDatum,
+    };
+    use crate::other::model::Other;
+    use crate::reflect::value::{Reflect, Value};
+    use crate::signal::model::Signal;
+    use crate::start_resume::model::StartResume;
+    use bytes::BytesMut;
+
+    fn tank() -> Pdu {
+        Pdu::finalize_from_parts(
+            PduHeader::new_v7(1, PduType::EntityState),
+            EntityState::builder()
+                .with_entity_id(EntityId::new(1, 2, 3))
+                .with_force_id(ForceId::Friendly)
+                .with_entity_type("1:1:225:1:1:1:0".parse::<EntityType>().unwrap())
+                .with_location(Location::new(1.0, 2.0, 3.0))
+                .with_marking(EntityMarking::new_ascii("TANK1"))
+                .with_variable_parameter(
+                    ArticulatedPart::default()
+                        .with_type_class(ArticulatedPartsTypeClass::PrimaryTurretNumber1)
+                        .with_type_metric(ArticulatedPartsTypeMetric::Azimuth)
+                        .with_parameter_value(0.5)
+                        .to_variable_parameter(),
+                )
+                .build()
+                .into_pdu_body(),
+            DisTimeStamp::new_absolute_from_secs(100),
+        )
+    }
+
+    fn serialize(pdus: &[Pdu]) -> Vec<u8> {
+        let mut buf = BytesMut::new();
+        for pdu in pdus {
+            pdu.serialize(&mut buf).unwrap();
+        }
+        buf.to_vec()
+    }
+
+    fn leaf_octets(node: &DissectedNode) -> usize {
+        if node.is_leaf() {
+            node.length
+        } else {
+            node.children.iter().map(leaf_octets).sum()
+        }
+    }
+
+    #[test]
+    fn dissect_entity_state() {
+        let data = serialize(&[tank()]);
+        let dissection = dissect(&data);
+        assert_eq!(dissection.divergence, None);
+        assert_eq!(dissection.pdus.len(), 1);
+
+        let pdu = &dissection.pdus[0];
+        assert_eq!(pdu.length, data.len());
+        assert_eq!(leaf_octets(pdu), data.len());
+        let version = pdu.get("header.protocol_version").unwrap();
+        assert_eq!((version.offset, version.length), (0, 1));
+        assert_eq!(version.value.as_deref(), Some("7"));
+        assert!(version.description.is_some());
+        assert_eq!(
+            pdu.get("body.entity_id").unwrap().raw,
+            vec![0, 1, 0, 2, 0, 3]
+        );
+        let location = pdu.get("body.entity_location").unwrap();
+        assert_eq!((location.offset, location.length), (48, 24));
+        assert_eq!(
+            pdu.get("body.entity_location.y_coordinate")
+                .unwrap()
+                .value
+                .as_deref(),
+            Some("2")
+        );
+        assert_eq!(
+            pdu.get("body.entity_marking.marking_string")
+                .unwrap()
+                .value
+                .as_deref(),
+            Some("TANK1")
+        );
+        let parameter = pdu
+            .get("body.variable_parameters[0].parameter_value")
+            .unwrap();
+        assert_eq!(parameter.offset, 152);
+        assert_eq!(parameter.value.as_deref(), Some("0.5"));
+
+        assert!(dissection.to_text().contains("entity_location"));
+        let hexdump = dissection.to_hexdump();
+        let line = hexdump.lines().nth(1).unwrap();
+        assert!(line.starts_with("0000  07 "));
+        assert!(line.contains("header.protocol_version: 7"));
+    }
+
+    #[test]
+    fn report_divergence_from_declared_length() {
+        let data = serialize(&[tank()]);
+
+        let mut short = data.clone();
+        short[8..10].copy_from_slice(&100u16.to_be_bytes());
+        let divergence = dissect(&short).divergence.unwrap();
+        assert_eq!(divergence.offset, 89);
+        assert_eq!(divergence.declared_length, 100);
+        assert!(divergence
+            .reason
+            .contains("`body.dead_reckoning_parameters.other_parameters` does not fit"));
+
+        let mut long = data.clone();
+        long.extend_from_slice(&[0; 8]);
+        long[8..10].copy_from_slice(&(data.len() as u16 + 8).to_be_bytes());
+        let dissection = dissect(&long);
+        let divergence = dissection.divergence.unwrap();
+        assert_eq!(divergence.offset, data.len());
+        assert_eq!(divergence.parsed_length, data.len());
+        let unparsed = dissection.pdus[0].get("unparsed").unwrap();
+        assert_eq!((unparsed.offset, unparsed.length), (data.len(), 8));
+
+        let divergence = dissect(&data[..150]).divergence.unwrap();
+        assert_eq!(divergence.offset, 148);
+        assert!(divergence.reason.contains("ends 10 octets before"));
+
+        let divergence = dissect(&data[..6]).divergence.unwrap();
+        assert_eq!(divergence.offset, 6);
+        assert!(dissect(&[]).pdus.is_empty());
+    }
+
+    #[test]
+    fn dissect_bundle_with_generic_body() {
+        let other = Pdu::finalize_from_parts(
+            PduHeader::new_v7(1, PduType::Other),
+            Other::builder()
+                .with_body(vec![0xAB; 12])
+                .build()
+                .into_pdu_body(),
+            DisTimeStamp::new_absolute_from_secs(100),
+        );
+        let start_resume = Pdu::finalize_from_parts(
+            PduHeader::new_v7(1, PduType::StartResume),
+            StartResume::builder().build().into_pdu_body(),
+            DisTimeStamp::new_absolute_from_secs(100),
+        );
+        let data = serialize(&[other, start_resume]);
+        let dissection = dissect(&data);
+        assert_eq!(dissection.divergence, None);
+        assert_eq!(dissection.pdus.len(), 2);
+
+        assert!(dissection.pdus[0].get("body").unwrap().is_leaf());
+        let second = &dissection.pdus[1];
+        assert_eq!(second.offset, 24);
+        assert_eq!(
+            second.get("header.pdu_type").unwrap().description,
+            Some(PduType::StartResume.to_string())
+        );
+        let body = second.get("body").unwrap();
+        assert!(body.get("simulation_time.time_past_hour").is_some());
+        assert_eq!(body.offset + body.length, data.len());
+    }
+
+    #[test]
+    fn dissect_the_fields_of_the_models() {
+        let time_stamp = DisTimeStamp::new_absolute_from_secs(100);
+        let pdus = [
+            Pdu::finalize_from_parts(
+                PduHeader::new_v7(1, PduType::Collision),
+                Collision::builder()
+                    .with_issuing_entity_id(EntityId::new(1, 2, 3))
+                    .with_mass(1000.0)
+                    .build()
+                    .into_pdu_body(),
+                time_stamp,
+            ),
+            Pdu::finalize_from_parts(
+                PduHeader::new_v7(1, PduType::ActionRequest),
+                ActionRequest::builder()
+                    .with_request_id(7)
+                    .with_fixed_datums(vec![FixedDatum::new(VariableRecordType::from(1u32), 5)])
+                    .with_variable_datums(vec![VariableDatum::new(
+                        VariableRecordType::from(2u32),
+                        vec![1, 2, 3],
+                    )])
+                    .build()
+                    .into_pdu_body(),
+                time_stamp,
+            ),
+            Pdu::finalize_from_parts(
+                PduHeader::new_v7(1, PduType::Signal),
+                Signal::builder()
+                    .with_radio_number(1)
+                    .with_data(vec![1, 2, 3, 4, 5, 6])
+                    .build()
+                    .into_pdu_body(),
+                time_stamp,
+            ),
+        ];
+        for pdu in &pdus {
+            let data = serialize(std::slice::from_ref(pdu));
+            let dissection = dissect(&data);
+            assert_eq!(dissection.divergence, None);
+            let dissected = &dissection.pdus[0];
+            assert_eq!(leaf_octets(dissected), data.len());
+
+            // every field of the model has a node, named after the field
+            let body = dissected.get("body").unwrap();
+            let Some((_, Value::Record(model))) = pdu.body.fields().into_iter().next() else {
+                panic!("The body of a {:?} PDU has no model", pdu.header.pdu_type);
+            };
+            for name in model.field_names() {
+                assert!(
+                    body.children
+                        .iter()
+                        .any(|child| child.name.split('[').next() == Some(*name)),
+                    "{name} is not dissected"
+                );
+            }
+        }
+
+        let dissection = dissect(&serialize(&pdus[1..]));
+        let action_request = &dissection.pdus[0];
+        let datum = action_request
+            .get("body.variable_datum_records[0].datum_value")
+            .unwrap();
+        assert_eq!(datum.raw, vec![1, 2, 3]);
+        assert_eq!(
+            action_request
+                .get("body.variable_datum_records[0].padding")
+                .unwrap()
+                .length,
+            5
+        );
+        let encoding_scheme = dissection.pdus[1].get("body.encoding_scheme").unwrap();
+        assert!(encoding_scheme.value.is_some());
+    }
+}
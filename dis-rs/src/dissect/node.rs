@@ -0,0 +1,72 @@
+use std::fmt::{Display, Formatter};
+
+/// A field (or record of fields) of a dissected PDU, with its position in the dissected data.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DissectedNode {
+    /// The name of the field, e.g. `entity_id` or `variable_parameters[0]`.
+    pub name: String,
+    /// The offset of the field in the dissected data, in octets.
+    pub offset: usize,
+    /// The length of the field, in octets.
+    pub length: usize,
+    /// The octets of the field.
+    pub raw: Vec<u8>,
+    /// The decoded value of the field, when it has one.
+    pub value: Option<String>,
+    /// The description of an enumerated value or of a record that is decoded as a whole.
+    pub description: Option<String>,
+    /// The fields of a record, in the order they appear in the data.
+    pub children: Vec<DissectedNode>,
+}
+
+impl DissectedNode {
+    /// The descendant node at `path`, which consists of node names separated by dots,
+    /// e.g. `body.entity_id.site_id`.
+    #[must_use]
+    pub fn get(&self, path: &str) -> Option<&DissectedNode> {
+        path.split('.').try_fold(self, |node, name| {
+            node.children.iter().find(|child| child.name == name)
+        })
+    }
+
+    /// Whether the node is a field without nested fields.
+    #[must_use]
+    pub fn is_leaf(&self) -> bool {
+        self.children.is_empty()
+    }
+}
+
+/// The point where parsing a PDU diverges from the length declared in its header.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Divergence {
+    /// The offset of the PDU in the dissected data.
+    pub pdu_offset: usize,
+    /// The offset in the dissected data where parsing diverges.
+    pub offset: usize,
+    /// The length of the PDU as declared in its header.
+    pub declared_length: usize,
+    /// The number of octets of the PDU that were parsed.
+    pub parsed_length: usize,
+    pub reason: String,
+}
+
+impl Display for Divergence {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Parsing diverges at octet {} ({} octets into the PDU at octet {}): {}",
+            self.offset,
+            self.offset - self.pdu_offset,
+            self.pdu_offset,
+            self.reason
+        )
+    }
+}
+
+/// The result of dissecting a datagram: a tree of nodes per PDU,
+/// and the first point where parsing diverges from the declared PDU length, if any.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Dissection {
+    pub pdus: Vec<DissectedNode>,
+    pub divergence: Option<Divergence>,
+}
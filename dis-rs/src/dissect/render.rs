@@ -0,0 +1,99 @@
+use crate::dissect::node::{DissectedNode, Dissection};
+use std::fmt::{Display, Formatter, Write};
+
+const INDENT: usize = 2;
+const OCTETS_PER_LINE: usize = 16;
+const HEX_COLUMN_WIDTH: usize = OCTETS_PER_LINE * 3 - 1;
+
+impl Dissection {
+    /// Renders the dissection as an indented tree with one field per line,
+    /// listing the offset and length of each field in octets, followed by its name, value and description.
+    #[must_use]
+    pub fn to_text(&self) -> String {
+        let mut text = String::new();
+        for pdu in &self.pdus {
+            write_tree(&mut text, pdu, 0);
+        }
+        if let Some(divergence) = &self.divergence {
+            let _ = writeln!(text, "{divergence}");
+        }
+        text
+    }
+
+    /// Renders the dissected octets as a hexdump, annotating the first line of each field
+    /// with its path, value and description.
+    #[must_use]
+    pub fn to_hexdump(&self) -> String {
+        let mut text = String::new();
+        for pdu in &self.pdus {
+            let _ = writeln!(text, "{}{}", pdu.name, annotation(pdu));
+            write_leaves(&mut text, pdu, "");
+        }
+        if let Some(divergence) = &self.divergence {
+            let _ = writeln!(text, "{divergence}");
+        }
+        text
+    }
+}
+
+impl Display for Dissection {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.to_text())
+    }
+}
+
+fn write_tree(text: &mut String, node: &DissectedNode, depth: usize) {
+    let _ = writeln!(
+        text,
+        "{:>6} {:>5}  {:indent$}{}{}",
+        node.offset,
+        node.length,
+        "",
+        node.name,
+        annotation(node),
+        indent = depth * INDENT
+    );
+    for child in &node.children {
+        write_tree(text, child, depth + 1);
+    }
+}
+
+fn write_leaves(text: &mut String, node: &DissectedNode, path: &str) {
+    for child in &node.children {
+        let path = if path.is_empty() {
+            child.name.clone()
+        } else {
+            format!("{path}.{}", child.name)
+        };
+        if !child.is_leaf() {
+            write_leaves(text, child, &path);
+            continue;
+        }
+        for (line, octets) in child.raw.chunks(OCTETS_PER_LINE).enumerate() {
+            let offset = child.offset + line * OCTETS_PER_LINE;
+            let hex = octets
+                .iter()
+                .map(|octet| format!("{octet:02x}"))
+                .collect::<Vec<_>>()
+                .join(" ");
+            let _ = if line == 0 {
+                writeln!(
+                    text,
+                    "{offset:04x}  {hex:<HEX_COLUMN_WIDTH$}  {path}{}",
+                    annotation(child)
+                )
+            } else {
+                writeln!(text, "{offset:04x}  {hex}")
+            };
+        }
+    }
+}
+
+fn annotation(node: &DissectedNode) -> String {
+    match (&node.value, &node.description) {
+        (Some(value), Some(description)) => format!(": {value} ({description})"),
+        (Some(value), None) => format!(": {value}"),
+        (None, Some(description)) => format!(" ({description})"),
+        (None, None) => String::new(),
+    }
+}
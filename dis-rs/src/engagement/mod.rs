@@ -0,0 +1,211 @@
+pub mod tracker;
+
+#[cfg(test)]
+mod tests {
+    use crate::detonation::model::Detonation;
+    use crate::engagement::tracker::{EngagementOutcome, EngagementTracker};
+    use crate::entity_state::model::{DrParameters, EntityState};
+    use crate::enumerations::{DeadReckoningAlgorithm, PduType};
+    use crate::fire::model::Fire;
+    use crate::model::{
+        DisTimeStamp, EntityId, EventId, Location, Pdu, PduBody, PduHeader, VectorF32,
+    };
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    fn now() -> SystemTime {
+        UNIX_EPOCH + Duration::from_secs(1_700_000_000)
+    }
+
+    fn fire(munition: EntityId) -> PduBody {
+        Fire::builder()
+            .with_firing_entity_id(EntityId::new(1, 1, 1))
+            .with_target_entity_id(EntityId::new(2, 1, 1))
+            .with_entity_id(munition)
+            .with_event_id(EventId::new(1, 1, 7))
+            .with_location_in_world(Location::new(0.0, 0.0, 0.0))
+            .build()
+            .into_pdu_body()
+    }
+
+    fn at(body: PduBody, units_past_the_hour: u32) -> Pdu {
+        Pdu::finalize_from_parts(
+            PduHeader::new_v7(1, PduType::Other),
+            body,
+            DisTimeStamp::new_absolute_from_units(units_past_the_hour),
+        )
+    }
+
+    fn detonation(munition: EntityId, location: Location) -> PduBody {
+        Detonation::builder()
+            .with_source_entity_id(EntityId::new(1, 1, 1))
+            .with_exploding_entity_id(munition)
+            .with_event_id(EventId::new(1, 1, 7))
+            .with_world_location(location)
+            .build()
+            .into_pdu_body()
+    }
+
+    #[test]
+    fn dead_reckoned_location() {
+        let state = EntityState::builder()
+            .with_location(Location::new(100.0, 0.0, 0.0))
+            .with_velocity(VectorF32::new(10.0, 0.0, 0.0))
+            .with_dead_reckoning_parameters(
+                DrParameters::default()
+                    .with_algorithm(DeadReckoningAlgorithm::DRM_FVW_HighSpeedOrManeuveringEntity)
+                    .with_linear_acceleration(VectorF32::new(0.0, 2.0, 0.0)),
+            )
+            .build();
+        let location = state.dead_reckoned_location(Duration::from_secs(2));
+        assert_eq!(location, Location::new(120.0, 4.0, 0.0));
+
+        let state = state
+            .into_builder()
+            .with_dead_reckoning_parameters(
+                DrParameters::default()
+                    .with_algorithm(DeadReckoningAlgorithm::StaticNonmovingEntity),
+            )
+            .build();
+        let location = state.dead_reckoned_location(Duration::from_secs(2));
+        assert_eq!(location, Location::new(100.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn fire_and_detonation_are_correlated() {
+        let mut tracker = EngagementTracker::new();
+        let munition = EntityId::new(1, 1, 100);
+        let target = EntityState::builder()
+            .with_entity_id(EntityId::new(2, 1, 1))
+            .with_location(Location::new(1000.0, 0.0, 0.0))
+            .with_velocity(VectorF32::new(0.0, 10.0, 0.0))
+            .with_dead_reckoning_parameters(DrParameters::default().with_algorithm(
+                DeadReckoningAlgorithm::DRM_FPW_ConstantVelocityLowAccelerationLinearMotionEntity,
+            ))
+            .build()
+            .into_pdu_body();
+
+        assert!(tracker.process(&target, now()).is_none());
+        assert!(tracker.process(&fire(munition), now()).is_none());
+        assert_eq!(tracker.pending_count(), 1);
+
+        let detonated_at = now() + Duration::from_secs(3);
+        let other_event = Detonation::builder()
+            .with_source_entity_id(EntityId::new(1, 1, 1))
+            .with_exploding_entity_id(munition)
+            .with_event_id(EventId::new(1, 1, 8))
+            .build()
+            .into_pdu_body();
+        let record = tracker.process(&other_event, detonated_at).unwrap();
+        assert_eq!(record.outcome, EngagementOutcome::DetonationWithoutFire);
+        assert!(record.fired_at.is_none());
+        assert_eq!(tracker.pending_count(), 1);
+
+        let record = tracker
+            .process(
+                &detonation(munition, Location::new(1000.0, 26.0, 0.0)),
+                detonated_at,
+            )
+            .unwrap();
+        assert_eq!(record.outcome, EngagementOutcome::Detonated);
+        assert_eq!(record.target_entity_id, EntityId::new(2, 1, 1));
+        assert_eq!(record.time_of_flight, Some(Duration::from_secs(3)));
+        assert_eq!(
+            record.target_location,
+            Some(Location::new(1000.0, 30.0, 0.0))
+        );
+        assert_eq!(record.miss_distance, Some(4.0));
+        assert_eq!(tracker.pending_count(), 0);
+    }
+
+    #[test]
+    fn munition_mismatch_is_reported() {
+        let mut tracker = EngagementTracker::new();
+        tracker.process(&fire(EntityId::new(1, 1, 100)), now());
+
+        let other_munition = detonation(EntityId::new(1, 1, 101), Location::default());
+        let record = tracker
+            .process(&other_munition, now() + Duration::from_secs(3))
+            .unwrap();
+        assert_eq!(record.outcome, EngagementOutcome::MunitionMismatch);
+        assert_eq!(record.munition_entity_id, EntityId::new(1, 1, 101));
+        assert_eq!(record.fired_at, Some(now()));
+        assert_eq!(record.time_of_flight, Some(Duration::from_secs(3)));
+        assert_eq!(tracker.pending_count(), 0);
+    }
+
+    #[test]
+    fn time_of_flight_from_absolute_timestamps() {
+        let munition = EntityId::new(1, 1, 100);
+        let mut tracker = EngagementTracker::new();
+        tracker.process_pdu(&at(fire(munition), 1 << 30), now());
+        let record = tracker
+            .process_pdu(
+                &at(
+                    detonation(munition, Location::default()),
+                    (1 << 30) + (1 << 27),
+                ),
+                now() + Duration::from_secs(3),
+            )
+            .unwrap();
+        assert_eq!(record.time_of_flight, Some(Duration::from_secs(225)));
+
+        tracker.process_pdu(&at(fire(munition), (1 << 31) - (1 << 27)), now());
+        let record = tracker
+            .process_pdu(
+                &at(detonation(munition, Location::default()), 1 << 26),
+                now() + Duration::from_secs(3),
+            )
+            .unwrap();
+        assert_eq!(record.time_of_flight, Some(Duration::from_millis(337_500)));
+
+        let relative = Pdu::finalize_from_parts(
+            PduHeader::new_v7(1, PduType::Fire),
+            fire(munition),
+            DisTimeStamp::new_relative_from_units(1 << 30),
+        );
+        tracker.process_pdu(&relative, now());
+        let record = tracker
+            .process_pdu(
+                &at(
+                    detonation(munition, Location::default()),
+                    (1 << 30) + (1 << 27),
+                ),
+                now() + Duration::from_secs(3),
+            )
+            .unwrap();
+        assert_eq!(record.time_of_flight, Some(Duration::from_secs(3)));
+    }
+
+    #[test]
+    fn repeated_fire_reports_earlier_shot() {
+        let mut tracker = EngagementTracker::new();
+        assert!(tracker
+            .process(&fire(EntityId::new(1, 1, 100)), now())
+            .is_none());
+
+        let record = tracker
+            .process(
+                &fire(EntityId::new(1, 1, 101)),
+                now() + Duration::from_secs(1),
+            )
+            .unwrap();
+        assert_eq!(record.outcome, EngagementOutcome::Superseded);
+        assert_eq!(record.munition_entity_id, EntityId::new(1, 1, 100));
+        assert_eq!(record.fired_at, Some(now()));
+        assert_eq!(tracker.pending_count(), 1);
+    }
+
+    #[test]
+    fn unmatched_shots_time_out() {
+        let mut tracker = EngagementTracker::new().with_timeout(Duration::from_secs(10));
+        tracker.process(&fire(EntityId::new(1, 1, 100)), now());
+
+        assert!(tracker.poll(now() + Duration::from_secs(9)).is_empty());
+        let records = tracker.poll(now() + Duration::from_secs(10));
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].outcome, EngagementOutcome::TimedOut);
+        assert_eq!(records[0].event_id, EventId::new(1, 1, 7));
+        assert!(records[0].time_of_flight.is_none());
+        assert_eq!(tracker.pending_count(), 0);
+    }
+}
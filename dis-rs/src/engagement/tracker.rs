@@ -0,0 +1,284 @@
+use crate::common::detonation::model::Detonation;
+use crate::common::entity_state::model::EntityState;
+use crate::common::fire::model::Fire;
+use crate::common::model::{
+    DescriptorRecord, DisTimeStamp, EntityId, EventId, Location, Pdu, PduBody,
+};
+use crate::enumerations::DetonationResult;
+use crate::fixed_parameters::NO_ENTITY;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::time::{Duration, SystemTime};
+
+/// The period over which the timestamp in the PDU header counts the time past the hour
+const ONE_HOUR: Duration = Duration::from_secs(3600);
+
+/// Default time after which a shot without a matching detonation is reported as timed out
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// The outcome of an engagement.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum EngagementOutcome {
+    /// A Fire PDU was matched by a Detonation PDU
+    Detonated,
+    /// No Detonation PDU was received for a Fire PDU within the timeout
+    TimedOut,
+    /// A Detonation PDU was received without a matching Fire PDU
+    DetonationWithoutFire,
+    /// A Detonation PDU matched a Fire PDU by firing entity and `EventId`, but reported another munition
+    MunitionMismatch,
+    /// Another Fire PDU with the same firing entity and `EventId` was received before a Detonation PDU
+    Superseded,
+}
+
+/// A completed engagement, correlating a Fire PDU and a Detonation PDU.
+///
+/// The fields of the Fire or Detonation PDU are `None` when the respective PDU was not received.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct EngagementRecord {
+    pub event_id: EventId,
+    pub firing_entity_id: EntityId,
+    pub target_entity_id: EntityId,
+    pub munition_entity_id: EntityId,
+    pub descriptor: DescriptorRecord,
+    pub fire_mission_index: Option<u32>,
+    pub fired_at: Option<SystemTime>,
+    pub fire_location: Option<Location>,
+    pub detonated_at: Option<SystemTime>,
+    pub detonation_location: Option<Location>,
+    pub detonation_result: Option<DetonationResult>,
+    pub time_of_flight: Option<Duration>,
+    /// The dead-reckoned location of the target at the time of detonation
+    pub target_location: Option<Location>,
+    /// The distance in meters between the detonation and the dead-reckoned location of the target
+    pub miss_distance: Option<f64>,
+    pub outcome: EngagementOutcome,
+}
+
+#[derive(Clone, Debug)]
+struct Shot {
+    fire: Fire,
+    fired_at: SystemTime,
+    /// The absolute timestamp of the Fire PDU as time past the hour, if known
+    fired_past_the_hour: Option<Duration>,
+}
+
+impl Shot {
+    /// The engagement of a shot that is not matched by a Detonation PDU.
+    fn into_record(self, outcome: EngagementOutcome) -> EngagementRecord {
+        EngagementRecord {
+            event_id: self.fire.event_id,
+            firing_entity_id: self.fire.firing_entity_id,
+            target_entity_id: self.fire.target_entity_id,
+            munition_entity_id: self.fire.entity_id,
+            descriptor: self.fire.descriptor,
+            fire_mission_index: Some(self.fire.fire_mission_index),
+            fired_at: Some(self.fired_at),
+            fire_location: Some(self.fire.location_in_world),
+            detonated_at: None,
+            detonation_location: None,
+            detonation_result: None,
+            time_of_flight: None,
+            target_location: None,
+            miss_distance: None,
+            outcome,
+        }
+    }
+}
+
+/// Correlates Fire and Detonation PDUs into engagements.
+///
+/// Fire and Detonation PDUs are matched by the firing entity and `EventId`, and by the munition entity when the
+/// munition is tracked. Entity State PDUs are used to dead-reckon the location of the target at the time of
+/// detonation, to compute the miss distance. Shots without a detonation are reported as timed out by
+/// `EngagementTracker::poll`, or as superseded when another Fire PDU reuses their firing entity and `EventId`.
+/// Laser Engagement and Directed Energy Fire PDUs are not modelled yet, and are ignored.
+///
+/// The caller is responsible for feeding received PDUs to `EngagementTracker::process_pdu` (or their bodies to
+/// `EngagementTracker::process`) together with their reception time, and for regularly calling
+/// `EngagementTracker::poll`. The time of flight is computed from the header timestamps when those of both the
+/// Fire and the Detonation PDU are absolute, and from the reception times otherwise.
+#[derive(Clone, Debug)]
+pub struct EngagementTracker {
+    timeout: Duration,
+    shots: HashMap<(EntityId, EventId), Shot>,
+    entities: HashMap<EntityId, (EntityState, SystemTime)>,
+}
+
+impl Default for EngagementTracker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EngagementTracker {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            timeout: DEFAULT_TIMEOUT,
+            shots: HashMap::new(),
+            entities: HashMap::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    #[must_use]
+    pub fn timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    /// The number of shots awaiting a detonation.
+    #[must_use]
+    pub fn pending_count(&self) -> usize {
+        self.shots.len()
+    }
+
+    /// Processes a PDU received at `now`, returning the engagement that is completed by it, if any.
+    pub fn process_pdu(&mut self, pdu: &Pdu, now: SystemTime) -> Option<EngagementRecord> {
+        self.process_at(
+            &pdu.body,
+            now,
+            absolute_past_the_hour(pdu.header.time_stamp),
+        )
+    }
+
+    /// Processes a PDU body received at `now`, returning the engagement that is completed by it, if any.
+    pub fn process(&mut self, body: &PduBody, now: SystemTime) -> Option<EngagementRecord> {
+        self.process_at(body, now, None)
+    }
+
+    fn process_at(
+        &mut self,
+        body: &PduBody,
+        now: SystemTime,
+        past_the_hour: Option<Duration>,
+    ) -> Option<EngagementRecord> {
+        match body {
+            PduBody::EntityState(state) => {
+                self.entities.insert(state.entity_id, (state.clone(), now));
+                None
+            }
+            PduBody::Fire(fire) => self
+                .shots
+                .insert(
+                    (fire.firing_entity_id, fire.event_id),
+                    Shot {
+                        fire: fire.clone(),
+                        fired_at: now,
+                        fired_past_the_hour: past_the_hour,
+                    },
+                )
+                .map(|earlier| earlier.into_record(EngagementOutcome::Superseded)),
+            PduBody::Detonation(detonation) => Some(self.detonate(detonation, now, past_the_hour)),
+            _ => None,
+        }
+    }
+
+    /// Reports the shots that did not detonate within the timeout at `now`.
+    pub fn poll(&mut self, now: SystemTime) -> Vec<EngagementRecord> {
+        let timeout = self.timeout;
+        let expired: Vec<_> = self
+            .shots
+            .iter()
+            .filter(|(_, shot)| {
+                now.duration_since(shot.fired_at)
+                    .is_ok_and(|elapsed| elapsed >= timeout)
+            })
+            .map(|(key, _)| *key)
+            .collect();
+
+        let mut records: Vec<_> = expired
+            .iter()
+            .filter_map(|key| self.shots.remove(key))
+            .map(|shot| shot.into_record(EngagementOutcome::TimedOut))
+            .collect();
+        records.sort_by_key(|record| record.fired_at);
+        records
+    }
+
+    /// Stops tracking the state of an entity, e.g. when it is removed from the exercise.
+    pub fn forget_entity(&mut self, entity_id: &EntityId) {
+        self.entities.remove(entity_id);
+    }
+
+    fn detonate(
+        &mut self,
+        detonation: &Detonation,
+        now: SystemTime,
+        past_the_hour: Option<Duration>,
+    ) -> EngagementRecord {
+        let shot = self
+            .shots
+            .remove(&(detonation.source_entity_id, detonation.event_id));
+        let matches_munition = shot.as_ref().is_some_and(|shot| {
+            shot.fire.entity_id.entity_id == NO_ENTITY
+                || shot.fire.entity_id == detonation.exploding_entity_id
+        });
+
+        let target_entity_id = match &shot {
+            Some(shot) if detonation.target_entity_id.entity_id == NO_ENTITY => {
+                shot.fire.target_entity_id
+            }
+            _ => detonation.target_entity_id,
+        };
+        let target_location = self
+            .entities
+            .get(&target_entity_id)
+            .map(|(state, received)| {
+                state.dead_reckoned_location(now.duration_since(*received).unwrap_or_default())
+            });
+        let miss_distance = target_location
+            .map(|target| distance(&target, &detonation.location_in_world_coordinates));
+
+        EngagementRecord {
+            event_id: detonation.event_id,
+            firing_entity_id: detonation.source_entity_id,
+            target_entity_id,
+            munition_entity_id: detonation.exploding_entity_id,
+            descriptor: detonation.descriptor.clone(),
+            fire_mission_index: shot.as_ref().map(|shot| shot.fire.fire_mission_index),
+            fired_at: shot.as_ref().map(|shot| shot.fired_at),
+            fire_location: shot.as_ref().map(|shot| shot.fire.location_in_world),
+            detonated_at: Some(now),
+            detonation_location: Some(detonation.location_in_world_coordinates),
+            detonation_result: Some(detonation.detonation_result),
+            time_of_flight: shot.as_ref().map(|shot| {
+                match (shot.fired_past_the_hour, past_the_hour) {
+                    (Some(fired), Some(detonated)) if detonated >= fired => detonated - fired,
+                    (Some(fired), Some(detonated)) => detonated + ONE_HOUR - fired,
+                    _ => now.duration_since(shot.fired_at).unwrap_or_default(),
+                }
+            }),
+            target_location,
+            miss_distance,
+            outcome: match shot {
+                Some(_) if matches_munition => EngagementOutcome::Detonated,
+                Some(_) => EngagementOutcome::MunitionMismatch,
+                None => EngagementOutcome::DetonationWithoutFire,
+            },
+        }
+    }
+}
+
+/// Converts the timestamp of a PDU header to the time past the hour, if the timestamp is absolute.
+fn absolute_past_the_hour(time_stamp: u32) -> Option<Duration> {
+    match DisTimeStamp::from(time_stamp) {
+        absolute @ DisTimeStamp::Absolute { .. } => Some(absolute.duration_past_the_hour()),
+        DisTimeStamp::Relative { .. } => None,
+    }
+}
+
+fn distance(a: &Location, b: &Location) -> f64 {
+    let dx = a.x_coordinate - b.x_coordinate;
+    let dy = a.y_coordinate - b.y_coordinate;
+    let dz = a.z_coordinate - b.z_coordinate;
+    (dx * dx + dy * dy + dz * dz).sqrt()
+}
@@ -0,0 +1,88 @@
+pub mod pattern;
+pub mod table;
+
+#[cfg(test)]
+mod tests {
+    use crate::entity_type::pattern::{EntityTypePattern, FieldPattern};
+    use crate::entity_type::table::EntityTypeTable;
+    use crate::model::EntityType;
+    use crate::DisError;
+    use std::cmp::Ordering;
+    use std::str::FromStr;
+
+    fn entity_type(s: &str) -> EntityType {
+        EntityType::from_str(s).unwrap()
+    }
+
+    fn pattern(s: &str) -> EntityTypePattern {
+        EntityTypePattern::from_str(s).unwrap()
+    }
+
+    #[test]
+    fn parse_and_match_patterns() {
+        let ranged = pattern("1.2.*.1-3.*.*.*");
+        assert_eq!(ranged.fields()[2], FieldPattern::Any);
+        assert_eq!(ranged.fields()[3], FieldPattern::Range(1, 3));
+        assert_eq!(ranged.to_string(), "1:2:*:1-3:*:*:*");
+        assert_eq!(pattern(&ranged.to_string()), ranged);
+        assert!(ranged.matches(&entity_type("1:2:225:2:5:0:0")));
+        assert!(!ranged.matches(&entity_type("1:2:225:4:5:0:0")));
+
+        assert_eq!(pattern("1.1.225"), pattern("1:1:225:*:*:*:*"));
+        assert_eq!(pattern("*"), EntityTypePattern::ANY);
+        let exact = EntityTypePattern::from(entity_type("1:1:225:1:1:3:0"));
+        assert!(exact.matches(&entity_type("1:1:225:1:1:3:0")));
+        assert!(!exact.matches(&entity_type("1:1:225:1:1:3:1")));
+
+        assert!(matches!(
+            EntityTypePattern::from_str("1.2.3.4.5.6.7.8"),
+            Err(DisError::ParseError(_))
+        ));
+        assert!(EntityTypePattern::from_str("1.300").is_err());
+        assert!(EntityTypePattern::from_str("1.2.225.3-1").is_err());
+        assert!(EntityTypePattern::from_str("1.x").is_err());
+    }
+
+    #[test]
+    fn order_by_specificity() {
+        assert_eq!(
+            pattern("1.1.225.*").cmp_specificity(&pattern("1.1.*.1")),
+            Ordering::Greater
+        );
+        assert_eq!(
+            pattern("1.1.1-2").cmp_specificity(&pattern("1.1.1-5")),
+            Ordering::Greater
+        );
+        assert_eq!(
+            pattern("1.1.1-5").cmp_specificity(&pattern("1.1.7")),
+            Ordering::Less
+        );
+        assert_eq!(
+            pattern("1.1.*").cmp_specificity(&pattern("1.2.*")),
+            Ordering::Equal
+        );
+    }
+
+    #[test]
+    fn most_specific_pattern_wins() {
+        let mut table: EntityTypeTable<u32> = [
+            (EntityTypePattern::ANY, 5),
+            (pattern("1.1.225"), 3),
+            (pattern("1.1.225.1.1.3"), 1),
+            (pattern("1.2"), 10),
+        ]
+        .into_iter()
+        .collect();
+
+        assert_eq!(table.get(&entity_type("1:1:225:1:1:3:0")), Some(&1));
+        assert_eq!(table.get(&entity_type("1:1:225:2:1:0:0")), Some(&3));
+        assert_eq!(table.get(&entity_type("1:2:153:2:1:0:0")), Some(&10));
+        assert_eq!(table.get(&entity_type("3:1:225:0:0:0:0")), Some(&5));
+
+        assert_eq!(table.insert(pattern("1.1.225"), 4), Some(3));
+        assert_eq!(table.get(&entity_type("1:1:225:2:1:0:0")), Some(&4));
+        assert_eq!(table.remove(&EntityTypePattern::ANY), Some(5));
+        assert_eq!(table.get(&entity_type("3:1:225:0:0:0:0")), None);
+        assert_eq!(table.len(), 3);
+    }
+}
@@ -0,0 +1,223 @@
+use crate::common::model::EntityType;
+use crate::DisError;
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+const NUM_FIELDS: usize = 7;
+const FIELD_NAMES: [&str; NUM_FIELDS] = [
+    "kind",
+    "domain",
+    "country",
+    "category",
+    "subcategory",
+    "specific",
+    "extra",
+];
+
+/// The pattern for a single field of an entity type.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum FieldPattern {
+    /// Matches any value, written as `*`.
+    #[default]
+    Any,
+    /// Matches a single value.
+    Value(u16),
+    /// Matches the values in an inclusive range, written as e.g. `1-3`.
+    Range(u16, u16),
+}
+
+impl FieldPattern {
+    /// Whether the value of a field matches this pattern.
+    #[must_use]
+    pub fn matches(self, value: u16) -> bool {
+        match self {
+            FieldPattern::Any => true,
+            FieldPattern::Value(expected) => value == expected,
+            FieldPattern::Range(low, high) => (low..=high).contains(&value),
+        }
+    }
+
+    /// Orders field patterns from least to most specific: any value, then wider to narrower ranges, then single values.
+    fn cmp_specificity(self, other: Self) -> Ordering {
+        let rank = |pattern: Self| match pattern {
+            FieldPattern::Any => (0, 0),
+            FieldPattern::Range(low, high) => (1, u16::MAX - (high - low)),
+            FieldPattern::Value(_) => (2, 0),
+        };
+        rank(self).cmp(&rank(other))
+    }
+}
+
+impl Display for FieldPattern {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FieldPattern::Any => f.write_str("*"),
+            FieldPattern::Value(value) => write!(f, "{value}"),
+            FieldPattern::Range(low, high) => write!(f, "{low}-{high}"),
+        }
+    }
+}
+
+/// A pattern that matches a group of entity types, such as "all land platforms of country 225".
+///
+/// Patterns are written like entity types, with the fields separated by `:` or `.`, where each field is a value,
+/// an inclusive range such as `1-3`, or `*` for any value. Fields that are left out at the end match any value,
+/// so `1.1.225` is the same pattern as `1.1.225.*.*.*.*`.
+///
+/// When serialized using `serde`, a pattern is represented by its string form.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(try_from = "String", into = "String")
+)]
+pub struct EntityTypePattern {
+    fields: [FieldPattern; NUM_FIELDS],
+}
+
+impl EntityTypePattern {
+    /// The pattern that matches all entity types.
+    pub const ANY: EntityTypePattern = EntityTypePattern {
+        fields: [FieldPattern::Any; NUM_FIELDS],
+    };
+
+    /// Creates a pattern from the patterns for the kind, domain, country, category, subcategory,
+    /// specific and extra fields.
+    #[must_use]
+    pub fn new(fields: [FieldPattern; NUM_FIELDS]) -> Self {
+        Self { fields }
+    }
+
+    /// The patterns for the kind, domain, country, category, subcategory, specific and extra fields.
+    #[must_use]
+    pub fn fields(&self) -> &[FieldPattern; NUM_FIELDS] {
+        &self.fields
+    }
+
+    /// Whether the entity type matches this pattern.
+    #[must_use]
+    pub fn matches(&self, entity_type: &EntityType) -> bool {
+        self.fields
+            .iter()
+            .zip(field_values(entity_type))
+            .all(|(pattern, value)| pattern.matches(value))
+    }
+
+    /// Orders patterns from least to most specific.
+    ///
+    /// The fields are compared in order from kind to extra, so that a pattern that is more specific for a
+    /// higher-level field is more specific overall: `1.1.225.*` is more specific than `1.1.*.1`.
+    /// Per field, a single value is more specific than a range, a narrower range is more specific than a wider range,
+    /// and any range is more specific than any value.
+    #[must_use]
+    pub fn cmp_specificity(&self, other: &Self) -> Ordering {
+        self.fields
+            .iter()
+            .zip(&other.fields)
+            .map(|(a, b)| a.cmp_specificity(*b))
+            .find(|ordering| ordering.is_ne())
+            .unwrap_or(Ordering::Equal)
+    }
+}
+
+fn field_values(entity_type: &EntityType) -> [u16; NUM_FIELDS] {
+    [
+        u16::from(u8::from(entity_type.kind)),
+        u16::from(u8::from(entity_type.domain)),
+        u16::from(entity_type.country),
+        u16::from(entity_type.category),
+        u16::from(entity_type.subcategory),
+        u16::from(entity_type.specific),
+        u16::from(entity_type.extra),
+    ]
+}
+
+/// A pattern that matches exactly the entity type.
+impl From<EntityType> for EntityTypePattern {
+    fn from(entity_type: EntityType) -> Self {
+        Self {
+            fields: field_values(&entity_type).map(FieldPattern::Value),
+        }
+    }
+}
+
+impl Display for EntityTypePattern {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let [first, rest @ ..] = &self.fields;
+        write!(f, "{first}")?;
+        for field in rest {
+            write!(f, ":{field}")?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for EntityTypePattern {
+    type Err = DisError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parts: Vec<&str> = s.trim().split([':', '.']).collect();
+        if parts.len() > NUM_FIELDS {
+            return Err(DisError::ParseError(format!(
+                "EntityTypePattern string pattern contains more than {NUM_FIELDS} fields"
+            )));
+        }
+
+        let mut fields = [FieldPattern::Any; NUM_FIELDS];
+        for (index, part) in parts.into_iter().enumerate() {
+            let name = FIELD_NAMES[index];
+            let max = if index == 2 {
+                u16::MAX
+            } else {
+                u16::from(u8::MAX)
+            };
+            let value = |digits: &str| {
+                digits
+                    .parse::<u16>()
+                    .ok()
+                    .filter(|value| *value <= max)
+                    .ok_or_else(|| DisError::ParseError(format!("Invalid {name} pattern `{part}`")))
+            };
+            fields[index] = match part.split_once('-') {
+                _ if part == "*" => FieldPattern::Any,
+                Some((low, high)) => {
+                    let (low, high) = (value(low)?, value(high)?);
+                    if low > high {
+                        return Err(DisError::ParseError(format!(
+                            "Invalid {name} pattern `{part}`, the range is empty"
+                        )));
+                    }
+                    FieldPattern::Range(low, high)
+                }
+                None => FieldPattern::Value(value(part)?),
+            };
+        }
+        Ok(Self { fields })
+    }
+}
+
+impl TryFrom<&str> for EntityTypePattern {
+    type Error = DisError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        EntityTypePattern::from_str(value)
+    }
+}
+
+impl TryFrom<String> for EntityTypePattern {
+    type Error = DisError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        EntityTypePattern::from_str(&value)
+    }
+}
+
+impl From<EntityTypePattern> for String {
+    fn from(pattern: EntityTypePattern) -> Self {
+        pattern.to_string()
+    }
+}
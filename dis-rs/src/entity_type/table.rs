@@ -0,0 +1,85 @@
+use crate::common::model::EntityType;
+use crate::entity_type::pattern::EntityTypePattern;
+
+/// A lookup table keyed by entity type patterns, where the most specific matching pattern wins.
+///
+/// Useful for configuration that is defined for groups of entity types, such as dead reckoning parameters
+/// or heartbeat intervals, with more specific entries overriding general ones.
+#[derive(Clone, Debug, PartialEq)]
+pub struct EntityTypeTable<T> {
+    /// The entries, ordered from most to least specific pattern.
+    entries: Vec<(EntityTypePattern, T)>,
+}
+
+impl<T> EntityTypeTable<T> {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            entries: Vec::new(),
+        }
+    }
+
+    /// Inserts a value for a pattern, returning the value that was stored for the same pattern, if any.
+    pub fn insert(&mut self, pattern: EntityTypePattern, value: T) -> Option<T> {
+        if let Some((_, existing)) = self.entries.iter_mut().find(|(p, _)| *p == pattern) {
+            return Some(std::mem::replace(existing, value));
+        }
+        let index = self
+            .entries
+            .partition_point(|(p, _)| p.cmp_specificity(&pattern).is_ge());
+        self.entries.insert(index, (pattern, value));
+        None
+    }
+
+    /// Removes the value for a pattern.
+    pub fn remove(&mut self, pattern: &EntityTypePattern) -> Option<T> {
+        let index = self.entries.iter().position(|(p, _)| p == pattern)?;
+        Some(self.entries.remove(index).1)
+    }
+
+    /// The value of the most specific pattern that matches the entity type.
+    #[must_use]
+    pub fn get(&self, entity_type: &EntityType) -> Option<&T> {
+        self.get_entry(entity_type).map(|(_, value)| value)
+    }
+
+    /// The most specific pattern that matches the entity type, with its value.
+    #[must_use]
+    pub fn get_entry(&self, entity_type: &EntityType) -> Option<(&EntityTypePattern, &T)> {
+        self.entries
+            .iter()
+            .find(|(pattern, _)| pattern.matches(entity_type))
+            .map(|(pattern, value)| (pattern, value))
+    }
+
+    /// The entries, ordered from most to least specific pattern.
+    pub fn entries(&self) -> impl Iterator<Item = (&EntityTypePattern, &T)> {
+        self.entries.iter().map(|(pattern, value)| (pattern, value))
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+impl<T> Default for EntityTypeTable<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> FromIterator<(EntityTypePattern, T)> for EntityTypeTable<T> {
+    fn from_iter<I: IntoIterator<Item = (EntityTypePattern, T)>>(iter: I) -> Self {
+        let mut table = Self::new();
+        for (pattern, value) in iter {
+            table.insert(pattern, value);
+        }
+        table
+    }
+}
@@ -0,0 +1,195 @@
+use crate::common::model::{
+    DisTimeStamp, EntityId, EventId, Pdu, PduBody, PduHeader, PduStatus, SimulationAddress,
+    TimeStamp,
+};
+use crate::common::{BodyInfo, Interaction};
+use crate::enumerations::ProtocolVersion;
+use crate::DisError;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Source of the timestamps of the PDUs created by an `Exercise`.
+pub trait ClockSource {
+    fn time_stamp(&self) -> TimeStamp;
+}
+
+impl<F: Fn() -> TimeStamp> ClockSource for F {
+    fn time_stamp(&self) -> TimeStamp {
+        self()
+    }
+}
+
+/// Clock source using the system clock, producing absolute (UTC) or relative timestamps.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct SystemClock {
+    absolute: bool,
+}
+
+impl Default for SystemClock {
+    fn default() -> Self {
+        Self::absolute()
+    }
+}
+
+impl SystemClock {
+    /// A clock producing absolute timestamps, for simulations synchronised to UTC.
+    #[must_use]
+    pub fn absolute() -> Self {
+        Self { absolute: true }
+    }
+
+    /// A clock producing relative timestamps, for simulations that are not synchronised to UTC.
+    #[must_use]
+    pub fn relative() -> Self {
+        Self { absolute: false }
+    }
+
+    /// The timestamp of the provided point in time.
+    #[must_use]
+    pub fn time_stamp_at(self, time: SystemTime) -> TimeStamp {
+        let since_epoch = time.duration_since(UNIX_EPOCH).unwrap_or_default();
+        if self.absolute {
+            DisTimeStamp::new_absolute_from_duration(since_epoch).into()
+        } else {
+            DisTimeStamp::new_relative_from_duration(since_epoch).into()
+        }
+    }
+}
+
+impl ClockSource for SystemClock {
+    fn time_stamp(&self) -> TimeStamp {
+        self.time_stamp_at(SystemTime::now())
+    }
+}
+
+/// The context of a simulation in a DIS exercise, used to stamp outgoing PDUs consistently.
+///
+/// The `Exercise` holds the protocol version, exercise ID and simulation address of the simulation,
+/// the clock source for timestamps, and the default `PduStatus` of the PDUs it creates.
+/// The PDU Status is only included for protocol versions that have one (DIS v7).
+#[derive(Clone, Debug)]
+pub struct Exercise<C: ClockSource = SystemClock> {
+    protocol_version: ProtocolVersion,
+    exercise_id: u8,
+    simulation_address: SimulationAddress,
+    pdu_status: PduStatus,
+    clock: C,
+}
+
+impl Exercise<SystemClock> {
+    /// Creates an `Exercise` using the system clock for absolute timestamps.
+    #[must_use]
+    pub fn new(
+        protocol_version: ProtocolVersion,
+        exercise_id: u8,
+        simulation_address: SimulationAddress,
+    ) -> Self {
+        Self {
+            protocol_version,
+            exercise_id,
+            simulation_address,
+            pdu_status: PduStatus::default(),
+            clock: SystemClock::default(),
+        }
+    }
+}
+
+impl<C: ClockSource> Exercise<C> {
+    #[must_use]
+    pub fn with_clock<D: ClockSource>(self, clock: D) -> Exercise<D> {
+        Exercise {
+            protocol_version: self.protocol_version,
+            exercise_id: self.exercise_id,
+            simulation_address: self.simulation_address,
+            pdu_status: self.pdu_status,
+            clock,
+        }
+    }
+
+    #[must_use]
+    pub fn with_pdu_status(mut self, pdu_status: PduStatus) -> Self {
+        self.pdu_status = pdu_status;
+        self
+    }
+
+    #[must_use]
+    pub fn protocol_version(&self) -> ProtocolVersion {
+        self.protocol_version
+    }
+
+    #[must_use]
+    pub fn exercise_id(&self) -> u8 {
+        self.exercise_id
+    }
+
+    #[must_use]
+    pub fn simulation_address(&self) -> SimulationAddress {
+        self.simulation_address
+    }
+
+    #[must_use]
+    pub fn pdu_status(&self) -> PduStatus {
+        self.pdu_status
+    }
+
+    #[must_use]
+    pub fn clock(&self) -> &C {
+        &self.clock
+    }
+
+    /// An `EntityId` with the simulation address of the exercise.
+    #[must_use]
+    pub fn entity_id(&self, entity_id: u16) -> EntityId {
+        EntityId::new_sim_address(self.simulation_address, entity_id)
+    }
+
+    /// An `EventId` with the simulation address of the exercise.
+    #[must_use]
+    pub fn event_id(&self, event_id: u16) -> EventId {
+        EventId::new_sim_address(self.simulation_address, event_id)
+    }
+
+    /// The header for a PDU with the provided body, without timestamp.
+    #[must_use]
+    pub fn header(&self, body: &PduBody) -> PduHeader {
+        let header = PduHeader::new(self.protocol_version, self.exercise_id, body.body_type());
+        if self.protocol_version == ProtocolVersion::IEEE1278_12012 {
+            header.with_pdu_status(self.pdu_status)
+        } else {
+            header
+        }
+    }
+
+    /// Creates a complete PDU from the body, timestamped by the clock source of the exercise.
+    #[must_use]
+    pub fn pdu(&self, body: PduBody) -> Pdu {
+        self.pdu_at(body, self.clock.time_stamp())
+    }
+
+    /// Creates a complete PDU from the body, with the provided timestamp.
+    #[must_use]
+    pub fn pdu_at(&self, body: PduBody, time_stamp: impl Into<TimeStamp>) -> Pdu {
+        Pdu::finalize_from_parts(self.header(&body), body, time_stamp)
+    }
+
+    /// Creates a complete PDU from the body, after validating the body with `Exercise::validate`.
+    ///
+    /// # Errors
+    /// Returns `DisError::ForeignOriginator` when the originator of the body does not belong to the simulation address.
+    pub fn checked_pdu(&self, body: PduBody) -> Result<Pdu, DisError> {
+        self.validate(&body)?;
+        Ok(self.pdu(body))
+    }
+
+    /// Validates that the originator of the body, if any, belongs to the simulation address of the exercise.
+    ///
+    /// # Errors
+    /// Returns `DisError::ForeignOriginator` when the originator of the body does not belong to the simulation address.
+    pub fn validate(&self, body: &PduBody) -> Result<(), DisError> {
+        match body.originator() {
+            Some(originator) if originator.simulation_address != self.simulation_address => {
+                Err(DisError::ForeignOriginator(*originator))
+            }
+            _ => Ok(()),
+        }
+    }
+}
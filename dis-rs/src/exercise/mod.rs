@@ -0,0 +1,79 @@
+pub mod context;
+
+#[cfg(test)]
+mod tests {
+    use crate::entity_state::model::EntityState;
+    use crate::enumerations::{LvcIndicator, PduType, ProtocolVersion};
+    use crate::exercise::context::{Exercise, SystemClock};
+    use crate::model::{EntityId, PduStatus, SimulationAddress, TimeStamp};
+    use crate::DisError;
+    use std::time::{Duration, UNIX_EPOCH};
+
+    #[test]
+    fn exercise_stamps_pdus() {
+        let exercise = Exercise::new(
+            ProtocolVersion::IEEE1278_12012,
+            3,
+            SimulationAddress::new(1, 2),
+        )
+        .with_pdu_status(PduStatus::default().with_lvc_indicator(LvcIndicator::from(2)))
+        .with_clock(|| TimeStamp::new(1001));
+
+        let body = EntityState::builder()
+            .with_entity_id(exercise.entity_id(10))
+            .build()
+            .into_pdu_body();
+        let pdu = exercise.checked_pdu(body).unwrap();
+
+        assert_eq!(pdu.header.protocol_version, ProtocolVersion::IEEE1278_12012);
+        assert_eq!(pdu.header.exercise_id, 3);
+        assert_eq!(pdu.header.pdu_type, PduType::EntityState);
+        assert_eq!(pdu.header.time_stamp, 1001);
+        assert_eq!(pdu.header.pdu_length, pdu.pdu_length());
+        assert_eq!(
+            pdu.header.pdu_status.unwrap().lvc_indicator,
+            Some(LvcIndicator::from(2))
+        );
+    }
+
+    #[test]
+    fn v6_exercise_has_no_pdu_status() {
+        let exercise = Exercise::new(
+            ProtocolVersion::IEEE1278_1A1998,
+            1,
+            SimulationAddress::new(1, 2),
+        );
+
+        let pdu = exercise.pdu_at(EntityState::builder().build().into_pdu_body(), 5u32);
+        assert!(pdu.header.pdu_status.is_none());
+        assert_eq!(pdu.header.time_stamp, 5);
+    }
+
+    #[test]
+    fn foreign_originators_are_rejected() {
+        let exercise = Exercise::new(
+            ProtocolVersion::IEEE1278_12012,
+            1,
+            SimulationAddress::new(1, 2),
+        );
+        let body = EntityState::builder()
+            .with_entity_id(EntityId::new(1, 3, 10))
+            .build()
+            .into_pdu_body();
+
+        assert_eq!(
+            exercise.checked_pdu(body),
+            Err(DisError::ForeignOriginator(EntityId::new(1, 3, 10)))
+        );
+    }
+
+    #[test]
+    fn system_clock_time_stamps() {
+        let half_past = UNIX_EPOCH + Duration::from_secs(3600 * 10 + 1800);
+
+        let absolute = SystemClock::absolute().time_stamp_at(half_past);
+        assert_eq!(absolute.raw_timestamp, (1 << 31) | 1);
+        let relative = SystemClock::relative().time_stamp_at(half_past);
+        assert_eq!(relative.raw_timestamp, 1 << 31);
+    }
+}
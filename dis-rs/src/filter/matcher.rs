@@ -0,0 +1,346 @@
+use crate::common::model::{EntityType, Location, Pdu, PduBody};
+use crate::common::Interaction;
+use crate::entity_type::pattern::EntityTypePattern;
+use crate::enumerations::enumeration_value;
+use crate::filter::parser::parse;
+use crate::reflect::value::{Reflect, Value};
+use crate::utils::geodetic_lla_to_ecef;
+use crate::DisError;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// The named fields that can be used in a filter, next to the paths of the body fields.
+pub(crate) const FIELD_NAMES: [&str; 9] = [
+    "pdu_type",
+    "protocol_version",
+    "exercise_id",
+    "site",
+    "application",
+    "entity",
+    "force_id",
+    "entity_type",
+    "marking",
+];
+
+/// A predicate over PDUs, compiled from a filter expression.
+///
+/// A filter consists of comparisons of a field with a value, combined with `&&`, `||`, `!` and parentheses:
+///
+/// `pdu_type in [EntityState, Fire] && force_id == Opposing && entity_type ~ "1.2.225.*"`
+///
+/// The named fields are `pdu_type`, `protocol_version`, `exercise_id`, `site`, `application` and `entity`
+/// (the originator of the PDU), `force_id`, `entity_type` and `marking`.
+/// Any other field of a body is addressed by its path, e.g. `entity_state.entity_appearance.air_platform.damage`.
+/// `distance(location, 52.1N 4.3E)` is the straight-line distance in metres from the location of an
+/// Entity State, Fire, Detonation or Aggregate State PDU to the given point on the WGS84 ellipsoid.
+///
+/// The operators are `==`, `!=`, `<`, `<=`, `>`, `>=`, `in` (a list of values) and `~` (a pattern,
+/// where `*` matches any sequence of characters and `?` a single character). For `entity_type`, the pattern
+/// of `~` is an `EntityTypePattern`.
+/// Enumerations compare by the name of the variant or by their value. Lengths can be written
+/// with a unit of `m`, `km` or `nm` (nautical miles). A comparison on a field that a PDU does not have is false.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PduFilter {
+    source: String,
+    expression: Expression,
+}
+
+impl PduFilter {
+    /// Compiles a filter expression into a reusable `PduFilter`.
+    ///
+    /// # Errors
+    /// Returns `DisError::InvalidFilter` with the column and a description of the problem when the
+    /// expression cannot be parsed.
+    pub fn compile(source: &str) -> Result<Self, DisError> {
+        Ok(Self {
+            source: source.to_string(),
+            expression: parse(source)?,
+        })
+    }
+
+    /// The expression the filter was compiled from.
+    #[must_use]
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// Whether the PDU satisfies the filter.
+    #[must_use]
+    pub fn matches(&self, pdu: &Pdu) -> bool {
+        self.expression.evaluate(pdu)
+    }
+}
+
+impl FromStr for PduFilter {
+    type Err = DisError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::compile(s)
+    }
+}
+
+impl Display for PduFilter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.source)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum Expression {
+    Constant(bool),
+    Not(Box<Expression>),
+    And(Box<Expression>, Box<Expression>),
+    Or(Box<Expression>, Box<Expression>),
+    Comparison(Field, Operator, Literal),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum Field {
+    PduType,
+    ProtocolVersion,
+    ExerciseId,
+    Site,
+    Application,
+    Entity,
+    ForceId,
+    EntityType,
+    Marking,
+    Distance(GeoPoint),
+    Path(String),
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) enum Operator {
+    Equal,
+    NotEqual,
+    Less,
+    LessOrEqual,
+    Greater,
+    GreaterOrEqual,
+    In,
+    Match,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum Literal {
+    Number(f64),
+    Text(String),
+    Name(String),
+    List(Vec<Literal>),
+    EntityType(EntityTypePattern),
+}
+
+/// A point on the WGS84 ellipsoid, in degrees.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub(crate) struct GeoPoint {
+    pub latitude: f64,
+    pub longitude: f64,
+}
+
+/// The value of a field of a PDU, in the form it is compared in.
+enum FieldValue {
+    Number(f64),
+    Enumeration(String, f64),
+    Text(String),
+    EntityType(EntityType),
+}
+
+impl Field {
+    pub(crate) fn from_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "pdu_type" => Field::PduType,
+            "protocol_version" => Field::ProtocolVersion,
+            "exercise_id" => Field::ExerciseId,
+            "site" => Field::Site,
+            "application" => Field::Application,
+            "entity" => Field::Entity,
+            "force_id" => Field::ForceId,
+            "entity_type" => Field::EntityType,
+            "marking" => Field::Marking,
+            _ if name.contains('.') => Field::Path(name.to_string()),
+            _ => return None,
+        })
+    }
+
+    fn value(&self, pdu: &Pdu) -> Option<FieldValue> {
+        let originator = pdu.body.originator();
+        Some(match self {
+            Field::PduType => FieldValue::Enumeration(
+                format!("{:?}", pdu.header.pdu_type),
+                f64::from(u8::from(pdu.header.pdu_type)),
+            ),
+            Field::ProtocolVersion => FieldValue::Enumeration(
+                format!("{:?}", pdu.header.protocol_version),
+                f64::from(u8::from(pdu.header.protocol_version)),
+            ),
+            Field::ExerciseId => FieldValue::Number(f64::from(pdu.header.exercise_id)),
+            Field::Site => FieldValue::Number(f64::from(originator?.simulation_address.site_id)),
+            Field::Application => {
+                FieldValue::Number(f64::from(originator?.simulation_address.application_id))
+            }
+            Field::Entity => FieldValue::Number(f64::from(originator?.entity_id)),
+            Field::ForceId => {
+                let force_id = match &pdu.body {
+                    PduBody::EntityState(body) => body.force_id,
+                    PduBody::AggregateState(body) => body.force_id,
+                    _ => return None,
+                };
+                FieldValue::Enumeration(format!("{force_id:?}"), f64::from(u8::from(force_id)))
+            }
+            Field::EntityType => match &pdu.body {
+                PduBody::EntityState(body) => FieldValue::EntityType(body.entity_type),
+                _ => return None,
+            },
+            Field::Marking => match &pdu.body {
+                PduBody::EntityState(body) => {
+                    FieldValue::Text(body.entity_marking.marking_string.clone())
+                }
+                _ => return None,
+            },
+            Field::Distance(point) => FieldValue::Number(point.distance_to(location(&pdu.body)?)),
+            Field::Path(path) => path_value(pdu.body.get(path)?)?,
+        })
+    }
+}
+
+impl GeoPoint {
+    /// The straight-line distance in metres between this point and an ECEF location.
+    fn distance_to(self, location: &Location) -> f64 {
+        let (x, y, z) =
+            geodetic_lla_to_ecef(self.latitude.to_radians(), self.longitude.to_radians(), 0.0);
+        ((location.x_coordinate - x).powi(2)
+            + (location.y_coordinate - y).powi(2)
+            + (location.z_coordinate - z).powi(2))
+        .sqrt()
+    }
+}
+
+/// The location in the world that a PDU body is about, if any.
+fn location(body: &PduBody) -> Option<&Location> {
+    match body {
+        PduBody::EntityState(body) => Some(&body.entity_location),
+        PduBody::EntityStateUpdate(body) => Some(&body.entity_location),
+        PduBody::Fire(body) => Some(&body.location_in_world),
+        PduBody::Detonation(body) => Some(&body.location_in_world_coordinates),
+        PduBody::AggregateState(body) => Some(&body.center_of_mass),
+        _ => None,
+    }
+}
+
+/// Converts a reflected leaf value into a comparable value: numbers as numbers, enumerations by their name and value,
+/// and other values as text.
+#[allow(clippy::cast_precision_loss)]
+fn path_value(value: Value<'_>) -> Option<FieldValue> {
+    macro_rules! number {
+        ($($ty:ty),*) => {
+            $(
+                if let Some(number) = value.downcast_ref::<$ty>() {
+                    return Some(FieldValue::Number(f64::from(*number)));
+                }
+            )*
+        };
+    }
+
+    let Value::Leaf(leaf) = value else {
+        return None;
+    };
+    number!(u8, u16, u32, i8, i16, i32, f32, f64);
+    if let Some(number) = value.downcast_ref::<u64>() {
+        return Some(FieldValue::Number(*number as f64));
+    }
+    if let Some(text) = value.downcast_ref::<String>() {
+        return Some(FieldValue::Text(text.clone()));
+    }
+    if let Some(number) = enumeration_value(leaf.as_any()) {
+        return Some(FieldValue::Enumeration(format!("{leaf:?}"), number as f64));
+    }
+    Some(FieldValue::Text(format!("{leaf:?}")))
+}
+
+impl Expression {
+    fn evaluate(&self, pdu: &Pdu) -> bool {
+        match self {
+            Expression::Constant(value) => *value,
+            Expression::Not(expression) => !expression.evaluate(pdu),
+            Expression::And(left, right) => left.evaluate(pdu) && right.evaluate(pdu),
+            Expression::Or(left, right) => left.evaluate(pdu) || right.evaluate(pdu),
+            Expression::Comparison(field, operator, literal) => field
+                .value(pdu)
+                .is_some_and(|value| value.compare(*operator, literal)),
+        }
+    }
+}
+
+impl FieldValue {
+    fn compare(&self, operator: Operator, literal: &Literal) -> bool {
+        match operator {
+            Operator::Equal => self.equals(literal),
+            Operator::NotEqual => !self.equals(literal),
+            Operator::In => match literal {
+                Literal::List(items) => items.iter().any(|item| self.equals(item)),
+                _ => false,
+            },
+            Operator::Match => match (self, literal) {
+                (
+                    FieldValue::Text(text) | FieldValue::Enumeration(text, _),
+                    Literal::Text(pattern),
+                ) => glob_match(pattern, text),
+                (FieldValue::EntityType(entity_type), Literal::EntityType(pattern)) => {
+                    pattern.matches(entity_type)
+                }
+                _ => false,
+            },
+            Operator::Less => self.number_and(literal, |a, b| a < b),
+            Operator::LessOrEqual => self.number_and(literal, |a, b| a <= b),
+            Operator::Greater => self.number_and(literal, |a, b| a > b),
+            Operator::GreaterOrEqual => self.number_and(literal, |a, b| a >= b),
+        }
+    }
+
+    fn equals(&self, literal: &Literal) -> bool {
+        match (self, literal) {
+            (FieldValue::Number(a) | FieldValue::Enumeration(_, a), Literal::Number(b)) => a == b,
+            (FieldValue::Enumeration(name, _), Literal::Name(other) | Literal::Text(other)) => {
+                name.eq_ignore_ascii_case(other)
+            }
+            (FieldValue::Text(text), Literal::Name(other) | Literal::Text(other)) => text == other,
+            (FieldValue::EntityType(entity_type), Literal::Text(other)) => {
+                entity_type.to_string() == other.replace('.', ":")
+            }
+            _ => false,
+        }
+    }
+
+    fn number_and(&self, literal: &Literal, predicate: impl Fn(f64, f64) -> bool) -> bool {
+        match (self, literal) {
+            (FieldValue::Number(a) | FieldValue::Enumeration(_, a), Literal::Number(b)) => {
+                predicate(*a, *b)
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Matches `text` against a pattern where `*` matches any sequence of characters and `?` a single character.
+fn glob_match(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+    let (mut p, mut t) = (0, 0);
+    let mut backtrack = None;
+    while t < text.len() {
+        if p < pattern.len() && (pattern[p] == '?' || pattern[p] == text[t]) {
+            p += 1;
+            t += 1;
+        } else if p < pattern.len() && pattern[p] == '*' {
+            backtrack = Some((p, t));
+            p += 1;
+        } else if let Some((star, matched)) = backtrack {
+            p = star + 1;
+            t = matched + 1;
+            backtrack = Some((star, t));
+        } else {
+            return false;
+        }
+    }
+    pattern[p..].iter().all(|c| *c == '*')
+}
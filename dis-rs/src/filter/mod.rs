@@ -0,0 +1,150 @@
+pub mod matcher;
+mod parser;
+
+#[cfg(test)]
+mod tests {
+    use crate::entity_state::model::{EntityAppearance, EntityMarking, EntityState};
+    use crate::enumerations::{
+        AirPlatformAppearance, AppearanceDamage, Country, EntityKind, ForceId, PduType,
+        PlatformDomain, ProtocolVersion,
+    };
+    use crate::filter::matcher::PduFilter;
+    use crate::model::{EntityId, EntityType, Location, Pdu, PduHeader, TimeStamp};
+    use crate::other::model::Other;
+    use crate::utils::geodetic_lla_to_ecef;
+    use crate::DisError;
+
+    fn tank(force_id: ForceId, latitude: f64, longitude: f64) -> Pdu {
+        let (x, y, z) = geodetic_lla_to_ecef(latitude.to_radians(), longitude.to_radians(), 0.0);
+        let body = EntityState::builder()
+            .with_entity_id(EntityId::new(12, 1, 5))
+            .with_force_id(force_id)
+            .with_entity_type(
+                EntityType::default()
+                    .with_kind(EntityKind::Platform)
+                    .with_domain(PlatformDomain::Land)
+                    .with_country(Country::Netherlands_NLD_)
+                    .with_category(1),
+            )
+            .with_marking(EntityMarking::new_ascii("TANK1"))
+            .with_location(Location::new(x, y, z))
+            .build()
+            .into_pdu_body();
+        Pdu::finalize_from_parts(
+            PduHeader::new(ProtocolVersion::IEEE1278_12012, 1, PduType::EntityState),
+            body,
+            TimeStamp::new(0),
+        )
+    }
+
+    fn filter(source: &str) -> PduFilter {
+        PduFilter::compile(source).unwrap()
+    }
+
+    #[test]
+    fn match_fields() {
+        let tank = tank(ForceId::Opposing, 52.0, 4.3);
+        let other = Pdu::finalize_from_parts(
+            PduHeader::new(ProtocolVersion::IEEE1278_12012, 1, PduType::Other),
+            Other::builder().build().into_pdu_body(),
+            TimeStamp::new(0),
+        );
+
+        let filter =
+            filter("pdu_type in [EntityState, Fire] && force_id == Opposing && entity_type ~ \"1.1.153.*\"");
+        assert!(filter.matches(&tank));
+        assert!(!filter.matches(&other));
+        assert!(!filter.matches(&self::tank(ForceId::Friendly, 52.0, 4.3)));
+
+        assert!(self::filter("site == 12 && entity >= 5 && !(application != 1)").matches(&tank));
+        assert!(self::filter("pdu_type == 1 || false").matches(&tank));
+        assert!(self::filter("marking == \"TANK1\"").matches(&tank));
+        assert!(self::filter("entity_state.force_id == Opposing").matches(&tank));
+        assert!(
+            self::filter("entity_state.entity_id.simulation_address.site_id < 13").matches(&tank)
+        );
+        assert!(!self::filter("force_id != Opposing").matches(&other));
+        assert!(self::filter("entity_type ~ \"1.1.100-200.1\"").matches(&tank));
+        assert!(self::filter("entity_type == \"1.1.153.1.0.0.0\"").matches(&tank));
+    }
+
+    #[test]
+    fn match_paths() {
+        let aircraft = Pdu::finalize_from_parts(
+            PduHeader::new(ProtocolVersion::IEEE1278_12012, 1, PduType::EntityState),
+            EntityState::builder()
+                .with_appearance(EntityAppearance::AirPlatform(AirPlatformAppearance {
+                    damage: AppearanceDamage::Destroyed,
+                    ..Default::default()
+                }))
+                .build()
+                .into_pdu_body(),
+            TimeStamp::new(0),
+        );
+        assert!(
+            filter("entity_state.entity_appearance.air_platform.damage == Destroyed")
+                .matches(&aircraft)
+        );
+        assert!(
+            !filter("entity_state.entity_appearance.land_platform.damage == Destroyed")
+                .matches(&aircraft)
+        );
+    }
+
+    #[test]
+    fn match_enumerations_by_value() {
+        let tank = tank(ForceId::Opposing, 52.0, 4.3);
+        assert!(filter("entity_state.force_id == 2").matches(&tank));
+        assert!(filter("entity_state.force_id in [1, 2]").matches(&tank));
+        assert!(filter(
+            "entity_state.entity_type.kind >= 1 && entity_state.entity_type.domain < 2"
+        )
+        .matches(&tank));
+        assert!(!filter("entity_state.force_id > 2").matches(&tank));
+    }
+
+    #[test]
+    fn match_distance() {
+        let tank = tank(ForceId::Opposing, 52.0, 4.3);
+        assert!(filter("distance(location, 52.1N 4.3E) < 20km").matches(&tank));
+        assert!(filter("distance(location, 52.1N 4.3E) > 10000").matches(&tank));
+        assert!(!filter("distance(location, 52.1S 4.3W) < 20nm").matches(&tank));
+    }
+
+    #[test]
+    fn parse_errors() {
+        let error = |source: &str| PduFilter::compile(source).unwrap_err();
+
+        assert!(
+            matches!(error("sitee == 12"), DisError::InvalidFilter(1, message) if message.starts_with("unknown field `sitee`"))
+        );
+        assert!(matches!(error("site = 12"), DisError::InvalidFilter(6, _)));
+        assert!(
+            matches!(error("(site == 12"), DisError::InvalidFilter(12, message) if message.contains("column 1"))
+        );
+        assert!(matches!(
+            error("pdu_type in Fire"),
+            DisError::InvalidFilter(13, _)
+        ));
+        assert!(matches!(
+            error("marking == \"TANK"),
+            DisError::InvalidFilter(12, _)
+        ));
+        assert!(matches!(
+            error("distance(location, 52.1N) < 20km"),
+            DisError::InvalidFilter(25, _)
+        ));
+        assert!(matches!(
+            error("distance(location, 52.1N 4.3E) < 20mi"),
+            DisError::InvalidFilter(36, _)
+        ));
+        assert!(matches!(
+            error("entity_type ~ \"1.1.x\""),
+            DisError::InvalidFilter(15, _)
+        ));
+        assert!(matches!(
+            error("site == 12 site"),
+            DisError::InvalidFilter(12, _)
+        ));
+    }
+}
@@ -0,0 +1,418 @@
+use crate::filter::matcher::{Expression, Field, GeoPoint, Literal, Operator, FIELD_NAMES};
+use crate::DisError;
+use std::fmt::{Display, Formatter};
+
+const METRES_PER_KILOMETRE: f64 = 1000.0;
+const METRES_PER_NAUTICAL_MILE: f64 = 1852.0;
+
+/// The operator symbols, longest first so that `<=` is not read as `<` followed by `=`.
+const SYMBOLS: [&str; 15] = [
+    "==", "!=", "<=", ">=", "&&", "||", "<", ">", "!", "~", "(", ")", "[", "]", ",",
+];
+
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+    Identifier(String),
+    Number(f64),
+    Latitude(f64),
+    Longitude(f64),
+    Text(String),
+    Symbol(&'static str),
+    End,
+}
+
+impl Display for Token {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Token::Identifier(name) => write!(f, "`{name}`"),
+            Token::Number(number) => write!(f, "number `{number}`"),
+            Token::Latitude(_) => f.write_str("a latitude"),
+            Token::Longitude(_) => f.write_str("a longitude"),
+            Token::Text(text) => write!(f, "string \"{text}\""),
+            Token::Symbol(symbol) => write!(f, "`{symbol}`"),
+            Token::End => f.write_str("the end of the expression"),
+        }
+    }
+}
+
+/// A token with its (one-based) column in the expression.
+struct Lexeme {
+    token: Token,
+    column: usize,
+}
+
+fn error(column: usize, message: impl Into<String>) -> DisError {
+    DisError::InvalidFilter(column, message.into())
+}
+
+/// Parses a filter expression into its syntax tree.
+pub(crate) fn parse(source: &str) -> Result<Expression, DisError> {
+    let mut parser = Parser {
+        lexemes: tokenize(source)?,
+        position: 0,
+    };
+    let expression = parser.or()?;
+    let next = parser.peek();
+    if next.token != Token::End {
+        return Err(error(
+            next.column,
+            format!(
+                "expected `&&`, `||` or the end of the expression, found {}",
+                next.token
+            ),
+        ));
+    }
+    Ok(expression)
+}
+
+fn tokenize(source: &str) -> Result<Vec<Lexeme>, DisError> {
+    let chars: Vec<char> = source.chars().collect();
+    let mut lexemes = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        let column = i + 1;
+        let token = if c.is_whitespace() {
+            i += 1;
+            continue;
+        } else if c == '"' {
+            let mut text = String::new();
+            i += 1;
+            loop {
+                match chars.get(i) {
+                    None => return Err(error(column, "unterminated string")),
+                    Some('"') => break,
+                    Some('\\') if matches!(chars.get(i + 1), Some('"' | '\\')) => {
+                        text.push(chars[i + 1]);
+                        i += 2;
+                    }
+                    Some(c) => {
+                        text.push(*c);
+                        i += 1;
+                    }
+                }
+            }
+            i += 1;
+            Token::Text(text)
+        } else if c.is_ascii_digit()
+            || (c == '-' && chars.get(i + 1).is_some_and(char::is_ascii_digit))
+        {
+            let start = i;
+            i += 1;
+            while i < chars.len() && (chars[i].is_ascii_digit() || chars[i] == '.') {
+                i += 1;
+            }
+            let digits: String = chars[start..i].iter().collect();
+            let number: f64 = digits
+                .parse()
+                .map_err(|_| error(column, format!("invalid number `{digits}`")))?;
+            let unit_start = i;
+            while i < chars.len() && chars[i].is_ascii_alphabetic() {
+                i += 1;
+            }
+            let unit: String = chars[unit_start..i].iter().collect();
+            match unit.as_str() {
+                "" | "m" => Token::Number(number),
+                "km" => Token::Number(number * METRES_PER_KILOMETRE),
+                "nm" => Token::Number(number * METRES_PER_NAUTICAL_MILE),
+                "N" => Token::Latitude(number),
+                "S" => Token::Latitude(-number),
+                "E" => Token::Longitude(number),
+                "W" => Token::Longitude(-number),
+                _ => {
+                    return Err(error(
+                        unit_start + 1,
+                        format!("unknown unit `{unit}`, expected `m`, `km` or `nm`, or `N`, `S`, `E` or `W` for a coordinate"),
+                    ))
+                }
+            }
+        } else if c.is_alphabetic() || c == '_' {
+            let start = i;
+            while i < chars.len() {
+                if chars[i].is_alphanumeric() || matches!(chars[i], '_' | '.') {
+                    i += 1;
+                } else if let Some(length) = index_length(&chars[i..]) {
+                    if chars[start..i] == ['i', 'n'] {
+                        break;
+                    }
+                    i += length;
+                } else {
+                    break;
+                }
+            }
+            Token::Identifier(chars[start..i].iter().collect())
+        } else if let Some(symbol) = SYMBOLS.iter().find(|symbol| {
+            symbol
+                .chars()
+                .enumerate()
+                .all(|(offset, s)| chars.get(i + offset) == Some(&s))
+        }) {
+            i += symbol.len();
+            Token::Symbol(*symbol)
+        } else {
+            return Err(error(column, format!("unexpected character `{c}`")));
+        };
+        lexemes.push(Lexeme { token, column });
+    }
+    lexemes.push(Lexeme {
+        token: Token::End,
+        column: chars.len() + 1,
+    });
+    Ok(lexemes)
+}
+
+/// The length of a list index such as `[2]` at the start of `chars`, if there is one.
+fn index_length(chars: &[char]) -> Option<usize> {
+    let digits = chars
+        .iter()
+        .skip(1)
+        .take_while(|c| c.is_ascii_digit())
+        .count();
+    (chars.first() == Some(&'[') && digits > 0 && chars.get(digits + 1) == Some(&']'))
+        .then_some(digits + 2)
+}
+
+struct Parser {
+    lexemes: Vec<Lexeme>,
+    position: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> &Lexeme {
+        &self.lexemes[self.position.min(self.lexemes.len() - 1)]
+    }
+
+    fn next(&mut self) -> &Lexeme {
+        let position = self.position.min(self.lexemes.len() - 1);
+        self.position += 1;
+        &self.lexemes[position]
+    }
+
+    fn eat(&mut self, symbol: &str) -> bool {
+        let found = matches!(self.peek().token, Token::Symbol(s) if s == symbol);
+        if found {
+            self.position += 1;
+        }
+        found
+    }
+
+    fn expect(&mut self, symbol: &str, context: &str) -> Result<(), DisError> {
+        if self.eat(symbol) {
+            return Ok(());
+        }
+        let next = self.peek();
+        Err(error(
+            next.column,
+            format!("expected `{symbol}` {context}, found {}", next.token),
+        ))
+    }
+
+    fn or(&mut self) -> Result<Expression, DisError> {
+        let mut expression = self.and()?;
+        while self.eat("||") {
+            expression = Expression::Or(Box::new(expression), Box::new(self.and()?));
+        }
+        Ok(expression)
+    }
+
+    fn and(&mut self) -> Result<Expression, DisError> {
+        let mut expression = self.not()?;
+        while self.eat("&&") {
+            expression = Expression::And(Box::new(expression), Box::new(self.not()?));
+        }
+        Ok(expression)
+    }
+
+    fn not(&mut self) -> Result<Expression, DisError> {
+        if self.eat("!") {
+            return Ok(Expression::Not(Box::new(self.not()?)));
+        }
+        self.primary()
+    }
+
+    fn primary(&mut self) -> Result<Expression, DisError> {
+        let column = self.peek().column;
+        if self.eat("(") {
+            let expression = self.or()?;
+            self.expect(")", &format!("to close the `(` at column {column}"))?;
+            return Ok(expression);
+        }
+        match &self.peek().token {
+            Token::Identifier(name) if name == "true" || name == "false" => {
+                let value = name == "true";
+                self.position += 1;
+                Ok(Expression::Constant(value))
+            }
+            _ => self.comparison(),
+        }
+    }
+
+    fn comparison(&mut self) -> Result<Expression, DisError> {
+        let field = self.field()?;
+
+        let lexeme = self.next();
+        let operator = match &lexeme.token {
+            Token::Symbol("==") => Operator::Equal,
+            Token::Symbol("!=") => Operator::NotEqual,
+            Token::Symbol("<") => Operator::Less,
+            Token::Symbol("<=") => Operator::LessOrEqual,
+            Token::Symbol(">") => Operator::Greater,
+            Token::Symbol(">=") => Operator::GreaterOrEqual,
+            Token::Symbol("~") => Operator::Match,
+            Token::Identifier(name) if name == "in" => Operator::In,
+            token => {
+                return Err(error(
+                    lexeme.column,
+                    format!("expected a comparison operator (`==`, `!=`, `<`, `<=`, `>`, `>=`, `in` or `~`), found {token}"),
+                ))
+            }
+        };
+
+        let column = self.peek().column;
+        let literal = self.literal()?;
+        match (operator, &literal) {
+            (Operator::In, Literal::List(_)) => {}
+            (Operator::In, _) => {
+                return Err(error(
+                    column,
+                    "expected a list of values such as `[EntityState, Fire]` after `in`",
+                ))
+            }
+            (_, Literal::List(_)) => {
+                return Err(error(column, "a list of values can only be used with `in`"))
+            }
+            (Operator::Match, Literal::Text(_)) => {}
+            (Operator::Match, _) => {
+                return Err(error(
+                    column,
+                    "expected a pattern string such as \"1.2.225.*\" after `~`",
+                ))
+            }
+            (
+                Operator::Less
+                | Operator::LessOrEqual
+                | Operator::Greater
+                | Operator::GreaterOrEqual,
+                Literal::Text(_) | Literal::Name(_),
+            ) => return Err(error(column, "expected a number to compare with")),
+            _ => {}
+        }
+        let literal = match (&field, operator, literal) {
+            (Field::EntityType, Operator::Match, Literal::Text(pattern)) => Literal::EntityType(
+                pattern
+                    .parse()
+                    .map_err(|e: DisError| error(column, e.to_string()))?,
+            ),
+            (_, _, literal) => literal,
+        };
+        Ok(Expression::Comparison(field, operator, literal))
+    }
+
+    fn field(&mut self) -> Result<Field, DisError> {
+        let lexeme = self.next();
+        let column = lexeme.column;
+        let name = match &lexeme.token {
+            Token::Identifier(name) => name.clone(),
+            token => return Err(error(column, format!("expected a field, found {token}"))),
+        };
+        if name == "distance" {
+            self.expect("(", "after `distance`")?;
+            match self.next() {
+                Lexeme {
+                    token: Token::Identifier(argument),
+                    ..
+                } if argument == "location" => {}
+                Lexeme { token, column } => {
+                    return Err(error(
+                        *column,
+                        format!(
+                        "expected `location` as the first argument of `distance`, found {token}"
+                    ),
+                    ))
+                }
+            }
+            self.expect(",", "after `location`")?;
+            let point = self.coordinate()?;
+            self.expect(")", "to close `distance(`")?;
+            return Ok(Field::Distance(point));
+        }
+        Field::from_name(&name).ok_or_else(|| {
+            error(
+                column,
+                format!(
+                    "unknown field `{name}`, expected one of {} or the path of a body field such as `entity_state.force_id`",
+                    FIELD_NAMES.join(", ")
+                ),
+            )
+        })
+    }
+
+    fn coordinate(&mut self) -> Result<GeoPoint, DisError> {
+        let lexeme = self.next();
+        let latitude = match lexeme.token {
+            Token::Latitude(latitude) if latitude.abs() <= 90.0 => latitude,
+            Token::Latitude(_) => {
+                return Err(error(
+                    lexeme.column,
+                    "a latitude must be at most 90 degrees",
+                ))
+            }
+            ref token => {
+                return Err(error(
+                    lexeme.column,
+                    format!("expected a latitude such as `52.1N`, found {token}"),
+                ))
+            }
+        };
+        let lexeme = self.next();
+        let longitude = match lexeme.token {
+            Token::Longitude(longitude) if longitude.abs() <= 180.0 => longitude,
+            Token::Longitude(_) => {
+                return Err(error(
+                    lexeme.column,
+                    "a longitude must be at most 180 degrees",
+                ))
+            }
+            ref token => {
+                return Err(error(
+                    lexeme.column,
+                    format!("expected a longitude such as `4.3E`, found {token}"),
+                ))
+            }
+        };
+        Ok(GeoPoint {
+            latitude,
+            longitude,
+        })
+    }
+
+    fn literal(&mut self) -> Result<Literal, DisError> {
+        let lexeme = self.next();
+        let column = lexeme.column;
+        match &lexeme.token {
+            Token::Number(number) => Ok(Literal::Number(*number)),
+            Token::Text(text) => Ok(Literal::Text(text.clone())),
+            Token::Identifier(name) => Ok(Literal::Name(name.clone())),
+            Token::Symbol("[") => {
+                let mut items = Vec::new();
+                if self.eat("]") {
+                    return Ok(Literal::List(items));
+                }
+                loop {
+                    let item_column = self.peek().column;
+                    match self.literal()? {
+                        Literal::List(_) => {
+                            return Err(error(item_column, "lists cannot contain lists"))
+                        }
+                        item => items.push(item),
+                    }
+                    if self.eat("]") {
+                        return Ok(Literal::List(items));
+                    }
+                    self.expect(",", &format!("or `]` in the list at column {column}"))?;
+                }
+            }
+            token => Err(error(column, format!("expected a value, found {token}"))),
+        }
+    }
+}
@@ -0,0 +1,697 @@
+use crate::common::designator::model::Designator;
+use crate::common::electromagnetic_emission::model::{
+    Beam, ElectromagneticEmission, EmitterSystem, FundamentalParameterData, JammingTechnique,
+    TrackJam,
+};
+use crate::common::iff::model::{
+    ChangeOptionsRecord, DamageStatus, DapSource, DapValue, EnabledStatus, EnhancedMode1Code,
+    FundamentalOperationalData, Iff, IffDataRecord, IffDataSpecification,
+    IffFundamentalParameterData, IffLayer2, IffLayer3, IffLayer4, IffLayer5, IffPresence,
+    IffSystemRole, InformationLayers, LatLonAltSource, LayerHeader, LayersPresenceApplicability,
+    MalfunctionStatus, Mode5BasicData, Mode5InterrogatorBasicData, Mode5InterrogatorStatus,
+    Mode5MessageFormats, Mode5TransponderBasicData, Mode5TransponderStatus,
+    Mode5TransponderSupplementalData, ModeSAltitude, ModeSBasicData, ModeSInterrogatorBasicData,
+    ModeSInterrogatorStatus, ModeSLevelsPresent, ModeSTransponderBasicData, ModeSTransponderStatus,
+    OnOffStatus, SquitterStatus, SystemId, SystemSpecificData, SystemStatus,
+    BASE_IFF_DATA_RECORD_LENGTH_OCTETS,
+};
+use crate::common::sees::model::{PropulsionSystemData, VectoringNozzleSystemData, SEES};
+use crate::common::underwater_acoustic::model::{
+    AcousticEmitterSystem, PropulsionPlantConfiguration, Shaft, UABeam, UAEmitterSystem,
+    UAFundamentalParameterData, UnderwaterAcoustic, APA,
+};
+use crate::enumerations::{
+    IffSystemType, UAAdditionalPassiveActivityParameterIndex, UAPropulsionPlantConfiguration,
+};
+use crate::generators::{alphanumeric, elements, finite_f32, list, octets, MAX_ELEMENTS};
+use arbitrary::{Arbitrary, Unstructured};
+
+/// The APA Parameter Index shares a 16-bit field with the two-bit APA status.
+const MAX_APA_PARAMETER_INDEX: u16 = 0x3FFF;
+/// The propulsion plant configuration shares an 8-bit field with the hull mounted masker flag.
+const MAX_PROPULSION_PLANT_CONFIGURATION: u8 = 0x7F;
+/// Length of the Aircraft Identification string of Mode S Transponder basic data.
+const AIRCRAFT_IDENTIFICATION_LENGTH: usize = 8;
+/// The Mode S altitude shares a 16-bit field with the altitude resolution and padding.
+const MAX_MODE_S_ALTITUDE: u16 = 0x07FF;
+
+impl<'a> Arbitrary<'a> for ElectromagneticEmission {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(ElectromagneticEmission {
+            emitting_entity_id: u.arbitrary()?,
+            event_id: u.arbitrary()?,
+            state_update_indicator: u.arbitrary()?,
+            emitter_systems: elements(u)?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for EmitterSystem {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(EmitterSystem {
+            name: u.arbitrary()?,
+            function: u.arbitrary()?,
+            number: u.arbitrary()?,
+            location: u.arbitrary()?,
+            beams: elements(u)?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for Beam {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Beam {
+            number: u.arbitrary()?,
+            parameter_index: u.arbitrary()?,
+            parameter_data: u.arbitrary()?,
+            beam_data: u.arbitrary()?,
+            beam_function: u.arbitrary()?,
+            high_density_track_jam: u.arbitrary()?,
+            beam_status: u.arbitrary()?,
+            jamming_technique: u.arbitrary()?,
+            track_jam_data: elements(u)?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for FundamentalParameterData {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(FundamentalParameterData {
+            frequency: finite_f32(u)?,
+            frequency_range: finite_f32(u)?,
+            effective_power: finite_f32(u)?,
+            pulse_repetition_frequency: finite_f32(u)?,
+            pulse_width: finite_f32(u)?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for JammingTechnique {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(JammingTechnique {
+            kind: u.arbitrary()?,
+            category: u.arbitrary()?,
+            subcategory: u.arbitrary()?,
+            specific: u.arbitrary()?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for TrackJam {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(TrackJam {
+            entity_id: u.arbitrary()?,
+            emitter: u.arbitrary()?,
+            beam: u.arbitrary()?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for Designator {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Designator {
+            designating_entity_id: u.arbitrary()?,
+            system_name: u.arbitrary()?,
+            designated_entity_id: u.arbitrary()?,
+            code: u.arbitrary()?,
+            power: finite_f32(u)?,
+            wavelength: finite_f32(u)?,
+            spot_wrt_designated_entity: u.arbitrary()?,
+            spot_location: u.arbitrary()?,
+            dead_reckoning_algorithm: u.arbitrary()?,
+            linear_acceleration: u.arbitrary()?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for UnderwaterAcoustic {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(UnderwaterAcoustic {
+            emitting_entity_id: u.arbitrary()?,
+            event_id: u.arbitrary()?,
+            state_change_update_indicator: u.arbitrary()?,
+            passive_parameter_index: u.arbitrary()?,
+            propulsion_plant_configuration: u.arbitrary()?,
+            shafts: elements(u)?,
+            apas: elements(u)?,
+            emitter_systems: elements(u)?,
+        })
+    }
+}
+
+/// The configuration is limited to the seven bits next to the hull mounted masker flag.
+impl<'a> Arbitrary<'a> for PropulsionPlantConfiguration {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(PropulsionPlantConfiguration {
+            configuration: UAPropulsionPlantConfiguration::from(
+                u.int_in_range(0..=MAX_PROPULSION_PLANT_CONFIGURATION)?,
+            ),
+            hull_mounted_masker: u.arbitrary()?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for Shaft {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Shaft {
+            current_rpm: u.arbitrary()?,
+            ordered_rpm: u.arbitrary()?,
+            rpm_rate_of_change: u.arbitrary()?,
+        })
+    }
+}
+
+/// The parameter index is limited to the fourteen bits next to the APA status.
+impl<'a> Arbitrary<'a> for APA {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(APA {
+            parameter: UAAdditionalPassiveActivityParameterIndex::from(
+                u.int_in_range(0..=MAX_APA_PARAMETER_INDEX)?,
+            ),
+            status: u.arbitrary()?,
+            value: u.arbitrary()?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for UAEmitterSystem {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(UAEmitterSystem {
+            acoustic_emitter_system: u.arbitrary()?,
+            location: u.arbitrary()?,
+            beams: elements(u)?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for AcousticEmitterSystem {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(AcousticEmitterSystem {
+            acoustic_system_name: u.arbitrary()?,
+            function: u.arbitrary()?,
+            acoustic_id_number: u.arbitrary()?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for UABeam {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(UABeam {
+            beam_data_length: u.arbitrary()?,
+            beam_id_number: u.arbitrary()?,
+            fundamental_parameters: u.arbitrary()?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for UAFundamentalParameterData {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(UAFundamentalParameterData {
+            active_emission_parameter_index: u.arbitrary()?,
+            scan_pattern: u.arbitrary()?,
+            beam_center_azimuth: finite_f32(u)?,
+            azimuthal_beamwidth: finite_f32(u)?,
+            beam_center_depression_elevation: finite_f32(u)?,
+            depression_elevation_beamwidth: finite_f32(u)?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for SEES {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(SEES {
+            originating_entity_id: u.arbitrary()?,
+            infrared_signature_representation_index: u.arbitrary()?,
+            acoustic_signature_representation_index: u.arbitrary()?,
+            radar_cross_section_representation_index: u.arbitrary()?,
+            propulsion_systems: elements(u)?,
+            vectoring_nozzle_systems: elements(u)?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for PropulsionSystemData {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(PropulsionSystemData {
+            power_setting: finite_f32(u)?,
+            engine_rpm: finite_f32(u)?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for VectoringNozzleSystemData {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(VectoringNozzleSystemData {
+            horizontal_deflection_angle: finite_f32(u)?,
+            vertical_deflection_angle: finite_f32(u)?,
+        })
+    }
+}
+
+/// The presence of layers 2 to 5 is reflected in the Information Layers of the operational data,
+/// and the formats of the layers follow the system type, as when parsing.
+impl<'a> Arbitrary<'a> for Iff {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        let system_id: SystemId = u.arbitrary()?;
+        let system_type = system_id.system_type;
+        let layer_2 = if u.arbitrary()? {
+            Some(iff_layer_2(u, system_type)?)
+        } else {
+            None
+        };
+        let layer_3 = if u.arbitrary()? {
+            Some(iff_layer_3(u, system_type)?)
+        } else {
+            None
+        };
+        let layer_4 = if u.arbitrary()? {
+            Some(iff_layer_4(u, system_type)?)
+        } else {
+            None
+        };
+        let layer_5 = if u.arbitrary()? {
+            Some(iff_layer_5(u)?)
+        } else {
+            None
+        };
+
+        let mut fundamental_operational_data: FundamentalOperationalData = u.arbitrary()?;
+        let information_layers = &mut fundamental_operational_data.information_layers;
+        information_layers.layer_2 = presence(layer_2.is_some());
+        information_layers.layer_3 = presence(layer_3.is_some());
+        information_layers.layer_4 = presence(layer_4.is_some());
+        information_layers.layer_5 = presence(layer_5.is_some());
+
+        Ok(Iff {
+            emitting_entity_id: u.arbitrary()?,
+            event_id: u.arbitrary()?,
+            relative_antenna_location: u.arbitrary()?,
+            system_id,
+            system_designator: u.arbitrary()?,
+            system_specific_data: u.arbitrary()?,
+            fundamental_operational_data,
+            layer_2,
+            layer_3,
+            layer_4,
+            layer_5,
+        })
+    }
+}
+
+fn presence(present: bool) -> LayersPresenceApplicability {
+    if present {
+        LayersPresenceApplicability::PresentApplicable
+    } else {
+        LayersPresenceApplicability::NotPresentApplicable
+    }
+}
+
+fn layer_header(u: &mut Unstructured<'_>, layer_number: u8) -> arbitrary::Result<LayerHeader> {
+    Ok(LayerHeader {
+        layer_number,
+        layer_specific_information: u.arbitrary()?,
+        length: 0,
+    })
+}
+
+fn iff_layer_2(
+    u: &mut Unstructured<'_>,
+    system_type: IffSystemType,
+) -> arbitrary::Result<IffLayer2> {
+    Ok(IffLayer2 {
+        layer_header: layer_header(u, 2)?,
+        beam_data: u.arbitrary()?,
+        operational_parameter_1: u.arbitrary()?,
+        operational_parameter_2: u.arbitrary()?,
+        iff_fundamental_parameters: list(u, MAX_ELEMENTS, |u| {
+            iff_fundamental_parameter_data(u, system_type)
+        })?,
+    }
+    .finalize_layer_header_length())
+}
+
+fn iff_layer_3(
+    u: &mut Unstructured<'_>,
+    system_type: IffSystemType,
+) -> arbitrary::Result<IffLayer3> {
+    let mode_5_basic_data = match IffSystemRole::from_system_type(system_type) {
+        Some(IffSystemRole::Interrogator) => Mode5BasicData::Interrogator(u.arbitrary()?),
+        Some(IffSystemRole::Transponder) => Mode5BasicData::Transponder(u.arbitrary()?),
+        None => Mode5BasicData::Undetermined(u.arbitrary()?),
+    };
+    Ok(IffLayer3 {
+        layer_header: layer_header(u, 3)?,
+        reporting_simulation: u.arbitrary()?,
+        mode_5_basic_data,
+        data_records: u.arbitrary()?,
+    }
+    .finalize_layer_header_length())
+}
+
+fn iff_layer_4(
+    u: &mut Unstructured<'_>,
+    system_type: IffSystemType,
+) -> arbitrary::Result<IffLayer4> {
+    let mode_s_basic_data = match IffSystemRole::from_system_type(system_type) {
+        Some(IffSystemRole::Interrogator) => ModeSBasicData::Interrogator(u.arbitrary()?),
+        Some(IffSystemRole::Transponder) => ModeSBasicData::Transponder(u.arbitrary()?),
+        None => ModeSBasicData::Undetermined(u.arbitrary()?),
+    };
+    Ok(IffLayer4 {
+        layer_header: layer_header(u, 4)?,
+        reporting_simulation: u.arbitrary()?,
+        mode_s_basic_data,
+        data_records: u.arbitrary()?,
+    }
+    .finalize_layer_header_length())
+}
+
+fn iff_layer_5(u: &mut Unstructured<'_>) -> arbitrary::Result<IffLayer5> {
+    Ok(IffLayer5 {
+        layer_header: layer_header(u, 5)?,
+        reporting_simulation: u.arbitrary()?,
+        applicable_layers: u.arbitrary()?,
+        data_category: u.arbitrary()?,
+        data_records: u.arbitrary()?,
+    }
+    .finalize_layer_header_length())
+}
+
+/// Generates a value of a field of `width` bits in a packed record.
+fn bits<T: From<u8>>(u: &mut Unstructured<'_>, width: u8) -> arbitrary::Result<T> {
+    Ok(T::from(u.int_in_range(0..=(1u8 << width) - 1)?))
+}
+
+fn iff_fundamental_parameter_data(
+    u: &mut Unstructured<'_>,
+    system_type: IffSystemType,
+) -> arbitrary::Result<IffFundamentalParameterData> {
+    Ok(IffFundamentalParameterData {
+        erp: finite_f32(u)?,
+        frequency: finite_f32(u)?,
+        pgrf: finite_f32(u)?,
+        pulse_width: finite_f32(u)?,
+        burst_length: finite_f32(u)?,
+        applicable_modes: u.arbitrary()?,
+        system_specific_data: SystemSpecificData::from_bytes(system_type, u.arbitrary()?),
+    })
+}
+
+impl<'a> Arbitrary<'a> for SystemId {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(SystemId {
+            system_type: u.arbitrary()?,
+            system_name: u.arbitrary()?,
+            system_mode: u.arbitrary()?,
+            change_options: u.arbitrary()?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for ChangeOptionsRecord {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(ChangeOptionsRecord::from(u.arbitrary::<u8>()?))
+    }
+}
+
+impl<'a> Arbitrary<'a> for SystemStatus {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(SystemStatus::from(u.arbitrary::<u8>()?))
+    }
+}
+
+impl<'a> Arbitrary<'a> for InformationLayers {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(InformationLayers::from(u.arbitrary::<u8>()?))
+    }
+}
+
+impl<'a> Arbitrary<'a> for FundamentalOperationalData {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(FundamentalOperationalData {
+            system_status: u.arbitrary()?,
+            data_field_1: u.arbitrary()?,
+            information_layers: u.arbitrary()?,
+            data_field_2: u.arbitrary()?,
+            parameter_1: u.arbitrary()?,
+            parameter_2: u.arbitrary()?,
+            parameter_3: u.arbitrary()?,
+            parameter_4: u.arbitrary()?,
+            parameter_5: u.arbitrary()?,
+            parameter_6: u.arbitrary()?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for IffDataSpecification {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(IffDataSpecification {
+            iff_data_records: elements(u)?,
+        })
+    }
+}
+
+/// The record specific fields fill up the record to a 4-octet boundary, so no padding is written.
+impl<'a> Arbitrary<'a> for IffDataRecord {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        const FIELDS_BASE_OCTETS: usize = 4 - BASE_IFF_DATA_RECORD_LENGTH_OCTETS as usize % 4;
+        let blocks = u.int_in_range(0..=MAX_ELEMENTS)?;
+        Ok(IffDataRecord {
+            record_type: u.arbitrary()?,
+            record_specific_fields: octets(u, FIELDS_BASE_OCTETS + 4 * blocks)?,
+        })
+    }
+}
+
+/// Implements `Arbitrary` for the two-state enumerations of the IFF records.
+macro_rules! impl_arbitrary_two_state {
+    ($($name:ident: $off:ident, $on:ident;)*) => {
+        $(
+            impl<'a> Arbitrary<'a> for $name {
+                fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+                    Ok(if u.arbitrary()? { $name::$on } else { $name::$off })
+                }
+            }
+        )*
+    };
+}
+
+impl_arbitrary_two_state! {
+    OnOffStatus: Off, On;
+    DamageStatus: NoDamage, Damaged;
+    MalfunctionStatus: NoMalfunction, Malfunction;
+    EnabledStatus: NotEnabled, Enabled;
+    LatLonAltSource: ComputeLocally, TransponderLocationDataRecordPresent;
+    IffPresence: NotPresent, Present;
+    SquitterStatus: Off, On;
+    DapValue: ComputeLocally, DataRecordAvailable;
+}
+
+impl<'a> Arbitrary<'a> for Mode5InterrogatorBasicData {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Mode5InterrogatorBasicData {
+            status: u.arbitrary()?,
+            mode_5_message_formats_present: u.arbitrary()?,
+            interrogated_entity_id: u.arbitrary()?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for Mode5InterrogatorStatus {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Mode5InterrogatorStatus {
+            iff_mission: bits(u, 3)?,
+            mode_5_message_formats_status: bits(u, 1)?,
+            on_off_status: u.arbitrary()?,
+            damage_status: u.arbitrary()?,
+            malfunction_status: u.arbitrary()?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for Mode5MessageFormats {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Mode5MessageFormats {
+            message_format_0: u.arbitrary()?,
+            message_format_1: u.arbitrary()?,
+            message_format_2: u.arbitrary()?,
+            message_format_3: u.arbitrary()?,
+            message_format_4: u.arbitrary()?,
+            message_format_5: u.arbitrary()?,
+            message_format_6: u.arbitrary()?,
+            message_format_7: u.arbitrary()?,
+            message_format_8: u.arbitrary()?,
+            message_format_9: u.arbitrary()?,
+            message_format_10: u.arbitrary()?,
+            message_format_11: u.arbitrary()?,
+            message_format_12: u.arbitrary()?,
+            message_format_13: u.arbitrary()?,
+            message_format_14: u.arbitrary()?,
+            message_format_15: u.arbitrary()?,
+            message_format_16: u.arbitrary()?,
+            message_format_17: u.arbitrary()?,
+            message_format_18: u.arbitrary()?,
+            message_format_19: u.arbitrary()?,
+            message_format_20: u.arbitrary()?,
+            message_format_21: u.arbitrary()?,
+            message_format_22: u.arbitrary()?,
+            message_format_23: u.arbitrary()?,
+            message_format_24: u.arbitrary()?,
+            message_format_25: u.arbitrary()?,
+            message_format_26: u.arbitrary()?,
+            message_format_27: u.arbitrary()?,
+            message_format_28: u.arbitrary()?,
+            message_format_29: u.arbitrary()?,
+            message_format_30: u.arbitrary()?,
+            message_format_31: u.arbitrary()?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for Mode5TransponderBasicData {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Mode5TransponderBasicData {
+            status: u.arbitrary()?,
+            pin: u.arbitrary()?,
+            mode_5_message_formats_present: u.arbitrary()?,
+            enhanced_mode_1: u.arbitrary()?,
+            national_origin: u.arbitrary()?,
+            supplemental_data: u.arbitrary()?,
+            navigation_source: u.arbitrary()?,
+            figure_of_merit: u.arbitrary()?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for Mode5TransponderStatus {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Mode5TransponderStatus {
+            mode_5_reply: bits(u, 4)?,
+            line_test: u.arbitrary()?,
+            antenna_selection: bits(u, 2)?,
+            crypto_control: u.arbitrary()?,
+            lat_lon_alt_source: u.arbitrary()?,
+            location_errors: bits(u, 1)?,
+            platform_type: bits(u, 1)?,
+            mode_5_level_selection: bits(u, 1)?,
+            on_off_status: u.arbitrary()?,
+            damage_status: u.arbitrary()?,
+            malfunction_status: u.arbitrary()?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for EnhancedMode1Code {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(EnhancedMode1Code {
+            code_element_1_d: u.int_in_range(0..=7)?,
+            code_element_2_c: u.int_in_range(0..=7)?,
+            code_element_3_b: u.int_in_range(0..=7)?,
+            code_element_4_a: u.int_in_range(0..=7)?,
+            on_off_status: u.arbitrary()?,
+            damage_status: u.arbitrary()?,
+            malfunction_status: u.arbitrary()?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for Mode5TransponderSupplementalData {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Mode5TransponderSupplementalData {
+            squitter_on_off_status: u.arbitrary()?,
+            level_2_squitter_status: bits(u, 1)?,
+            iff_mission: bits(u, 3)?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for ModeSInterrogatorBasicData {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(ModeSInterrogatorBasicData {
+            mode_s_interrogator_status: u.arbitrary()?,
+            mode_s_levels_present: u.arbitrary()?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for ModeSInterrogatorStatus {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(ModeSInterrogatorStatus {
+            on_off_status: u.arbitrary()?,
+            transmit_state: bits(u, 3)?,
+            damage_status: u.arbitrary()?,
+            malfunction_status: u.arbitrary()?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for ModeSLevelsPresent {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(ModeSLevelsPresent {
+            level_1: u.arbitrary()?,
+            level_2_els: u.arbitrary()?,
+            level_2_ehs: u.arbitrary()?,
+            level_3: u.arbitrary()?,
+            level_4: u.arbitrary()?,
+        })
+    }
+}
+
+/// The Aircraft Identification is alphanumeric, as trailing non-alphanumeric characters are dropped when parsing.
+impl<'a> Arbitrary<'a> for ModeSTransponderBasicData {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(ModeSTransponderBasicData {
+            status: u.arbitrary()?,
+            levels_present: u.arbitrary()?,
+            aircraft_present_domain: u.arbitrary()?,
+            aircraft_identification: alphanumeric(u, AIRCRAFT_IDENTIFICATION_LENGTH)?,
+            aircraft_address: u.arbitrary()?,
+            aircraft_identification_type: u.arbitrary()?,
+            dap_source: u.arbitrary()?,
+            altitude: u.arbitrary()?,
+            capability_report: u.arbitrary()?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for ModeSTransponderStatus {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(ModeSTransponderStatus {
+            squitter_status: u.arbitrary()?,
+            squitter_type: bits(u, 3)?,
+            squitter_record_source: bits(u, 1)?,
+            airborne_position_report_indicator: u.arbitrary()?,
+            airborne_velocity_report_indicator: u.arbitrary()?,
+            surface_position_report_indicator: u.arbitrary()?,
+            identification_report_indicator: u.arbitrary()?,
+            event_driven_report_indicator: u.arbitrary()?,
+            on_off_status: u.arbitrary()?,
+            damage_status: u.arbitrary()?,
+            malfunction_status: u.arbitrary()?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for DapSource {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(DapSource {
+            indicated_air_speed: u.arbitrary()?,
+            mach_number: u.arbitrary()?,
+            ground_speed: u.arbitrary()?,
+            magnetic_heading: u.arbitrary()?,
+            track_angle_rate: u.arbitrary()?,
+            true_track_angle: u.arbitrary()?,
+            true_airspeed: u.arbitrary()?,
+            vertical_rate: u.arbitrary()?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for ModeSAltitude {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(ModeSAltitude {
+            altitude: u.int_in_range(0..=MAX_MODE_S_ALTITUDE)?,
+            resolution: bits(u, 1)?,
+        })
+    }
+}
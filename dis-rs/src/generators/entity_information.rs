@@ -0,0 +1,260 @@
+use crate::common::attribute::model::{
+    AngleDeception, Attribute, AttributeRecord, AttributeRecordFields, AttributeRecordSet,
+    AttributeStateIndicator, BlankingSector, FalseTargets,
+};
+use crate::common::collision::model::Collision;
+use crate::common::collision_elastic::model::CollisionElastic;
+use crate::common::entity_state::model::{
+    DrEulerAngles, DrOtherParameters, DrParameters, DrWorldOrientationQuaternion, EntityAppearance,
+    EntityMarking, EntityState,
+};
+use crate::common::entity_state_update::model::EntityStateUpdate;
+use crate::common::model::EntityType;
+use crate::enumerations::{DeadReckoningAlgorithm, VariableRecordType};
+use crate::generators::records::appearance_for;
+use crate::generators::{alphanumeric, elements, finite_f32, octets};
+use crate::v7::entity_state::entity_capabilities_from_bytes;
+use arbitrary::{Arbitrary, Unstructured};
+
+/// Length of the marking string of an Entity Marking record.
+const ENTITY_MARKING_LENGTH: usize = 11;
+/// Unspecified attribute record fields are a whole number of 8-octet blocks, minus the 6-octet record header.
+const UNSPECIFIED_ATTRIBUTE_FIELDS_BASE_OCTETS: usize = 2;
+
+/// The appearance and capabilities are interpreted according to the entity type, as when parsing.
+impl<'a> Arbitrary<'a> for EntityState {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        let entity_type: EntityType = u.arbitrary()?;
+        Ok(EntityState {
+            entity_id: u.arbitrary()?,
+            force_id: u.arbitrary()?,
+            entity_type,
+            alternative_entity_type: u.arbitrary()?,
+            entity_linear_velocity: u.arbitrary()?,
+            entity_location: u.arbitrary()?,
+            entity_orientation: u.arbitrary()?,
+            entity_appearance: appearance_for(u, &entity_type)?,
+            dead_reckoning_parameters: u.arbitrary()?,
+            entity_marking: u.arbitrary()?,
+            entity_capabilities: entity_capabilities_from_bytes(u.arbitrary()?, &entity_type),
+            variable_parameters: elements(u)?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for EntityMarking {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(EntityMarking {
+            marking_character_set: u.arbitrary()?,
+            marking_string: alphanumeric(u, ENTITY_MARKING_LENGTH)?,
+        })
+    }
+}
+
+/// The Other Parameters match the format selected by the Dead Reckoning algorithm.
+impl<'a> Arbitrary<'a> for DrParameters {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        let algorithm: DeadReckoningAlgorithm = u.arbitrary()?;
+        Ok(DrParameters {
+            algorithm,
+            other_parameters: dr_other_parameters(u, algorithm)?,
+            linear_acceleration: u.arbitrary()?,
+            angular_velocity: u.arbitrary()?,
+        })
+    }
+}
+
+fn dr_other_parameters(
+    u: &mut Unstructured<'_>,
+    algorithm: DeadReckoningAlgorithm,
+) -> arbitrary::Result<DrOtherParameters> {
+    Ok(match algorithm {
+        DeadReckoningAlgorithm::StaticNonmovingEntity
+        | DeadReckoningAlgorithm::DRM_FPW_ConstantVelocityLowAccelerationLinearMotionEntity
+        | DeadReckoningAlgorithm::DRM_FVW_HighSpeedOrManeuveringEntity
+        | DeadReckoningAlgorithm::DRM_FPB_SimilarToFPWExceptInBodyCoordinates
+        | DeadReckoningAlgorithm::DRM_FVB_SimilarToFVWExceptInBodyCoordinates => {
+            DrOtherParameters::LocalEulerAngles(DrEulerAngles {
+                local_yaw: finite_f32(u)?,
+                local_pitch: finite_f32(u)?,
+                local_roll: finite_f32(u)?,
+            })
+        }
+        DeadReckoningAlgorithm::DRM_RPW_ConstantVelocityLowAccelerationLinearMotionEntityWithExtrapolationOfOrientation
+        | DeadReckoningAlgorithm::DRM_RVW_HighSpeedOrManeuveringEntityWithExtrapolationOfOrientation
+        | DeadReckoningAlgorithm::DRM_RPB_SimilarToRPWExceptInBodyCoordinates
+        | DeadReckoningAlgorithm::DRM_RVB_SimilarToRVWExceptInBodyCoordinates => {
+            DrOtherParameters::WorldOrientationQuaternion(DrWorldOrientationQuaternion {
+                nil: u.arbitrary()?,
+                x: finite_f32(u)?,
+                y: finite_f32(u)?,
+                z: finite_f32(u)?,
+            })
+        }
+        DeadReckoningAlgorithm::Other | DeadReckoningAlgorithm::Unspecified(_) => {
+            DrOtherParameters::None(u.arbitrary()?)
+        }
+    })
+}
+
+/// The Entity State Update PDU carries no entity type, so the appearance is always unspecified.
+impl<'a> Arbitrary<'a> for EntityStateUpdate {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(EntityStateUpdate {
+            entity_id: u.arbitrary()?,
+            entity_linear_velocity: u.arbitrary()?,
+            entity_location: u.arbitrary()?,
+            entity_orientation: u.arbitrary()?,
+            entity_appearance: EntityAppearance::Unspecified(u.arbitrary()?),
+            variable_parameters: elements(u)?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for Collision {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Collision {
+            issuing_entity_id: u.arbitrary()?,
+            colliding_entity_id: u.arbitrary()?,
+            event_id: u.arbitrary()?,
+            collision_type: u.arbitrary()?,
+            velocity: u.arbitrary()?,
+            mass: finite_f32(u)?,
+            location: u.arbitrary()?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for CollisionElastic {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(CollisionElastic {
+            issuing_entity_id: u.arbitrary()?,
+            colliding_entity_id: u.arbitrary()?,
+            event_id: u.arbitrary()?,
+            velocity: u.arbitrary()?,
+            mass: finite_f32(u)?,
+            location: u.arbitrary()?,
+            intermediate_result_xx: finite_f32(u)?,
+            intermediate_result_xy: finite_f32(u)?,
+            intermediate_result_xz: finite_f32(u)?,
+            intermediate_result_yy: finite_f32(u)?,
+            intermediate_result_yz: finite_f32(u)?,
+            intermediate_result_zz: finite_f32(u)?,
+            unit_surface_normal: u.arbitrary()?,
+            coefficient_of_restitution: finite_f32(u)?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for Attribute {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Attribute {
+            originating_simulation_address: u.arbitrary()?,
+            record_pdu_type: u.arbitrary()?,
+            record_protocol_version: u.arbitrary()?,
+            master_attribute_record_type: u.arbitrary()?,
+            action_code: u.arbitrary()?,
+            attribute_record_sets: elements(u)?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for AttributeRecordSet {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(AttributeRecordSet {
+            entity_id: u.arbitrary()?,
+            attribute_records: elements(u)?,
+        })
+    }
+}
+
+/// Typed record fields come with their record type.
+/// Unspecified fields fill up the record to an 8-octet boundary, and never have the length of a typed record.
+impl<'a> Arbitrary<'a> for AttributeRecord {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        let (record_type, specific_fields) = match u.int_in_range(0..=3u8)? {
+            0 => (
+                VariableRecordType::BlankingSectorAttributeRecord_3500,
+                AttributeRecordFields::BlankingSector(u.arbitrary()?),
+            ),
+            1 => (
+                VariableRecordType::AngleDeceptionAttributeRecord_3501,
+                AttributeRecordFields::AngleDeception(u.arbitrary()?),
+            ),
+            2 => (
+                VariableRecordType::FalseTargetsAttributeRecord_3502,
+                AttributeRecordFields::FalseTargets(u.arbitrary()?),
+            ),
+            _ => {
+                let blocks = u.int_in_range(0..=3usize)?;
+                (
+                    u.arbitrary()?,
+                    AttributeRecordFields::Unspecified(octets(
+                        u,
+                        UNSPECIFIED_ATTRIBUTE_FIELDS_BASE_OCTETS + 8 * blocks,
+                    )?),
+                )
+            }
+        };
+        Ok(AttributeRecord {
+            record_type,
+            specific_fields,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for AttributeStateIndicator {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(AttributeStateIndicator::from(u.arbitrary::<u8>()?))
+    }
+}
+
+impl<'a> Arbitrary<'a> for BlankingSector {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(BlankingSector {
+            emitter_number: u.arbitrary()?,
+            beam_number: u.arbitrary()?,
+            state_indicator: u.arbitrary()?,
+            left_azimuth: finite_f32(u)?,
+            right_azimuth: finite_f32(u)?,
+            lower_elevation: finite_f32(u)?,
+            upper_elevation: finite_f32(u)?,
+            residual_power: finite_f32(u)?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for AngleDeception {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(AngleDeception {
+            emitter_number: u.arbitrary()?,
+            beam_number: u.arbitrary()?,
+            state_indicator: u.arbitrary()?,
+            azimuth_offset: finite_f32(u)?,
+            azimuth_width: finite_f32(u)?,
+            azimuth_pull_rate: finite_f32(u)?,
+            azimuth_pull_acceleration: finite_f32(u)?,
+            elevation_offset: finite_f32(u)?,
+            elevation_width: finite_f32(u)?,
+            elevation_pull_rate: finite_f32(u)?,
+            elevation_pull_acceleration: finite_f32(u)?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for FalseTargets {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(FalseTargets {
+            emitter_number: u.arbitrary()?,
+            beam_number: u.arbitrary()?,
+            state_indicator: u.arbitrary()?,
+            false_target_count: u.arbitrary()?,
+            walk_speed: finite_f32(u)?,
+            walk_acceleration: finite_f32(u)?,
+            maximum_walk_distance: finite_f32(u)?,
+            keep_time: finite_f32(u)?,
+            echo_spacing: finite_f32(u)?,
+            first_target_offset: finite_f32(u)?,
+        })
+    }
+}
@@ -0,0 +1,357 @@
+use crate::common::aggregate_state::model::{
+    AggregateMarking, AggregateState, AggregateType, SilentAggregateSystem, SilentEntitySystem,
+};
+use crate::common::entity_state::model::EntityAppearance;
+use crate::common::is_group_of::model::{
+    GEDEntityLocation, GEDEntityOrientation, GEDRecord1, GEDRecord2, GEDRecord3, GEDRecord4,
+    GEDRecord5, GEDRecord6, GEDRecord7, GEDRecord8, GEDRecord9, GroupEntityDescription,
+    GroupReferencePoint, IsGroupOf,
+};
+use crate::common::is_part_of::model::{IsPartOf, NamedLocationId, Relationship};
+use crate::common::model::EntityType;
+use crate::common::transfer_ownership::model::TransferOwnership;
+use crate::enumerations::{EntityKind, IsGroupOfGroupedEntityCategory, PlatformDomain};
+use crate::generators::records::appearance_for;
+use crate::generators::{alphanumeric, elements, finite_f64, list, MAX_ELEMENTS};
+use arbitrary::{Arbitrary, Unstructured};
+
+/// Length of the marking string of an Aggregate Marking record.
+const AGGREGATE_MARKING_LENGTH: usize = 31;
+
+impl<'a> Arbitrary<'a> for AggregateState {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(AggregateState {
+            aggregate_id: u.arbitrary()?,
+            force_id: u.arbitrary()?,
+            aggregate_state: u.arbitrary()?,
+            aggregate_type: u.arbitrary()?,
+            formation: u.arbitrary()?,
+            aggregate_marking: u.arbitrary()?,
+            dimensions: u.arbitrary()?,
+            orientation: u.arbitrary()?,
+            center_of_mass: u.arbitrary()?,
+            velocity: u.arbitrary()?,
+            aggregates: elements(u)?,
+            entities: elements(u)?,
+            silent_aggregate_systems: elements(u)?,
+            silent_entity_systems: elements(u)?,
+            variable_datums: elements(u)?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for AggregateMarking {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(AggregateMarking {
+            marking_character_set: u.arbitrary()?,
+            marking_string: alphanumeric(u, AGGREGATE_MARKING_LENGTH)?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for AggregateType {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(AggregateType {
+            aggregate_kind: u.arbitrary()?,
+            domain: u.arbitrary()?,
+            country: u.arbitrary()?,
+            category: u.arbitrary()?,
+            subcategory: u.arbitrary()?,
+            specific: u.arbitrary()?,
+            extra: u.arbitrary()?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for SilentAggregateSystem {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(SilentAggregateSystem {
+            number_of_aggregates: u.arbitrary()?,
+            aggregate_type: u.arbitrary()?,
+        })
+    }
+}
+
+/// The appearances are interpreted according to the entity type of the system, as when parsing.
+impl<'a> Arbitrary<'a> for SilentEntitySystem {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        let entity_type: EntityType = u.arbitrary()?;
+        Ok(SilentEntitySystem {
+            number_of_entities: u.arbitrary()?,
+            entity_type,
+            appearances: list(u, MAX_ELEMENTS, |u| appearance_for(u, &entity_type))?,
+        })
+    }
+}
+
+/// All descriptions are of the record type selected by the grouped entity category.
+impl<'a> Arbitrary<'a> for IsGroupOf {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        let grouped_entity_category: IsGroupOfGroupedEntityCategory = u.arbitrary()?;
+        Ok(IsGroupOf {
+            group_id: u.arbitrary()?,
+            grouped_entity_category,
+            group_reference_point: u.arbitrary()?,
+            descriptions: list(u, MAX_ELEMENTS, |u| {
+                group_entity_description(u, grouped_entity_category)
+            })?,
+        })
+    }
+}
+
+fn group_entity_description(
+    u: &mut Unstructured<'_>,
+    category: IsGroupOfGroupedEntityCategory,
+) -> arbitrary::Result<GroupEntityDescription> {
+    Ok(match category {
+        IsGroupOfGroupedEntityCategory::BasicGroundCombatVehicle => {
+            GroupEntityDescription::BasicGroundCombatVehicle(u.arbitrary()?)
+        }
+        IsGroupOfGroupedEntityCategory::EnhancedGroundCombatVehicle => {
+            GroupEntityDescription::EnhancedGroundCombatVehicle(u.arbitrary()?)
+        }
+        IsGroupOfGroupedEntityCategory::BasicGroundCombatSoldier => {
+            GroupEntityDescription::BasicGroundCombatSoldier(u.arbitrary()?)
+        }
+        IsGroupOfGroupedEntityCategory::EnhancedGroundCombatSoldier => {
+            GroupEntityDescription::EnhancedGroundCombatSoldier(u.arbitrary()?)
+        }
+        IsGroupOfGroupedEntityCategory::BasicRotorWingAircraft => {
+            GroupEntityDescription::BasicRotorWingAircraft(u.arbitrary()?)
+        }
+        IsGroupOfGroupedEntityCategory::EnhancedRotorWingAircraft => {
+            GroupEntityDescription::EnhancedRotorWingAircraft(u.arbitrary()?)
+        }
+        IsGroupOfGroupedEntityCategory::BasicFixedWingAircraft => {
+            GroupEntityDescription::BasicFixedWingAircraft(u.arbitrary()?)
+        }
+        IsGroupOfGroupedEntityCategory::EnhancedFixedWingAircraft => {
+            GroupEntityDescription::EnhancedFixedWingAircraft(u.arbitrary()?)
+        }
+        IsGroupOfGroupedEntityCategory::GroundLogisticsVehicle => {
+            GroupEntityDescription::GroundLogisticsVehicle(u.arbitrary()?)
+        }
+        IsGroupOfGroupedEntityCategory::Undefined
+        | IsGroupOfGroupedEntityCategory::Unspecified(_) => GroupEntityDescription::Undefined,
+    })
+}
+
+/// Appearance of ground vehicles and soldiers, as interpreted when parsing.
+fn land_platform_appearance(u: &mut Unstructured<'_>) -> arbitrary::Result<EntityAppearance> {
+    appearance_for(
+        u,
+        &EntityType::default()
+            .with_kind(EntityKind::Platform)
+            .with_domain(PlatformDomain::Land),
+    )
+}
+
+/// Appearance of rotor wing and fixed wing aircraft, as interpreted when parsing.
+fn air_platform_appearance(u: &mut Unstructured<'_>) -> arbitrary::Result<EntityAppearance> {
+    appearance_for(
+        u,
+        &EntityType::default()
+            .with_kind(EntityKind::Platform)
+            .with_domain(PlatformDomain::Air),
+    )
+}
+
+impl<'a> Arbitrary<'a> for GroupReferencePoint {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(GroupReferencePoint {
+            latitude: finite_f64(u)?,
+            longitude: finite_f64(u)?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for GEDEntityLocation {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(GEDEntityLocation {
+            x_offset: u.arbitrary()?,
+            y_offset: u.arbitrary()?,
+            z_offset: u.arbitrary()?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for GEDEntityOrientation {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(GEDEntityOrientation {
+            psi: u.arbitrary()?,
+            theta: u.arbitrary()?,
+            phi: u.arbitrary()?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for GEDRecord1 {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(GEDRecord1 {
+            entity_id: u.arbitrary()?,
+            location: u.arbitrary()?,
+            appearance: land_platform_appearance(u)?,
+            orientation: u.arbitrary()?,
+            speed: u.arbitrary()?,
+            turret_azimuth: u.arbitrary()?,
+            gun_elevation: u.arbitrary()?,
+            turret_slew_rate: u.arbitrary()?,
+            gun_elevation_rate: u.arbitrary()?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for GEDRecord2 {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(GEDRecord2 {
+            basic_ground_combat_vehicle: u.arbitrary()?,
+            fuel_status: u.arbitrary()?,
+            ground_maintenance_status: u.arbitrary()?,
+            primary_ammunition: u.arbitrary()?,
+            secondary_ammunition: u.arbitrary()?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for GEDRecord3 {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(GEDRecord3 {
+            entity_id: u.arbitrary()?,
+            location: u.arbitrary()?,
+            appearance: land_platform_appearance(u)?,
+            orientation: u.arbitrary()?,
+            speed: u.arbitrary()?,
+            head_azimuth: u.arbitrary()?,
+            head_elevation: u.arbitrary()?,
+            head_scan_rate: u.arbitrary()?,
+            head_elevation_rate: u.arbitrary()?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for GEDRecord4 {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(GEDRecord4 {
+            basic_ground_combat_soldier: u.arbitrary()?,
+            water_status: u.arbitrary()?,
+            reset_status: u.arbitrary()?,
+            primary_ammunition: u.arbitrary()?,
+            secondary_ammunition: u.arbitrary()?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for GEDRecord5 {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(GEDRecord5 {
+            entity_id: u.arbitrary()?,
+            location: u.arbitrary()?,
+            appearance: air_platform_appearance(u)?,
+            orientation: u.arbitrary()?,
+            fuel_status: u.arbitrary()?,
+            movement_horizontal_deviation: u.arbitrary()?,
+            movement_vertical_deviation: u.arbitrary()?,
+            movement_speed: u.arbitrary()?,
+            turret_azimuth: u.arbitrary()?,
+            gun_elevation: u.arbitrary()?,
+            turret_scan_rate: u.arbitrary()?,
+            gun_elevation_rate: u.arbitrary()?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for GEDRecord6 {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(GEDRecord6 {
+            basic_rotor_wing_aircraft: u.arbitrary()?,
+            supplemental_fuel_status: u.arbitrary()?,
+            air_maintenance_status: u.arbitrary()?,
+            primary_ammunition: u.arbitrary()?,
+            secondary_ammunition: u.arbitrary()?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for GEDRecord7 {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(GEDRecord7 {
+            entity_id: u.arbitrary()?,
+            location: u.arbitrary()?,
+            appearance: air_platform_appearance(u)?,
+            orientation: u.arbitrary()?,
+            fuel_status: u.arbitrary()?,
+            movement_horizontal_deviation: u.arbitrary()?,
+            movement_vertical_deviation: u.arbitrary()?,
+            movement_speed: u.arbitrary()?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for GEDRecord8 {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(GEDRecord8 {
+            basic_fixed_wing_aircraft: u.arbitrary()?,
+            supplemental_fuel_status: u.arbitrary()?,
+            air_maintenance_status: u.arbitrary()?,
+            primary_ammunition: u.arbitrary()?,
+            secondary_ammunition: u.arbitrary()?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for GEDRecord9 {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(GEDRecord9 {
+            entity_id: u.arbitrary()?,
+            location: u.arbitrary()?,
+            appearance: land_platform_appearance(u)?,
+            orientation: u.arbitrary()?,
+            speed: u.arbitrary()?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for TransferOwnership {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(TransferOwnership {
+            originating_id: u.arbitrary()?,
+            receiving_id: u.arbitrary()?,
+            request_id: u.arbitrary()?,
+            required_reliability_service: u.arbitrary()?,
+            transfer_type: u.arbitrary()?,
+            transfer_entity_id: u.arbitrary()?,
+            record_specification: u.arbitrary()?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for IsPartOf {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(IsPartOf {
+            originating_simulation_id: u.arbitrary()?,
+            receiving_entity_id: u.arbitrary()?,
+            relationship: u.arbitrary()?,
+            part_location: u.arbitrary()?,
+            named_location_id: u.arbitrary()?,
+            part_type: u.arbitrary()?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for Relationship {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Relationship {
+            nature: u.arbitrary()?,
+            position: u.arbitrary()?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for NamedLocationId {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(NamedLocationId {
+            station_name: u.arbitrary()?,
+            station_number: u.arbitrary()?,
+        })
+    }
+}
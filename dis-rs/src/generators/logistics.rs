@@ -0,0 +1,68 @@
+use crate::common::repair_complete::model::RepairComplete;
+use crate::common::repair_response::model::RepairResponse;
+use crate::common::resupply_cancel::model::ResupplyCancel;
+use crate::common::resupply_offer::model::ResupplyOffer;
+use crate::common::resupply_received::model::ResupplyReceived;
+use crate::common::service_request::model::ServiceRequest;
+use crate::generators::elements;
+use arbitrary::{Arbitrary, Unstructured};
+
+impl<'a> Arbitrary<'a> for ServiceRequest {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(ServiceRequest {
+            requesting_id: u.arbitrary()?,
+            servicing_id: u.arbitrary()?,
+            service_type_requested: u.arbitrary()?,
+            supplies: elements(u)?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for ResupplyOffer {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(ResupplyOffer {
+            requesting_id: u.arbitrary()?,
+            servicing_id: u.arbitrary()?,
+            supplies: elements(u)?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for ResupplyReceived {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(ResupplyReceived {
+            requesting_id: u.arbitrary()?,
+            servicing_id: u.arbitrary()?,
+            supplies: elements(u)?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for ResupplyCancel {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(ResupplyCancel {
+            requesting_id: u.arbitrary()?,
+            servicing_id: u.arbitrary()?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for RepairComplete {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(RepairComplete {
+            receiving_id: u.arbitrary()?,
+            repairing_id: u.arbitrary()?,
+            repair: u.arbitrary()?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for RepairResponse {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(RepairResponse {
+            receiving_id: u.arbitrary()?,
+            repairing_id: u.arbitrary()?,
+            repair_result: u.arbitrary()?,
+        })
+    }
+}
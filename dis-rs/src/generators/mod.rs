@@ -0,0 +1,325 @@
+//! Generators for arbitrary PDUs, for use in property tests and fuzzing.
+//!
+//! With the `arbitrary` feature enabled, all modelled PDU bodies and their records implement [`Arbitrary`].
+//! Generated values are valid by construction: they respect field widths, lengths, counts and padding,
+//! and the variants of records that are selected by other fields (such as the Dead Reckoning algorithm,
+//! the IFF system type or the signal encoding class).
+//! As a result, serializing and then parsing a generated `Pdu` yields the same `Pdu` again.
+//!
+//! Use [`arbitrary_pdu`] to generate a PDU of a specific type and protocol version.
+//! The `Arbitrary` implementation of `Pdu` selects both at random.
+//!
+//! Floating point fields are always finite, as `NaN` is not equal to itself.
+use crate::common::model::{Pdu, PduBody, PduHeader, TimeStamp};
+use crate::common::other::model::Other;
+use crate::common::{supported_protocol_versions, BodyInfo};
+use crate::enumerations::{
+    DetonationTypeIndicator, EntityCapabilities as EntityCapabilitiesV7, FireTypeIndicator,
+    PduType, ProtocolVersion,
+};
+use crate::model::DescriptorRecord;
+use crate::v6::entity_state::model::EntityCapabilities;
+use crate::v7::parser::parse_pdu_status_fields;
+use arbitrary::{Arbitrary, Unstructured};
+
+mod emissions;
+mod entity_information;
+mod entity_management;
+mod logistics;
+mod radio;
+mod records;
+mod simulation_management;
+mod warfare;
+
+/// Maximum number of elements in generated lists, keeping generated PDUs small.
+const MAX_ELEMENTS: usize = 3;
+/// Maximum length in octets of generated raw data fields.
+const MAX_DATA_OCTETS: usize = 24;
+/// Characters used in generated markings and identifications.
+const ALPHANUMERIC: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789";
+
+/// The `PduType`s for which a modelled body is generated.
+pub const GENERATED_PDU_TYPES: &[PduType] = &[
+    PduType::Other,
+    PduType::EntityState,
+    PduType::Fire,
+    PduType::Detonation,
+    PduType::Collision,
+    PduType::ServiceRequest,
+    PduType::ResupplyOffer,
+    PduType::ResupplyReceived,
+    PduType::ResupplyCancel,
+    PduType::RepairComplete,
+    PduType::RepairResponse,
+    PduType::CreateEntity,
+    PduType::RemoveEntity,
+    PduType::StartResume,
+    PduType::StopFreeze,
+    PduType::Acknowledge,
+    PduType::ActionRequest,
+    PduType::ActionResponse,
+    PduType::DataQuery,
+    PduType::SetData,
+    PduType::Data,
+    PduType::EventReport,
+    PduType::Comment,
+    PduType::ElectromagneticEmission,
+    PduType::Designator,
+    PduType::Transmitter,
+    PduType::Signal,
+    PduType::Receiver,
+    PduType::IFF,
+    PduType::UnderwaterAcoustic,
+    PduType::SupplementalEmissionEntityState,
+    PduType::AggregateState,
+    PduType::IsGroupOf,
+    PduType::TransferOwnership,
+    PduType::IsPartOf,
+    PduType::CreateEntityR,
+    PduType::RemoveEntityR,
+    PduType::StartResumeR,
+    PduType::StopFreezeR,
+    PduType::AcknowledgeR,
+    PduType::ActionRequestR,
+    PduType::ActionResponseR,
+    PduType::DataQueryR,
+    PduType::SetDataR,
+    PduType::DataR,
+    PduType::EventReportR,
+    PduType::CommentR,
+    PduType::RecordR,
+    PduType::SetRecordR,
+    PduType::RecordQueryR,
+    PduType::CollisionElastic,
+    PduType::EntityStateUpdate,
+    PduType::Attribute,
+];
+
+/// Generates a `Pdu` of the given type for the given protocol version.
+///
+/// The header is consistent with the body: the length and type are set from the body,
+/// and for v7 the PDU Status fields are generated for the type, with the Fire and Detonation
+/// type indicators matching the descriptor record of the body.
+/// Bodies for v6 are restricted to what v6 can express.
+/// Types that are not listed in [`GENERATED_PDU_TYPES`] result in a PDU of type `Other`.
+///
+/// # Errors
+/// Returns an `arbitrary::Error` when a value cannot be generated from the provided data.
+pub fn arbitrary_pdu(
+    u: &mut Unstructured<'_>,
+    pdu_type: PduType,
+    protocol_version: ProtocolVersion,
+) -> arbitrary::Result<Pdu> {
+    let body = arbitrary_body(u, pdu_type)?;
+    let header = PduHeader::new(protocol_version, u.arbitrary()?, body.body_type());
+
+    let (header, body) = if protocol_version == ProtocolVersion::IEEE1278_12012 {
+        let status = parse_pdu_status_fields(u8::from(body.body_type()), u.arbitrary()?);
+        let status = match &body {
+            PduBody::Fire(fire) => status.with_fire_type_indicator(match fire.descriptor {
+                DescriptorRecord::Munition { .. } => FireTypeIndicator::Munition,
+                _ => FireTypeIndicator::Expendable,
+            }),
+            PduBody::Detonation(detonation) => {
+                status.with_detonation_type_indicator(match detonation.descriptor {
+                    DescriptorRecord::Munition { .. } => DetonationTypeIndicator::Munition,
+                    DescriptorRecord::Expendable { .. } => DetonationTypeIndicator::Expendable,
+                    DescriptorRecord::Explosion { .. } => {
+                        DetonationTypeIndicator::NonmunitionExplosion
+                    }
+                })
+            }
+            _ => status,
+        };
+        (header.with_pdu_status(status), body)
+    } else {
+        (header, restrict_to_v6(u, body)?)
+    };
+
+    Ok(Pdu::finalize_from_parts(
+        header,
+        body,
+        TimeStamp::from(u.arbitrary::<u32>()?),
+    ))
+}
+
+/// Generates a body of the given type, with v7 semantics.
+fn arbitrary_body(u: &mut Unstructured<'_>, pdu_type: PduType) -> arbitrary::Result<PduBody> {
+    Ok(match pdu_type {
+        PduType::EntityState => PduBody::EntityState(u.arbitrary()?),
+        PduType::Fire => PduBody::Fire(u.arbitrary()?),
+        PduType::Detonation => PduBody::Detonation(u.arbitrary()?),
+        PduType::Collision => PduBody::Collision(u.arbitrary()?),
+        PduType::ServiceRequest => PduBody::ServiceRequest(u.arbitrary()?),
+        PduType::ResupplyOffer => PduBody::ResupplyOffer(u.arbitrary()?),
+        PduType::ResupplyReceived => PduBody::ResupplyReceived(u.arbitrary()?),
+        PduType::ResupplyCancel => PduBody::ResupplyCancel(u.arbitrary()?),
+        PduType::RepairComplete => PduBody::RepairComplete(u.arbitrary()?),
+        PduType::RepairResponse => PduBody::RepairResponse(u.arbitrary()?),
+        PduType::CreateEntity => PduBody::CreateEntity(u.arbitrary()?),
+        PduType::RemoveEntity => PduBody::RemoveEntity(u.arbitrary()?),
+        PduType::StartResume => PduBody::StartResume(u.arbitrary()?),
+        PduType::StopFreeze => PduBody::StopFreeze(u.arbitrary()?),
+        PduType::Acknowledge => PduBody::Acknowledge(u.arbitrary()?),
+        PduType::ActionRequest => PduBody::ActionRequest(u.arbitrary()?),
+        PduType::ActionResponse => PduBody::ActionResponse(u.arbitrary()?),
+        PduType::DataQuery => PduBody::DataQuery(u.arbitrary()?),
+        PduType::SetData => PduBody::SetData(u.arbitrary()?),
+        PduType::Data => PduBody::Data(u.arbitrary()?),
+        PduType::EventReport => PduBody::EventReport(u.arbitrary()?),
+        PduType::Comment => PduBody::Comment(u.arbitrary()?),
+        PduType::ElectromagneticEmission => PduBody::ElectromagneticEmission(u.arbitrary()?),
+        PduType::Designator => PduBody::Designator(u.arbitrary()?),
+        PduType::Transmitter => PduBody::Transmitter(u.arbitrary()?),
+        PduType::Signal => PduBody::Signal(u.arbitrary()?),
+        PduType::Receiver => PduBody::Receiver(u.arbitrary()?),
+        PduType::IFF => PduBody::IFF(u.arbitrary()?),
+        PduType::UnderwaterAcoustic => PduBody::UnderwaterAcoustic(u.arbitrary()?),
+        PduType::SupplementalEmissionEntityState => {
+            PduBody::SupplementalEmissionEntityState(u.arbitrary()?)
+        }
+        PduType::AggregateState => PduBody::AggregateState(u.arbitrary()?),
+        PduType::IsGroupOf => PduBody::IsGroupOf(u.arbitrary()?),
+        PduType::TransferOwnership => PduBody::TransferOwnership(u.arbitrary()?),
+        PduType::IsPartOf => PduBody::IsPartOf(u.arbitrary()?),
+        PduType::CreateEntityR => PduBody::CreateEntityR(u.arbitrary()?),
+        PduType::RemoveEntityR => PduBody::RemoveEntityR(u.arbitrary()?),
+        PduType::StartResumeR => PduBody::StartResumeR(u.arbitrary()?),
+        PduType::StopFreezeR => PduBody::StopFreezeR(u.arbitrary()?),
+        PduType::AcknowledgeR => PduBody::AcknowledgeR(u.arbitrary()?),
+        PduType::ActionRequestR => PduBody::ActionRequestR(u.arbitrary()?),
+        PduType::ActionResponseR => PduBody::ActionResponseR(u.arbitrary()?),
+        PduType::DataQueryR => PduBody::DataQueryR(u.arbitrary()?),
+        PduType::SetDataR => PduBody::SetDataR(u.arbitrary()?),
+        PduType::DataR => PduBody::DataR(u.arbitrary()?),
+        PduType::EventReportR => PduBody::EventReportR(u.arbitrary()?),
+        PduType::CommentR => PduBody::CommentR(u.arbitrary()?),
+        PduType::RecordR => PduBody::RecordR(u.arbitrary()?),
+        PduType::SetRecordR => PduBody::SetRecordR(u.arbitrary()?),
+        PduType::RecordQueryR => PduBody::RecordQueryR(u.arbitrary()?),
+        PduType::CollisionElastic => PduBody::CollisionElastic(u.arbitrary()?),
+        PduType::EntityStateUpdate => PduBody::EntityStateUpdate(u.arbitrary()?),
+        PduType::Attribute => PduBody::Attribute(u.arbitrary()?),
+        _ => PduBody::Other(u.arbitrary()?),
+    })
+}
+
+/// Replaces the parts of a body that v6 cannot express:
+/// v6 has no Fire and Detonation type indicators (only the Munition descriptor),
+/// only the four v6 entity capabilities, and no Variable Transmitter Parameters.
+fn restrict_to_v6(u: &mut Unstructured<'_>, body: PduBody) -> arbitrary::Result<PduBody> {
+    Ok(match body {
+        PduBody::EntityState(mut entity_state) => {
+            let capabilities = EntityCapabilities {
+                ammunition_supply: u.arbitrary()?,
+                fuel_supply: u.arbitrary()?,
+                recovery: u.arbitrary()?,
+                repair: u.arbitrary()?,
+            };
+            entity_state.entity_capabilities = EntityCapabilitiesV7::from(capabilities);
+            PduBody::EntityState(entity_state)
+        }
+        PduBody::Fire(mut fire) => {
+            fire.descriptor = munition_descriptor(u, fire.descriptor)?;
+            PduBody::Fire(fire)
+        }
+        PduBody::Detonation(mut detonation) => {
+            detonation.descriptor = munition_descriptor(u, detonation.descriptor)?;
+            PduBody::Detonation(detonation)
+        }
+        PduBody::Transmitter(mut transmitter) => {
+            transmitter.variable_transmitter_parameters.clear();
+            PduBody::Transmitter(transmitter)
+        }
+        body => body,
+    })
+}
+
+fn munition_descriptor(
+    u: &mut Unstructured<'_>,
+    descriptor: DescriptorRecord,
+) -> arbitrary::Result<DescriptorRecord> {
+    Ok(match descriptor {
+        DescriptorRecord::Munition { .. } => descriptor,
+        DescriptorRecord::Expendable { entity_type }
+        | DescriptorRecord::Explosion { entity_type, .. } => {
+            DescriptorRecord::new_munition(entity_type, u.arbitrary()?)
+        }
+    })
+}
+
+impl<'a> Arbitrary<'a> for Pdu {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        let protocol_version = *u.choose(&supported_protocol_versions())?;
+        let pdu_type = *u.choose(GENERATED_PDU_TYPES)?;
+        arbitrary_pdu(u, pdu_type, protocol_version)
+    }
+}
+
+/// Generates a body of one of the [`GENERATED_PDU_TYPES`], as it can be expressed in v7.
+impl<'a> Arbitrary<'a> for PduBody {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        let pdu_type = *u.choose(GENERATED_PDU_TYPES)?;
+        arbitrary_body(u, pdu_type)
+    }
+}
+
+/// A PDU of an unmodelled type, without originating or receiving entity.
+impl<'a> Arbitrary<'a> for Other {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Other {
+            originating_entity_id: None,
+            receiving_entity_id: None,
+            body: bytes(u, MAX_DATA_OCTETS)?,
+        })
+    }
+}
+
+/// Generates a finite `f32`; non-finite bit patterns become zero.
+pub(crate) fn finite_f32(u: &mut Unstructured<'_>) -> arbitrary::Result<f32> {
+    let value = f32::from_bits(u.arbitrary()?);
+    Ok(if value.is_finite() { value } else { 0.0 })
+}
+
+/// Generates a finite `f64`; non-finite bit patterns become zero.
+pub(crate) fn finite_f64(u: &mut Unstructured<'_>) -> arbitrary::Result<f64> {
+    let value = f64::from_bits(u.arbitrary()?);
+    Ok(if value.is_finite() { value } else { 0.0 })
+}
+
+/// Generates a list of at most `max` elements using `element`.
+pub(crate) fn list<'a, T>(
+    u: &mut Unstructured<'a>,
+    max: usize,
+    mut element: impl FnMut(&mut Unstructured<'a>) -> arbitrary::Result<T>,
+) -> arbitrary::Result<Vec<T>> {
+    let length = u.int_in_range(0..=max)?;
+    (0..length).map(|_| element(u)).collect()
+}
+
+/// Generates a list of at most [`MAX_ELEMENTS`] arbitrary elements.
+pub(crate) fn elements<'a, T: Arbitrary<'a>>(
+    u: &mut Unstructured<'a>,
+) -> arbitrary::Result<Vec<T>> {
+    list(u, MAX_ELEMENTS, |u| u.arbitrary())
+}
+
+/// Generates exactly `length` octets.
+pub(crate) fn octets(u: &mut Unstructured<'_>, length: usize) -> arbitrary::Result<Vec<u8>> {
+    (0..length).map(|_| u.arbitrary::<u8>()).collect()
+}
+
+/// Generates at most `max` octets.
+pub(crate) fn bytes(u: &mut Unstructured<'_>, max: usize) -> arbitrary::Result<Vec<u8>> {
+    let length = u.int_in_range(0..=max)?;
+    octets(u, length)
+}
+
+/// Generates a string of at most `max` alphanumeric ASCII characters,
+/// which survives the sanitizing of markings when parsing.
+pub(crate) fn alphanumeric(u: &mut Unstructured<'_>, max: usize) -> arbitrary::Result<String> {
+    let length = u.int_in_range(0..=max)?;
+    (0..length)
+        .map(|_| u.choose(ALPHANUMERIC).map(|c| char::from(*c)))
+        .collect()
+}
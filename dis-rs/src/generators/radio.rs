@@ -0,0 +1,235 @@
+use crate::common::receiver::model::Receiver;
+use crate::common::signal::model::{EncodingScheme, Signal};
+use crate::common::transmitter::model::{
+    AntennaPattern, BeamAntennaPattern, CryptoKeyId, ModulationParameters, ModulationType,
+    SphericalHarmonicAntennaPattern, SpreadSpectrum, Transmitter, VariableTransmitterParameter,
+    VariableTransmitterParameterFields, BASE_VTP_RECORD_LENGTH,
+};
+use crate::enumerations::{
+    SignalEncodingClass, SignalEncodingType, SignalUserProtocolIdentificationNumber,
+    TransmitterAntennaPatternType, TransmitterMajorModulation,
+};
+use crate::generators::{bytes, elements, finite_f32, octets, MAX_DATA_OCTETS, MAX_ELEMENTS};
+use arbitrary::{Arbitrary, Unstructured};
+
+/// Major Modulation values from this value onward are not defined, and cannot be written back as such.
+const MAX_MAJOR_MODULATION: u16 = 8;
+/// The encoding type and the number of messages share a 16-bit field with the two-bit encoding class.
+const MAX_ENCODING_SCHEME_LOW_BITS: u16 = 0x3FFF;
+/// Antenna pattern types from this value onward are not modelled, and their patterns are kept as raw bytes.
+const MIN_UNMODELLED_ANTENNA_PATTERN_TYPE: u16 = 3;
+/// Keeps the number of (N+1)^2 coefficients of a Spherical Harmonic Antenna Pattern small.
+const MAX_SPHERICAL_HARMONIC_ORDER: u8 = 3;
+
+/// The modulation parameters are decoded for the radio system of the modulation type, as when parsing.
+impl<'a> Arbitrary<'a> for Transmitter {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        let modulation_type: ModulationType = u.arbitrary()?;
+        let modulation_parameters = if u.arbitrary()? {
+            let length = u.int_in_range(1..=MAX_DATA_OCTETS)?;
+            Some(ModulationParameters::from_bytes(
+                &modulation_type,
+                &octets(u, length)?,
+            ))
+        } else {
+            None
+        };
+        let antenna_pattern: Option<AntennaPattern> = u.arbitrary()?;
+        let antenna_pattern_type = match antenna_pattern {
+            Some(AntennaPattern::Beam(_)) => TransmitterAntennaPatternType::Beam,
+            Some(AntennaPattern::SphericalHarmonic(_)) => {
+                TransmitterAntennaPatternType::SphericalHarmonic
+            }
+            Some(AntennaPattern::Unspecified(_)) => TransmitterAntennaPatternType::from(
+                u.int_in_range(MIN_UNMODELLED_ANTENNA_PATTERN_TYPE..=u16::MAX)?,
+            ),
+            None => u.arbitrary()?,
+        };
+        Ok(Transmitter {
+            radio_reference_id: u.arbitrary()?,
+            radio_number: u.arbitrary()?,
+            radio_type: u.arbitrary()?,
+            transmit_state: u.arbitrary()?,
+            input_source: u.arbitrary()?,
+            antenna_location: u.arbitrary()?,
+            relative_antenna_location: u.arbitrary()?,
+            antenna_pattern_type,
+            frequency: u.arbitrary()?,
+            transmit_frequency_bandwidth: finite_f32(u)?,
+            power: finite_f32(u)?,
+            modulation_type,
+            crypto_system: u.arbitrary()?,
+            crypto_key_id: u.arbitrary()?,
+            modulation_parameters,
+            antenna_pattern,
+            variable_transmitter_parameters: elements(u)?,
+        })
+    }
+}
+
+/// The Major Modulation is one of the defined values, with a detail interpreted as when parsing.
+impl<'a> Arbitrary<'a> for ModulationType {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        let major_modulation = u.int_in_range(0..=MAX_MAJOR_MODULATION)?;
+        let detail = if major_modulation == 0 {
+            0
+        } else {
+            u.arbitrary()?
+        };
+        Ok(ModulationType {
+            spread_spectrum: u.arbitrary()?,
+            major_modulation: TransmitterMajorModulation::new_from_bytes_with_detail(
+                major_modulation,
+                detail,
+            ),
+            radio_system: u.arbitrary()?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for SpreadSpectrum {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(SpreadSpectrum::from(u.arbitrary::<u16>()?))
+    }
+}
+
+impl<'a> Arbitrary<'a> for CryptoKeyId {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(CryptoKeyId::from(u.arbitrary::<u16>()?))
+    }
+}
+
+/// The pattern type of the Transmitter is set to match the pattern; unspecified patterns are never empty.
+impl<'a> Arbitrary<'a> for AntennaPattern {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(match u.int_in_range(0..=2u8)? {
+            0 => AntennaPattern::Beam(u.arbitrary()?),
+            1 => AntennaPattern::SphericalHarmonic(u.arbitrary()?),
+            _ => {
+                let length = u.int_in_range(1..=MAX_DATA_OCTETS)?;
+                AntennaPattern::Unspecified(octets(u, length)?)
+            }
+        })
+    }
+}
+
+/// The number of coefficients matches the order of the pattern.
+impl<'a> Arbitrary<'a> for SphericalHarmonicAntennaPattern {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        let order = u.int_in_range(0..=MAX_SPHERICAL_HARMONIC_ORDER)?;
+        let coefficients = (0..SphericalHarmonicAntennaPattern::number_of_coefficients(order))
+            .map(|_| finite_f32(u))
+            .collect::<arbitrary::Result<Vec<f32>>>()?;
+        Ok(SphericalHarmonicAntennaPattern {
+            order,
+            coefficients,
+            reference_system: u.arbitrary()?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for BeamAntennaPattern {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(BeamAntennaPattern {
+            beam_direction: u.arbitrary()?,
+            azimuth_beamwidth: finite_f32(u)?,
+            elevation_beamwidth: finite_f32(u)?,
+            reference_system: u.arbitrary()?,
+            e_z: finite_f32(u)?,
+            e_x: finite_f32(u)?,
+            phase: finite_f32(u)?,
+        })
+    }
+}
+
+/// The fields fill up the record to an 8-octet boundary, so no padding is written,
+/// and are decoded for the record type, as when parsing.
+impl<'a> Arbitrary<'a> for VariableTransmitterParameter {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        const FIELDS_BASE_OCTETS: usize = 8 - BASE_VTP_RECORD_LENGTH as usize % 8;
+        let blocks = u.int_in_range(0..=MAX_ELEMENTS)?;
+        let record_type = u.arbitrary()?;
+        let fields = octets(u, FIELDS_BASE_OCTETS + 8 * blocks)?;
+        Ok(VariableTransmitterParameter {
+            record_type,
+            fields: VariableTransmitterParameterFields::from_bytes(record_type, &fields),
+        })
+    }
+}
+
+/// The encoding scheme matches the data: Application Specific Data starts with the User Protocol ID,
+/// and a Database Index with the index, offset and duration, as they are read from the data when parsing.
+impl<'a> Arbitrary<'a> for Signal {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        let (encoding_scheme, mut data) = match u.int_in_range(0..=3u8)? {
+            0 => (
+                EncodingScheme::EncodedAudio {
+                    encoding_class: SignalEncodingClass::EncodedAudio,
+                    encoding_type: SignalEncodingType::from(
+                        u.int_in_range(0..=MAX_ENCODING_SCHEME_LOW_BITS)?,
+                    ),
+                },
+                Vec::new(),
+            ),
+            1 => (
+                EncodingScheme::RawBinaryData {
+                    encoding_class: SignalEncodingClass::RawBinaryData,
+                    nr_of_messages: u.int_in_range(0..=MAX_ENCODING_SCHEME_LOW_BITS)?,
+                },
+                Vec::new(),
+            ),
+            2 => {
+                let user_protocol_id: SignalUserProtocolIdentificationNumber = u.arbitrary()?;
+                (
+                    EncodingScheme::ApplicationSpecificData {
+                        encoding_class: SignalEncodingClass::ApplicationSpecificData,
+                        user_protocol_id,
+                    },
+                    u32::from(user_protocol_id).to_be_bytes().to_vec(),
+                )
+            }
+            _ => {
+                let index: u32 = u.arbitrary()?;
+                let offset_milli_secs: u32 = u.arbitrary()?;
+                let duration_milli_secs: u32 = u.arbitrary()?;
+                let data = [index, offset_milli_secs, duration_milli_secs]
+                    .iter()
+                    .flat_map(|field| field.to_be_bytes())
+                    .collect();
+                (
+                    EncodingScheme::DatabaseIndex {
+                        encoding_class: SignalEncodingClass::DatabaseIndex,
+                        index,
+                        offset_milli_secs,
+                        duration_milli_secs,
+                    },
+                    data,
+                )
+            }
+        };
+        data.extend(bytes(u, MAX_DATA_OCTETS)?);
+
+        Ok(Signal {
+            radio_reference_id: u.arbitrary()?,
+            radio_number: u.arbitrary()?,
+            encoding_scheme,
+            tdl_type: u.arbitrary()?,
+            sample_rate: u.arbitrary()?,
+            samples: u.arbitrary()?,
+            data,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for Receiver {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Receiver {
+            radio_reference_id: u.arbitrary()?,
+            radio_number: u.arbitrary()?,
+            receiver_state: u.arbitrary()?,
+            received_power: finite_f32(u)?,
+            transmitter_radio_reference_id: u.arbitrary()?,
+            transmitter_radio_number: u.arbitrary()?,
+        })
+    }
+}
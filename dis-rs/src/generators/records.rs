@@ -0,0 +1,281 @@
+use crate::common::entity_state::model::EntityAppearance;
+use crate::common::model::{
+    ArticulatedPart, AttachedPart, BeamData, ClockTime, DatumSpecification, DescriptorRecord,
+    EntityAssociationParameter, EntityId, EntityType, EntityTypeParameter, EventId, FixedDatum,
+    Location, MunitionDescriptor, Orientation, RecordSet, RecordSpecification, SeparationParameter,
+    SimulationAddress, SupplyQuantity, TimeStamp, VariableDatum, VariableParameter, VectorF32,
+};
+use crate::enumerations::{ArticulatedPartsTypeClass, ArticulatedPartsTypeMetric};
+use crate::generators::{
+    bytes, elements, finite_f32, finite_f64, list, octets, MAX_DATA_OCTETS, MAX_ELEMENTS,
+};
+use arbitrary::{Arbitrary, Unstructured};
+
+/// The Type Metric occupies the five least significant bits of the Parameter Type of an Articulated Part.
+const TYPE_METRIC_BITS: u32 = 0x1F;
+/// Designators from this value onward are not defined, and are modelled as `VariableParameter::Unspecified`.
+const FIRST_UNSPECIFIED_VARIABLE_PARAMETER_DESIGNATOR: u8 = 5;
+
+impl<'a> Arbitrary<'a> for SimulationAddress {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(SimulationAddress {
+            site_id: u.arbitrary()?,
+            application_id: u.arbitrary()?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for EntityId {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(EntityId {
+            simulation_address: u.arbitrary()?,
+            entity_id: u.arbitrary()?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for EventId {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(EventId {
+            simulation_address: u.arbitrary()?,
+            event_id: u.arbitrary()?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for VectorF32 {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(VectorF32 {
+            first_vector_component: finite_f32(u)?,
+            second_vector_component: finite_f32(u)?,
+            third_vector_component: finite_f32(u)?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for Location {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Location {
+            x_coordinate: finite_f64(u)?,
+            y_coordinate: finite_f64(u)?,
+            z_coordinate: finite_f64(u)?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for Orientation {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Orientation {
+            psi: finite_f32(u)?,
+            theta: finite_f32(u)?,
+            phi: finite_f32(u)?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for EntityType {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(EntityType {
+            kind: u.arbitrary()?,
+            domain: u.arbitrary()?,
+            country: u.arbitrary()?,
+            category: u.arbitrary()?,
+            subcategory: u.arbitrary()?,
+            specific: u.arbitrary()?,
+            extra: u.arbitrary()?,
+        })
+    }
+}
+
+/// An appearance as interpreted for an arbitrary entity type.
+impl<'a> Arbitrary<'a> for EntityAppearance {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        let entity_type: EntityType = u.arbitrary()?;
+        appearance_for(u, &entity_type)
+    }
+}
+
+/// Generates an appearance as it is interpreted for `entity_type` when parsing.
+pub(crate) fn appearance_for(
+    u: &mut Unstructured<'_>,
+    entity_type: &EntityType,
+) -> arbitrary::Result<EntityAppearance> {
+    Ok(EntityAppearance::from_bytes(u.arbitrary()?, entity_type))
+}
+
+impl<'a> Arbitrary<'a> for MunitionDescriptor {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(MunitionDescriptor {
+            warhead: u.arbitrary()?,
+            fuse: u.arbitrary()?,
+            quantity: u.arbitrary()?,
+            rate: u.arbitrary()?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for DescriptorRecord {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(match u.int_in_range(0..=2u8)? {
+            0 => DescriptorRecord::new_munition(u.arbitrary()?, u.arbitrary()?),
+            1 => DescriptorRecord::new_expendable(u.arbitrary()?),
+            _ => DescriptorRecord::new_explosion(u.arbitrary()?, u.arbitrary()?, finite_f32(u)?),
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for TimeStamp {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(TimeStamp::from(u.arbitrary::<u32>()?))
+    }
+}
+
+impl<'a> Arbitrary<'a> for ClockTime {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(ClockTime::new(u.arbitrary()?, u.arbitrary()?))
+    }
+}
+
+impl<'a> Arbitrary<'a> for FixedDatum {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(FixedDatum {
+            datum_id: u.arbitrary()?,
+            datum_value: u.arbitrary()?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for VariableDatum {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(VariableDatum {
+            datum_id: u.arbitrary()?,
+            datum_value: bytes(u, MAX_DATA_OCTETS)?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for DatumSpecification {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(DatumSpecification {
+            fixed_datum_records: elements(u)?,
+            variable_datum_records: elements(u)?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for VariableParameter {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(
+            match u.int_in_range(0..=FIRST_UNSPECIFIED_VARIABLE_PARAMETER_DESIGNATOR)? {
+                0 => VariableParameter::Articulated(u.arbitrary()?),
+                1 => VariableParameter::Attached(u.arbitrary()?),
+                2 => VariableParameter::Separation(u.arbitrary()?),
+                3 => VariableParameter::EntityType(u.arbitrary()?),
+                4 => VariableParameter::EntityAssociation(u.arbitrary()?),
+                _ => VariableParameter::Unspecified(
+                    u.int_in_range(FIRST_UNSPECIFIED_VARIABLE_PARAMETER_DESIGNATOR..=u8::MAX)?,
+                    u.arbitrary()?,
+                ),
+            },
+        )
+    }
+}
+
+/// The Type Class and Type Metric share a single field on the wire,
+/// so the Type Metric is limited to five bits and the Type Class to the remaining bits.
+impl<'a> Arbitrary<'a> for ArticulatedPart {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(ArticulatedPart {
+            change_indicator: u.arbitrary()?,
+            attachment_id: u.arbitrary()?,
+            type_metric: ArticulatedPartsTypeMetric::from(u.int_in_range(0..=TYPE_METRIC_BITS)?),
+            type_class: ArticulatedPartsTypeClass::from(u.arbitrary::<u32>()? & !TYPE_METRIC_BITS),
+            parameter_value: finite_f32(u)?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for AttachedPart {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(AttachedPart {
+            detached_indicator: u.arbitrary()?,
+            attachment_id: u.arbitrary()?,
+            parameter_type: u.arbitrary()?,
+            attached_part_type: u.arbitrary()?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for SeparationParameter {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(SeparationParameter {
+            reason: u.arbitrary()?,
+            pre_entity_indicator: u.arbitrary()?,
+            parent_entity_id: u.arbitrary()?,
+            station_name: u.arbitrary()?,
+            station_number: u.arbitrary()?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for EntityTypeParameter {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(EntityTypeParameter {
+            change_indicator: u.arbitrary()?,
+            entity_type: u.arbitrary()?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for EntityAssociationParameter {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(EntityAssociationParameter {
+            change_indicator: u.arbitrary()?,
+            association_status: u.arbitrary()?,
+            association_type: u.arbitrary()?,
+            entity_id: u.arbitrary()?,
+            own_station_location: u.arbitrary()?,
+            physical_connection_type: u.arbitrary()?,
+            group_member_type: u.arbitrary()?,
+            group_number: u.arbitrary()?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for BeamData {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(BeamData {
+            azimuth_center: finite_f32(u)?,
+            azimuth_sweep: finite_f32(u)?,
+            elevation_center: finite_f32(u)?,
+            elevation_sweep: finite_f32(u)?,
+            sweep_sync: finite_f32(u)?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for SupplyQuantity {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(SupplyQuantity {
+            supply_type: u.arbitrary()?,
+            quantity: finite_f32(u)?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for RecordSpecification {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(RecordSpecification::default().with_record_sets(elements(u)?))
+    }
+}
+
+/// All Record Values in a set have the same length.
+impl<'a> Arbitrary<'a> for RecordSet {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        let record_length = u.int_in_range(1..=MAX_DATA_OCTETS)?;
+        Ok(RecordSet::default()
+            .with_record_id(u.arbitrary()?)
+            .with_record_serial_number(u.arbitrary()?)
+            .with_records(list(u, MAX_ELEMENTS, |u| octets(u, record_length))?))
+    }
+}
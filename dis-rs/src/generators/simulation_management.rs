@@ -0,0 +1,369 @@
+use crate::common::acknowledge::model::Acknowledge;
+use crate::common::acknowledge_r::model::AcknowledgeR;
+use crate::common::action_request::model::ActionRequest;
+use crate::common::action_request_r::model::ActionRequestR;
+use crate::common::action_response::model::ActionResponse;
+use crate::common::action_response_r::model::ActionResponseR;
+use crate::common::comment::model::Comment;
+use crate::common::comment_r::model::CommentR;
+use crate::common::create_entity::model::CreateEntity;
+use crate::common::create_entity_r::model::CreateEntityR;
+use crate::common::data::model::Data;
+use crate::common::data_query::model::DataQuery;
+use crate::common::data_query_r::model::DataQueryR;
+use crate::common::data_r::model::DataR;
+use crate::common::event_report::model::EventReport;
+use crate::common::event_report_r::model::EventReportR;
+use crate::common::record_query_r::model::{RecordQueryR, RecordQuerySpecification};
+use crate::common::record_r::model::RecordR;
+use crate::common::remove_entity::model::RemoveEntity;
+use crate::common::remove_entity_r::model::RemoveEntityR;
+use crate::common::set_data::model::SetData;
+use crate::common::set_data_r::model::SetDataR;
+use crate::common::set_record_r::model::SetRecordR;
+use crate::common::start_resume::model::StartResume;
+use crate::common::start_resume_r::model::StartResumeR;
+use crate::common::stop_freeze::model::StopFreeze;
+use crate::common::stop_freeze_r::model::StopFreezeR;
+use crate::generators::elements;
+use arbitrary::{Arbitrary, Unstructured};
+
+impl<'a> Arbitrary<'a> for CreateEntity {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(CreateEntity {
+            originating_id: u.arbitrary()?,
+            receiving_id: u.arbitrary()?,
+            request_id: u.arbitrary()?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for CreateEntityR {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(CreateEntityR {
+            originating_id: u.arbitrary()?,
+            receiving_id: u.arbitrary()?,
+            required_reliability_service: u.arbitrary()?,
+            request_id: u.arbitrary()?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for RemoveEntity {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(RemoveEntity {
+            originating_id: u.arbitrary()?,
+            receiving_id: u.arbitrary()?,
+            request_id: u.arbitrary()?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for RemoveEntityR {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(RemoveEntityR {
+            originating_id: u.arbitrary()?,
+            receiving_id: u.arbitrary()?,
+            required_reliability_service: u.arbitrary()?,
+            request_id: u.arbitrary()?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for StartResume {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(StartResume {
+            originating_id: u.arbitrary()?,
+            receiving_id: u.arbitrary()?,
+            real_world_time: u.arbitrary()?,
+            simulation_time: u.arbitrary()?,
+            request_id: u.arbitrary()?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for StartResumeR {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(StartResumeR {
+            originating_id: u.arbitrary()?,
+            receiving_id: u.arbitrary()?,
+            real_world_time: u.arbitrary()?,
+            simulation_time: u.arbitrary()?,
+            required_reliability_service: u.arbitrary()?,
+            request_id: u.arbitrary()?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for StopFreeze {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(StopFreeze {
+            originating_id: u.arbitrary()?,
+            receiving_id: u.arbitrary()?,
+            real_world_time: u.arbitrary()?,
+            reason: u.arbitrary()?,
+            frozen_behavior: u.arbitrary()?,
+            request_id: u.arbitrary()?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for StopFreezeR {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(StopFreezeR {
+            originating_id: u.arbitrary()?,
+            receiving_id: u.arbitrary()?,
+            real_world_time: u.arbitrary()?,
+            reason: u.arbitrary()?,
+            frozen_behavior: u.arbitrary()?,
+            required_reliability_service: u.arbitrary()?,
+            request_id: u.arbitrary()?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for Acknowledge {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Acknowledge {
+            originating_id: u.arbitrary()?,
+            receiving_id: u.arbitrary()?,
+            acknowledge_flag: u.arbitrary()?,
+            response_flag: u.arbitrary()?,
+            request_id: u.arbitrary()?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for AcknowledgeR {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(AcknowledgeR {
+            originating_id: u.arbitrary()?,
+            receiving_id: u.arbitrary()?,
+            acknowledge_flag: u.arbitrary()?,
+            response_flag: u.arbitrary()?,
+            request_id: u.arbitrary()?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for ActionRequest {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(ActionRequest {
+            originating_id: u.arbitrary()?,
+            receiving_id: u.arbitrary()?,
+            request_id: u.arbitrary()?,
+            action_id: u.arbitrary()?,
+            fixed_datum_records: elements(u)?,
+            variable_datum_records: elements(u)?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for ActionRequestR {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(ActionRequestR {
+            originating_id: u.arbitrary()?,
+            receiving_id: u.arbitrary()?,
+            required_reliability_service: u.arbitrary()?,
+            request_id: u.arbitrary()?,
+            action_id: u.arbitrary()?,
+            fixed_datum_records: elements(u)?,
+            variable_datum_records: elements(u)?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for ActionResponse {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(ActionResponse {
+            originating_id: u.arbitrary()?,
+            receiving_id: u.arbitrary()?,
+            request_id: u.arbitrary()?,
+            request_status: u.arbitrary()?,
+            fixed_datum_records: elements(u)?,
+            variable_datum_records: elements(u)?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for ActionResponseR {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(ActionResponseR {
+            originating_id: u.arbitrary()?,
+            receiving_id: u.arbitrary()?,
+            request_id: u.arbitrary()?,
+            request_status: u.arbitrary()?,
+            fixed_datum_records: elements(u)?,
+            variable_datum_records: elements(u)?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for DataQuery {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(DataQuery {
+            originating_id: u.arbitrary()?,
+            receiving_id: u.arbitrary()?,
+            request_id: u.arbitrary()?,
+            time_interval: u.arbitrary()?,
+            fixed_datum_records: elements(u)?,
+            variable_datum_records: elements(u)?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for DataQueryR {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(DataQueryR {
+            originating_id: u.arbitrary()?,
+            receiving_id: u.arbitrary()?,
+            required_reliability_service: u.arbitrary()?,
+            request_id: u.arbitrary()?,
+            time_interval: u.arbitrary()?,
+            fixed_datum_records: elements(u)?,
+            variable_datum_records: elements(u)?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for SetData {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(SetData {
+            originating_id: u.arbitrary()?,
+            receiving_id: u.arbitrary()?,
+            request_id: u.arbitrary()?,
+            fixed_datum_records: elements(u)?,
+            variable_datum_records: elements(u)?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for SetDataR {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(SetDataR {
+            originating_id: u.arbitrary()?,
+            receiving_id: u.arbitrary()?,
+            required_reliability_service: u.arbitrary()?,
+            request_id: u.arbitrary()?,
+            fixed_datum_records: elements(u)?,
+            variable_datum_records: elements(u)?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for Data {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Data {
+            originating_id: u.arbitrary()?,
+            receiving_id: u.arbitrary()?,
+            request_id: u.arbitrary()?,
+            fixed_datum_records: elements(u)?,
+            variable_datum_records: elements(u)?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for DataR {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(DataR {
+            originating_id: u.arbitrary()?,
+            receiving_id: u.arbitrary()?,
+            request_id: u.arbitrary()?,
+            required_reliability_service: u.arbitrary()?,
+            fixed_datum_records: elements(u)?,
+            variable_datum_records: elements(u)?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for EventReport {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(EventReport {
+            originating_id: u.arbitrary()?,
+            receiving_id: u.arbitrary()?,
+            event_type: u.arbitrary()?,
+            fixed_datum_records: elements(u)?,
+            variable_datum_records: elements(u)?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for EventReportR {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(EventReportR {
+            originating_id: u.arbitrary()?,
+            receiving_id: u.arbitrary()?,
+            event_type: u.arbitrary()?,
+            fixed_datum_records: elements(u)?,
+            variable_datum_records: elements(u)?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for Comment {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Comment {
+            originating_id: u.arbitrary()?,
+            receiving_id: u.arbitrary()?,
+            variable_datum_records: elements(u)?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for CommentR {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(CommentR {
+            originating_id: u.arbitrary()?,
+            receiving_id: u.arbitrary()?,
+            variable_datum_records: elements(u)?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for RecordR {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(RecordR {
+            originating_id: u.arbitrary()?,
+            receiving_id: u.arbitrary()?,
+            request_id: u.arbitrary()?,
+            required_reliability_service: u.arbitrary()?,
+            event_type: u.arbitrary()?,
+            response_serial_number: u.arbitrary()?,
+            record_specification: u.arbitrary()?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for SetRecordR {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(SetRecordR {
+            originating_id: u.arbitrary()?,
+            receiving_id: u.arbitrary()?,
+            request_id: u.arbitrary()?,
+            required_reliability_service: u.arbitrary()?,
+            record_specification: u.arbitrary()?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for RecordQueryR {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(RecordQueryR {
+            originating_id: u.arbitrary()?,
+            receiving_id: u.arbitrary()?,
+            request_id: u.arbitrary()?,
+            required_reliability_service: u.arbitrary()?,
+            event_type: u.arbitrary()?,
+            time: u.arbitrary()?,
+            record_query_specification: u.arbitrary()?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for RecordQuerySpecification {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(RecordQuerySpecification {
+            record_ids: elements(u)?,
+        })
+    }
+}
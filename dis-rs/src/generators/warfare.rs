@@ -0,0 +1,44 @@
+use crate::common::detonation::model::Detonation;
+use crate::common::fire::model::Fire;
+use crate::common::model::DescriptorRecord;
+use crate::generators::{elements, finite_f32};
+use arbitrary::{Arbitrary, Unstructured};
+
+/// The descriptor is a Munition or Expendable descriptor, the two that a Fire Type Indicator can select.
+impl<'a> Arbitrary<'a> for Fire {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        let descriptor = if u.arbitrary()? {
+            DescriptorRecord::new_munition(u.arbitrary()?, u.arbitrary()?)
+        } else {
+            DescriptorRecord::new_expendable(u.arbitrary()?)
+        };
+        Ok(Fire {
+            firing_entity_id: u.arbitrary()?,
+            target_entity_id: u.arbitrary()?,
+            entity_id: u.arbitrary()?,
+            event_id: u.arbitrary()?,
+            fire_mission_index: u.arbitrary()?,
+            location_in_world: u.arbitrary()?,
+            descriptor,
+            velocity: u.arbitrary()?,
+            range: finite_f32(u)?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for Detonation {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Detonation {
+            source_entity_id: u.arbitrary()?,
+            target_entity_id: u.arbitrary()?,
+            exploding_entity_id: u.arbitrary()?,
+            event_id: u.arbitrary()?,
+            velocity: u.arbitrary()?,
+            location_in_world_coordinates: u.arbitrary()?,
+            descriptor: u.arbitrary()?,
+            location_in_entity_coordinates: u.arbitrary()?,
+            detonation_result: u.arbitrary()?,
+            variable_parameters: elements(u)?,
+        })
+    }
+}
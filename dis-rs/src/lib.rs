@@ -9,9 +9,28 @@
 
 extern crate core;
 
+pub mod aggregate;
+pub mod allocator;
+pub mod bundle;
+pub mod capture;
 mod common;
 mod constants;
+pub mod diff;
+pub mod dissect;
+pub mod engagement;
+pub mod entity_type;
+pub mod exercise;
+pub mod filter;
 mod fixed_parameters;
+#[cfg(feature = "arbitrary")]
+pub mod generators;
+#[cfg(feature = "net")]
+pub mod net;
+pub mod ownership;
+pub mod radio;
+pub mod recording;
+pub mod reflect;
+pub mod siman;
 pub mod utils;
 mod v6;
 mod v7;
@@ -25,6 +44,7 @@
 pub use common::parse_v7 as parse_v7_pdus;
 pub use common::supported_protocol_versions;
 pub use common::Serialize;
+pub use dissect::dissector::dissect;
 pub use v7::entity_state::entity_capabilities_from_bytes;
 pub use v7::parser::parse_pdu_status_fields;
 pub use v7::writer::serialize_pdu_status;
@@ -0,0 +1,100 @@
+pub mod socket;
+
+#[cfg(test)]
+mod tests {
+    use crate::entity_state::model::EntityState;
+    use crate::enumerations::PduType;
+    use crate::model::{DisTimeStamp, Pdu, PduHeader};
+    use crate::net::socket::{DisSocket, DisSocketOptions, NetError, UdpMode};
+    use std::net::SocketAddr;
+
+    fn loopback() -> SocketAddr {
+        "127.0.0.1:0".parse().unwrap()
+    }
+
+    fn entity_state_pdu() -> Pdu {
+        Pdu::finalize_from_parts(
+            PduHeader::new_v7(1, PduType::EntityState),
+            EntityState::builder().build().into_pdu_body(),
+            DisTimeStamp::new_absolute_from_secs(100),
+        )
+    }
+
+    #[tokio::test]
+    async fn unicast_send_and_receive() {
+        let mut receiver = DisSocket::bind(DisSocketOptions::new(
+            UdpMode::UniCast,
+            loopback(),
+            loopback(),
+        ))
+        .unwrap();
+        let sender = DisSocket::bind(DisSocketOptions::new(
+            UdpMode::UniCast,
+            loopback(),
+            receiver.local_addr().unwrap(),
+        ))
+        .unwrap();
+
+        let pdu = entity_state_pdu();
+        sender.send(&pdu).await.unwrap();
+        sender
+            .send_batch(&[pdu.clone(), pdu.clone()])
+            .await
+            .unwrap();
+
+        for _ in 0..3 {
+            let received = receiver.recv().await.unwrap();
+            assert_eq!(received.source, sender.local_addr().unwrap());
+            assert_eq!(received.pdu.header.pdu_type, PduType::EntityState);
+            assert_eq!(received.pdu.pdu_length(), pdu.pdu_length());
+        }
+    }
+
+    #[tokio::test]
+    async fn own_datagrams_are_blocked() {
+        let mut socket = DisSocket::bind(DisSocketOptions::new(
+            UdpMode::UniCast,
+            loopback(),
+            loopback(),
+        ))
+        .unwrap();
+        let other = DisSocket::bind(DisSocketOptions::new(
+            UdpMode::UniCast,
+            loopback(),
+            socket.local_addr().unwrap(),
+        ))
+        .unwrap();
+
+        let pdu = entity_state_pdu();
+        socket
+            .send_to(&pdu, socket.local_addr().unwrap())
+            .await
+            .unwrap();
+        other.send(&pdu).await.unwrap();
+
+        let received = socket.recv().await.unwrap();
+        assert_eq!(received.source, other.local_addr().unwrap());
+    }
+
+    #[test]
+    fn invalid_options_are_refused() {
+        let ipv6: SocketAddr = "[::1]:3000".parse().unwrap();
+
+        assert!(matches!(
+            DisSocket::bind(DisSocketOptions::new(UdpMode::UniCast, loopback(), ipv6)),
+            Err(NetError::MixedIpVersions(_, _))
+        ));
+        assert!(matches!(
+            DisSocket::bind(DisSocketOptions::new(UdpMode::BroadCast, ipv6, ipv6)),
+            Err(NetError::NoIpv6Broadcast(_))
+        ));
+        assert!(matches!(
+            DisSocket::bind(DisSocketOptions::new(
+                UdpMode::MultiCast,
+                loopback(),
+                "127.0.0.1:3000".parse().unwrap()
+            )),
+            Err(NetError::NotMulticast(_))
+        ));
+    }
+}
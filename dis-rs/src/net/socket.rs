@@ -0,0 +1,305 @@
+use crate::bundle::bundler::aligned;
+use crate::common::model::Pdu;
+use crate::common::parse;
+use crate::DisError;
+use bytes::{BufMut, BytesMut};
+use futures_core::Stream;
+use socket2::{Domain, Protocol, Socket, Type};
+use std::collections::VecDeque;
+use std::future::poll_fn;
+use std::io;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+use std::pin::Pin;
+use std::task::{ready, Context, Poll};
+use std::time::SystemTime;
+use thiserror::Error;
+use tokio::io::ReadBuf;
+use tokio::net::UdpSocket;
+
+pub const DEFAULT_BUFFER_SIZE: usize = 32_768;
+pub const DEFAULT_TTL: u32 = 1;
+pub const DEFAULT_BLOCK_OWN_SOCKET: bool = true;
+/// Largest payload of a single UDP datagram
+const MAX_DATAGRAM_SIZE: usize = 65_507;
+
+#[derive(Debug, Error)]
+pub enum NetError {
+    #[error("Socket error: {0}")]
+    Io(#[from] io::Error),
+    #[error("Interface {0} and address {1} are not of the same IP version.")]
+    MixedIpVersions(SocketAddr, SocketAddr),
+    #[error("Address {0} is not a multicast address.")]
+    NotMulticast(IpAddr),
+    #[error("Broadcast is not available for IPv6 address {0}.")]
+    NoIpv6Broadcast(SocketAddr),
+    #[error("Batch of {0} bytes does not fit in a single datagram.")]
+    BatchTooLarge(usize),
+    #[error(transparent)]
+    Dis(#[from] DisError),
+}
+
+#[allow(clippy::enum_variant_names)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum UdpMode {
+    #[default]
+    UniCast,
+    BroadCast,
+    MultiCast,
+}
+
+/// Settings for binding a `DisSocket`.
+///
+/// The `interface` is the local address the socket binds to, the `address` is where sent PDUs go to.
+/// For multicast, the `address` is the group to join and the socket binds to the port of the `interface`
+/// on all addresses, as is required to receive the group's traffic on most platforms.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct DisSocketOptions {
+    pub mode: UdpMode,
+    pub interface: SocketAddr,
+    pub address: SocketAddr,
+    pub ttl: u32,
+    pub buffer_size: usize,
+    pub block_own_socket: bool,
+}
+
+impl DisSocketOptions {
+    #[must_use]
+    pub fn new(mode: UdpMode, interface: SocketAddr, address: SocketAddr) -> Self {
+        Self {
+            mode,
+            interface,
+            address,
+            ttl: DEFAULT_TTL,
+            buffer_size: DEFAULT_BUFFER_SIZE,
+            block_own_socket: DEFAULT_BLOCK_OWN_SOCKET,
+        }
+    }
+
+    #[must_use]
+    pub fn with_ttl(mut self, ttl: u32) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    #[must_use]
+    pub fn with_buffer_size(mut self, buffer_size: usize) -> Self {
+        self.buffer_size = buffer_size;
+        self
+    }
+
+    #[must_use]
+    pub fn with_block_own_socket(mut self, block_own_socket: bool) -> Self {
+        self.block_own_socket = block_own_socket;
+        self
+    }
+}
+
+/// A PDU received by a `DisSocket`, with the address it was sent from and the time it was received.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ReceivedPdu {
+    pub pdu: Pdu,
+    pub source: SocketAddr,
+    pub received_at: SystemTime,
+}
+
+/// An UDP socket sending and receiving DIS PDUs, using unicast, broadcast or multicast over IPv4 or IPv6.
+///
+/// Received datagrams are parsed into PDUs, which are available through `DisSocket::recv` or by using
+/// the socket as a `Stream`. Datagrams sent by the socket itself are filtered out when `block_own_socket` is set,
+/// which requires the socket to be bound to a specific interface address.
+#[derive(Debug)]
+pub struct DisSocket {
+    socket: UdpSocket,
+    address: SocketAddr,
+    own_address: SocketAddr,
+    block_own_socket: bool,
+    buffer: Vec<u8>,
+    received: VecDeque<ReceivedPdu>,
+}
+
+impl DisSocket {
+    /// Creates and binds the socket according to the provided options.
+    ///
+    /// # Errors
+    /// Returns a `NetError` when the options are not valid, or when the socket cannot be created or bound.
+    ///
+    /// # Panics
+    /// Panics when not called from within a Tokio runtime.
+    pub fn bind(options: DisSocketOptions) -> Result<Self, NetError> {
+        let socket = create_socket(&options)?;
+        let socket = UdpSocket::from_std(std::net::UdpSocket::from(socket))?;
+
+        let local_address = socket.local_addr()?;
+        let own_address = if local_address.ip().is_unspecified() {
+            SocketAddr::new(options.interface.ip(), local_address.port())
+        } else {
+            local_address
+        };
+
+        Ok(Self {
+            socket,
+            address: options.address,
+            own_address,
+            block_own_socket: options.block_own_socket,
+            buffer: vec![0; options.buffer_size],
+            received: VecDeque::new(),
+        })
+    }
+
+    /// The local address the socket is bound to.
+    ///
+    /// # Errors
+    /// Returns a `NetError` when the address of the socket cannot be determined.
+    pub fn local_addr(&self) -> Result<SocketAddr, NetError> {
+        Ok(self.socket.local_addr()?)
+    }
+
+    /// The address PDUs are sent to.
+    #[must_use]
+    pub fn address(&self) -> SocketAddr {
+        self.address
+    }
+
+    /// Sends the PDU to the address of the socket, returning the number of bytes sent.
+    ///
+    /// # Errors
+    /// Returns a `NetError` when the PDU cannot be serialized or sent.
+    pub async fn send(&self, pdu: &Pdu) -> Result<usize, NetError> {
+        self.send_to(pdu, self.address).await
+    }
+
+    /// Sends the PDU to the provided address, returning the number of bytes sent.
+    ///
+    /// # Errors
+    /// Returns a `NetError` when the PDU cannot be serialized or sent.
+    pub async fn send_to(&self, pdu: &Pdu, target: SocketAddr) -> Result<usize, NetError> {
+        let mut buf = BytesMut::with_capacity(pdu.pdu_length() as usize);
+        pdu.serialize(&mut buf)?;
+        Ok(self.socket.send_to(&buf, target).await?)
+    }
+
+    /// Sends the PDUs together in a single datagram to the address of the socket, returning the number of bytes sent.
+    ///
+    /// Each PDU in the datagram starts on an 8-octet boundary. Use a `PduBundler` to spread PDUs over multiple datagrams.
+    ///
+    /// # Errors
+    /// Returns a `NetError` when the PDUs cannot be serialized, do not fit in a single datagram, or cannot be sent.
+    pub async fn send_batch(&self, pdus: &[Pdu]) -> Result<usize, NetError> {
+        let length = pdus
+            .iter()
+            .fold(0, |length, pdu| aligned(length) + pdu.pdu_length() as usize);
+        if length > MAX_DATAGRAM_SIZE {
+            return Err(NetError::BatchTooLarge(length));
+        }
+
+        let mut buf = BytesMut::with_capacity(length);
+        for pdu in pdus {
+            buf.put_bytes(0, aligned(buf.len()) - buf.len());
+            pdu.serialize(&mut buf)?;
+        }
+        Ok(self.socket.send_to(&buf, self.address).await?)
+    }
+
+    /// Receives the next PDU from the network.
+    ///
+    /// # Errors
+    /// Returns a `NetError` when receiving fails or the received datagram cannot be parsed.
+    pub async fn recv(&mut self) -> Result<ReceivedPdu, NetError> {
+        poll_fn(|cx| self.poll_recv(cx)).await
+    }
+
+    fn poll_recv(&mut self, cx: &mut Context<'_>) -> Poll<Result<ReceivedPdu, NetError>> {
+        loop {
+            if let Some(received) = self.received.pop_front() {
+                return Poll::Ready(Ok(received));
+            }
+
+            let mut buf = ReadBuf::new(&mut self.buffer);
+            let source = ready!(self.socket.poll_recv_from(cx, &mut buf))?;
+            if self.block_own_socket && source == self.own_address {
+                continue;
+            }
+
+            let received_at = SystemTime::now();
+            let pdus = parse(buf.filled())?;
+            self.received
+                .extend(pdus.into_iter().map(|pdu| ReceivedPdu {
+                    pdu,
+                    source,
+                    received_at,
+                }));
+        }
+    }
+}
+
+impl Stream for DisSocket {
+    type Item = Result<ReceivedPdu, NetError>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.get_mut().poll_recv(cx).map(Some)
+    }
+}
+
+fn create_socket(options: &DisSocketOptions) -> Result<Socket, NetError> {
+    if options.interface.is_ipv4() != options.address.is_ipv4() {
+        return Err(NetError::MixedIpVersions(
+            options.interface,
+            options.address,
+        ));
+    }
+
+    let domain = if options.address.is_ipv4() {
+        Domain::IPV4
+    } else {
+        Domain::IPV6
+    };
+    let socket = Socket::new(domain, Type::DGRAM, Some(Protocol::UDP))?;
+    // Allow multiple applications on the same host to take part in the exercise
+    socket.set_reuse_address(true)?;
+    #[cfg(all(
+        target_family = "unix",
+        not(any(target_os = "solaris", target_os = "illumos"))
+    ))]
+    socket.set_reuse_port(true)?;
+    socket.set_nonblocking(true)?;
+
+    match (options.mode, options.address.ip(), options.interface) {
+        (UdpMode::UniCast, _, interface) => socket.bind(&interface.into())?,
+        (UdpMode::BroadCast, IpAddr::V4(_), interface) => {
+            socket.set_broadcast(true)?;
+            socket.set_ttl(options.ttl)?;
+            socket.bind(&interface.into())?;
+        }
+        (UdpMode::BroadCast, IpAddr::V6(_), _) => {
+            return Err(NetError::NoIpv6Broadcast(options.address))
+        }
+        (UdpMode::MultiCast, IpAddr::V4(group), SocketAddr::V4(interface)) => {
+            if !group.is_multicast() {
+                return Err(NetError::NotMulticast(options.address.ip()));
+            }
+            let any = SocketAddr::new(IpAddr::V4(Ipv4Addr::UNSPECIFIED), interface.port());
+            socket.bind(&any.into())?;
+            socket.join_multicast_v4(&group, interface.ip())?;
+            socket.set_multicast_if_v4(interface.ip())?;
+            socket.set_multicast_ttl_v4(options.ttl)?;
+        }
+        (UdpMode::MultiCast, IpAddr::V6(group), SocketAddr::V6(interface)) => {
+            if !group.is_multicast() {
+                return Err(NetError::NotMulticast(options.address.ip()));
+            }
+            let any = SocketAddr::new(IpAddr::V6(Ipv6Addr::UNSPECIFIED), interface.port());
+            socket.bind(&any.into())?;
+            socket.join_multicast_v6(&group, interface.scope_id())?;
+            socket.set_multicast_if_v6(interface.scope_id())?;
+            socket.set_multicast_hops_v6(options.ttl)?;
+        }
+        (UdpMode::MultiCast, _, _) => {
+            return Err(NetError::MixedIpVersions(
+                options.interface,
+                options.address,
+            ))
+        }
+    }
+
+    Ok(socket)
+}
@@ -0,0 +1,207 @@
+pub mod transfer;
+
+#[cfg(test)]
+mod tests {
+    use crate::enumerations::{
+        ResponseFlag, TransferControlTransferType, TransferredEntityIndicator,
+    };
+    use crate::model::{EntityId, PduBody};
+    use crate::ownership::transfer::{
+        OwnershipHandler, OwnershipTransfer, TransferKind, TransferOutcome,
+    };
+    use crate::transfer_ownership::model::TransferOwnership;
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    #[derive(Default)]
+    struct Publisher {
+        published: Vec<EntityId>,
+        refuse_pulls: bool,
+    }
+
+    impl OwnershipHandler for Publisher {
+        fn approve_pull(
+            &mut self,
+            _request: &TransferOwnership,
+            _kind: TransferKind,
+        ) -> ResponseFlag {
+            if self.refuse_pulls {
+                ResponseFlag::Other
+            } else {
+                ResponseFlag::AbleToComply
+            }
+        }
+
+        fn ownership_acquired(&mut self, entity_id: &EntityId) {
+            self.published.push(*entity_id);
+        }
+
+        fn ownership_released(&mut self, entity_id: &EntityId) {
+            self.published.retain(|published| published != entity_id);
+        }
+    }
+
+    fn now() -> SystemTime {
+        UNIX_EPOCH + Duration::from_secs(1_700_000_000)
+    }
+
+    fn simulations() -> (OwnershipTransfer<Publisher>, OwnershipTransfer<Publisher>) {
+        let mut first = OwnershipTransfer::new(EntityId::new(1, 1, 0), Publisher::default());
+        first.own(EntityId::new(1, 1, 10));
+        first.handler_mut().published.push(EntityId::new(1, 1, 10));
+        let second = OwnershipTransfer::new(EntityId::new(1, 2, 0), Publisher::default());
+        (first, second)
+    }
+
+    #[test]
+    fn push_transfer() {
+        let (mut first, mut second) = simulations();
+        let entity = EntityId::new(1, 1, 10);
+
+        let request = first.push(entity, second.id(), now());
+        assert_eq!(first.pending_transfers().count(), 1);
+
+        let result = second.process(&request.into_pdu_body()).unwrap();
+        assert_eq!(result.outcome, TransferOutcome::Acquired);
+        assert!(second.is_owned(&entity));
+        assert_eq!(second.handler().published, vec![entity]);
+        assert_ne!(
+            second.transferred_entity_indicator(&entity),
+            TransferredEntityIndicator::NoDifference
+        );
+
+        let acks = second.take_outgoing();
+        assert_eq!(acks.len(), 1);
+        let result = first.process(&acks[0]).unwrap();
+        assert_eq!(result.kind, TransferKind::Push);
+        assert_eq!(result.outcome, TransferOutcome::Released);
+        assert!(!first.is_owned(&entity));
+        assert!(first.handler().published.is_empty());
+        assert_eq!(first.pending_transfers().count(), 0);
+    }
+
+    #[test]
+    fn pull_transfer() {
+        let (mut first, mut second) = simulations();
+        let entity = EntityId::new(1, 1, 10);
+
+        let request = second.pull(entity, first.id(), false, now());
+        let result = first.process(&request.into_pdu_body()).unwrap();
+        assert_eq!(result.kind, TransferKind::AutomaticPull);
+        assert_eq!(result.outcome, TransferOutcome::Released);
+
+        let result = second.process(&first.take_outgoing()[0]).unwrap();
+        assert_eq!(result.outcome, TransferOutcome::Acquired);
+        assert!(second.is_owned(&entity));
+        assert_eq!(
+            first.transferred_entity_indicator(&entity),
+            TransferredEntityIndicator::NoDifference
+        );
+
+        // pulling the entity back
+        let request = first.pull(entity, EntityId::new(0xFFFF, 0xFFFF, 0xFFFF), true, now());
+        assert!(second.process(&request.into_pdu_body()).is_some());
+        let result = first.process(&second.take_outgoing()[0]).unwrap();
+        assert_eq!(result.kind, TransferKind::ManualPull);
+        assert_eq!(result.outcome, TransferOutcome::Acquired);
+        assert_eq!(first.handler().published, vec![entity]);
+    }
+
+    #[test]
+    fn refused_and_timed_out_transfers() {
+        let (mut first, mut second) = simulations();
+        let entity = EntityId::new(1, 1, 10);
+        first.handler_mut().refuse_pulls = true;
+        let mut first = first.with_timeout(Duration::from_secs(2));
+
+        let request = second.pull(entity, first.id(), true, now());
+        assert!(first.process(&request.into_pdu_body()).is_none());
+        let result = second.process(&first.take_outgoing()[0]).unwrap();
+        assert_eq!(
+            result.outcome,
+            TransferOutcome::Refused(ResponseFlag::Other)
+        );
+        assert!(first.is_owned(&entity));
+        assert!(!second.is_owned(&entity));
+
+        let request = first.push(entity, second.id(), now());
+        assert!(first
+            .poll_timeouts(now() + Duration::from_secs(1))
+            .is_empty());
+        let results = first.poll_timeouts(now() + Duration::from_secs(2));
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].request_id, request.request_id);
+        assert_eq!(results[0].outcome, TransferOutcome::TimedOut);
+        assert!(first.is_owned(&entity));
+    }
+
+    #[test]
+    fn retransmitted_request_is_handled_once() {
+        let (mut first, mut second) = simulations();
+        let entity = EntityId::new(1, 1, 10);
+
+        let request = first.push(entity, second.id(), now()).into_pdu_body();
+        assert!(second.process(&request).is_some());
+        assert!(second.process(&request).is_none());
+        assert_eq!(second.handler().published, vec![entity]);
+
+        let acks = second.take_outgoing();
+        assert_eq!(acks.len(), 2);
+        assert_eq!(acks[0], acks[1]);
+    }
+
+    #[test]
+    fn acknowledge_of_other_simulation_is_ignored() {
+        let (mut first, mut second) = simulations();
+        let entity = EntityId::new(1, 1, 10);
+        let mut third = OwnershipTransfer::new(EntityId::new(1, 3, 0), Publisher::default());
+
+        let request = first.push(entity, second.id(), now());
+        // the third simulation answers a request that was not sent to it
+        let mut misdirected = request.clone();
+        misdirected.receiving_id = third.id();
+        assert!(third.process(&misdirected.into_pdu_body()).is_some());
+        assert!(first.process(&third.take_outgoing()[0]).is_none());
+        assert_eq!(first.pending_transfers().count(), 1);
+        assert!(first.is_owned(&entity));
+
+        assert!(second.process(&request.into_pdu_body()).is_some());
+        let result = first.process(&second.take_outgoing()[0]).unwrap();
+        assert_eq!(result.outcome, TransferOutcome::Released);
+    }
+
+    #[test]
+    fn cancelled_transfers() {
+        let (mut first, mut second) = simulations();
+        let entity = EntityId::new(1, 1, 10);
+
+        // cancelled before it arrived: the late request is refused
+        let request = second.pull(entity, first.id(), true, now());
+        let cancel = second.cancel(request.request_id).unwrap();
+        assert!(first.process(&cancel.into_pdu_body()).is_none());
+        assert!(first.process(&request.into_pdu_body()).is_none());
+        let acks = first.take_outgoing();
+        assert_eq!(acks.len(), 2);
+        let PduBody::Acknowledge(ack) = &acks[0] else {
+            panic!("Expected an Acknowledge PDU body");
+        };
+        assert_eq!(ack.response_flag, ResponseFlag::AbleToComply);
+        let PduBody::Acknowledge(ack) = &acks[1] else {
+            panic!("Expected an Acknowledge PDU body");
+        };
+        assert_eq!(ack.response_flag, ResponseFlag::UnableToComply);
+        assert!(first.is_owned(&entity));
+
+        // cancelled after it was complied with: the cancellation is refused
+        let request = second.pull(entity, first.id(), false, now());
+        assert!(first.process(&request.clone().into_pdu_body()).is_some());
+        let mut cancel = request;
+        cancel.transfer_type = TransferControlTransferType::CancelTransfer_7;
+        assert!(first.process(&cancel.into_pdu_body()).is_none());
+        let acks = first.take_outgoing();
+        let PduBody::Acknowledge(ack) = &acks[1] else {
+            panic!("Expected an Acknowledge PDU body");
+        };
+        assert_eq!(ack.response_flag, ResponseFlag::UnableToComply);
+        assert!(!first.is_owned(&entity));
+    }
+}
@@ -0,0 +1,475 @@
+use crate::common::acknowledge::model::Acknowledge;
+use crate::common::model::{EntityId, PduBody};
+use crate::common::transfer_ownership::model::TransferOwnership;
+use crate::enumerations::{
+    AcknowledgeFlag, RequiredReliabilityService, ResponseFlag, TransferControlTransferType,
+    TransferredEntityIndicator,
+};
+use crate::siman::is_addressed_to;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::time::{Duration, SystemTime};
+
+/// Default time to wait for the acknowledgement of a transfer request
+pub const DEFAULT_TRANSFER_TIMEOUT: Duration = Duration::from_secs(5);
+/// Number of handled requests remembered to detect retransmitted requests
+const HANDLED_REQUESTS_CAPACITY: usize = 64;
+
+/// The kinds of ownership transfers.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TransferKind {
+    /// The owner hands the entity over to another simulation
+    Push,
+    /// Another simulation takes the entity over without operator approval
+    AutomaticPull,
+    /// Another simulation takes the entity over after operator approval
+    ManualPull,
+}
+
+impl TransferKind {
+    fn from_transfer_type(transfer_type: TransferControlTransferType) -> Option<Self> {
+        match transfer_type {
+            TransferControlTransferType::PushTransferEntity_1
+            | TransferControlTransferType::PushTransferEnvironmentalProcess_4 => {
+                Some(TransferKind::Push)
+            }
+            TransferControlTransferType::AutomaticPullTransferEntity_2
+            | TransferControlTransferType::AutomaticPullTransferEnvironmentalProcess_5 => {
+                Some(TransferKind::AutomaticPull)
+            }
+            TransferControlTransferType::ManualPullTransferEntity_8
+            | TransferControlTransferType::ManualPullTransferEnvironmentalProcess_9 => {
+                Some(TransferKind::ManualPull)
+            }
+            _ => None,
+        }
+    }
+
+    fn transfer_type(self) -> TransferControlTransferType {
+        match self {
+            TransferKind::Push => TransferControlTransferType::PushTransferEntity_1,
+            TransferKind::AutomaticPull => {
+                TransferControlTransferType::AutomaticPullTransferEntity_2
+            }
+            TransferKind::ManualPull => TransferControlTransferType::ManualPullTransferEntity_8,
+        }
+    }
+}
+
+/// The way an ownership transfer concluded, seen from this simulation.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TransferOutcome {
+    /// This simulation became the owner of the entity
+    Acquired,
+    /// This simulation is no longer the owner of the entity
+    Released,
+    /// The other simulation did not comply with the request of this simulation
+    Refused(ResponseFlag),
+    /// No acknowledgement was received within the timeout
+    TimedOut,
+}
+
+/// A concluded ownership transfer.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct TransferResult {
+    pub request_id: u32,
+    pub kind: TransferKind,
+    pub entity_id: EntityId,
+    /// The other simulation involved in the transfer
+    pub other_id: EntityId,
+    pub outcome: TransferOutcome,
+}
+
+/// A transfer request issued by this simulation that is awaiting its acknowledgement.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct PendingTransfer {
+    pub request_id: u32,
+    pub kind: TransferKind,
+    pub entity_id: EntityId,
+    pub receiving_id: EntityId,
+    pub sent_at: SystemTime,
+}
+
+/// A transfer request of another simulation that was answered, to answer retransmissions and cancellations.
+struct HandledRequest {
+    originating_id: EntityId,
+    request_id: u32,
+    response_flag: ResponseFlag,
+}
+
+/// The integration point of `OwnershipTransfer` into the entity table or publisher of a simulation.
+///
+/// The default implementations comply with all requests.
+pub trait OwnershipHandler {
+    /// Called when another simulation pushes an entity to this simulation.
+    /// The returned flag is acknowledged to the other simulation; `AbleToComply` accepts the entity.
+    fn accept_push(&mut self, _request: &TransferOwnership) -> ResponseFlag {
+        ResponseFlag::AbleToComply
+    }
+
+    /// Called when another simulation pulls an entity owned by this simulation. For manual pull transfers
+    /// the implementation is expected to obtain operator approval. The returned flag is acknowledged to the other
+    /// simulation; `AbleToComply` hands the entity over.
+    fn approve_pull(&mut self, _request: &TransferOwnership, _kind: TransferKind) -> ResponseFlag {
+        ResponseFlag::AbleToComply
+    }
+
+    /// Called when this simulation becomes the owner of an entity, and should start publishing it.
+    fn ownership_acquired(&mut self, _entity_id: &EntityId) {}
+
+    /// Called when this simulation is no longer the owner of an entity, and should stop publishing it.
+    fn ownership_released(&mut self, _entity_id: &EntityId) {}
+}
+
+/// Handles the transfer of entity ownership between simulations, using Transfer Ownership and Acknowledge PDUs.
+///
+/// The handler initiates push and pull transfers (`OwnershipTransfer::push` and `OwnershipTransfer::pull`), and
+/// answers the transfer requests of other simulations, for the entities it owns. Entities created by the
+/// simulation itself are registered with `OwnershipTransfer::own`. Entities acquired through a transfer are published
+/// with the Transferred Entity Indicator set, as reported by `OwnershipTransfer::transferred_entity_indicator`.
+///
+/// Retransmitted requests are acknowledged again without invoking the handler a second time. A cancellation
+/// (Cancel Transfer) of a request that was already complied with is refused, as the ownership has changed hands;
+/// a request that arrives after its cancellation is refused.
+///
+/// The caller is responsible for sending the created requests and the PDUs returned by
+/// `OwnershipTransfer::take_outgoing`, for feeding received PDUs to `OwnershipTransfer::process`,
+/// and for regularly calling `OwnershipTransfer::poll_timeouts`.
+pub struct OwnershipTransfer<H: OwnershipHandler> {
+    id: EntityId,
+    handler: H,
+    timeout: Duration,
+    next_request_id: u32,
+    owned: HashSet<EntityId>,
+    transferred: HashSet<EntityId>,
+    pending: HashMap<u32, PendingTransfer>,
+    handled: VecDeque<HandledRequest>,
+    outgoing: Vec<PduBody>,
+}
+
+impl<H: OwnershipHandler> OwnershipTransfer<H> {
+    /// Creates an `OwnershipTransfer` for the simulation with the provided id, typically a simulation identifier.
+    #[must_use]
+    pub fn new(id: EntityId, handler: H) -> Self {
+        Self {
+            id,
+            handler,
+            timeout: DEFAULT_TRANSFER_TIMEOUT,
+            next_request_id: 1,
+            owned: HashSet::new(),
+            transferred: HashSet::new(),
+            pending: HashMap::new(),
+            handled: VecDeque::with_capacity(HANDLED_REQUESTS_CAPACITY),
+            outgoing: vec![],
+        }
+    }
+
+    #[must_use]
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    #[must_use]
+    pub fn id(&self) -> EntityId {
+        self.id
+    }
+
+    #[must_use]
+    pub fn handler(&self) -> &H {
+        &self.handler
+    }
+
+    pub fn handler_mut(&mut self) -> &mut H {
+        &mut self.handler
+    }
+
+    /// Registers an entity created by this simulation as owned.
+    pub fn own(&mut self, entity_id: EntityId) {
+        self.owned.insert(entity_id);
+    }
+
+    /// Stops tracking the ownership of an entity, e.g. when it is removed from the exercise.
+    pub fn forget(&mut self, entity_id: &EntityId) {
+        self.owned.remove(entity_id);
+        self.transferred.remove(entity_id);
+    }
+
+    #[must_use]
+    pub fn is_owned(&self, entity_id: &EntityId) -> bool {
+        self.owned.contains(entity_id)
+    }
+
+    /// The Transferred Entity Indicator to set in the PDU Status of PDUs issued for an owned entity.
+    #[must_use]
+    pub fn transferred_entity_indicator(&self, entity_id: &EntityId) -> TransferredEntityIndicator {
+        if self.transferred.contains(entity_id) {
+            TransferredEntityIndicator::Difference
+        } else {
+            TransferredEntityIndicator::NoDifference
+        }
+    }
+
+    /// The transfer requests that are awaiting an acknowledgement.
+    pub fn pending_transfers(&self) -> impl Iterator<Item = &PendingTransfer> {
+        self.pending.values()
+    }
+
+    /// Creates a push transfer request, handing the owned entity over to the simulation `receiving_id`.
+    pub fn push(
+        &mut self,
+        entity_id: EntityId,
+        receiving_id: EntityId,
+        now: SystemTime,
+    ) -> TransferOwnership {
+        self.request(TransferKind::Push, entity_id, receiving_id, now)
+    }
+
+    /// Creates a pull transfer request, taking the entity over from its current owner `receiving_id`.
+    /// A manual pull transfer requires operator approval at the owning simulation.
+    pub fn pull(
+        &mut self,
+        entity_id: EntityId,
+        receiving_id: EntityId,
+        manual: bool,
+        now: SystemTime,
+    ) -> TransferOwnership {
+        let kind = if manual {
+            TransferKind::ManualPull
+        } else {
+            TransferKind::AutomaticPull
+        };
+        self.request(kind, entity_id, receiving_id, now)
+    }
+
+    /// Cancels a pending transfer request, returning the Transfer Ownership PDU body notifying the other simulation.
+    pub fn cancel(&mut self, request_id: u32) -> Option<TransferOwnership> {
+        let pending = self.pending.remove(&request_id)?;
+        Some(
+            TransferOwnership::builder()
+                .with_originating_id(self.id)
+                .with_receiving_id(pending.receiving_id)
+                .with_request_id(request_id)
+                .with_required_reliability_service(RequiredReliabilityService::Acknowledged)
+                .with_transfer_type(TransferControlTransferType::CancelTransfer_7)
+                .with_transfer_entity_id(pending.entity_id)
+                .build(),
+        )
+    }
+
+    /// Processes a received PDU body. Returns the concluded transfer when the body completes one.
+    /// Acknowledgements to send to other simulations are available through `OwnershipTransfer::take_outgoing`.
+    pub fn process(&mut self, body: &PduBody) -> Option<TransferResult> {
+        match body {
+            PduBody::TransferOwnership(request)
+                if is_addressed_to(&request.receiving_id, &self.id) =>
+            {
+                self.handle_request(request)
+            }
+            PduBody::Acknowledge(ack)
+                if ack.receiving_id == self.id
+                    && ack.acknowledge_flag == AcknowledgeFlag::TransferOwnership =>
+            {
+                self.handle_acknowledge(ack)
+            }
+            _ => None,
+        }
+    }
+
+    /// Concludes the pending transfer requests that have not been acknowledged within the timeout.
+    /// The ownership of the entities is unchanged.
+    pub fn poll_timeouts(&mut self, now: SystemTime) -> Vec<TransferResult> {
+        let timeout = self.timeout;
+        let expired: Vec<u32> = self
+            .pending
+            .values()
+            .filter(|pending| {
+                now.duration_since(pending.sent_at)
+                    .is_ok_and(|elapsed| elapsed >= timeout)
+            })
+            .map(|pending| pending.request_id)
+            .collect();
+
+        let mut results: Vec<TransferResult> = expired
+            .into_iter()
+            .filter_map(|request_id| self.pending.remove(&request_id))
+            .map(|pending| TransferResult {
+                request_id: pending.request_id,
+                kind: pending.kind,
+                entity_id: pending.entity_id,
+                other_id: pending.receiving_id,
+                outcome: TransferOutcome::TimedOut,
+            })
+            .collect();
+        results.sort_by_key(|result| result.request_id);
+        results
+    }
+
+    /// Takes the PDU bodies to send to other simulations.
+    pub fn take_outgoing(&mut self) -> Vec<PduBody> {
+        std::mem::take(&mut self.outgoing)
+    }
+
+    fn request(
+        &mut self,
+        kind: TransferKind,
+        entity_id: EntityId,
+        receiving_id: EntityId,
+        now: SystemTime,
+    ) -> TransferOwnership {
+        let request_id = self.next_request_id;
+        self.next_request_id = self.next_request_id.wrapping_add(1);
+        self.pending.insert(
+            request_id,
+            PendingTransfer {
+                request_id,
+                kind,
+                entity_id,
+                receiving_id,
+                sent_at: now,
+            },
+        );
+
+        TransferOwnership::builder()
+            .with_originating_id(self.id)
+            .with_receiving_id(receiving_id)
+            .with_request_id(request_id)
+            .with_required_reliability_service(RequiredReliabilityService::Acknowledged)
+            .with_transfer_type(kind.transfer_type())
+            .with_transfer_entity_id(entity_id)
+            .build()
+    }
+
+    fn handle_request(&mut self, request: &TransferOwnership) -> Option<TransferResult> {
+        let previous = self
+            .handled
+            .iter()
+            .find(|handled| {
+                handled.originating_id == request.originating_id
+                    && handled.request_id == request.request_id
+            })
+            .map(|handled| handled.response_flag);
+
+        if request.transfer_type == TransferControlTransferType::CancelTransfer_7 {
+            if previous.is_none() && request.receiving_id != self.id {
+                // cancellations addressed to multiple simulations are only answered by those that know the request
+                return None;
+            }
+            let response_flag = if previous == Some(ResponseFlag::AbleToComply) {
+                ResponseFlag::UnableToComply
+            } else {
+                self.remember(request, ResponseFlag::UnableToComply);
+                ResponseFlag::AbleToComply
+            };
+            self.acknowledge(request, response_flag);
+            return None;
+        }
+
+        let kind = TransferKind::from_transfer_type(request.transfer_type)?;
+        if let Some(response_flag) = previous {
+            // a retransmission of a request that was already answered
+            self.acknowledge(request, response_flag);
+            return None;
+        }
+        let entity_id = request.transfer_entity_id;
+        let owned = self.owned.contains(&entity_id);
+        if kind != TransferKind::Push && !owned && request.receiving_id != self.id {
+            // pull requests addressed to multiple simulations are only answered by the owner
+            return None;
+        }
+        let response_flag = match kind {
+            TransferKind::Push => self.handler.accept_push(request),
+            TransferKind::AutomaticPull | TransferKind::ManualPull if owned => {
+                self.handler.approve_pull(request, kind)
+            }
+            TransferKind::AutomaticPull | TransferKind::ManualPull => ResponseFlag::UnableToComply,
+        };
+
+        self.remember(request, response_flag);
+        self.acknowledge(request, response_flag);
+        if response_flag != ResponseFlag::AbleToComply {
+            return None;
+        }
+
+        let outcome = if kind == TransferKind::Push {
+            self.acquire(entity_id)
+        } else {
+            self.release(&entity_id)
+        };
+        Some(TransferResult {
+            request_id: request.request_id,
+            kind,
+            entity_id,
+            other_id: request.originating_id,
+            outcome,
+        })
+    }
+
+    fn handle_acknowledge(&mut self, ack: &Acknowledge) -> Option<TransferResult> {
+        let pending = self.pending.get(&ack.request_id)?;
+        if !is_addressed_to(&pending.receiving_id, &ack.originating_id) {
+            // an acknowledgement by a simulation the request was not sent to
+            return None;
+        }
+        let pending = self.pending.remove(&ack.request_id)?;
+
+        let outcome = if ack.response_flag != ResponseFlag::AbleToComply {
+            TransferOutcome::Refused(ack.response_flag)
+        } else if pending.kind == TransferKind::Push {
+            self.release(&pending.entity_id)
+        } else {
+            self.acquire(pending.entity_id)
+        };
+        Some(TransferResult {
+            request_id: pending.request_id,
+            kind: pending.kind,
+            entity_id: pending.entity_id,
+            other_id: ack.originating_id,
+            outcome,
+        })
+    }
+
+    fn remember(&mut self, request: &TransferOwnership, response_flag: ResponseFlag) {
+        if let Some(handled) = self.handled.iter_mut().find(|handled| {
+            handled.originating_id == request.originating_id
+                && handled.request_id == request.request_id
+        }) {
+            handled.response_flag = response_flag;
+            return;
+        }
+        if self.handled.len() == HANDLED_REQUESTS_CAPACITY {
+            self.handled.pop_front();
+        }
+        self.handled.push_back(HandledRequest {
+            originating_id: request.originating_id,
+            request_id: request.request_id,
+            response_flag,
+        });
+    }
+
+    fn acknowledge(&mut self, request: &TransferOwnership, response_flag: ResponseFlag) {
+        self.outgoing.push(
+            Acknowledge::builder()
+                .with_origination_id(self.id)
+                .with_receiving_id(request.originating_id)
+                .with_acknowledge_flag(AcknowledgeFlag::TransferOwnership)
+                .with_response_flag(response_flag)
+                .with_request_id(request.request_id)
+                .build()
+                .into_pdu_body(),
+        );
+    }
+
+    fn acquire(&mut self, entity_id: EntityId) -> TransferOutcome {
+        self.owned.insert(entity_id);
+        self.transferred.insert(entity_id);
+        self.handler.ownership_acquired(&entity_id);
+        TransferOutcome::Acquired
+    }
+
+    fn release(&mut self, entity_id: &EntityId) -> TransferOutcome {
+        self.owned.remove(entity_id);
+        self.transferred.remove(entity_id);
+        self.handler.ownership_released(entity_id);
+        TransferOutcome::Released
+    }
+}
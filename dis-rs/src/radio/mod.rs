@@ -0,0 +1,188 @@
+pub mod net;
+pub mod propagation;
+
+#[cfg(test)]
+mod tests {
+    use crate::enumerations::{
+        ReceiverState, TransmitterAntennaPatternType, TransmitterCryptoSystem,
+        TransmitterTransmitState,
+    };
+    use crate::model::{EntityId, Location, Orientation};
+    use crate::radio::net::{RadioId, RadioNet, RadioReceiver};
+    use crate::radio::propagation::{
+        beam_antenna_gain, free_space_path_loss, two_ray_path_loss, PropagationModel,
+        SPEED_OF_LIGHT,
+    };
+    use crate::signal::model::Signal;
+    use crate::transmitter::model::{BeamAntennaPattern, CryptoKeyId, Transmitter};
+    use crate::utils::geodetic_lla_to_ecef;
+
+    const FREQUENCY: u64 = 299_792_458;
+
+    fn location(north_offset_meters: f64) -> Location {
+        let latitude = 52f64.to_radians() + north_offset_meters / 6_371_000.0;
+        let (x, y, z) = geodetic_lla_to_ecef(latitude, 5f64.to_radians(), 10.0);
+        Location::new(x, y, z)
+    }
+
+    fn transmitter(entity_id: u16, transmitting: bool) -> Transmitter {
+        Transmitter::builder()
+            .with_radio_reference_id(EntityId::new(1, 1, entity_id))
+            .with_radio_number(1)
+            .with_transmit_state(if transmitting {
+                TransmitterTransmitState::OnAndTransmitting
+            } else {
+                TransmitterTransmitState::OnButNotTransmitting
+            })
+            .with_antenna_location(location(0.0))
+            .with_frequency(FREQUENCY)
+            .with_transmit_frequency_bandwidth(25_000.0)
+            .with_power(40.0)
+            .build()
+    }
+
+    #[test]
+    fn path_loss_models() {
+        let loss = free_space_path_loss(1000.0, SPEED_OF_LIGHT);
+        assert!((loss - 81.98).abs() < 0.01, "{loss}");
+
+        let loss = two_ray_path_loss(10_000.0, SPEED_OF_LIGHT, 10.0, 10.0);
+        assert!((loss - 120.0).abs() < 0.01, "{loss}");
+        let loss = two_ray_path_loss(100.0, SPEED_OF_LIGHT, 10.0, 10.0);
+        assert!((loss - free_space_path_loss(100.0, SPEED_OF_LIGHT)).abs() < 0.01);
+    }
+
+    #[test]
+    fn beam_antenna_gain_depends_on_direction() {
+        let pattern = BeamAntennaPattern::new()
+            .with_beam_direction(Orientation::new(0.0, 0.0, 0.0))
+            .with_azimuth_beamwidth(0.1)
+            .with_elevation_beamwidth(0.1);
+        let antenna = Location::new(0.0, 0.0, 0.0);
+
+        let boresight =
+            beam_antenna_gain(&pattern, &antenna, &Location::new(1000.0, 0.0, 0.0), None);
+        assert!((boresight - 30.99).abs() < 0.01, "{boresight}");
+
+        let half_beamwidth = Location::new(1000.0, 1000.0 * 0.05f64.tan(), 0.0);
+        let half_power = beam_antenna_gain(&pattern, &antenna, &half_beamwidth, None);
+        assert!((boresight - half_power - 3.01).abs() < 0.01, "{half_power}");
+
+        let off_boresight =
+            beam_antenna_gain(&pattern, &antenna, &Location::new(0.0, 1000.0, 0.0), None);
+        assert!(
+            (boresight - off_boresight - 30.0).abs() < 0.01,
+            "{off_boresight}"
+        );
+    }
+
+    #[test]
+    fn receiver_receives_tuned_transmitter() {
+        let mut net = RadioNet::new(PropagationModel::FreeSpace);
+        let transmitter = transmitter(1, true);
+        net.update_transmitter(&transmitter);
+
+        let radio = RadioId::new(EntityId::new(1, 1, 2), 1);
+        net.update_receiver(
+            radio,
+            RadioReceiver::from_transmitter(&transmitter).with_antenna_location(location(1000.0)),
+        );
+
+        let receiver = net.receiver_state(&radio).unwrap();
+        assert_eq!(receiver.receiver_state, ReceiverState::OnAndReceiving);
+        assert_eq!(
+            receiver.transmitter_radio_reference_id,
+            EntityId::new(1, 1, 1)
+        );
+        assert_eq!(receiver.transmitter_radio_number, 1);
+        assert!(
+            (receiver.received_power - (40.0 - 81.98)).abs() < 0.1,
+            "{}",
+            receiver.received_power
+        );
+
+        let signal = Signal::builder()
+            .with_radio_reference_id(EntityId::new(1, 1, 1))
+            .with_radio_number(1)
+            .build();
+        assert_eq!(net.receivers_of(&signal), vec![radio]);
+    }
+
+    #[test]
+    fn receiver_ignores_mismatching_transmitters() {
+        let mut net = RadioNet::default();
+        let tuned = transmitter(1, true);
+        let radio = RadioId::new(EntityId::new(1, 1, 2), 1);
+        net.update_receiver(
+            radio,
+            RadioReceiver::from_transmitter(&tuned).with_antenna_location(location(1000.0)),
+        );
+
+        net.update_transmitter(&transmitter(1, false));
+        net.update_transmitter(
+            &tuned
+                .clone()
+                .into_builder()
+                .with_radio_number(2)
+                .with_frequency(FREQUENCY + 100_000)
+                .build(),
+        );
+        net.update_transmitter(
+            &tuned
+                .clone()
+                .into_builder()
+                .with_radio_number(3)
+                .with_crypto_system(TransmitterCryptoSystem::KGV135A)
+                .with_crypto_key_id(CryptoKeyId::from(42u16))
+                .build(),
+        );
+        net.update_transmitter(
+            &tuned
+                .clone()
+                .into_builder()
+                .with_radio_number(4)
+                .with_power(-100.0)
+                .build(),
+        );
+
+        assert!(net.receptions(&radio).is_empty());
+        let receiver = net.receiver_state(&radio).unwrap();
+        assert_eq!(receiver.receiver_state, ReceiverState::OnButNotReceiving);
+
+        net.update_receiver(radio, net.receiver(&radio).unwrap().clone().with_on(false));
+        let receiver = net.receiver_state(&radio).unwrap();
+        assert_eq!(receiver.receiver_state, ReceiverState::from(0u16));
+    }
+
+    #[test]
+    fn beam_pattern_affects_received_power() {
+        let mut net = RadioNet::default();
+        let omni = transmitter(1, true);
+        let beam = omni
+            .clone()
+            .into_builder()
+            .with_radio_number(2)
+            .with_antenna_pattern_type(TransmitterAntennaPatternType::Beam)
+            .with_antenna_pattern(
+                BeamAntennaPattern::new()
+                    .with_azimuth_beamwidth(0.05)
+                    .with_elevation_beamwidth(0.05)
+                    .to_antenna_pattern(),
+            )
+            .build();
+        net.update_transmitter(&omni);
+        net.update_transmitter(&beam);
+        let radio = RadioId::new(EntityId::new(1, 1, 2), 1);
+        let receiver =
+            RadioReceiver::from_transmitter(&omni).with_antenna_location(location(1000.0));
+        net.update_receiver(radio, receiver.clone());
+
+        let omni_power = net.received_power(&omni, &receiver).unwrap();
+        let beam_power = net.received_power(&beam, &receiver).unwrap();
+        assert!((omni_power - beam_power).abs() > 1.0);
+        assert_eq!(net.receptions(&radio).len(), 2);
+
+        net.remove_entity(&EntityId::new(1, 1, 1));
+        assert!(net.receptions(&radio).is_empty());
+    }
+}
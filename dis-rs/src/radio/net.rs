@@ -0,0 +1,383 @@
+use crate::common::model::{EntityId, Location, Orientation};
+use crate::common::receiver::model::Receiver;
+use crate::common::signal::model::Signal;
+use crate::common::transmitter::model::{AntennaPattern, CryptoKeyId, ModulationType, Transmitter};
+use crate::enumerations::{
+    ReceiverState, TransmitterAntennaPatternType, TransmitterCryptoSystem, TransmitterTransmitState,
+};
+use crate::radio::propagation::{beam_antenna_gain, PropagationModel};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Default receiver sensitivity in dBm
+pub const DEFAULT_RECEIVER_SENSITIVITY: f32 = -110.0;
+
+/// Identifies a radio by the entity it is attached to and its radio number.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RadioId {
+    pub entity_id: EntityId,
+    pub radio_number: u16,
+}
+
+impl RadioId {
+    #[must_use]
+    pub fn new(entity_id: EntityId, radio_number: u16) -> Self {
+        Self {
+            entity_id,
+            radio_number,
+        }
+    }
+}
+
+impl From<&Transmitter> for RadioId {
+    fn from(value: &Transmitter) -> Self {
+        Self::new(value.radio_reference_id, value.radio_number)
+    }
+}
+
+impl From<&Signal> for RadioId {
+    fn from(value: &Signal) -> Self {
+        Self::new(value.radio_reference_id, value.radio_number)
+    }
+}
+
+impl From<&Receiver> for RadioId {
+    fn from(value: &Receiver) -> Self {
+        Self::new(value.radio_reference_id, value.radio_number)
+    }
+}
+
+/// Tuning and characteristics of a receiving radio.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RadioReceiver {
+    /// Whether the receiver is switched on
+    pub on: bool,
+    /// Location of the antenna, in world coordinates
+    pub antenna_location: Location,
+    /// Centre frequency in Hz
+    pub frequency: u64,
+    /// Bandwidth in Hz
+    pub bandwidth: f32,
+    pub modulation_type: ModulationType,
+    pub crypto_system: TransmitterCryptoSystem,
+    pub crypto_key_id: CryptoKeyId,
+    /// Minimum received power in dBm for the receiver to receive a transmission
+    pub sensitivity: f32,
+    /// Gain of the receiving antenna in dBi
+    pub antenna_gain: f32,
+}
+
+impl Default for RadioReceiver {
+    fn default() -> Self {
+        Self {
+            on: true,
+            antenna_location: Location::default(),
+            frequency: 0,
+            bandwidth: 0.0,
+            modulation_type: ModulationType::default(),
+            crypto_system: TransmitterCryptoSystem::default(),
+            crypto_key_id: CryptoKeyId::default(),
+            sensitivity: DEFAULT_RECEIVER_SENSITIVITY,
+            antenna_gain: 0.0,
+        }
+    }
+}
+
+impl RadioReceiver {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a receiver tuned like the radio described by the Transmitter PDU.
+    /// The receiver is on unless the transmitter is off.
+    #[must_use]
+    pub fn from_transmitter(transmitter: &Transmitter) -> Self {
+        Self {
+            on: transmitter.transmit_state != TransmitterTransmitState::Off,
+            antenna_location: transmitter.antenna_location,
+            frequency: transmitter.frequency,
+            bandwidth: transmitter.transmit_frequency_bandwidth,
+            modulation_type: transmitter.modulation_type,
+            crypto_system: transmitter.crypto_system,
+            crypto_key_id: transmitter.crypto_key_id,
+            ..Default::default()
+        }
+    }
+
+    #[must_use]
+    pub fn with_on(mut self, on: bool) -> Self {
+        self.on = on;
+        self
+    }
+
+    #[must_use]
+    pub fn with_antenna_location(mut self, antenna_location: Location) -> Self {
+        self.antenna_location = antenna_location;
+        self
+    }
+
+    #[must_use]
+    pub fn with_frequency(mut self, frequency: u64) -> Self {
+        self.frequency = frequency;
+        self
+    }
+
+    #[must_use]
+    pub fn with_bandwidth(mut self, bandwidth: f32) -> Self {
+        self.bandwidth = bandwidth;
+        self
+    }
+
+    #[must_use]
+    pub fn with_modulation_type(mut self, modulation_type: ModulationType) -> Self {
+        self.modulation_type = modulation_type;
+        self
+    }
+
+    #[must_use]
+    pub fn with_crypto_system(mut self, crypto_system: TransmitterCryptoSystem) -> Self {
+        self.crypto_system = crypto_system;
+        self
+    }
+
+    #[must_use]
+    pub fn with_crypto_key_id(mut self, crypto_key_id: CryptoKeyId) -> Self {
+        self.crypto_key_id = crypto_key_id;
+        self
+    }
+
+    #[must_use]
+    pub fn with_sensitivity(mut self, sensitivity: f32) -> Self {
+        self.sensitivity = sensitivity;
+        self
+    }
+
+    #[must_use]
+    pub fn with_antenna_gain(mut self, antenna_gain: f32) -> Self {
+        self.antenna_gain = antenna_gain;
+        self
+    }
+
+    /// Checks whether the receiver is tuned to the transmitter: the frequency bands overlap,
+    /// and the modulation type, crypto system and crypto key are equal.
+    #[must_use]
+    pub fn is_tuned_to(&self, transmitter: &Transmitter) -> bool {
+        let separation = self.frequency.abs_diff(transmitter.frequency) as f64;
+        let half_bandwidths =
+            (f64::from(self.bandwidth) + f64::from(transmitter.transmit_frequency_bandwidth)) / 2.0;
+        let frequency_match = separation <= half_bandwidths;
+
+        let crypto_match = self.crypto_system == transmitter.crypto_system
+            && (self.crypto_system == TransmitterCryptoSystem::NoEncryptionDevice
+                || self.crypto_key_id.pseudo_crypto_key
+                    == transmitter.crypto_key_id.pseudo_crypto_key);
+
+        frequency_match && self.modulation_type == transmitter.modulation_type && crypto_match
+    }
+}
+
+/// Reception of a transmitter by a receiver.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Reception {
+    pub transmitter: RadioId,
+    /// Received power in dBm
+    pub received_power: f32,
+}
+
+/// Relates transmitters and receivers of a radio net.
+///
+/// The net tracks the latest Transmitter PDU of each radio and the local receivers,
+/// and determines which transmissions each receiver receives, and at what power.
+/// The resulting `Receiver` PDU bodies can be issued for the local receivers.
+#[derive(Clone, Debug, Default)]
+pub struct RadioNet {
+    propagation_model: PropagationModel,
+    transmitters: HashMap<RadioId, Transmitter>,
+    receivers: HashMap<RadioId, RadioReceiver>,
+    orientations: HashMap<EntityId, Orientation>,
+}
+
+impl RadioNet {
+    #[must_use]
+    pub fn new(propagation_model: PropagationModel) -> Self {
+        Self {
+            propagation_model,
+            ..Default::default()
+        }
+    }
+
+    #[must_use]
+    pub fn propagation_model(&self) -> PropagationModel {
+        self.propagation_model
+    }
+
+    /// Stores the latest state of a transmitter, replacing the previous state of the same radio.
+    pub fn update_transmitter(&mut self, transmitter: &Transmitter) {
+        self.transmitters
+            .insert(RadioId::from(transmitter), transmitter.clone());
+    }
+
+    #[must_use]
+    pub fn transmitter(&self, radio: &RadioId) -> Option<&Transmitter> {
+        self.transmitters.get(radio)
+    }
+
+    pub fn remove_transmitter(&mut self, radio: &RadioId) -> Option<Transmitter> {
+        self.transmitters.remove(radio)
+    }
+
+    /// Adds or replaces a local receiver.
+    pub fn update_receiver(&mut self, radio: RadioId, receiver: RadioReceiver) {
+        self.receivers.insert(radio, receiver);
+    }
+
+    #[must_use]
+    pub fn receiver(&self, radio: &RadioId) -> Option<&RadioReceiver> {
+        self.receivers.get(radio)
+    }
+
+    pub fn remove_receiver(&mut self, radio: &RadioId) -> Option<RadioReceiver> {
+        self.receivers.remove(radio)
+    }
+
+    /// Sets the orientation of an entity, used for antenna patterns defined in entity coordinates.
+    pub fn update_entity_orientation(&mut self, entity_id: EntityId, orientation: Orientation) {
+        self.orientations.insert(entity_id, orientation);
+    }
+
+    /// Removes all transmitters, receivers and state of the entity.
+    pub fn remove_entity(&mut self, entity_id: &EntityId) {
+        self.transmitters
+            .retain(|radio, _| radio.entity_id != *entity_id);
+        self.receivers
+            .retain(|radio, _| radio.entity_id != *entity_id);
+        self.orientations.remove(entity_id);
+    }
+
+    /// Calculates the power in dBm received by `receiver` from `transmitter`,
+    /// or `None` when the receiver is not tuned to the transmitter.
+    #[must_use]
+    pub fn received_power(
+        &self,
+        transmitter: &Transmitter,
+        receiver: &RadioReceiver,
+    ) -> Option<f32> {
+        if !receiver.is_tuned_to(transmitter) {
+            return None;
+        }
+
+        let transmitter_gain = match &transmitter.antenna_pattern {
+            Some(AntennaPattern::Beam(pattern))
+                if transmitter.antenna_pattern_type == TransmitterAntennaPatternType::Beam =>
+            {
+                beam_antenna_gain(
+                    pattern,
+                    &transmitter.antenna_location,
+                    &receiver.antenna_location,
+                    self.orientations.get(&transmitter.radio_reference_id),
+                )
+            }
+            _ => 0.0,
+        };
+        let path_loss = self.propagation_model.path_loss(
+            &transmitter.antenna_location,
+            &receiver.antenna_location,
+            transmitter.frequency,
+        );
+
+        Some(
+            (f64::from(transmitter.power) + transmitter_gain + f64::from(receiver.antenna_gain)
+                - path_loss) as f32,
+        )
+    }
+
+    /// Lists the transmissions received by the receiver, strongest first.
+    /// Only transmitters that are transmitting and received above the sensitivity of the receiver are included.
+    #[must_use]
+    pub fn receptions(&self, radio: &RadioId) -> Vec<Reception> {
+        let Some(receiver) = self.receivers.get(radio).filter(|receiver| receiver.on) else {
+            return vec![];
+        };
+
+        let mut receptions: Vec<Reception> = self
+            .transmitters
+            .iter()
+            .filter_map(|(id, transmitter)| self.reception(id, transmitter, radio, receiver))
+            .collect();
+        receptions.sort_by(|a, b| b.received_power.total_cmp(&a.received_power));
+        receptions
+    }
+
+    /// Determines the reception of a single transmitter by a receiver that is on.
+    /// A radio does not receive its own transmissions.
+    fn reception(
+        &self,
+        id: &RadioId,
+        transmitter: &Transmitter,
+        radio: &RadioId,
+        receiver: &RadioReceiver,
+    ) -> Option<Reception> {
+        if id == radio || transmitter.transmit_state != TransmitterTransmitState::OnAndTransmitting
+        {
+            return None;
+        }
+        self.received_power(transmitter, receiver)
+            .filter(|power| *power >= receiver.sensitivity)
+            .map(|received_power| Reception {
+                transmitter: *id,
+                received_power,
+            })
+    }
+
+    /// Determines the state of the receiver as a `Receiver` PDU body, referring to the strongest received transmitter.
+    #[must_use]
+    pub fn receiver_state(&self, radio: &RadioId) -> Option<Receiver> {
+        let receiver = self.receivers.get(radio)?;
+        let builder = Receiver::builder()
+            .with_radio_reference_id(radio.entity_id)
+            .with_radio_number(radio.radio_number);
+
+        let builder = if !receiver.on {
+            builder.with_receiver_state(ReceiverState::Off)
+        } else if let Some(reception) = self.receptions(radio).first() {
+            builder
+                .with_receiver_state(ReceiverState::OnAndReceiving)
+                .with_received_power(reception.received_power)
+                .with_transmitter_radio_reference_id(reception.transmitter.entity_id)
+                .with_transmitter_radio_number(reception.transmitter.radio_number)
+        } else {
+            builder.with_receiver_state(ReceiverState::OnButNotReceiving)
+        };
+
+        Some(builder.build())
+    }
+
+    /// Determines the state of all local receivers.
+    #[must_use]
+    pub fn receiver_states(&self) -> Vec<Receiver> {
+        self.receivers
+            .keys()
+            .filter_map(|radio| self.receiver_state(radio))
+            .collect()
+    }
+
+    /// Lists the local receivers that receive the transmitter of the Signal PDU.
+    #[must_use]
+    pub fn receivers_of(&self, signal: &Signal) -> Vec<RadioId> {
+        let id = RadioId::from(signal);
+        let Some(transmitter) = self.transmitters.get(&id) else {
+            return vec![];
+        };
+        self.receivers
+            .iter()
+            .filter(|(radio, receiver)| {
+                receiver.on && self.reception(&id, transmitter, radio, receiver).is_some()
+            })
+            .map(|(radio, _)| *radio)
+            .collect()
+    }
+}
@@ -0,0 +1,155 @@
+use crate::common::model::{Location, Orientation};
+use crate::common::transmitter::model::BeamAntennaPattern;
+use crate::enumerations::TransmitterAntennaPatternReferenceSystem;
+use crate::utils::ecef_to_geodetic_lla;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::f64::consts::{LN_10, LN_2, PI};
+
+/// Speed of light in vacuum, in m/s
+pub const SPEED_OF_LIGHT: f64 = 299_792_458.0;
+
+/// Minimum distance between antennas, in meters, to keep path loss finite
+const MIN_DISTANCE_METERS: f64 = 1.0;
+/// Minimum antenna height above the surface, in meters, used by the two-ray model
+const MIN_ANTENNA_HEIGHT_METERS: f64 = 1.0;
+/// Gain of the side lobes of a beam antenna, relative to the main lobe
+const BEAM_SIDE_LOBE_LEVEL_DB: f64 = -30.0;
+
+/// Path loss models for estimating the power received from a transmitter.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum PropagationModel {
+    /// Free-space (Friis) path loss
+    #[default]
+    FreeSpace,
+    /// Two-ray ground reflection path loss over a flat earth, using the height of the antennas above the WGS-84 ellipsoid.
+    /// Below the crossover distance the free-space path loss is used.
+    TwoRayGroundReflection,
+}
+
+impl PropagationModel {
+    /// Calculates the path loss in dB between the antenna locations (ECEF) for the frequency in Hz.
+    #[must_use]
+    pub fn path_loss(&self, transmitter: &Location, receiver: &Location, frequency: u64) -> f64 {
+        let distance = distance(transmitter, receiver);
+        match self {
+            PropagationModel::FreeSpace => free_space_path_loss(distance, frequency as f64),
+            PropagationModel::TwoRayGroundReflection => two_ray_path_loss(
+                distance,
+                frequency as f64,
+                antenna_height(transmitter),
+                antenna_height(receiver),
+            ),
+        }
+    }
+}
+
+/// Free-space path loss in dB for a distance in meters and a frequency in Hz.
+#[must_use]
+pub fn free_space_path_loss(distance: f64, frequency: f64) -> f64 {
+    let distance = distance.max(MIN_DISTANCE_METERS);
+    20.0 * (4.0 * PI * distance * frequency / SPEED_OF_LIGHT).log10()
+}
+
+/// Two-ray ground reflection path loss in dB for a distance in meters, a frequency in Hz,
+/// and the heights in meters of the transmitting and receiving antennas.
+#[must_use]
+pub fn two_ray_path_loss(
+    distance: f64,
+    frequency: f64,
+    transmitter_height: f64,
+    receiver_height: f64,
+) -> f64 {
+    let distance = distance.max(MIN_DISTANCE_METERS);
+    let transmitter_height = transmitter_height.max(MIN_ANTENNA_HEIGHT_METERS);
+    let receiver_height = receiver_height.max(MIN_ANTENNA_HEIGHT_METERS);
+    let crossover_distance =
+        4.0 * PI * transmitter_height * receiver_height * frequency / SPEED_OF_LIGHT;
+
+    if distance < crossover_distance {
+        free_space_path_loss(distance, frequency)
+    } else {
+        40.0 * distance.log10() - 20.0 * transmitter_height.log10() - 20.0 * receiver_height.log10()
+    }
+}
+
+/// Estimates the gain in dBi of a beam antenna in the direction of `target`.
+///
+/// The main lobe is approximated as a Gaussian beam, of which the directivity follows from the half-power
+/// azimuth and elevation beamwidths. Patterns in entity coordinates are oriented using `entity_orientation`;
+/// when it is not known the pattern is treated as being in world coordinates.
+/// A pattern without beamwidths is treated as an isotropic antenna.
+#[must_use]
+pub fn beam_antenna_gain(
+    pattern: &BeamAntennaPattern,
+    antenna: &Location,
+    target: &Location,
+    entity_orientation: Option<&Orientation>,
+) -> f64 {
+    let azimuth_beamwidth = f64::from(pattern.azimuth_beamwidth);
+    let elevation_beamwidth = f64::from(pattern.elevation_beamwidth);
+    if azimuth_beamwidth <= 0.0 || elevation_beamwidth <= 0.0 {
+        return 0.0;
+    }
+
+    let line_of_sight = [
+        target.x_coordinate - antenna.x_coordinate,
+        target.y_coordinate - antenna.y_coordinate,
+        target.z_coordinate - antenna.z_coordinate,
+    ];
+    let line_of_sight = match entity_orientation {
+        Some(orientation)
+            if pattern.reference_system
+                == TransmitterAntennaPatternReferenceSystem::EntityCoordinates =>
+        {
+            rotate_to_body(line_of_sight, orientation)
+        }
+        _ => line_of_sight,
+    };
+    let [x, y, z] = rotate_to_body(line_of_sight, &pattern.beam_direction);
+
+    let azimuth_off_boresight = y.atan2(x);
+    let elevation_off_boresight = (-z).atan2(x.hypot(y));
+
+    let peak_gain = 10.0 * (4.0 * PI / (azimuth_beamwidth * elevation_beamwidth)).log10();
+    let attenuation = -10.0 / LN_10
+        * 4.0
+        * LN_2
+        * ((azimuth_off_boresight / azimuth_beamwidth).powi(2)
+            + (elevation_off_boresight / elevation_beamwidth).powi(2));
+
+    peak_gain + attenuation.max(BEAM_SIDE_LOBE_LEVEL_DB)
+}
+
+/// Straight-line distance in meters between two locations.
+#[must_use]
+pub fn distance(a: &Location, b: &Location) -> f64 {
+    let dx = a.x_coordinate - b.x_coordinate;
+    let dy = a.y_coordinate - b.y_coordinate;
+    let dz = a.z_coordinate - b.z_coordinate;
+    (dx * dx + dy * dy + dz * dz).sqrt()
+}
+
+fn antenna_height(location: &Location) -> f64 {
+    let (_lat, _lon, altitude) = ecef_to_geodetic_lla(
+        location.x_coordinate,
+        location.y_coordinate,
+        location.z_coordinate,
+    );
+    altitude
+}
+
+/// Transforms a vector into the frame described by the Euler angles (psi, theta, phi; z-y-x rotation order).
+fn rotate_to_body(vector: [f64; 3], orientation: &Orientation) -> [f64; 3] {
+    let (sin_psi, cos_psi) = f64::from(orientation.psi).sin_cos();
+    let (sin_theta, cos_theta) = f64::from(orientation.theta).sin_cos();
+    let (sin_phi, cos_phi) = f64::from(orientation.phi).sin_cos();
+    let [x, y, z] = vector;
+
+    let (x, y) = (cos_psi * x + sin_psi * y, -sin_psi * x + cos_psi * y);
+    let (x, z) = (cos_theta * x - sin_theta * z, sin_theta * x + cos_theta * z);
+    let (y, z) = (cos_phi * y + sin_phi * z, -sin_phi * y + cos_phi * z);
+
+    [x, y, z]
+}
@@ -0,0 +1,435 @@
+use crate::enumerations::PduType;
+use crate::DisError;
+use bytes::{Buf, BufMut, Bytes, BytesMut};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+pub(crate) const FILE_MAGIC: &[u8; 6] = b"DISREC";
+pub(crate) const FILE_VERSION: u16 = 1;
+pub(crate) const FILE_HEADER_OCTETS: usize = 16;
+pub(crate) const RECORD_HEADER_OCTETS: usize = 32;
+pub(crate) const INDEX_MAGIC: &[u8; 4] = b"DIDX";
+pub(crate) const FOOTER_MAGIC: &[u8; 4] = b"DEND";
+pub(crate) const FOOTER_OCTETS: usize = 20;
+
+/// The interval between entries of the time index.
+pub(crate) const INDEX_INTERVAL_MICROS: u64 = 1_000_000;
+
+const FAMILY_NONE: u8 = 0;
+const FAMILY_IPV4: u8 = 4;
+const FAMILY_IPV6: u8 = 6;
+const PDU_TYPE_OFFSET: usize = 2;
+const PDU_LENGTH_OFFSET: usize = 8;
+
+/// A datagram stored in a recording, with the time it was received and the address it was received from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RecordedDatagram {
+    pub recorded_at: SystemTime,
+    pub source: Option<SocketAddr>,
+    pub data: Bytes,
+}
+
+impl RecordedDatagram {
+    /// The type of the (first) PDU in the datagram, as stated in its header.
+    #[must_use]
+    pub fn pdu_type(&self) -> PduType {
+        PduType::from(self.data.get(PDU_TYPE_OFFSET).copied().unwrap_or_default())
+    }
+}
+
+/// A DIS recording, read from the native recording format written by a `Recorder`.
+///
+/// A recording consists of a header, the recorded datagrams in the order they were received, and an index.
+/// Each datagram is stored with its receive time, source address and the type of its (first) PDU.
+/// The index holds the position of the first datagram of every second of the recording, and the positions of
+/// the datagrams that contain a PDU of each PDU type. A recording that was not finished, for instance because the recording process stopped,
+/// has no index; the index is then rebuilt when reading the recording, up to the last complete datagram.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Recording {
+    data: Bytes,
+    start_time: SystemTime,
+    end_time: SystemTime,
+    records_end: usize,
+    record_count: u64,
+    time_index: Vec<(u64, usize)>,
+    pdu_type_index: Vec<(PduType, Vec<usize>)>,
+}
+
+impl Recording {
+    /// Reads a recording from its bytes.
+    ///
+    /// # Errors
+    /// Returns `DisError::InvalidRecording` when the data is not a recording, or has an unsupported version.
+    pub fn from_bytes(data: impl Into<Bytes>) -> Result<Self, DisError> {
+        let data = data.into();
+        let mut header = data.get(..FILE_HEADER_OCTETS).ok_or_else(|| {
+            DisError::InvalidRecording("the data is too short for a recording".to_string())
+        })?;
+        if &header[..FILE_MAGIC.len()] != FILE_MAGIC {
+            return Err(DisError::InvalidRecording(
+                "the data does not start with a recording header".to_string(),
+            ));
+        }
+        header.advance(FILE_MAGIC.len());
+        let version = header.get_u16();
+        if version != FILE_VERSION {
+            return Err(DisError::InvalidRecording(format!(
+                "recording version {version} is not supported"
+            )));
+        }
+        let start_time = time_from_micros(header.get_u64());
+
+        let recording = Self {
+            data,
+            start_time,
+            end_time: start_time,
+            records_end: FILE_HEADER_OCTETS,
+            record_count: 0,
+            time_index: Vec::new(),
+            pdu_type_index: Vec::new(),
+        };
+        Ok(recording
+            .clone()
+            .read_index()
+            .unwrap_or_else(|| recording.rebuild_index()))
+    }
+
+    /// The time the recording started.
+    #[must_use]
+    pub fn start_time(&self) -> SystemTime {
+        self.start_time
+    }
+
+    /// The receive time of the last datagram, or the start time for an empty recording.
+    #[must_use]
+    pub fn end_time(&self) -> SystemTime {
+        self.end_time
+    }
+
+    /// The time between the start of the recording and the last datagram.
+    #[must_use]
+    pub fn duration(&self) -> Duration {
+        self.end_time
+            .duration_since(self.start_time)
+            .unwrap_or_default()
+    }
+
+    /// The number of recorded datagrams.
+    #[must_use]
+    pub fn record_count(&self) -> u64 {
+        self.record_count
+    }
+
+    /// The number of recorded datagrams that contain a PDU of the provided type.
+    #[must_use]
+    pub fn pdu_type_count(&self, pdu_type: PduType) -> u64 {
+        self.pdu_type_positions(pdu_type).len() as u64
+    }
+
+    /// The recorded datagrams, in the order they were received.
+    pub fn records(&self) -> impl Iterator<Item = RecordedDatagram> + '_ {
+        let mut position = FILE_HEADER_OCTETS;
+        std::iter::from_fn(move || {
+            let (record, next) = self.record_at(position)?;
+            position = next;
+            Some(record)
+        })
+    }
+
+    /// The record at a position in the data, and the position of the next record.
+    pub(crate) fn record_at(&self, position: usize) -> Option<(RecordedDatagram, usize)> {
+        if position >= self.records_end {
+            return None;
+        }
+        let (time, _, next) = self.record_header_at(position)?;
+        let mut header = &self.data[position..position + RECORD_HEADER_OCTETS];
+        header.advance(8 + 4 + 1);
+        let family = header.get_u8();
+        let port = header.get_u16();
+        let source = match family {
+            FAMILY_IPV4 => Some(SocketAddr::new(
+                IpAddr::V4(Ipv4Addr::from(header.get_u32())),
+                port,
+            )),
+            FAMILY_IPV6 => Some(SocketAddr::new(
+                IpAddr::V6(Ipv6Addr::from(header.get_u128())),
+                port,
+            )),
+            _ => None,
+        };
+        let record = RecordedDatagram {
+            recorded_at: time_from_micros(time),
+            source,
+            data: self.data.slice(position + RECORD_HEADER_OCTETS..next),
+        };
+        Some((record, next))
+    }
+
+    /// The time and PDU type of the record at a position in the data, and the position of the next record.
+    pub(crate) fn record_header_at(&self, position: usize) -> Option<(u64, u8, usize)> {
+        let mut header = self.data.get(position..position + RECORD_HEADER_OCTETS)?;
+        let time = header.get_u64();
+        let length = header.get_u32() as usize;
+        let pdu_type = header.get_u8();
+        let next = position + RECORD_HEADER_OCTETS + length;
+        (next <= self.data.len()).then_some((time, pdu_type, next))
+    }
+
+    /// The position of the first record received at or after the provided time.
+    pub(crate) fn position_at(&self, time: SystemTime) -> usize {
+        let target = micros_since_epoch(time);
+        let entry = self
+            .time_index
+            .partition_point(|(entry_time, _)| *entry_time <= target);
+        let mut position = match entry {
+            0 => FILE_HEADER_OCTETS,
+            entry => self.time_index[entry - 1].1,
+        };
+        while position < self.records_end {
+            match self.record_header_at(position) {
+                Some((time, _, next)) if time < target => position = next,
+                _ => break,
+            }
+        }
+        position.min(self.records_end)
+    }
+
+    /// The position of the first record at or after `position` that contains a PDU of one of the provided types,
+    /// or the end of the records when there is none.
+    pub(crate) fn position_of_types(&self, pdu_types: &[PduType], position: usize) -> usize {
+        pdu_types
+            .iter()
+            .filter_map(|pdu_type| {
+                let positions = self.pdu_type_positions(*pdu_type);
+                positions
+                    .get(positions.partition_point(|recorded| *recorded < position))
+                    .copied()
+            })
+            .min()
+            .unwrap_or(self.records_end)
+    }
+
+    pub(crate) fn first_position(&self) -> usize {
+        FILE_HEADER_OCTETS
+    }
+
+    fn pdu_type_positions(&self, pdu_type: PduType) -> &[usize] {
+        self.pdu_type_index
+            .iter()
+            .find(|(recorded_type, _)| *recorded_type == pdu_type)
+            .map_or(&[], |(_, positions)| positions.as_slice())
+    }
+
+    fn read_index(mut self) -> Option<Self> {
+        let footer_start = self.data.len().checked_sub(FOOTER_OCTETS)?;
+        let mut footer = &self.data[footer_start..];
+        let index_start = usize::try_from(footer.get_u64()).ok()?;
+        let record_count = footer.get_u64();
+        if footer != FOOTER_MAGIC || index_start < FILE_HEADER_OCTETS || index_start > footer_start
+        {
+            return None;
+        }
+
+        let mut index = &self.data[index_start..footer_start];
+        if index.len() < INDEX_MAGIC.len() + 12 || &index[..INDEX_MAGIC.len()] != INDEX_MAGIC {
+            return None;
+        }
+        index.advance(INDEX_MAGIC.len());
+        self.end_time = time_from_micros(index.get_u64());
+        let entries = index.get_u32() as usize;
+        if index.remaining() < entries * 16 + 2 {
+            return None;
+        }
+        self.time_index = (0..entries)
+            .map(|_| (index.get_u64(), index.get_u64() as usize))
+            .collect();
+        let types = usize::from(index.get_u16());
+        let mut pdu_type_index = Vec::with_capacity(types);
+        for _ in 0..types {
+            if index.remaining() < 9 {
+                return None;
+            }
+            let pdu_type = PduType::from(index.get_u8());
+            let count = usize::try_from(index.get_u64()).ok()?;
+            if index.remaining() / 8 < count {
+                return None;
+            }
+            let positions = (0..count).map(|_| index.get_u64() as usize).collect();
+            pdu_type_index.push((pdu_type, positions));
+        }
+        self.pdu_type_index = pdu_type_index;
+        self.records_end = index_start;
+        self.record_count = record_count;
+        Some(self)
+    }
+
+    fn rebuild_index(mut self) -> Self {
+        let mut builder = IndexBuilder::default();
+        let mut position = FILE_HEADER_OCTETS;
+        self.records_end = self.data.len();
+        while let Some((time, _, next)) = self.record_header_at(position) {
+            builder.add(
+                time,
+                &self.data[position + RECORD_HEADER_OCTETS..next],
+                position,
+            );
+            position = next;
+        }
+        self.records_end = position;
+        self.end_time = builder.end_time.map_or(self.start_time, time_from_micros);
+        self.record_count = builder.record_count;
+        self.pdu_type_index = builder.pdu_type_index();
+        self.time_index = builder.time_index;
+        self
+    }
+}
+
+/// Collects the index of a recording while the records are written or scanned.
+#[derive(Debug)]
+pub(crate) struct IndexBuilder {
+    pub(crate) time_index: Vec<(u64, usize)>,
+    pub(crate) type_positions: Vec<Vec<usize>>,
+    pub(crate) record_count: u64,
+    pub(crate) end_time: Option<u64>,
+}
+
+impl Default for IndexBuilder {
+    fn default() -> Self {
+        Self {
+            time_index: Vec::new(),
+            type_positions: vec![Vec::new(); 256],
+            record_count: 0,
+            end_time: None,
+        }
+    }
+}
+
+impl IndexBuilder {
+    /// Adds the record of a datagram with `data` at `position` to the index.
+    pub(crate) fn add(&mut self, time: u64, data: &[u8], position: usize) {
+        let interval = time / INDEX_INTERVAL_MICROS;
+        let last_interval = self
+            .time_index
+            .last()
+            .map(|(last, _)| last / INDEX_INTERVAL_MICROS);
+        if last_interval < Some(interval) {
+            self.time_index.push((time, position));
+        }
+        for pdu_type in pdu_types(data) {
+            self.type_positions[usize::from(pdu_type)].push(position);
+        }
+        self.record_count += 1;
+        self.end_time = Some(self.end_time.map_or(time, |end| end.max(time)));
+    }
+
+    pub(crate) fn pdu_type_index(&self) -> Vec<(PduType, Vec<usize>)> {
+        self.type_positions
+            .iter()
+            .enumerate()
+            .filter(|(_, positions)| !positions.is_empty())
+            .map(|(pdu_type, positions)| (PduType::from(pdu_type as u8), positions.clone()))
+            .collect()
+    }
+
+    /// Writes the index and the footer, for an index that starts at `index_start` in the recording.
+    pub(crate) fn write(&self, index_start: usize, start_time: u64, buf: &mut BytesMut) {
+        buf.put_slice(INDEX_MAGIC);
+        buf.put_u64(self.end_time.unwrap_or(start_time));
+        buf.put_u32(self.time_index.len() as u32);
+        for (time, position) in &self.time_index {
+            buf.put_u64(*time);
+            buf.put_u64(*position as u64);
+        }
+        let pdu_type_index = self.pdu_type_index();
+        buf.put_u16(pdu_type_index.len() as u16);
+        for (pdu_type, positions) in pdu_type_index {
+            buf.put_u8(pdu_type.into());
+            buf.put_u64(positions.len() as u64);
+            for position in positions {
+                buf.put_u64(position as u64);
+            }
+        }
+
+        buf.put_u64(index_start as u64);
+        buf.put_u64(self.record_count);
+        buf.put_slice(FOOTER_MAGIC);
+    }
+}
+
+pub(crate) fn write_file_header(start_time: u64, buf: &mut BytesMut) {
+    buf.put_slice(FILE_MAGIC);
+    buf.put_u16(FILE_VERSION);
+    buf.put_u64(start_time);
+}
+
+/// Writes a record, with the type of the (first) PDU in the data in the record header.
+pub(crate) fn write_record(
+    time: u64,
+    source: Option<SocketAddr>,
+    data: &[u8],
+    buf: &mut BytesMut,
+) -> Result<(), DisError> {
+    let length = u32::try_from(data.len()).map_err(|_| {
+        DisError::InvalidRecording(format!("a datagram of {} bytes is too large", data.len()))
+    })?;
+    let pdu_type = data.get(PDU_TYPE_OFFSET).copied().unwrap_or_default();
+    buf.put_u64(time);
+    buf.put_u32(length);
+    buf.put_u8(pdu_type);
+    match source {
+        Some(SocketAddr::V4(address)) => {
+            buf.put_u8(FAMILY_IPV4);
+            buf.put_u16(address.port());
+            buf.put_slice(&address.ip().octets());
+            buf.put_bytes(0, 12);
+        }
+        Some(SocketAddr::V6(address)) => {
+            buf.put_u8(FAMILY_IPV6);
+            buf.put_u16(address.port());
+            buf.put_slice(&address.ip().octets());
+        }
+        None => {
+            buf.put_u8(FAMILY_NONE);
+            buf.put_u16(0);
+            buf.put_bytes(0, 16);
+        }
+    }
+    buf.put_slice(data);
+    Ok(())
+}
+
+/// The distinct types of the PDUs in a datagram, as stated in their headers.
+/// The first is the type of the (first) PDU in the record header, also when the datagram is not a PDU.
+fn pdu_types(data: &[u8]) -> Vec<u8> {
+    let mut pdu_types = Vec::new();
+    let mut offset = 0;
+    while offset < data.len() || pdu_types.is_empty() {
+        pdu_types.push(
+            data.get(offset + PDU_TYPE_OFFSET)
+                .copied()
+                .unwrap_or_default(),
+        );
+        let length = data
+            .get(offset + PDU_LENGTH_OFFSET..offset + PDU_LENGTH_OFFSET + 2)
+            .map_or(0, |length| {
+                usize::from(u16::from_be_bytes([length[0], length[1]]))
+            });
+        if length == 0 {
+            break;
+        }
+        offset += length;
+    }
+    pdu_types.sort_unstable();
+    pdu_types.dedup();
+    pdu_types
+}
+
+pub(crate) fn micros_since_epoch(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_micros() as u64
+}
+
+pub(crate) fn time_from_micros(micros: u64) -> SystemTime {
+    UNIX_EPOCH + Duration::from_micros(micros)
+}
@@ -0,0 +1,198 @@
+pub mod format;
+pub mod player;
+pub mod recorder;
+
+#[cfg(test)]
+mod tests {
+    use crate::enumerations::PduType;
+    use crate::model::{DisTimeStamp, Pdu, PduHeader};
+    use crate::other::model::Other;
+    use crate::parse;
+    use crate::recording::format::Recording;
+    use crate::recording::player::{Pacing, Player};
+    use crate::recording::recorder::Recorder;
+    use crate::start_resume::model::StartResume;
+    use crate::DisError;
+    use bytes::BytesMut;
+    use std::io::Write;
+    use std::net::SocketAddr;
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    fn start() -> SystemTime {
+        UNIX_EPOCH + Duration::from_secs(1_700_000_000)
+    }
+
+    fn other() -> Pdu {
+        Pdu::finalize_from_parts(
+            PduHeader::new_v7(1, PduType::Other),
+            Other::builder()
+                .with_body(vec![0xAB; 12])
+                .build()
+                .into_pdu_body(),
+            DisTimeStamp::new_absolute_from_secs(100),
+        )
+    }
+
+    fn start_resume() -> Pdu {
+        Pdu::finalize_from_parts(
+            PduHeader::new_v7(1, PduType::StartResume),
+            StartResume::builder().build().into_pdu_body(),
+            DisTimeStamp::new_absolute_from_secs(100),
+        )
+    }
+
+    fn source() -> SocketAddr {
+        "10.0.0.1:3000".parse().unwrap()
+    }
+
+    fn record<W: Write>(recorder: &mut Recorder<W>) {
+        recorder
+            .record_pdu(&other(), Some(source()), start())
+            .unwrap();
+        recorder
+            .record_pdu(
+                &start_resume(),
+                Some("[fe80::1]:3000".parse().unwrap()),
+                start() + Duration::from_millis(500),
+            )
+            .unwrap();
+        recorder
+            .record_bytes(b"not a pdu", None, start() + Duration::from_secs(2))
+            .unwrap();
+        recorder
+            .record_pdu(&other(), None, start() + Duration::from_millis(2500))
+            .unwrap();
+    }
+
+    fn recording() -> Recording {
+        let mut recorder = Recorder::new(Vec::new(), start()).unwrap();
+        record(&mut recorder);
+        Recording::from_bytes(recorder.finish().unwrap()).unwrap()
+    }
+
+    #[test]
+    fn record_and_read() {
+        let recording = recording();
+        assert_eq!(recording.start_time(), start());
+        assert_eq!(recording.duration(), Duration::from_millis(2500));
+        assert_eq!(recording.record_count(), 4);
+        assert_eq!(recording.pdu_type_count(PduType::Other), 2);
+        assert_eq!(recording.pdu_type_count(PduType::StartResume), 1);
+
+        let records: Vec<_> = recording.records().collect();
+        assert_eq!(records.len(), 4);
+        assert_eq!(records[0].source, Some(source()));
+        assert_eq!(records[0].pdu_type(), PduType::Other);
+        assert_eq!(parse(&records[0].data).unwrap(), vec![other()]);
+        assert_eq!(records[1].source, Some("[fe80::1]:3000".parse().unwrap()));
+        assert_eq!(&records[2].data[..], b"not a pdu");
+        assert_eq!(records[3].source, None);
+
+        // without an index, as left by a recorder that did not finish
+        let mut unfinished = Vec::new();
+        let mut recorder = Recorder::new(&mut unfinished, start()).unwrap();
+        record(&mut recorder);
+        drop(recorder);
+        unfinished.extend_from_slice(&[0; 10]);
+        let unfinished = Recording::from_bytes(unfinished).unwrap();
+        assert_eq!(unfinished.record_count(), 4);
+        assert_eq!(unfinished.duration(), recording.duration());
+        assert_eq!(unfinished.records().collect::<Vec<_>>(), records);
+
+        assert!(matches!(
+            Recording::from_bytes(&b"not a recording"[..]),
+            Err(DisError::InvalidRecording(_))
+        ));
+    }
+
+    #[test]
+    fn play_in_real_time() {
+        let mut player = Player::new(recording());
+        let now = start() + Duration::from_secs(3600);
+
+        let played = player.poll(now);
+        assert_eq!(played.len(), 1);
+        assert_eq!(played[0].pdu, other());
+        assert_eq!(played[0].source, Some(source()));
+        assert_eq!(player.next_due(now), Some(now + Duration::from_millis(500)));
+        assert!(player.poll(now + Duration::from_millis(400)).is_empty());
+        assert_eq!(player.poll(now + Duration::from_millis(500)).len(), 1);
+        // the datagram that does not contain a PDU is skipped
+        assert_eq!(player.poll(now + Duration::from_millis(2500)).len(), 1);
+        assert!(player.is_finished());
+        assert_eq!(player.next_due(now), None);
+    }
+
+    #[test]
+    fn play_scaled_filtered_and_looped() {
+        let now = start() + Duration::from_secs(3600);
+
+        let mut scaled = Player::new(recording()).with_pacing(Pacing::Scaled(2.0));
+        assert_eq!(scaled.poll(now).len(), 1);
+        assert_eq!(scaled.poll(now + Duration::from_millis(250)).len(), 1);
+
+        let mut fast = Player::new(recording())
+            .with_pacing(Pacing::AsFastAsPossible)
+            .with_exercise_id(7)
+            .with_pdu_types([PduType::Other]);
+        let played = fast.poll(now);
+        assert_eq!(played.len(), 2);
+        assert!(played
+            .iter()
+            .all(|played| played.pdu.header.exercise_id == 7
+                && played.pdu.header.pdu_type == PduType::Other));
+
+        // the index skips the datagram without a PDU of the selected types
+        fast.seek(Duration::from_secs(1));
+        assert_eq!(
+            fast.current_time(),
+            Some(start() + Duration::from_millis(2500))
+        );
+        let played = fast.poll(now);
+        assert_eq!(played.len(), 1);
+        assert_eq!(played[0].recorded_at, start() + Duration::from_millis(2500));
+
+        let mut looping = Player::new(recording())
+            .with_pacing(Pacing::AsFastAsPossible)
+            .with_loop(true)
+            .with_max_batch(6);
+        assert_eq!(looping.poll(now).len(), 5);
+        assert!(!looping.is_finished());
+    }
+
+    #[test]
+    fn play_with_tiny_scale_factor() {
+        let now = start() + Duration::from_secs(3600);
+        let mut player = Player::new(recording()).with_pacing(Pacing::Scaled(f64::MIN_POSITIVE));
+        assert_eq!(player.poll(now).len(), 1);
+        assert!(player.next_due(now).is_some_and(|due| due > now));
+        assert!(player.poll(now + Duration::from_secs(3600)).is_empty());
+    }
+
+    #[test]
+    fn index_all_pdus_in_a_datagram() {
+        let mut datagram = BytesMut::new();
+        other().serialize(&mut datagram).unwrap();
+        start_resume().serialize(&mut datagram).unwrap();
+        let mut recorder = Recorder::new(Vec::new(), start()).unwrap();
+        recorder.record_pdu(&other(), None, start()).unwrap();
+        recorder
+            .record_bytes(&datagram, None, start() + Duration::from_secs(1))
+            .unwrap();
+        let recording = Recording::from_bytes(recorder.finish().unwrap()).unwrap();
+        assert_eq!(recording.pdu_type_count(PduType::Other), 2);
+        assert_eq!(recording.pdu_type_count(PduType::StartResume), 1);
+
+        let mut player = Player::new(recording)
+            .with_pacing(Pacing::AsFastAsPossible)
+            .with_pdu_types([PduType::StartResume]);
+        assert_eq!(
+            player.current_time(),
+            Some(start() + Duration::from_secs(1))
+        );
+        let played = player.poll(start());
+        assert_eq!(played.len(), 1);
+        assert_eq!(played[0].pdu, start_resume());
+        assert!(player.is_finished());
+    }
+}
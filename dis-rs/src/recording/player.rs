@@ -0,0 +1,242 @@
+use crate::common::model::Pdu;
+use crate::common::parse;
+use crate::enumerations::PduType;
+use crate::recording::format::Recording;
+use std::net::SocketAddr;
+use std::time::{Duration, SystemTime};
+
+/// The default maximum number of datagrams a `Player` replays per `Player::poll`.
+pub const DEFAULT_MAX_BATCH: usize = 1024;
+
+/// The longest delay between two datagrams when playing with a scaled timing, which keeps tiny factors from
+/// overflowing the due times.
+const MAX_SCALED_DELAY: Duration = Duration::from_secs(365 * 24 * 60 * 60);
+
+/// How fast a `Player` replays a recording.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub enum Pacing {
+    /// With the original timing between the datagrams.
+    #[default]
+    RealTime,
+    /// With the timing scaled by a factor, e.g. `2.0` for twice as fast. Factors of zero or less play as fast as possible.
+    Scaled(f64),
+    /// Without waiting between the datagrams.
+    AsFastAsPossible,
+}
+
+/// A PDU replayed by a `Player`, with the address and time it was originally received.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PlayedPdu {
+    pub pdu: Pdu,
+    pub source: Option<SocketAddr>,
+    pub recorded_at: SystemTime,
+}
+
+/// Replays a `Recording`, returning the PDUs that are due at the time the player is polled.
+///
+/// The player does not perform I/O or keep time itself: the caller polls it with the current time
+/// and sends the returned PDUs, using `Player::next_due` to determine when to poll again.
+/// Playback (re)starts with the first datagram due at the time of the first poll after creating the player,
+/// seeking, or looping back to the start of the recording.
+#[derive(Clone, Debug)]
+pub struct Player {
+    recording: Recording,
+    position: usize,
+    pacing: Pacing,
+    looping: bool,
+    exercise_id: Option<u8>,
+    pdu_types: Option<Vec<PduType>>,
+    max_batch: usize,
+    /// The playback time and recorded time that correspond to each other.
+    anchor: Option<(SystemTime, SystemTime)>,
+}
+
+impl Player {
+    #[must_use]
+    pub fn new(recording: Recording) -> Self {
+        let position = recording.first_position();
+        Self {
+            recording,
+            position,
+            pacing: Pacing::default(),
+            looping: false,
+            exercise_id: None,
+            pdu_types: None,
+            max_batch: DEFAULT_MAX_BATCH,
+            anchor: None,
+        }
+    }
+
+    #[must_use]
+    pub fn with_pacing(mut self, pacing: Pacing) -> Self {
+        self.pacing = pacing;
+        self.anchor = None;
+        self
+    }
+
+    /// Restarts playback at the start of the recording after the last datagram.
+    #[must_use]
+    pub fn with_loop(mut self, looping: bool) -> Self {
+        self.looping = looping;
+        self
+    }
+
+    /// Rewrites the exercise ID of the replayed PDUs.
+    #[must_use]
+    pub fn with_exercise_id(mut self, exercise_id: u8) -> Self {
+        self.exercise_id = Some(exercise_id);
+        self
+    }
+
+    /// Replays only PDUs of the provided types, skipping the datagrams without such PDUs using the index of the recording.
+    #[must_use]
+    pub fn with_pdu_types(mut self, pdu_types: impl IntoIterator<Item = PduType>) -> Self {
+        self.pdu_types = Some(pdu_types.into_iter().collect());
+        self
+    }
+
+    /// Limits the number of datagrams replayed per poll, which bounds the work per poll when playing as fast as possible.
+    #[must_use]
+    pub fn with_max_batch(mut self, max_batch: usize) -> Self {
+        self.max_batch = max_batch.max(1);
+        self
+    }
+
+    #[must_use]
+    pub fn recording(&self) -> &Recording {
+        &self.recording
+    }
+
+    /// Continues playback at the first datagram received at or after `offset` from the start of the recording.
+    pub fn seek(&mut self, offset: Duration) {
+        self.position = self
+            .recording
+            .position_at(self.recording.start_time() + offset);
+        self.anchor = None;
+    }
+
+    /// The receive time of the next datagram to replay, or `None` when playback is finished.
+    #[must_use]
+    pub fn current_time(&self) -> Option<SystemTime> {
+        self.next_record_time()
+    }
+
+    /// Whether all datagrams have been replayed, which never happens when looping a non-empty recording.
+    #[must_use]
+    pub fn is_finished(&self) -> bool {
+        self.next_record_time().is_none()
+    }
+
+    /// The time at which the next datagram is due, given the current time `now`, or `None` when playback is finished.
+    #[must_use]
+    pub fn next_due(&self, now: SystemTime) -> Option<SystemTime> {
+        let recorded_at = self.next_record_time()?;
+        let due = match (
+            self.anchor,
+            self.recording.record_at(self.next_position(self.position)),
+        ) {
+            (Some(anchor), Some(_)) => self.due_time(anchor, recorded_at),
+            // playback (re)starts with the next datagram
+            _ => None,
+        };
+        Some(due.unwrap_or(now))
+    }
+
+    /// Replays the datagrams that are due at `now`, returning their PDUs.
+    ///
+    /// Datagrams that do not contain valid PDUs are skipped.
+    pub fn poll(&mut self, now: SystemTime) -> Vec<PlayedPdu> {
+        let mut played = Vec::new();
+        let mut datagrams = 0;
+        while datagrams < self.max_batch {
+            self.position = self.next_position(self.position);
+            let Some((record, next)) = self.recording.record_at(self.position) else {
+                if self.looping && self.rewind() {
+                    continue;
+                }
+                break;
+            };
+            let anchor = *self.anchor.get_or_insert((now, record.recorded_at));
+            if self
+                .due_time(anchor, record.recorded_at)
+                .is_some_and(|due| due > now)
+            {
+                break;
+            }
+            self.position = next;
+            datagrams += 1;
+
+            let Ok(pdus) = parse(&record.data) else {
+                continue;
+            };
+            played.extend(
+                pdus.into_iter()
+                    .filter(|pdu| match &self.pdu_types {
+                        Some(types) => types.contains(&pdu.header.pdu_type),
+                        None => true,
+                    })
+                    .map(|mut pdu| {
+                        if let Some(exercise_id) = self.exercise_id {
+                            pdu.header.exercise_id = exercise_id;
+                        }
+                        PlayedPdu {
+                            pdu,
+                            source: record.source,
+                            recorded_at: record.recorded_at,
+                        }
+                    }),
+            );
+        }
+        played
+    }
+
+    /// The time a datagram received at `recorded_at` is due, or `None` when it is due immediately.
+    fn due_time(
+        &self,
+        (anchor_now, anchor_recorded): (SystemTime, SystemTime),
+        recorded_at: SystemTime,
+    ) -> Option<SystemTime> {
+        let elapsed = recorded_at
+            .duration_since(anchor_recorded)
+            .unwrap_or_default();
+        match self.pacing {
+            Pacing::RealTime => Some(anchor_now + elapsed),
+            Pacing::Scaled(factor) if factor > 0.0 => {
+                let delay = Duration::try_from_secs_f64(elapsed.as_secs_f64() / factor)
+                    .map_or(MAX_SCALED_DELAY, |delay| delay.min(MAX_SCALED_DELAY));
+                Some(anchor_now + delay)
+            }
+            Pacing::Scaled(_) | Pacing::AsFastAsPossible => None,
+        }
+    }
+
+    fn next_record_time(&self) -> Option<SystemTime> {
+        match self.recording.record_at(self.next_position(self.position)) {
+            Some((record, _)) => Some(record.recorded_at),
+            None if self.looping => self
+                .recording
+                .record_at(self.next_position(self.recording.first_position()))
+                .map(|(record, _)| record.recorded_at),
+            None => None,
+        }
+    }
+
+    /// Moves back to the start of the recording, returning whether there is anything to replay.
+    fn rewind(&mut self) -> bool {
+        let first = self.next_position(self.recording.first_position());
+        if self.position == first || self.recording.record_at(first).is_none() {
+            return false;
+        }
+        self.position = first;
+        self.anchor = None;
+        true
+    }
+
+    /// The position of the first datagram to replay at or after `position`.
+    fn next_position(&self, position: usize) -> usize {
+        match &self.pdu_types {
+            Some(pdu_types) => self.recording.position_of_types(pdu_types, position),
+            None => position,
+        }
+    }
+}
@@ -0,0 +1,102 @@
+use crate::common::model::Pdu;
+use crate::recording::format::{
+    micros_since_epoch, write_file_header, write_record, IndexBuilder, FILE_HEADER_OCTETS,
+};
+use crate::DisError;
+use bytes::BytesMut;
+use std::io::Write;
+use std::net::SocketAddr;
+use std::time::SystemTime;
+
+/// Writes datagrams and PDUs in the native recording format to a file or any other `Write` destination.
+///
+/// Datagrams are written as they are recorded, so that a recording is readable up to the last complete datagram
+/// when the recorder is not finished. `Recorder::finish` writes the index that makes the recording seekable.
+/// Datagrams are expected to be recorded in the order of their receive time.
+#[derive(Debug)]
+pub struct Recorder<W: Write> {
+    writer: W,
+    start_time: u64,
+    position: usize,
+    index: IndexBuilder,
+    buf: BytesMut,
+}
+
+impl<W: Write> Recorder<W> {
+    /// Starts a recording at `start_time`, writing the header of the recording.
+    ///
+    /// # Errors
+    /// Returns `DisError::IoError` when writing to the writer fails.
+    pub fn new(writer: W, start_time: SystemTime) -> Result<Self, DisError> {
+        let mut recorder = Self {
+            writer,
+            start_time: micros_since_epoch(start_time),
+            position: 0,
+            index: IndexBuilder::default(),
+            buf: BytesMut::with_capacity(FILE_HEADER_OCTETS),
+        };
+        write_file_header(recorder.start_time, &mut recorder.buf);
+        recorder.write_buf()?;
+        Ok(recorder)
+    }
+
+    /// Records the raw bytes of a datagram, received at `recorded_at` from `source`.
+    ///
+    /// # Errors
+    /// Returns `DisError::IoError` when writing to the writer fails.
+    pub fn record_bytes(
+        &mut self,
+        data: &[u8],
+        source: Option<SocketAddr>,
+        recorded_at: SystemTime,
+    ) -> Result<(), DisError> {
+        let time = micros_since_epoch(recorded_at);
+        write_record(time, source, data, &mut self.buf)?;
+        self.index.add(time, data, self.position);
+        self.write_buf()
+    }
+
+    /// Records a PDU, received at `recorded_at` from `source`.
+    ///
+    /// # Errors
+    /// Returns a `DisError` when the PDU cannot be serialized, or `DisError::IoError` when writing to the writer fails.
+    pub fn record_pdu(
+        &mut self,
+        pdu: &Pdu,
+        source: Option<SocketAddr>,
+        recorded_at: SystemTime,
+    ) -> Result<(), DisError> {
+        let mut data = BytesMut::with_capacity(pdu.pdu_length() as usize);
+        pdu.serialize(&mut data)?;
+        self.record_bytes(&data, source, recorded_at)
+    }
+
+    /// The number of recorded datagrams.
+    #[must_use]
+    pub fn record_count(&self) -> u64 {
+        self.index.record_count
+    }
+
+    /// Completes the recording by writing the index, and returns the writer.
+    ///
+    /// # Errors
+    /// Returns `DisError::IoError` when writing to or flushing the writer fails.
+    pub fn finish(mut self) -> Result<W, DisError> {
+        self.index
+            .write(self.position, self.start_time, &mut self.buf);
+        self.write_buf()?;
+        self.writer.flush().map_err(io_error)?;
+        Ok(self.writer)
+    }
+
+    fn write_buf(&mut self) -> Result<(), DisError> {
+        self.writer.write_all(&self.buf).map_err(io_error)?;
+        self.position += self.buf.len();
+        self.buf.clear();
+        Ok(())
+    }
+}
+
+fn io_error(error: std::io::Error) -> DisError {
+    DisError::IoError(error.to_string())
+}
@@ -0,0 +1,154 @@
+mod models;
+pub mod value;
+
+#[cfg(test)]
+mod tests {
+    use crate::entity_state::model::{
+        DrEulerAngles, DrOtherParameters, DrParameters, EntityAppearance, EntityState,
+    };
+    use crate::enumerations::{
+        AirPlatformAppearance, AppearanceDamage, ArticulatedPartsTypeClass,
+        ArticulatedPartsTypeMetric, EntityKind, ForceId,
+    };
+    use crate::fire::model::Fire;
+    use crate::iff::model::{Iff, IffLayer2};
+    use crate::model::{
+        ArticulatedPart, EntityId, EntityType, Location, MunitionDescriptor, PduBody,
+    };
+    use crate::reflect::value::{Leaf, Reflect, Value};
+
+    fn tank() -> EntityState {
+        EntityState::builder()
+            .with_entity_id(EntityId::new(1, 2, 3))
+            .with_force_id(ForceId::Friendly)
+            .with_location(Location::new(1.0, 2.0, 3.0))
+            .with_variable_parameter(
+                ArticulatedPart::default()
+                    .with_type_class(ArticulatedPartsTypeClass::PrimaryTurretNumber1)
+                    .with_type_metric(ArticulatedPartsTypeMetric::Azimuth)
+                    .with_parameter_value(0.5)
+                    .to_variable_parameter(),
+            )
+            .build()
+    }
+
+    #[test]
+    fn fields_by_name() {
+        let tank = tank();
+        assert_eq!(tank.type_name(), "EntityState");
+        assert_eq!(tank.field_names()[0], "entity_id");
+        assert_eq!(tank.fields().len(), tank.field_names().len());
+        assert!(matches!(tank.field("entity_id"), Some(Value::Record(_))));
+        assert!(
+            matches!(tank.field("variable_parameters"), Some(Value::List(items)) if items.len() == 1)
+        );
+        assert_eq!(
+            tank.field("force_id")
+                .and_then(|value| value.downcast_ref::<ForceId>().copied()),
+            Some(ForceId::Friendly)
+        );
+        assert!(tank.field("unknown").is_none());
+
+        assert!(PduBody::IntercomSignal.field_names().is_empty());
+    }
+
+    #[test]
+    fn values_by_path() {
+        let body = tank().into_pdu_body();
+        assert_eq!(
+            body.get("entity_state.entity_id.simulation_address.site_id")
+                .and_then(|value| value.downcast_ref::<u16>().copied()),
+            Some(1)
+        );
+        assert_eq!(
+            body.get("entity_state.variable_parameters[0].articulated.parameter_value")
+                .and_then(|value| value.downcast_ref::<f32>().copied()),
+            Some(0.5)
+        );
+        assert!(body.get("entity_state.variable_parameters[1]").is_none());
+        assert!(body.get("entity_state.entity_id.unknown").is_none());
+        assert!(body.get("fire.event_id").is_none());
+    }
+
+    #[test]
+    fn walk_all_leaves() {
+        let mut paths = Vec::new();
+        tank().walk(&mut |path: &str, _value: &dyn Leaf| paths.push(path.to_string()));
+
+        assert_eq!(paths[0], "entity_id.simulation_address.site_id");
+        assert!(paths.contains(&"entity_location.x_coordinate".to_string()));
+        assert!(paths.contains(&"variable_parameters[0].articulated.type_class".to_string()));
+    }
+
+    #[test]
+    fn nested_records_by_path() {
+        let fire = Fire::builder()
+            .with_munition_descriptor(
+                EntityType::default().with_kind(EntityKind::Munition),
+                MunitionDescriptor::default().with_quantity(4),
+            )
+            .build()
+            .into_pdu_body();
+        assert_eq!(
+            fire.get("fire.descriptor.munition.entity_type.kind")
+                .and_then(|value| value.downcast_ref::<EntityKind>().copied()),
+            Some(EntityKind::Munition)
+        );
+        assert_eq!(
+            fire.get("fire.descriptor.munition.munition.quantity")
+                .and_then(|value| value.downcast_ref::<u16>().copied()),
+            Some(4)
+        );
+        assert!(fire.get("fire.descriptor.expendable").is_none());
+
+        let aircraft = EntityState::builder()
+            .with_appearance(EntityAppearance::AirPlatform(AirPlatformAppearance {
+                damage: AppearanceDamage::Destroyed,
+                ..Default::default()
+            }))
+            .with_dead_reckoning_parameters(DrParameters {
+                other_parameters: DrOtherParameters::LocalEulerAngles(DrEulerAngles {
+                    local_yaw: 0.25,
+                    ..Default::default()
+                }),
+                ..Default::default()
+            })
+            .build()
+            .into_pdu_body();
+        assert_eq!(
+            aircraft
+                .get("entity_state.entity_appearance.air_platform.damage")
+                .and_then(|value| value.downcast_ref::<AppearanceDamage>().copied()),
+            Some(AppearanceDamage::Destroyed)
+        );
+        assert_eq!(
+            aircraft
+                .get("entity_state.dead_reckoning_parameters.other_parameters.local_euler_angles.local_yaw")
+                .and_then(|value| value.downcast_ref::<f32>().copied()),
+            Some(0.25)
+        );
+
+        let iff = Iff::builder()
+            .with_layer_2(IffLayer2::builder().with_operational_parameter_1(3).build())
+            .build()
+            .into_pdu_body();
+        assert_eq!(
+            iff.get("iff.layer_2.operational_parameter_1")
+                .and_then(|value| value.downcast_ref::<u8>().copied()),
+            Some(3)
+        );
+        assert!(iff.get("iff.layer_3.layer_header").is_none());
+    }
+
+    #[test]
+    fn walk_variant_fields() {
+        let mut paths = Vec::new();
+        Fire::builder()
+            .with_munition_descriptor(EntityType::default(), MunitionDescriptor::default())
+            .build()
+            .walk(&mut |path: &str, _value: &dyn Leaf| paths.push(path.to_string()));
+
+        assert!(paths.contains(&"descriptor.munition.entity_type.kind".to_string()));
+        assert!(paths.contains(&"descriptor.munition.munition.rate".to_string()));
+    }
+}
@@ -0,0 +1,173 @@
+use crate::common::acknowledge::model::Acknowledge;
+use crate::common::acknowledge_r::model::AcknowledgeR;
+use crate::common::action_request::model::ActionRequest;
+use crate::common::action_request_r::model::ActionRequestR;
+use crate::common::action_response::model::ActionResponse;
+use crate::common::action_response_r::model::ActionResponseR;
+use crate::common::aggregate_state::model::AggregateState;
+use crate::common::attribute::model::{
+    AngleDeception, Attribute, AttributeRecord, AttributeRecordFields, AttributeRecordSet,
+    BlankingSector, CommunicationsNodeId, EntityDestination, FalseTargets, GroupAssignment,
+    GroupDestination,
+};
+use crate::common::collision::model::Collision;
+use crate::common::collision_elastic::model::CollisionElastic;
+use crate::common::comment::model::Comment;
+use crate::common::comment_r::model::CommentR;
+use crate::common::create_entity::model::CreateEntity;
+use crate::common::create_entity_r::model::CreateEntityR;
+use crate::common::data::model::Data;
+use crate::common::data_query::model::DataQuery;
+use crate::common::data_query_r::model::DataQueryR;
+use crate::common::data_r::model::DataR;
+use crate::common::designator::model::Designator;
+use crate::common::detonation::model::Detonation;
+use crate::common::electromagnetic_emission::model::{
+    Beam, ElectromagneticEmission, EmitterSystem, FundamentalParameterData, JammingTechnique,
+    TrackJam,
+};
+use crate::common::entity_state::model::{
+    DrEulerAngles, DrOtherParameters, DrParameters, DrWorldOrientationQuaternion, EntityAppearance,
+    EntityMarking, EntityState,
+};
+use crate::common::entity_state_update::model::EntityStateUpdate;
+use crate::common::event_report::model::EventReport;
+use crate::common::event_report_r::model::EventReportR;
+use crate::common::fields::{with_body_variants, with_model_fields, with_record_variants};
+use crate::common::fire::model::Fire;
+use crate::common::iff::model::{
+    ChangeOptionsRecord, FundamentalOperationalData, Iff, IffDataRecord, IffDataSpecification,
+    IffFundamentalParameterData, IffLayer2, IffLayer3, IffLayer4, IffLayer5, InformationLayers,
+    LayerHeader, MarkXXIISystemSpecificData, ModeSSystemSpecificData, RrbSystemSpecificData,
+    SovietSystemSpecificData, SystemId, SystemSpecificData, SystemStatus,
+};
+use crate::common::is_group_of::model::IsGroupOf;
+use crate::common::is_part_of::model::IsPartOf;
+use crate::common::model::{
+    ArticulatedPart, AttachedPart, BeamData, ClockTime, DescriptorRecord, EntityId, EntityType,
+    EventId, FixedDatum, Location, MunitionDescriptor, Orientation, PduBody, SimulationAddress,
+    VariableDatum, VariableParameter, VectorF32,
+};
+use crate::common::other::model::Other;
+use crate::common::receiver::model::Receiver;
+use crate::common::record_query_r::model::RecordQueryR;
+use crate::common::record_r::model::RecordR;
+use crate::common::remove_entity::model::RemoveEntity;
+use crate::common::remove_entity_r::model::RemoveEntityR;
+use crate::common::repair_complete::model::RepairComplete;
+use crate::common::repair_response::model::RepairResponse;
+use crate::common::resupply_cancel::model::ResupplyCancel;
+use crate::common::resupply_offer::model::ResupplyOffer;
+use crate::common::resupply_received::model::ResupplyReceived;
+use crate::common::sees::model::SEES;
+use crate::common::service_request::model::ServiceRequest;
+use crate::common::set_data::model::SetData;
+use crate::common::set_data_r::model::SetDataR;
+use crate::common::set_record_r::model::SetRecordR;
+use crate::common::signal::model::Signal;
+use crate::common::start_resume::model::StartResume;
+use crate::common::start_resume_r::model::StartResumeR;
+use crate::common::stop_freeze::model::StopFreeze;
+use crate::common::stop_freeze_r::model::StopFreezeR;
+use crate::common::transfer_ownership::model::TransferOwnership;
+use crate::common::transmitter::model::{
+    AntennaPattern, BasicHaveQuickModulationParameters, BeamAntennaPattern,
+    CcttSincgarsModulationParameters, CryptoKeyId, HaveQuickNetId, HighFidelityHaveQuick,
+    JtidsMidsModulationParameters, ModulationParameters, ModulationType,
+    SphericalHarmonicAntennaPattern, SpreadSpectrum, Transmitter, VariableTransmitterParameter,
+    VariableTransmitterParameterFields,
+};
+use crate::common::underwater_acoustic::model::{
+    AcousticEmitterSystem, UABeam, UAEmitterSystem, UAFundamentalParameterData, UnderwaterAcoustic,
+};
+#[allow(clippy::wildcard_imports)] // the generated bitfield records
+use crate::enumerations::*;
+use crate::reflect::value::{
+    impl_reflect, impl_reflect_variants, leaf, leaves, list, nested, Reflect, Value,
+};
+use crate::with_bitfield_fields;
+
+with_model_fields!(impl_reflect);
+with_bitfield_fields!(impl_reflect);
+
+impl Reflect for VariableParameter {
+    fn type_name(&self) -> &'static str {
+        "VariableParameter"
+    }
+
+    fn field_names(&self) -> &'static [&'static str] {
+        match self {
+            VariableParameter::Articulated(_) => &["articulated"],
+            VariableParameter::Attached(_) => &["attached"],
+            VariableParameter::Separation(_) => &["separation"],
+            VariableParameter::EntityType(_) => &["entity_type"],
+            VariableParameter::EntityAssociation(_) => &["entity_association"],
+            VariableParameter::Unspecified(_, _) => &["unspecified"],
+        }
+    }
+
+    fn field(&self, name: &str) -> Option<Value<'_>> {
+        match (self, name) {
+            (VariableParameter::Articulated(part), "articulated") => Some(nested(part)),
+            (VariableParameter::Attached(part), "attached") => Some(nested(part)),
+            (VariableParameter::Separation(separation), "separation") => Some(leaf(separation)),
+            (VariableParameter::EntityType(entity_type), "entity_type") => Some(leaf(entity_type)),
+            (VariableParameter::EntityAssociation(association), "entity_association") => {
+                Some(leaf(association))
+            }
+            (VariableParameter::Unspecified(_, _), "unspecified") => Some(leaf(self)),
+            _ => None,
+        }
+    }
+}
+
+/// The fields of a descriptor are reflected under the name of the kind of descriptor,
+/// e.g. `descriptor.munition.entity_type`.
+impl Reflect for DescriptorRecord {
+    fn type_name(&self) -> &'static str {
+        "DescriptorRecord"
+    }
+
+    fn field_names(&self) -> &'static [&'static str] {
+        match self {
+            DescriptorRecord::Munition { .. } => &["munition"],
+            DescriptorRecord::Expendable { .. } => &["expendable"],
+            DescriptorRecord::Explosion { .. } => &["explosion"],
+        }
+    }
+
+    fn field(&self, name: &str) -> Option<Value<'_>> {
+        let fields = match (self, name) {
+            (
+                DescriptorRecord::Munition {
+                    entity_type,
+                    munition,
+                },
+                "munition",
+            ) => vec![
+                ("entity_type", nested(entity_type)),
+                ("munition", nested(munition)),
+            ],
+            (DescriptorRecord::Expendable { entity_type }, "expendable") => {
+                vec![("entity_type", nested(entity_type))]
+            }
+            (
+                DescriptorRecord::Explosion {
+                    entity_type,
+                    explosive_material,
+                    explosive_force,
+                },
+                "explosion",
+            ) => vec![
+                ("entity_type", nested(entity_type)),
+                ("explosive_material", leaf(explosive_material)),
+                ("explosive_force", leaf(explosive_force)),
+            ],
+            _ => return None,
+        };
+        Some(Value::Fields(fields))
+    }
+}
+
+with_record_variants!(impl_reflect_variants);
+with_body_variants!(impl_reflect_variants);
@@ -0,0 +1,239 @@
+use std::any::Any;
+use std::fmt::Debug;
+
+/// A value that is reflected as a whole, such as a number, string or enumeration value.
+///
+/// The value can be formatted using `Debug`, or downcast to its concrete type using `Leaf::as_any`.
+pub trait Leaf: Debug {
+    fn as_any(&self) -> &dyn Any;
+}
+
+impl<T: Debug + Any> Leaf for T {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// A reflected field value.
+#[derive(Debug)]
+pub enum Value<'a> {
+    /// A value that is handled as a whole.
+    Leaf(&'a dyn Leaf),
+    /// A record with named fields.
+    Record(&'a dyn Reflect),
+    /// A list of values.
+    List(Vec<Value<'a>>),
+    /// Named fields without a record type of their own, such as the fields of `DescriptorRecord::Munition`.
+    Fields(Vec<(&'static str, Value<'a>)>),
+}
+
+impl<'a> Value<'a> {
+    /// Downcasts a leaf value to its concrete type.
+    #[must_use]
+    pub fn downcast_ref<T: Any>(&self) -> Option<&'a T> {
+        match self {
+            Value::Leaf(leaf) => (*leaf).as_any().downcast_ref(),
+            _ => None,
+        }
+    }
+}
+
+/// Receives the leaf values of a model, together with their path, while walking a model using `Reflect::walk`.
+pub trait Visitor {
+    fn visit(&mut self, path: &str, value: &dyn Leaf);
+}
+
+impl<F: FnMut(&str, &dyn Leaf)> Visitor for F {
+    fn visit(&mut self, path: &str, value: &dyn Leaf) {
+        self(path, value);
+    }
+}
+
+/// Runtime reflection of the fields of the models, by name and by path.
+///
+/// Paths consist of field names separated by dots, with list elements selected by index,
+/// e.g. `entity_state.entity_id.simulation_address.site_id` or `variable_parameters[1].parameter_value`.
+pub trait Reflect: Debug {
+    /// The name of the type of the model.
+    fn type_name(&self) -> &'static str;
+
+    /// The names of the fields of the model, in the order of the model.
+    fn field_names(&self) -> &'static [&'static str];
+
+    /// The value of the field with the provided name.
+    fn field(&self, name: &str) -> Option<Value<'_>>;
+
+    /// The names and values of the fields of the model.
+    fn fields(&self) -> Vec<(&'static str, Value<'_>)> {
+        self.field_names()
+            .iter()
+            .filter_map(|name| self.field(name).map(|value| (*name, value)))
+            .collect()
+    }
+
+    /// The value at the provided path, if the path exists.
+    fn get(&self, path: &str) -> Option<Value<'_>> {
+        let mut segments = path.split('.');
+        let (name, indices) = split_segment(segments.next()?);
+        let mut value = select(self.field(name)?, indices)?;
+        for segment in segments {
+            let (name, indices) = split_segment(segment);
+            let field = match value {
+                Value::Record(record) => record.field(name)?,
+                Value::Fields(fields) => {
+                    fields
+                        .into_iter()
+                        .find(|(field_name, _)| *field_name == name)?
+                        .1
+                }
+                _ => return None,
+            };
+            value = select(field, indices)?;
+        }
+        Some(value)
+    }
+
+    /// Visits all leaf values of the model, depth first in the order of the fields.
+    fn walk(&self, visitor: &mut dyn Visitor) {
+        for (name, value) in self.fields() {
+            walk_value(name, &value, visitor);
+        }
+    }
+}
+
+/// Optional records reflect the fields of the record when present, and no fields otherwise.
+impl<T: Reflect> Reflect for Option<T> {
+    fn type_name(&self) -> &'static str {
+        match self {
+            Some(record) => record.type_name(),
+            None => "None",
+        }
+    }
+
+    fn field_names(&self) -> &'static [&'static str] {
+        match self {
+            Some(record) => record.field_names(),
+            None => &[],
+        }
+    }
+
+    fn field(&self, name: &str) -> Option<Value<'_>> {
+        self.as_ref().and_then(|record| record.field(name))
+    }
+}
+
+fn split_segment(segment: &str) -> (&str, std::str::Split<'_, char>) {
+    let mut parts = segment.split('[');
+    (parts.next().unwrap_or_default(), parts)
+}
+
+fn select<'a, 'b>(
+    mut value: Value<'a>,
+    indices: impl Iterator<Item = &'b str>,
+) -> Option<Value<'a>> {
+    for index in indices {
+        let index: usize = index.strip_suffix(']')?.parse().ok()?;
+        value = match value {
+            Value::List(mut items) if index < items.len() => items.swap_remove(index),
+            _ => return None,
+        };
+    }
+    Some(value)
+}
+
+fn walk_value(path: &str, value: &Value<'_>, visitor: &mut dyn Visitor) {
+    match value {
+        Value::Leaf(leaf) => visitor.visit(path, *leaf),
+        Value::Record(record) => {
+            for (name, value) in record.fields() {
+                walk_value(&format!("{path}.{name}"), &value, visitor);
+            }
+        }
+        Value::List(items) => {
+            for (index, item) in items.iter().enumerate() {
+                walk_value(&format!("{path}[{index}]"), item, visitor);
+            }
+        }
+        Value::Fields(fields) => {
+            for (name, value) in fields {
+                walk_value(&format!("{path}.{name}"), value, visitor);
+            }
+        }
+    }
+}
+
+pub(crate) fn leaf<T: Leaf>(value: &T) -> Value<'_> {
+    Value::Leaf(value)
+}
+
+pub(crate) fn nested<T: Reflect>(value: &T) -> Value<'_> {
+    Value::Record(value)
+}
+
+pub(crate) fn list<T: Reflect>(values: &[T]) -> Value<'_> {
+    Value::List(values.iter().map(|value| Value::Record(value)).collect())
+}
+
+pub(crate) fn leaves<T: Leaf>(values: &[T]) -> Value<'_> {
+    Value::List(values.iter().map(|value| Value::Leaf(value)).collect())
+}
+
+/// Implements `Reflect` for structs, reflecting each listed field with the named strategy
+/// (`leaf`, `nested`, `list` or `leaves`).
+///
+/// The listing is checked against the declaration of the struct: a field that is missing from the listing
+/// fails to compile the exhaustive destructuring in `field_names`.
+macro_rules! impl_reflect {
+    ($($ty:ident { $($field:ident: $kind:ident),* $(,)? })*) => {
+        $(
+            impl Reflect for $ty {
+                fn type_name(&self) -> &'static str {
+                    stringify!($ty)
+                }
+
+                fn field_names(&self) -> &'static [&'static str] {
+                    let $ty { $($field: _),* } = self;
+                    &[$(stringify!($field)),*]
+                }
+
+                fn field(&self, name: &str) -> Option<Value<'_>> {
+                    match name {
+                        $( stringify!($field) => Some($kind(&self.$field)), )*
+                        _ => None,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+/// Implements `Reflect` for enums with single-value variants, reflecting the value of the variant
+/// as a single field with the provided name.
+macro_rules! impl_reflect_variants {
+    ($($ty:ident { $($variant:ident => $name:ident),* $(,)? })*) => {
+        $(
+            impl Reflect for $ty {
+                fn type_name(&self) -> &'static str {
+                    stringify!($ty)
+                }
+
+                fn field_names(&self) -> &'static [&'static str] {
+                    match self {
+                        $( $ty::$variant(_) => &[stringify!($name)], )*
+                        _ => &[],
+                    }
+                }
+
+                fn field(&self, name: &str) -> Option<Value<'_>> {
+                    match self {
+                        $( $ty::$variant(value) if name == stringify!($name) => Some(Value::Record(value)), )*
+                        _ => None,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+pub(crate) use impl_reflect;
+pub(crate) use impl_reflect_variants;
@@ -0,0 +1,258 @@
+use crate::common::acknowledge::model::Acknowledge;
+use crate::common::create_entity::model::CreateEntity;
+use crate::common::model::{ClockTime, EntityId, PduBody};
+use crate::common::remove_entity::model::RemoveEntity;
+use crate::common::start_resume::model::StartResume;
+use crate::common::stop_freeze::model::StopFreeze;
+use crate::enumerations::{AcknowledgeFlag, ResponseFlag, StopFreezeReason};
+use crate::siman::is_addressed_to;
+use std::collections::VecDeque;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Number of handled requests remembered to detect retransmitted requests
+const HANDLED_REQUESTS_CAPACITY: usize = 64;
+
+/// The state of a managed simulation.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum SimulationState {
+    #[default]
+    Stopped,
+    Running,
+    Frozen,
+}
+
+/// Callbacks of a managed simulation, invoked by `ManagedSimulation`.
+///
+/// The default implementations comply with all requests.
+pub trait SimanHandler {
+    /// Called when a Start/Resume request takes effect, at its real-world time.
+    fn start_resume(&mut self, _request: &StartResume) {}
+
+    /// Called when a Stop/Freeze request takes effect, at its real-world time.
+    fn stop_freeze(&mut self, _request: &StopFreeze) {}
+
+    /// Called when a Create Entity request is received. The returned flag is acknowledged to the simulation manager.
+    fn create_entity(&mut self, _request: &CreateEntity) -> ResponseFlag {
+        ResponseFlag::AbleToComply
+    }
+
+    /// Called when a Remove Entity request is received. The returned flag is acknowledged to the simulation manager.
+    fn remove_entity(&mut self, _request: &RemoveEntity) -> ResponseFlag {
+        ResponseFlag::AbleToComply
+    }
+}
+
+#[derive(Clone, Debug)]
+enum ScheduledTransition {
+    StartResume(StartResume),
+    StopFreeze(StopFreeze),
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+struct HandledRequest {
+    originating_id: EntityId,
+    request_id: u32,
+    acknowledge_flag: AcknowledgeFlag,
+    response_flag: ResponseFlag,
+}
+
+/// The managed simulation role of Simulation Management (SIMAN).
+///
+/// The managed simulation acknowledges Create Entity, Remove Entity, Start/Resume and Stop/Freeze requests
+/// addressed to it, and invokes the callbacks of the `SimanHandler`. Start/Resume and Stop/Freeze requests
+/// take effect at their real-world time; a zero `ClockTime` means immediately.
+/// Retransmitted requests are acknowledged again without invoking the handler a second time.
+///
+/// The caller is responsible for feeding received PDUs to `ManagedSimulation::process`,
+/// for sending the returned Acknowledge PDUs, and for regularly calling `ManagedSimulation::poll`.
+pub struct ManagedSimulation<H: SimanHandler> {
+    id: EntityId,
+    handler: H,
+    state: SimulationState,
+    simulation_time: ClockTime,
+    scheduled: Vec<(SystemTime, ScheduledTransition)>,
+    handled: VecDeque<HandledRequest>,
+}
+
+impl<H: SimanHandler> ManagedSimulation<H> {
+    /// Creates a managed simulation with the provided id, typically a simulation identifier (site and application).
+    #[must_use]
+    pub fn new(id: EntityId, handler: H) -> Self {
+        Self {
+            id,
+            handler,
+            state: SimulationState::default(),
+            simulation_time: ClockTime::default(),
+            scheduled: vec![],
+            handled: VecDeque::with_capacity(HANDLED_REQUESTS_CAPACITY),
+        }
+    }
+
+    #[must_use]
+    pub fn id(&self) -> EntityId {
+        self.id
+    }
+
+    #[must_use]
+    pub fn state(&self) -> SimulationState {
+        self.state
+    }
+
+    /// The simulation time of the most recent Start/Resume request that took effect.
+    #[must_use]
+    pub fn simulation_time(&self) -> ClockTime {
+        self.simulation_time
+    }
+
+    #[must_use]
+    pub fn handler(&self) -> &H {
+        &self.handler
+    }
+
+    pub fn handler_mut(&mut self) -> &mut H {
+        &mut self.handler
+    }
+
+    /// The real-world time of the next scheduled state transition, if any.
+    #[must_use]
+    pub fn next_scheduled(&self) -> Option<SystemTime> {
+        self.scheduled.iter().map(|(time, _)| *time).min()
+    }
+
+    /// Processes a received PDU body, returning the Acknowledge PDU bodies to send.
+    /// Scheduled transitions that are due at `now` are applied.
+    pub fn process(&mut self, body: &PduBody, now: SystemTime) -> Vec<PduBody> {
+        let acknowledgements = match body {
+            PduBody::CreateEntity(request) if is_addressed_to(&request.receiving_id, &self.id) => {
+                self.handle(
+                    request.originating_id,
+                    request.request_id,
+                    AcknowledgeFlag::CreateEntity,
+                    |simulation| simulation.handler.create_entity(request),
+                )
+            }
+            PduBody::RemoveEntity(request) if is_addressed_to(&request.receiving_id, &self.id) => {
+                self.handle(
+                    request.originating_id,
+                    request.request_id,
+                    AcknowledgeFlag::RemoveEntity,
+                    |simulation| simulation.handler.remove_entity(request),
+                )
+            }
+            PduBody::StartResume(request) if is_addressed_to(&request.receiving_id, &self.id) => {
+                self.handle(
+                    request.originating_id,
+                    request.request_id,
+                    AcknowledgeFlag::StartResume,
+                    |simulation| {
+                        simulation.schedule(
+                            &request.real_world_time,
+                            ScheduledTransition::StartResume(request.clone()),
+                            now,
+                        );
+                        ResponseFlag::AbleToComply
+                    },
+                )
+            }
+            PduBody::StopFreeze(request) if is_addressed_to(&request.receiving_id, &self.id) => {
+                self.handle(
+                    request.originating_id,
+                    request.request_id,
+                    AcknowledgeFlag::StopFreeze,
+                    |simulation| {
+                        simulation.schedule(
+                            &request.real_world_time,
+                            ScheduledTransition::StopFreeze(request.clone()),
+                            now,
+                        );
+                        ResponseFlag::AbleToComply
+                    },
+                )
+            }
+            _ => vec![],
+        };
+
+        self.poll(now);
+        acknowledgements
+    }
+
+    /// Applies the scheduled state transitions that are due at `now`, in order of their real-world time.
+    pub fn poll(&mut self, now: SystemTime) {
+        self.scheduled.sort_by_key(|(time, _)| *time);
+        let due = self
+            .scheduled
+            .iter()
+            .take_while(|(time, _)| *time <= now)
+            .count();
+
+        for (_, transition) in self.scheduled.drain(..due).collect::<Vec<_>>() {
+            match transition {
+                ScheduledTransition::StartResume(request) => {
+                    self.state = SimulationState::Running;
+                    self.simulation_time = request.simulation_time;
+                    self.handler.start_resume(&request);
+                }
+                ScheduledTransition::StopFreeze(request) => {
+                    self.state = if request.reason == StopFreezeReason::Termination {
+                        SimulationState::Stopped
+                    } else {
+                        SimulationState::Frozen
+                    };
+                    self.handler.stop_freeze(&request);
+                }
+            }
+        }
+    }
+
+    fn handle(
+        &mut self,
+        originating_id: EntityId,
+        request_id: u32,
+        acknowledge_flag: AcknowledgeFlag,
+        action: impl FnOnce(&mut Self) -> ResponseFlag,
+    ) -> Vec<PduBody> {
+        let previous = self.handled.iter().find(|handled| {
+            handled.originating_id == originating_id
+                && handled.request_id == request_id
+                && handled.acknowledge_flag == acknowledge_flag
+        });
+        let response_flag = if let Some(previous) = previous {
+            previous.response_flag
+        } else {
+            let response_flag = action(self);
+            if self.handled.len() == HANDLED_REQUESTS_CAPACITY {
+                self.handled.pop_front();
+            }
+            self.handled.push_back(HandledRequest {
+                originating_id,
+                request_id,
+                acknowledge_flag,
+                response_flag,
+            });
+            response_flag
+        };
+
+        vec![Acknowledge::builder()
+            .with_origination_id(self.id)
+            .with_receiving_id(originating_id)
+            .with_acknowledge_flag(acknowledge_flag)
+            .with_response_flag(response_flag)
+            .with_request_id(request_id)
+            .build()
+            .into_pdu_body()]
+    }
+
+    fn schedule(
+        &mut self,
+        real_world_time: &ClockTime,
+        transition: ScheduledTransition,
+        now: SystemTime,
+    ) {
+        let at = if *real_world_time == ClockTime::default() {
+            now
+        } else {
+            UNIX_EPOCH + real_world_time.to_duration()
+        };
+        self.scheduled.push((at, transition));
+    }
+}
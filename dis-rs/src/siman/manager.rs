@@ -0,0 +1,414 @@
+use crate::common::action_request::model::ActionRequest;
+use crate::common::action_response::model::ActionResponse;
+use crate::common::create_entity::model::CreateEntity;
+use crate::common::data::model::Data;
+use crate::common::data_query::model::DataQuery;
+use crate::common::model::{ClockTime, EntityId, FixedDatum, PduBody, VariableDatum};
+use crate::common::remove_entity::model::RemoveEntity;
+use crate::common::set_data::model::SetData;
+use crate::common::start_resume::model::StartResume;
+use crate::common::stop_freeze::model::StopFreeze;
+use crate::enumerations::{
+    AcknowledgeFlag, ActionId, ResponseFlag, StopFreezeFrozenBehavior, StopFreezeReason,
+    VariableRecordType,
+};
+use std::collections::HashMap;
+use std::time::{Duration, SystemTime};
+
+/// Default time to wait for the response to a request
+pub const DEFAULT_REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// The kinds of Simulation Management requests issued by a `SimulationManager`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SimanRequestKind {
+    CreateEntity,
+    RemoveEntity,
+    StartResume,
+    StopFreeze,
+    ActionRequest,
+    DataQuery,
+    SetData,
+}
+
+impl SimanRequestKind {
+    /// The `AcknowledgeFlag` of the Acknowledge PDU answering the request,
+    /// or `None` when the request is not answered by an Acknowledge PDU.
+    #[must_use]
+    pub fn acknowledge_flag(&self) -> Option<AcknowledgeFlag> {
+        match self {
+            SimanRequestKind::CreateEntity => Some(AcknowledgeFlag::CreateEntity),
+            SimanRequestKind::RemoveEntity => Some(AcknowledgeFlag::RemoveEntity),
+            SimanRequestKind::StartResume => Some(AcknowledgeFlag::StartResume),
+            SimanRequestKind::StopFreeze => Some(AcknowledgeFlag::StopFreeze),
+            SimanRequestKind::ActionRequest
+            | SimanRequestKind::DataQuery
+            | SimanRequestKind::SetData => None,
+        }
+    }
+}
+
+/// A request issued by a `SimulationManager` that is awaiting its response.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PendingRequest {
+    pub request_id: u32,
+    pub kind: SimanRequestKind,
+    pub receiving_id: EntityId,
+    pub sent_at: SystemTime,
+    /// The issued request PDU body
+    pub request: PduBody,
+}
+
+/// The way a request issued by a `SimulationManager` concluded.
+#[derive(Clone, Debug, PartialEq)]
+pub enum SimanOutcome {
+    /// The request was answered by an Acknowledge PDU
+    Acknowledged(ResponseFlag),
+    /// The Action Request was answered by an Action Response PDU
+    ActionResponse(ActionResponse),
+    /// The Data Query or Set Data request was answered by a Data PDU
+    Data(Data),
+    /// No response was received within the timeout
+    TimedOut,
+}
+
+/// A concluded request issued by a `SimulationManager`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SimanResult {
+    pub request_id: u32,
+    pub kind: SimanRequestKind,
+    pub receiving_id: EntityId,
+    pub outcome: SimanOutcome,
+}
+
+/// The Simulation Manager role of Simulation Management (SIMAN).
+///
+/// The manager creates the request PDU bodies, assigning request IDs, and tracks the outstanding requests
+/// until they are answered or time out. The caller is responsible for sending the created PDUs, for feeding
+/// received PDUs to `SimulationManager::process`, and for regularly calling `SimulationManager::poll_timeouts`.
+/// A request concludes with the first matching response, also for requests addressed to multiple simulations.
+#[derive(Clone, Debug)]
+pub struct SimulationManager {
+    id: EntityId,
+    timeout: Duration,
+    next_request_id: u32,
+    pending: HashMap<u32, PendingRequest>,
+}
+
+impl SimulationManager {
+    /// Creates a `SimulationManager` that issues requests with the provided originating id.
+    #[must_use]
+    pub fn new(id: EntityId) -> Self {
+        Self {
+            id,
+            timeout: DEFAULT_REQUEST_TIMEOUT,
+            next_request_id: 1,
+            pending: HashMap::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    #[must_use]
+    pub fn with_first_request_id(mut self, request_id: u32) -> Self {
+        self.next_request_id = request_id;
+        self
+    }
+
+    #[must_use]
+    pub fn id(&self) -> EntityId {
+        self.id
+    }
+
+    #[must_use]
+    pub fn timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    /// The requests that are awaiting a response.
+    pub fn pending_requests(&self) -> impl Iterator<Item = &PendingRequest> {
+        self.pending.values()
+    }
+
+    #[must_use]
+    pub fn pending_request(&self, request_id: u32) -> Option<&PendingRequest> {
+        self.pending.get(&request_id)
+    }
+
+    /// Creates a Create Entity request.
+    pub fn create_entity(&mut self, receiving_id: EntityId, now: SystemTime) -> CreateEntity {
+        let request_id = self.next_request_id();
+        let body = CreateEntity::builder()
+            .with_origination_id(self.id)
+            .with_receiving_id(receiving_id)
+            .with_request_id(request_id)
+            .build();
+        self.track(
+            request_id,
+            SimanRequestKind::CreateEntity,
+            receiving_id,
+            now,
+            body.clone().into_pdu_body(),
+        );
+        body
+    }
+
+    /// Creates a Remove Entity request.
+    pub fn remove_entity(&mut self, receiving_id: EntityId, now: SystemTime) -> RemoveEntity {
+        let request_id = self.next_request_id();
+        let body = RemoveEntity::builder()
+            .with_origination_id(self.id)
+            .with_receiving_id(receiving_id)
+            .with_request_id(request_id)
+            .build();
+        self.track(
+            request_id,
+            SimanRequestKind::RemoveEntity,
+            receiving_id,
+            now,
+            body.clone().into_pdu_body(),
+        );
+        body
+    }
+
+    /// Creates a Start/Resume request, to take effect at `real_world_time` (a zero `ClockTime` means immediately).
+    pub fn start_resume(
+        &mut self,
+        receiving_id: EntityId,
+        real_world_time: ClockTime,
+        simulation_time: ClockTime,
+        now: SystemTime,
+    ) -> StartResume {
+        let request_id = self.next_request_id();
+        let body = StartResume::builder()
+            .with_origination_id(self.id)
+            .with_receiving_id(receiving_id)
+            .with_real_world_time(real_world_time)
+            .with_simulation_time(simulation_time)
+            .with_request_id(request_id)
+            .build();
+        self.track(
+            request_id,
+            SimanRequestKind::StartResume,
+            receiving_id,
+            now,
+            body.clone().into_pdu_body(),
+        );
+        body
+    }
+
+    /// Creates a Stop/Freeze request, to take effect at `real_world_time` (a zero `ClockTime` means immediately).
+    pub fn stop_freeze(
+        &mut self,
+        receiving_id: EntityId,
+        real_world_time: ClockTime,
+        reason: StopFreezeReason,
+        frozen_behavior: StopFreezeFrozenBehavior,
+        now: SystemTime,
+    ) -> StopFreeze {
+        let request_id = self.next_request_id();
+        let body = StopFreeze::builder()
+            .with_origination_id(self.id)
+            .with_receiving_id(receiving_id)
+            .with_real_world_time(real_world_time)
+            .with_reason(reason)
+            .with_frozen_behavior(frozen_behavior)
+            .with_request_id(request_id)
+            .build();
+        self.track(
+            request_id,
+            SimanRequestKind::StopFreeze,
+            receiving_id,
+            now,
+            body.clone().into_pdu_body(),
+        );
+        body
+    }
+
+    /// Creates an Action Request.
+    pub fn action_request(
+        &mut self,
+        receiving_id: EntityId,
+        action_id: ActionId,
+        fixed_datum_records: Vec<FixedDatum>,
+        variable_datum_records: Vec<VariableDatum>,
+        now: SystemTime,
+    ) -> ActionRequest {
+        let request_id = self.next_request_id();
+        let body = ActionRequest::builder()
+            .with_origination_id(self.id)
+            .with_receiving_id(receiving_id)
+            .with_request_id(request_id)
+            .with_action_id(action_id)
+            .with_fixed_datums(fixed_datum_records)
+            .with_variable_datums(variable_datum_records)
+            .build();
+        self.track(
+            request_id,
+            SimanRequestKind::ActionRequest,
+            receiving_id,
+            now,
+            body.clone().into_pdu_body(),
+        );
+        body
+    }
+
+    /// Creates a Data Query request.
+    pub fn data_query(
+        &mut self,
+        receiving_id: EntityId,
+        time_interval: u32,
+        fixed_datum_records: Vec<VariableRecordType>,
+        variable_datum_records: Vec<VariableRecordType>,
+        now: SystemTime,
+    ) -> DataQuery {
+        let request_id = self.next_request_id();
+        let body = DataQuery::builder()
+            .with_origination_id(self.id)
+            .with_receiving_id(receiving_id)
+            .with_request_id(request_id)
+            .with_time_interval(time_interval)
+            .with_fixed_datums(fixed_datum_records)
+            .with_variable_datums(variable_datum_records)
+            .build();
+        self.track(
+            request_id,
+            SimanRequestKind::DataQuery,
+            receiving_id,
+            now,
+            body.clone().into_pdu_body(),
+        );
+        body
+    }
+
+    /// Creates a Set Data request.
+    pub fn set_data(
+        &mut self,
+        receiving_id: EntityId,
+        fixed_datum_records: Vec<FixedDatum>,
+        variable_datum_records: Vec<VariableDatum>,
+        now: SystemTime,
+    ) -> SetData {
+        let request_id = self.next_request_id();
+        let body = SetData::builder()
+            .with_origination_id(self.id)
+            .with_receiving_id(receiving_id)
+            .with_request_id(request_id)
+            .with_fixed_datums(fixed_datum_records)
+            .with_variable_datums(variable_datum_records)
+            .build();
+        self.track(
+            request_id,
+            SimanRequestKind::SetData,
+            receiving_id,
+            now,
+            body.clone().into_pdu_body(),
+        );
+        body
+    }
+
+    /// Processes a received PDU body. Returns the concluded request when the body is the response to a pending request.
+    pub fn process(&mut self, body: &PduBody) -> Option<SimanResult> {
+        let (request_id, outcome) = match body {
+            PduBody::Acknowledge(ack) if ack.receiving_id == self.id => {
+                let pending = self.pending.get(&ack.request_id)?;
+                if pending.kind.acknowledge_flag() != Some(ack.acknowledge_flag) {
+                    return None;
+                }
+                (
+                    ack.request_id,
+                    SimanOutcome::Acknowledged(ack.response_flag),
+                )
+            }
+            PduBody::ActionResponse(response) if response.receiving_id == self.id => {
+                let pending = self.pending.get(&response.request_id)?;
+                if pending.kind != SimanRequestKind::ActionRequest {
+                    return None;
+                }
+                (
+                    response.request_id,
+                    SimanOutcome::ActionResponse(response.clone()),
+                )
+            }
+            PduBody::Data(data) if data.receiving_id == self.id => {
+                let pending = self.pending.get(&data.request_id)?;
+                if !matches!(
+                    pending.kind,
+                    SimanRequestKind::DataQuery | SimanRequestKind::SetData
+                ) {
+                    return None;
+                }
+                (data.request_id, SimanOutcome::Data(data.clone()))
+            }
+            _ => return None,
+        };
+
+        self.pending.remove(&request_id).map(|pending| SimanResult {
+            request_id,
+            kind: pending.kind,
+            receiving_id: pending.receiving_id,
+            outcome,
+        })
+    }
+
+    /// Concludes the pending requests that have not been answered within the timeout.
+    pub fn poll_timeouts(&mut self, now: SystemTime) -> Vec<SimanResult> {
+        let timeout = self.timeout;
+        let expired: Vec<u32> = self
+            .pending
+            .values()
+            .filter(|pending| {
+                now.duration_since(pending.sent_at)
+                    .is_ok_and(|elapsed| elapsed >= timeout)
+            })
+            .map(|pending| pending.request_id)
+            .collect();
+
+        let mut results: Vec<SimanResult> = expired
+            .into_iter()
+            .filter_map(|request_id| self.pending.remove(&request_id))
+            .map(|pending| SimanResult {
+                request_id: pending.request_id,
+                kind: pending.kind,
+                receiving_id: pending.receiving_id,
+                outcome: SimanOutcome::TimedOut,
+            })
+            .collect();
+        results.sort_by_key(|result| result.request_id);
+        results
+    }
+
+    /// Stops tracking a pending request, returning it when it was pending.
+    pub fn cancel(&mut self, request_id: u32) -> Option<PendingRequest> {
+        self.pending.remove(&request_id)
+    }
+
+    fn next_request_id(&mut self) -> u32 {
+        let request_id = self.next_request_id;
+        self.next_request_id = self.next_request_id.wrapping_add(1);
+        request_id
+    }
+
+    fn track(
+        &mut self,
+        request_id: u32,
+        kind: SimanRequestKind,
+        receiving_id: EntityId,
+        now: SystemTime,
+        request: PduBody,
+    ) {
+        self.pending.insert(
+            request_id,
+            PendingRequest {
+                request_id,
+                kind,
+                receiving_id,
+                sent_at: now,
+                request,
+            },
+        );
+    }
+}
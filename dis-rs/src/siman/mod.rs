@@ -0,0 +1,268 @@
+pub mod managed;
+pub mod manager;
+pub mod reliability;
+
+use crate::common::model::EntityId;
+use crate::fixed_parameters::{ALL_APPLIC, ALL_ENTITIES, ALL_SITES, NO_ENTITY};
+
+/// Checks whether a request with `receiving_id` is addressed to the simulation `simulation_id`,
+/// taking the `ALL_SITES`, `ALL_APPLIC` and `ALL_ENTITIES` wildcards into account.
+/// Requests for individual entities of the simulation are addressed to the simulation.
+#[must_use]
+pub fn is_addressed_to(receiving_id: &EntityId, simulation_id: &EntityId) -> bool {
+    let site = receiving_id.simulation_address.site_id;
+    let application = receiving_id.simulation_address.application_id;
+
+    (site == ALL_SITES as u16 || site == simulation_id.simulation_address.site_id)
+        && (application == ALL_APPLIC as u16
+            || application == simulation_id.simulation_address.application_id)
+        && (receiving_id.entity_id == ALL_ENTITIES as u16
+            || simulation_id.entity_id == receiving_id.entity_id
+            || simulation_id.entity_id == NO_ENTITY)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::acknowledge_r::model::AcknowledgeR;
+    use crate::create_entity_r::model::CreateEntityR;
+    use crate::enumerations::{
+        AcknowledgeFlag, RequiredReliabilityService, ResponseFlag, StopFreezeFrozenBehavior,
+        StopFreezeReason,
+    };
+    use crate::model::{ClockTime, EntityId, PduBody};
+    use crate::siman::is_addressed_to;
+    use crate::siman::managed::{ManagedSimulation, SimanHandler, SimulationState};
+    use crate::siman::manager::{SimanOutcome, SimanRequestKind, SimulationManager};
+    use crate::siman::reliability::{ReliabilityService, ReliableEvent, ReliableOutcome};
+    use crate::start_resume::model::StartResume;
+    use crate::stop_freeze::model::StopFreeze;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    #[derive(Default)]
+    struct Recorder {
+        started: usize,
+        stopped: usize,
+    }
+
+    impl SimanHandler for Recorder {
+        fn start_resume(&mut self, _request: &StartResume) {
+            self.started += 1;
+        }
+
+        fn stop_freeze(&mut self, _request: &StopFreeze) {
+            self.stopped += 1;
+        }
+    }
+
+    fn now() -> SystemTime {
+        UNIX_EPOCH + Duration::from_secs(1_700_000_000)
+    }
+
+    #[test]
+    fn addressing() {
+        let simulation = EntityId::new(1, 2, 0);
+        assert!(is_addressed_to(&EntityId::new(1, 2, 0), &simulation));
+        assert!(is_addressed_to(&EntityId::new(1, 2, 15), &simulation));
+        assert!(is_addressed_to(
+            &EntityId::new(0xFFFF, 0xFFFF, 0xFFFF),
+            &simulation
+        ));
+        assert!(!is_addressed_to(&EntityId::new(1, 3, 0), &simulation));
+        assert!(!is_addressed_to(
+            &EntityId::new(1, 2, 15),
+            &EntityId::new(1, 2, 16)
+        ));
+    }
+
+    #[test]
+    fn start_resume_is_acknowledged_and_applied() {
+        let mut manager = SimulationManager::new(EntityId::new(1, 1, 0));
+        let mut simulation = ManagedSimulation::new(EntityId::new(1, 2, 0), Recorder::default());
+
+        let request = manager.start_resume(
+            EntityId::new(1, 2, 0),
+            ClockTime::default(),
+            ClockTime::new(0, 1),
+            now(),
+        );
+        assert_eq!(manager.pending_requests().count(), 1);
+
+        let acks = simulation.process(&request.clone().into_pdu_body(), now());
+        assert_eq!(acks.len(), 1);
+        assert_eq!(simulation.state(), SimulationState::Running);
+        assert_eq!(simulation.handler().started, 1);
+
+        let result = manager.process(&acks[0]).unwrap();
+        assert_eq!(result.request_id, request.request_id);
+        assert_eq!(result.kind, SimanRequestKind::StartResume);
+        assert_eq!(
+            result.outcome,
+            SimanOutcome::Acknowledged(ResponseFlag::AbleToComply)
+        );
+        assert_eq!(manager.pending_requests().count(), 0);
+
+        let acks = simulation.process(&request.into_pdu_body(), now());
+        assert_eq!(acks.len(), 1);
+        assert_eq!(simulation.handler().started, 1);
+        assert!(manager.process(&acks[0]).is_none());
+    }
+
+    #[test]
+    fn stop_freeze_is_scheduled() {
+        let mut manager = SimulationManager::new(EntityId::new(1, 1, 0));
+        let mut simulation = ManagedSimulation::new(EntityId::new(1, 2, 0), Recorder::default());
+        let at = now() + Duration::from_secs(10);
+
+        let request = manager.stop_freeze(
+            EntityId::new(0xFFFF, 0xFFFF, 0xFFFF),
+            ClockTime::from_duration(at.duration_since(UNIX_EPOCH).unwrap()),
+            StopFreezeReason::Termination,
+            StopFreezeFrozenBehavior::default(),
+            now(),
+        );
+        let acks = simulation.process(&request.into_pdu_body(), now());
+        assert_eq!(acks.len(), 1);
+        assert!(simulation.next_scheduled().is_some());
+        assert_eq!(simulation.handler().stopped, 0);
+
+        simulation.poll(at + Duration::from_millis(1));
+        assert_eq!(simulation.handler().stopped, 1);
+        assert_eq!(simulation.state(), SimulationState::Stopped);
+        assert!(simulation.next_scheduled().is_none());
+    }
+
+    #[test]
+    fn unanswered_requests_time_out() {
+        let mut manager =
+            SimulationManager::new(EntityId::new(1, 1, 0)).with_timeout(Duration::from_secs(2));
+        let first = manager.create_entity(EntityId::new(1, 2, 0), now());
+        let second = manager.remove_entity(EntityId::new(1, 2, 3), now() + Duration::from_secs(1));
+        assert_ne!(first.request_id, second.request_id);
+
+        assert!(manager
+            .poll_timeouts(now() + Duration::from_secs(1))
+            .is_empty());
+        let results = manager.poll_timeouts(now() + Duration::from_secs(2));
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].request_id, first.request_id);
+        assert_eq!(results[0].outcome, SimanOutcome::TimedOut);
+
+        let unrelated = PduBody::Other(crate::other::model::Other::default());
+        assert!(manager.process(&unrelated).is_none());
+        assert_eq!(manager.pending_requests().count(), 1);
+    }
+
+    fn create_entity_r(reliability: RequiredReliabilityService) -> PduBody {
+        CreateEntityR::builder()
+            .with_receiving_id(EntityId::new(1, 2, 0))
+            .with_required_reliability_service(reliability)
+            .build()
+            .into_pdu_body()
+    }
+
+    #[test]
+    fn reliable_request_is_retransmitted_until_acknowledged() {
+        let mut manager = ReliabilityService::new(EntityId::new(1, 1, 0))
+            .with_retransmit_interval(Duration::from_secs(1));
+        let mut simulation = ReliabilityService::new(EntityId::new(1, 2, 0));
+        let concluded = Arc::new(AtomicBool::new(false));
+        let flag = concluded.clone();
+
+        let request_id = manager
+            .send_with_callback(
+                create_entity_r(RequiredReliabilityService::Acknowledged),
+                now(),
+                move |result| {
+                    assert!(matches!(result.outcome, ReliableOutcome::Replied(_)));
+                    flag.store(true, Ordering::SeqCst);
+                },
+            )
+            .unwrap();
+
+        // the in-memory transport delivers the request, but loses the reply
+        let outgoing = manager.take_outgoing();
+        assert_eq!(outgoing.len(), 1);
+        let Some(ReliableEvent::Request(PduBody::CreateEntityR(request))) =
+            simulation.process(&outgoing[0])
+        else {
+            panic!("Expected a Create Entity-R request");
+        };
+        assert_eq!(request.request_id, request_id);
+        assert_eq!(request.originating_id, EntityId::new(1, 1, 0));
+        simulation.reply(
+            AcknowledgeR::builder()
+                .with_origination_id(simulation.id())
+                .with_receiving_id(request.originating_id)
+                .with_acknowledge_flag(AcknowledgeFlag::CreateEntity)
+                .with_response_flag(ResponseFlag::AbleToComply)
+                .with_request_id(request.request_id)
+                .build()
+                .into_pdu_body(),
+        );
+        assert_eq!(simulation.take_outgoing().len(), 1);
+
+        assert!(manager.poll(now() + Duration::from_millis(500)).is_empty());
+        assert!(manager.take_outgoing().is_empty());
+        assert!(manager.poll(now() + Duration::from_secs(1)).is_empty());
+        let retransmitted = manager.take_outgoing();
+        assert_eq!(retransmitted, outgoing);
+
+        // the retransmission is answered with the original reply, without a new request event
+        assert!(simulation.process(&retransmitted[0]).is_none());
+        let replies = simulation.take_outgoing();
+        assert_eq!(replies.len(), 1);
+
+        let Some(ReliableEvent::Concluded(result)) = manager.process(&replies[0]) else {
+            panic!("Expected the request to conclude");
+        };
+        assert_eq!(result.request_id, request_id);
+        assert!(concluded.load(Ordering::SeqCst));
+        assert_eq!(manager.pending_count(), 0);
+        assert!(manager.process(&replies[0]).is_none());
+    }
+
+    #[test]
+    fn reliable_request_fails_after_max_retries() {
+        let mut manager = ReliabilityService::new(EntityId::new(1, 1, 0))
+            .with_retransmit_interval(Duration::from_secs(1))
+            .with_max_retries(2);
+
+        let request_id = manager
+            .send(
+                create_entity_r(RequiredReliabilityService::Acknowledged),
+                now(),
+            )
+            .unwrap();
+        for seconds in 1..=2 {
+            assert!(manager
+                .poll(now() + Duration::from_secs(seconds))
+                .is_empty());
+        }
+        assert_eq!(manager.take_outgoing().len(), 3);
+
+        let results = manager.poll(now() + Duration::from_secs(3));
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].request_id, request_id);
+        assert_eq!(results[0].outcome, ReliableOutcome::Failed);
+        assert!(manager.take_outgoing().is_empty());
+    }
+
+    #[test]
+    fn unacknowledged_requests_are_not_tracked() {
+        let mut manager = ReliabilityService::new(EntityId::new(1, 1, 0));
+
+        manager
+            .send(
+                create_entity_r(RequiredReliabilityService::Unacknowledged),
+                now(),
+            )
+            .unwrap();
+        assert_eq!(manager.take_outgoing().len(), 1);
+        assert_eq!(manager.pending_count(), 0);
+
+        let not_a_request = PduBody::Other(crate::other::model::Other::default());
+        assert!(manager.send(not_a_request, now()).is_err());
+    }
+}
@@ -0,0 +1,448 @@
+use crate::common::model::{EntityId, PduBody};
+use crate::common::BodyInfo;
+use crate::enumerations::{AcknowledgeFlag, RequiredReliabilityService};
+use crate::siman::is_addressed_to;
+use crate::DisError;
+use std::collections::{HashMap, VecDeque};
+use std::time::{Duration, SystemTime};
+
+/// Default interval between retransmissions of an unanswered request
+pub const DEFAULT_RETRANSMIT_INTERVAL: Duration = Duration::from_secs(1);
+/// Default number of retransmissions of an unanswered request before it fails
+pub const DEFAULT_MAX_RETRIES: u32 = 3;
+/// Number of received requests remembered to detect retransmitted requests
+const RECEIVED_REQUESTS_CAPACITY: usize = 256;
+
+/// The way a reliable request concluded.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ReliableOutcome {
+    /// The request was answered with the reply PDU body (`AcknowledgeR`, `ActionResponseR`, `DataR` or `RecordR`)
+    Replied(PduBody),
+    /// The request was not answered after the maximum number of retransmissions
+    Failed,
+}
+
+/// A concluded reliable request.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ReliableResult {
+    pub request_id: u32,
+    pub receiving_id: EntityId,
+    pub outcome: ReliableOutcome,
+}
+
+/// The events resulting from processing a received PDU body.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ReliableEvent {
+    /// A request sent by this service concluded
+    Concluded(ReliableResult),
+    /// A new request was received, which the application answers via `ReliabilityService::reply`
+    Request(PduBody),
+}
+
+/// Callback invoked when a reliable request concludes.
+pub type ReliableCallback = Box<dyn FnOnce(&ReliableResult) + Send>;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum ExpectedReply {
+    Acknowledge(AcknowledgeFlag),
+    ActionResponse,
+    Data,
+    Record,
+}
+
+struct PendingReliableRequest {
+    request: PduBody,
+    receiving_id: EntityId,
+    expected_reply: ExpectedReply,
+    last_sent: SystemTime,
+    retries: u32,
+    callback: Option<ReliableCallback>,
+}
+
+struct ReceivedRequest {
+    originating_id: EntityId,
+    request_id: u32,
+    reply: Option<PduBody>,
+}
+
+/// Reliability layer for the reliable Simulation Management (SIMAN-R) PDUs.
+///
+/// Outgoing requests with the `Acknowledged` reliability service are retransmitted at the agreed interval until
+/// they are answered, or fail after the maximum number of retries. Replies (`AcknowledgeR`, `ActionResponseR`,
+/// `DataR` and `RecordR`) are correlated with the requests by request ID.
+/// Incoming requests are de-duplicated: a retransmitted request is answered again with the reply to the original
+/// request, without being presented to the application a second time.
+///
+/// The service does not perform I/O. PDU bodies to send are collected by `ReliabilityService::take_outgoing`,
+/// received bodies are fed to `ReliabilityService::process`, and `ReliabilityService::poll` drives retransmissions.
+pub struct ReliabilityService {
+    id: EntityId,
+    retransmit_interval: Duration,
+    max_retries: u32,
+    next_request_id: u32,
+    outgoing: Vec<PduBody>,
+    pending: HashMap<u32, PendingReliableRequest>,
+    received: VecDeque<ReceivedRequest>,
+}
+
+impl ReliabilityService {
+    /// Creates a service sending requests with the provided originating id.
+    #[must_use]
+    pub fn new(id: EntityId) -> Self {
+        Self {
+            id,
+            retransmit_interval: DEFAULT_RETRANSMIT_INTERVAL,
+            max_retries: DEFAULT_MAX_RETRIES,
+            next_request_id: 1,
+            outgoing: vec![],
+            pending: HashMap::new(),
+            received: VecDeque::with_capacity(RECEIVED_REQUESTS_CAPACITY),
+        }
+    }
+
+    #[must_use]
+    pub fn with_retransmit_interval(mut self, retransmit_interval: Duration) -> Self {
+        self.retransmit_interval = retransmit_interval;
+        self
+    }
+
+    #[must_use]
+    pub fn with_max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    #[must_use]
+    pub fn with_first_request_id(mut self, request_id: u32) -> Self {
+        self.next_request_id = request_id;
+        self
+    }
+
+    #[must_use]
+    pub fn id(&self) -> EntityId {
+        self.id
+    }
+
+    /// The number of requests awaiting a reply.
+    #[must_use]
+    pub fn pending_count(&self) -> usize {
+        self.pending.len()
+    }
+
+    /// Takes the PDU bodies to be sent.
+    pub fn take_outgoing(&mut self) -> Vec<PduBody> {
+        std::mem::take(&mut self.outgoing)
+    }
+
+    /// Queues a reliable request for sending. The originating id and request id of the request are assigned by the service.
+    /// Returns the assigned request id.
+    ///
+    /// Requests with the `Unacknowledged` reliability service are sent once and not tracked.
+    ///
+    /// # Errors
+    /// Returns `DisError::NotAReliableRequest` when the body is not a SIMAN-R request.
+    pub fn send(&mut self, request: PduBody, now: SystemTime) -> Result<u32, DisError> {
+        self.send_request(request, now, None)
+    }
+
+    /// Queues a reliable request for sending, like `ReliabilityService::send`,
+    /// invoking `callback` when the request concludes.
+    ///
+    /// # Errors
+    /// Returns `DisError::NotAReliableRequest` when the body is not a SIMAN-R request.
+    pub fn send_with_callback(
+        &mut self,
+        request: PduBody,
+        now: SystemTime,
+        callback: impl FnOnce(&ReliableResult) + Send + 'static,
+    ) -> Result<u32, DisError> {
+        self.send_request(request, now, Some(Box::new(callback)))
+    }
+
+    /// Queues the reply to a received request. The reply is remembered to answer retransmissions of the request.
+    pub fn reply(&mut self, reply: PduBody) {
+        if let Some((receiving_id, request_id, _)) = reply_key(&reply) {
+            if let Some(received) = self.received.iter_mut().find(|received| {
+                received.originating_id == receiving_id && received.request_id == request_id
+            }) {
+                received.reply = Some(reply.clone());
+            }
+        }
+        self.outgoing.push(reply);
+    }
+
+    /// Processes a received PDU body.
+    pub fn process(&mut self, body: &PduBody) -> Option<ReliableEvent> {
+        if let Some((receiving_id, request_id, reply)) = reply_key(body) {
+            if receiving_id != self.id {
+                return None;
+            }
+            let pending = self.pending.get(&request_id)?;
+            if pending.expected_reply != reply {
+                return None;
+            }
+            let result = self.conclude(request_id, ReliableOutcome::Replied(body.clone()))?;
+            return Some(ReliableEvent::Concluded(result));
+        }
+
+        let (originating_id, receiving_id, request_id) = incoming_request(body)?;
+        if !is_addressed_to(&receiving_id, &self.id) {
+            return None;
+        }
+        if let Some(received) = self.received.iter().find(|received| {
+            received.originating_id == originating_id && received.request_id == request_id
+        }) {
+            if let Some(reply) = &received.reply {
+                self.outgoing.push(reply.clone());
+            }
+            return None;
+        }
+
+        if self.received.len() == RECEIVED_REQUESTS_CAPACITY {
+            self.received.pop_front();
+        }
+        self.received.push_back(ReceivedRequest {
+            originating_id,
+            request_id,
+            reply: None,
+        });
+        Some(ReliableEvent::Request(body.clone()))
+    }
+
+    /// Retransmits the unanswered requests of which the retransmit interval has elapsed,
+    /// and returns the requests that failed after the maximum number of retries.
+    pub fn poll(&mut self, now: SystemTime) -> Vec<ReliableResult> {
+        let interval = self.retransmit_interval;
+        let mut failed = vec![];
+        for (request_id, pending) in &mut self.pending {
+            let elapsed = now
+                .duration_since(pending.last_sent)
+                .is_ok_and(|elapsed| elapsed >= interval);
+            if !elapsed {
+                continue;
+            }
+            if pending.retries < self.max_retries {
+                pending.retries += 1;
+                pending.last_sent = now;
+                self.outgoing.push(pending.request.clone());
+            } else {
+                failed.push(*request_id);
+            }
+        }
+
+        failed.sort_unstable();
+        failed
+            .into_iter()
+            .filter_map(|request_id| self.conclude(request_id, ReliableOutcome::Failed))
+            .collect()
+    }
+
+    fn send_request(
+        &mut self,
+        mut request: PduBody,
+        now: SystemTime,
+        callback: Option<ReliableCallback>,
+    ) -> Result<u32, DisError> {
+        let request_id = self.next_request_id;
+        let (receiving_id, reliability, expected_reply) =
+            assign_request(&mut request, self.id, request_id)
+                .ok_or_else(|| DisError::NotAReliableRequest(request.body_type().into()))?;
+        self.next_request_id = self.next_request_id.wrapping_add(1);
+
+        self.outgoing.push(request.clone());
+        if reliability == RequiredReliabilityService::Acknowledged {
+            self.pending.insert(
+                request_id,
+                PendingReliableRequest {
+                    request,
+                    receiving_id,
+                    expected_reply,
+                    last_sent: now,
+                    retries: 0,
+                    callback,
+                },
+            );
+        }
+        Ok(request_id)
+    }
+
+    fn conclude(&mut self, request_id: u32, outcome: ReliableOutcome) -> Option<ReliableResult> {
+        let pending = self.pending.remove(&request_id)?;
+        let result = ReliableResult {
+            request_id,
+            receiving_id: pending.receiving_id,
+            outcome,
+        };
+        if let Some(callback) = pending.callback {
+            callback(&result);
+        }
+        Some(result)
+    }
+}
+
+/// Assigns the originating id and request id of a SIMAN-R request, returning its receiving id,
+/// required reliability service, and the kind of reply that answers the request.
+fn assign_request(
+    body: &mut PduBody,
+    originating_id: EntityId,
+    request_id: u32,
+) -> Option<(EntityId, RequiredReliabilityService, ExpectedReply)> {
+    let assigned = match body {
+        PduBody::CreateEntityR(request) => {
+            request.originating_id = originating_id;
+            request.request_id = request_id;
+            (
+                request.receiving_id,
+                request.required_reliability_service,
+                ExpectedReply::Acknowledge(AcknowledgeFlag::CreateEntity),
+            )
+        }
+        PduBody::RemoveEntityR(request) => {
+            request.originating_id = originating_id;
+            request.request_id = request_id;
+            (
+                request.receiving_id,
+                request.required_reliability_service,
+                ExpectedReply::Acknowledge(AcknowledgeFlag::RemoveEntity),
+            )
+        }
+        PduBody::StartResumeR(request) => {
+            request.originating_id = originating_id;
+            request.request_id = request_id;
+            (
+                request.receiving_id,
+                request.required_reliability_service,
+                ExpectedReply::Acknowledge(AcknowledgeFlag::StartResume),
+            )
+        }
+        PduBody::StopFreezeR(request) => {
+            request.originating_id = originating_id;
+            request.request_id = request_id;
+            (
+                request.receiving_id,
+                request.required_reliability_service,
+                ExpectedReply::Acknowledge(AcknowledgeFlag::StopFreeze),
+            )
+        }
+        PduBody::ActionRequestR(request) => {
+            request.originating_id = originating_id;
+            request.request_id = request_id;
+            (
+                request.receiving_id,
+                request.required_reliability_service,
+                ExpectedReply::ActionResponse,
+            )
+        }
+        PduBody::DataQueryR(request) => {
+            request.originating_id = originating_id;
+            request.request_id = request_id;
+            (
+                request.receiving_id,
+                request.required_reliability_service,
+                ExpectedReply::Data,
+            )
+        }
+        PduBody::SetDataR(request) => {
+            request.originating_id = originating_id;
+            request.request_id = request_id;
+            (
+                request.receiving_id,
+                request.required_reliability_service,
+                ExpectedReply::Data,
+            )
+        }
+        PduBody::RecordQueryR(request) => {
+            request.originating_id = originating_id;
+            request.request_id = request_id;
+            (
+                request.receiving_id,
+                request.required_reliability_service,
+                ExpectedReply::Record,
+            )
+        }
+        PduBody::SetRecordR(request) => {
+            request.originating_id = originating_id;
+            request.request_id = request_id;
+            (
+                request.receiving_id,
+                request.required_reliability_service,
+                ExpectedReply::Record,
+            )
+        }
+        _ => return None,
+    };
+    Some(assigned)
+}
+
+/// Returns the originating id, receiving id and request id of a received SIMAN-R request.
+fn incoming_request(body: &PduBody) -> Option<(EntityId, EntityId, u32)> {
+    match body {
+        PduBody::CreateEntityR(request) => Some((
+            request.originating_id,
+            request.receiving_id,
+            request.request_id,
+        )),
+        PduBody::RemoveEntityR(request) => Some((
+            request.originating_id,
+            request.receiving_id,
+            request.request_id,
+        )),
+        PduBody::StartResumeR(request) => Some((
+            request.originating_id,
+            request.receiving_id,
+            request.request_id,
+        )),
+        PduBody::StopFreezeR(request) => Some((
+            request.originating_id,
+            request.receiving_id,
+            request.request_id,
+        )),
+        PduBody::ActionRequestR(request) => Some((
+            request.originating_id,
+            request.receiving_id,
+            request.request_id,
+        )),
+        PduBody::DataQueryR(request) => Some((
+            request.originating_id,
+            request.receiving_id,
+            request.request_id,
+        )),
+        PduBody::SetDataR(request) => Some((
+            request.originating_id,
+            request.receiving_id,
+            request.request_id,
+        )),
+        PduBody::RecordQueryR(request) => Some((
+            request.originating_id,
+            request.receiving_id,
+            request.request_id,
+        )),
+        PduBody::SetRecordR(request) => Some((
+            request.originating_id,
+            request.receiving_id,
+            request.request_id,
+        )),
+        _ => None,
+    }
+}
+
+/// Returns the receiving id, request id and kind of a SIMAN-R reply.
+fn reply_key(body: &PduBody) -> Option<(EntityId, u32, ExpectedReply)> {
+    match body {
+        PduBody::AcknowledgeR(reply) => Some((
+            reply.receiving_id,
+            reply.request_id,
+            ExpectedReply::Acknowledge(reply.acknowledge_flag),
+        )),
+        PduBody::ActionResponseR(reply) => Some((
+            reply.receiving_id,
+            reply.request_id,
+            ExpectedReply::ActionResponse,
+        )),
+        PduBody::DataR(reply) => Some((reply.receiving_id, reply.request_id, ExpectedReply::Data)),
+        PduBody::RecordR(reply) => {
+            Some((reply.receiving_id, reply.request_id, ExpectedReply::Record))
+        }
+        _ => None,
+    }
+}
@@ -0,0 +1,96 @@
+//! Property tests asserting that every generated PDU survives serializing and parsing unchanged,
+//! for every modelled body and every supported protocol version.
+//!
+//! Requires the `arbitrary` feature: `cargo test -p dis-rs --features arbitrary --test roundtrip`
+use arbitrary::Unstructured;
+use bytes::BytesMut;
+use dis_rs::generators::{arbitrary_pdu, GENERATED_PDU_TYPES};
+use dis_rs::model::Pdu;
+use dis_rs::supported_protocol_versions;
+
+/// Number of generated PDUs per PDU type and protocol version.
+const CASES: u64 = 64;
+/// Amount of raw random data available for generating a single PDU.
+const RAW_DATA_OCTETS: usize = 2048;
+/// PDUs in a bundle are aligned to 8-octet boundaries.
+const BUNDLE_ALIGNMENT: usize = 8;
+
+/// Deterministic xorshift generator, so failures can be reproduced from the reported seed.
+struct XorShift(u64);
+
+impl XorShift {
+    fn new(seed: u64) -> Self {
+        Self(seed.wrapping_mul(0x9E37_79B9_7F4A_7C15) | 1)
+    }
+
+    fn fill(&mut self, buf: &mut [u8]) {
+        for chunk in buf.chunks_mut(8) {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            chunk.copy_from_slice(&self.0.to_be_bytes()[..chunk.len()]);
+        }
+    }
+}
+
+fn serialize(pdu: &Pdu) -> BytesMut {
+    let mut buf = BytesMut::with_capacity(pdu.pdu_length() as usize);
+    let written = pdu
+        .serialize(&mut buf)
+        .expect("serializing a generated PDU");
+    assert_eq!(written, pdu.header.pdu_length, "written length of {pdu:?}");
+    assert_eq!(
+        buf.len(),
+        usize::from(pdu.header.pdu_length),
+        "buffer length of {pdu:?}"
+    );
+    buf
+}
+
+#[test]
+fn parse_of_serialized_pdu_is_identity() {
+    let mut raw = vec![0u8; RAW_DATA_OCTETS];
+    for protocol_version in supported_protocol_versions() {
+        for (type_index, pdu_type) in GENERATED_PDU_TYPES.iter().enumerate() {
+            for case in 0..CASES {
+                let seed = ((type_index as u64) << 32)
+                    | (u64::from(u8::from(protocol_version)) << 16)
+                    | case;
+                XorShift::new(seed).fill(&mut raw);
+                let mut u = Unstructured::new(&raw);
+                let pdu =
+                    arbitrary_pdu(&mut u, *pdu_type, protocol_version).expect("generating a PDU");
+
+                let buf = serialize(&pdu);
+                let parsed = dis_rs::parse(&buf)
+                    .unwrap_or_else(|err| panic!("seed {seed}: parsing {pdu:?} failed: {err}"));
+
+                assert_eq!(parsed, vec![pdu], "seed {seed}");
+            }
+        }
+    }
+}
+
+#[test]
+fn parse_of_serialized_bundle_is_identity() {
+    let mut raw = vec![0u8; RAW_DATA_OCTETS];
+    for seed in 0..CASES {
+        XorShift::new(seed).fill(&mut raw);
+        let mut u = Unstructured::new(&raw);
+        let pdus: Vec<Pdu> = (0..4)
+            .map(|_| u.arbitrary::<Pdu>())
+            .collect::<arbitrary::Result<_>>()
+            .expect("generating PDUs");
+
+        let mut bundle = BytesMut::new();
+        for pdu in &pdus {
+            bundle.extend_from_slice(&serialize(pdu));
+            let padding = (BUNDLE_ALIGNMENT - bundle.len() % BUNDLE_ALIGNMENT) % BUNDLE_ALIGNMENT;
+            bundle.extend_from_slice(&[0u8; BUNDLE_ALIGNMENT][..padding]);
+        }
+        let parsed = dis_rs::parse(&bundle)
+            .unwrap_or_else(|err| panic!("seed {seed}: parsing bundle failed: {err}"));
+
+        assert_eq!(parsed, pdus, "seed {seed}");
+    }
+}
@@ -0,0 +1,8 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+// Parsing arbitrary input must never panic; it either yields C-DIS PDUs or an error.
+fuzz_target!(|data: &[u8]| {
+    let _ = cdis_assemble::parse(data);
+});
@@ -0,0 +1,13 @@
+#![no_main]
+
+use bytes::BytesMut;
+use dis_rs::model::Pdu;
+use libfuzzer_sys::fuzz_target;
+
+// Any generated PDU must parse back to itself after serializing.
+fuzz_target!(|pdu: Pdu| {
+    let mut buf = BytesMut::with_capacity(pdu.pdu_length() as usize);
+    pdu.serialize(&mut buf).expect("serializing a generated PDU");
+    let parsed = dis_rs::parse(&buf).expect("parsing a serialized PDU");
+    assert_eq!(parsed, vec![pdu]);
+});